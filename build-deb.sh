@@ -199,7 +199,7 @@ Section: ${CATEGORY}
 Priority: optional
 Architecture: ${ARCHITECTURE}
 Installed-Size: ${installed_size}
-Depends: libc6, libfontconfig1
+Depends: libc6, libfontconfig1, libdbus-1-3
 Maintainer: ${MAINTAINER}
 Homepage: ${HOMEPAGE}
 Description: ${APP_DESCRIPTION}
@@ -173,6 +173,7 @@ Group:          Applications/System
 URL:            https://github.com/user/docker-ui-app
 BuildArch:      x86_64
 Requires:       docker
+Requires:       dbus-libs
 
 %description
 Uma aplicação de monitoramento Docker construída com Rust e Slint, 
@@ -0,0 +1,143 @@
+// Notas e tags locais de servidores e containers (~/.config/docker-ui/annotations.json)
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, path::PathBuf};
+
+use crate::config::AppConfig;
+
+// Cores disponíveis para tags (nome, RGB)
+const TAG_COLORS: &[(&str, (u8, u8, u8))] = &[
+    ("red", (0xef, 0x44, 0x44)),
+    ("amber", (0xf5, 0x9e, 0x0b)),
+    ("green", (0x10, 0xb9, 0x81)),
+    ("blue", (0x0e, 0xa5, 0xe9)),
+    ("purple", (0x8b, 0x5c, 0xf6)),
+    ("gray", (0x6b, 0x72, 0x80)),
+];
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Tag {
+    pub name: String,
+    pub color: String,
+}
+
+impl Tag {
+    // Cor desconhecida cai para cinza
+    pub fn rgb(&self) -> (u8, u8, u8) {
+        TAG_COLORS
+            .iter()
+            .find(|(name, _)| *name == self.color)
+            .map(|(_, rgb)| *rgb)
+            .unwrap_or((0x6b, 0x72, 0x80))
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Annotation {
+    pub note: String,
+    pub tags: Vec<Tag>,
+}
+
+impl Annotation {
+    pub fn is_empty(&self) -> bool {
+        self.note.trim().is_empty() && self.tags.is_empty()
+    }
+
+    // Adiciona ou recolore a tag com o mesmo nome
+    pub fn set_tag(&mut self, name: &str, color: &str) -> Result<()> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(anyhow::anyhow!("Informe o nome da tag"));
+        }
+        if name.chars().count() > 24 {
+            return Err(anyhow::anyhow!("Tag muito longa (máximo 24 caracteres)"));
+        }
+        if !TAG_COLORS.iter().any(|(known, _)| *known == color) {
+            return Err(anyhow::anyhow!("Cor de tag inválida: {}", color));
+        }
+
+        match self.tags.iter_mut().find(|tag| tag.name == name) {
+            Some(tag) => tag.color = color.to_string(),
+            None => self.tags.push(Tag {
+                name: name.to_string(),
+                color: color.to_string(),
+            }),
+        }
+        Ok(())
+    }
+
+    pub fn remove_tag(&mut self, name: &str) {
+        self.tags.retain(|tag| tag.name != name);
+    }
+}
+
+// O que está sendo anotado: um servidor (contexto) ou um container dele
+pub enum AnnotationTarget {
+    Server(String),
+    Container { server: String, id: String },
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Annotations {
+    // Chave: nome do contexto
+    servers: HashMap<String, Annotation>,
+    // Chave: "servidor/id"
+    containers: HashMap<String, Annotation>,
+}
+
+impl Annotations {
+    fn path() -> PathBuf {
+        AppConfig::path().with_file_name("annotations.json")
+    }
+
+    // Arquivo ausente ou inválido resulta em nenhuma anotação
+    pub fn load() -> Self {
+        std::fs::read_to_string(Self::path())
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = Self::path();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .context("Falha ao criar diretório de configuração")?;
+        }
+        let content =
+            serde_json::to_string_pretty(self).context("Falha ao serializar anotações")?;
+        std::fs::write(&path, content).context("Falha ao gravar anotações")
+    }
+
+    pub fn server(&self, name: &str) -> Option<&Annotation> {
+        self.servers.get(name)
+    }
+
+    pub fn container(&self, server: &str, id: &str) -> Option<&Annotation> {
+        self.containers.get(&format!("{}/{}", server, id))
+    }
+
+    // Altera a anotação do alvo e grava; anotações vazias são removidas
+    pub fn update(
+        &mut self,
+        target: &AnnotationTarget,
+        change: impl FnOnce(&mut Annotation) -> Result<()>,
+    ) -> Result<()> {
+        let (map, key) = match target {
+            AnnotationTarget::Server(name) => (&mut self.servers, name.clone()),
+            AnnotationTarget::Container { server, id } => {
+                (&mut self.containers, format!("{}/{}", server, id))
+            }
+        };
+
+        let annotation = map.entry(key.clone()).or_default();
+        let result = change(annotation);
+        if annotation.is_empty() {
+            map.remove(&key);
+        }
+        result?;
+        self.save()
+    }
+}
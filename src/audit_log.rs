@@ -0,0 +1,77 @@
+// Registro de auditoria das operações feitas pela aplicação nos servidores
+// (~/.config/docker-ui/audit.log, uma entrada JSON por linha)
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local, Utc};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::PathBuf;
+
+use crate::config::AppConfig;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub at: DateTime<Utc>,
+    pub server: String,
+    // Ex: "prune agendado", "prune"
+    pub action: String,
+    // Ex: "imagens sem uso: 1.2GB liberados"
+    pub detail: String,
+    pub success: bool,
+}
+
+impl AuditEntry {
+    pub fn new(server: &str, action: &str, detail: String, success: bool) -> Self {
+        Self {
+            at: Utc::now(),
+            server: server.to_string(),
+            action: action.to_string(),
+            detail,
+            success,
+        }
+    }
+
+    // Ex: "16/10 03:00 · prod · prune agendado · imagens sem uso: 1.2GB liberados"
+    pub fn label(&self) -> String {
+        format!(
+            "{} · {} · {} · {}{}",
+            self.at.with_timezone(&Local).format("%d/%m %H:%M"),
+            self.server,
+            self.action,
+            if self.success { "" } else { "falha: " },
+            self.detail
+        )
+    }
+}
+
+fn path() -> PathBuf {
+    AppConfig::path().with_file_name("audit.log")
+}
+
+// Acrescenta ao fim do arquivo; entradas antigas nunca são reescritas
+pub fn append(entry: &AuditEntry) -> Result<()> {
+    let path = path();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).context("Falha ao criar diretório de configuração")?;
+    }
+    let mut line = serde_json::to_string(entry).context("Falha ao serializar auditoria")?;
+    line.push('\n');
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut file| file.write_all(line.as_bytes()))
+        .context("Falha ao gravar o registro de auditoria")
+}
+
+// Últimas entradas, da mais recente para a mais antiga; linhas inválidas são ignoradas
+pub fn recent(limit: usize) -> Vec<AuditEntry> {
+    let Ok(content) = std::fs::read_to_string(path()) else {
+        return Vec::new();
+    };
+    content
+        .lines()
+        .rev()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .take(limit)
+        .collect()
+}
@@ -0,0 +1,97 @@
+// Modo de economia de banda por servidor (conexões LTE/VPN com tráfego medido) e
+// modo de movimento reduzido (acessibilidade / área de trabalho remota), que também
+// diminui a frequência das atualizações. Guarda ainda a tela visível: as listas de
+// recursos só se atualizam sozinhas enquanto estão na tela
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, AtomicI32, Ordering},
+};
+use std::time::{Duration, Instant};
+
+// Intervalo mínimo entre redesenhos dos gráficos com movimento reduzido
+const REDUCED_MOTION_REDRAW: Duration = Duration::from_secs(30);
+
+// Estado do servidor ativo, compartilhado entre os timers de atualização
+#[derive(Clone, Default)]
+pub struct BandwidthMode {
+    low: Arc<AtomicBool>,
+    reduced_motion: Arc<AtomicBool>,
+    // Índice de `current-screen` no Slint
+    visible_screen: Arc<AtomicI32>,
+}
+
+impl BandwidthMode {
+    pub fn is_low(&self) -> bool {
+        self.low.load(Ordering::Relaxed)
+    }
+
+    pub fn set_low(&self, low: bool) {
+        self.low.store(low, Ordering::Relaxed);
+    }
+
+    pub fn is_reduced_motion(&self) -> bool {
+        self.reduced_motion.load(Ordering::Relaxed)
+    }
+
+    pub fn set_reduced_motion(&self, reduced: bool) {
+        self.reduced_motion.store(reduced, Ordering::Relaxed);
+    }
+
+    pub fn set_visible_screen(&self, screen: i32) {
+        self.visible_screen.store(screen, Ordering::Relaxed);
+    }
+
+    // Alguma das telas que exibem o recurso está aberta
+    pub fn is_visible(&self, screens: &[i32]) -> bool {
+        screens.contains(&self.visible_screen.load(Ordering::Relaxed))
+    }
+
+    // Qualquer um dos modos espaça os ticks dos timers
+    pub fn is_throttled(&self) -> bool {
+        self.is_low() || self.is_reduced_motion()
+    }
+}
+
+// Conta os ticks de um timer e, no modo econômico, libera só um a cada `low_every`
+pub struct TickGate {
+    low_every: u32,
+    ticks: u32,
+}
+
+impl TickGate {
+    pub fn new(low_every: u32) -> Self {
+        Self {
+            low_every: low_every.max(1),
+            ticks: 0,
+        }
+    }
+
+    pub fn should_run(&mut self, mode: &BandwidthMode) -> bool {
+        if !mode.is_throttled() {
+            self.ticks = 0;
+            return true;
+        }
+        // O primeiro tick após ativar o modo sempre roda
+        let run = self.ticks % self.low_every == 0;
+        self.ticks = self.ticks.wrapping_add(1);
+        run
+    }
+}
+
+// Com movimento reduzido, os gráficos continuam coletando pontos mas só são
+// redesenhados de tempos em tempos, em vez de a cada amostra
+#[derive(Default)]
+pub struct RedrawGate {
+    last: Option<Instant>,
+}
+
+impl RedrawGate {
+    pub fn should_redraw(&mut self, mode: &BandwidthMode) -> bool {
+        let due = !mode.is_reduced_motion()
+            || self.last.is_none_or(|last| last.elapsed() >= REDUCED_MOTION_REDRAW);
+        if due {
+            self.last = Some(Instant::now());
+        }
+        due
+    }
+}
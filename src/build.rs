@@ -0,0 +1,253 @@
+// Build de imagens com docker buildx (multi-arquitetura)
+use anyhow::{Context, Result};
+use std::process::{Command, Stdio};
+use tokio::io::{AsyncBufReadExt, BufReader};
+
+// Plataformas oferecidas na tela de build
+pub const SUPPORTED_PLATFORMS: [&str; 2] = ["linux/amd64", "linux/arm64"];
+
+// Prefixo dos builders docker-container criados pelo app, um por servidor
+const BUILDER_PREFIX: &str = "docker-ui-";
+
+// Parâmetros de um build
+#[derive(Debug, Clone)]
+pub struct BuildRequest {
+    pub context_dir: String,
+    pub dockerfile: String,
+    pub tag: String,
+    pub platforms: Vec<String>,
+    pub push: bool,
+}
+
+impl BuildRequest {
+    // O driver padrão do buildx ("docker") não gera imagens para várias plataformas
+    pub fn is_multi_platform(&self) -> bool {
+        self.platforms.len() > 1
+    }
+
+    // Argumentos do docker buildx build; `builder` escolhe um builder que não é o atual
+    pub fn args(&self, builder: Option<&str>) -> Vec<String> {
+        let mut args = vec!["buildx".to_string(), "build".to_string()];
+        if let Some(builder) = builder {
+            args.push("--builder".to_string());
+            args.push(builder.to_string());
+        }
+        args.extend([
+            "--progress=plain".to_string(),
+            "--platform".to_string(),
+            self.platforms.join(","),
+        ]);
+
+        if !self.tag.trim().is_empty() {
+            args.push("-t".to_string());
+            args.push(self.tag.trim().to_string());
+        }
+        if !self.dockerfile.trim().is_empty() {
+            args.push("-f".to_string());
+            args.push(self.dockerfile.trim().to_string());
+        }
+
+        // Manifest multi-arch só pode ir para um registry; uma plataforma pode ser carregada localmente
+        if self.push {
+            args.push("--push".to_string());
+        } else if self.platforms.len() == 1 {
+            args.push("--load".to_string());
+        }
+
+        args.push(self.context_dir.trim().to_string());
+        args
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if self.context_dir.trim().is_empty() {
+            return Err(anyhow::anyhow!("Diretório de contexto é obrigatório"));
+        }
+        if self.platforms.is_empty() {
+            return Err(anyhow::anyhow!("Selecione ao menos uma plataforma"));
+        }
+        if self.push && self.tag.trim().is_empty() {
+            return Err(anyhow::anyhow!("Informe a tag (registry/imagem:tag) para enviar o manifest"));
+        }
+        Ok(())
+    }
+}
+
+// Nome do builder docker-container do servidor, ex: "docker-ui-prod-1"
+pub fn builder_name(server: &str) -> String {
+    let server: String = server
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    format!("{}{}", BUILDER_PREFIX, server)
+}
+
+// Comandos que preparam o builder docker-container de um servidor
+pub struct BuilderSetup {
+    pub name: String,
+    // DOCKER_HOST do daemon; muda a cada conexão por túnel SSH
+    pub endpoint: String,
+    pub inspect: Command,
+    pub remove: Command,
+    pub create: Command,
+}
+
+impl BuilderSetup {
+    // Reaproveita o builder se ele já aponta para o daemon; senão o recria
+    pub async fn ensure(self) -> Result<()> {
+        let inspect = tokio::process::Command::from(self.inspect)
+            .output()
+            .await
+            .context("Falha ao executar docker buildx inspect")?;
+        let inspected = String::from_utf8_lossy(&inspect.stdout);
+        if inspect.status.success() && builder_endpoint(&inspected) == Some(self.endpoint.as_str()) {
+            return Ok(());
+        }
+
+        // Builder de uma conexão anterior: o nome é do app, pode ser recriado
+        if inspect.status.success() {
+            let _ = tokio::process::Command::from(self.remove).output().await;
+        }
+
+        let create = tokio::process::Command::from(self.create)
+            .output()
+            .await
+            .context("Falha ao executar docker buildx create")?;
+        if !create.status.success() {
+            return Err(anyhow::anyhow!(
+                "Build multiplataforma requer um builder buildx com driver docker-container; \
+                 não foi possível criar '{}': {}",
+                self.name,
+                String::from_utf8_lossy(&create.stderr).trim()
+            ));
+        }
+        Ok(())
+    }
+}
+
+// Endpoint do primeiro nó na saída do docker buildx inspect
+fn builder_endpoint(inspect: &str) -> Option<&str> {
+    inspect
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("Endpoint:"))
+        .map(str::trim)
+}
+
+// Progresso de uma plataforma
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlatformProgress {
+    pub platform: String,
+    pub step: u32,
+    pub total: u32,
+    pub status: String,
+}
+
+// Acompanha o progresso por plataforma a partir da saída --progress=plain
+#[derive(Debug, Default)]
+pub struct BuildProgress {
+    platforms: Vec<PlatformProgress>,
+}
+
+impl BuildProgress {
+    pub fn new(platforms: &[String]) -> Self {
+        Self {
+            platforms: platforms
+                .iter()
+                .map(|platform| PlatformProgress {
+                    platform: platform.clone(),
+                    step: 0,
+                    total: 0,
+                    status: "Aguardando".to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    pub fn platforms(&self) -> &[PlatformProgress] {
+        &self.platforms
+    }
+
+    // Processa uma linha como "#7 [linux/arm64 2/5] RUN apt-get update"; retorna true se mudou
+    pub fn update(&mut self, line: &str) -> bool {
+        let Some(start) = line.find('[') else {
+            return false;
+        };
+        let Some(end) = line[start..].find(']') else {
+            return false;
+        };
+        let inside = &line[start + 1..start + end];
+        let instruction = line[start + end + 1..].trim();
+
+        let mut parts = inside.split_whitespace();
+        let (Some(platform), Some(steps)) = (parts.next(), parts.next()) else {
+            return false;
+        };
+        let Some((step, total)) = steps.split_once('/') else {
+            return false;
+        };
+        let (Ok(step), Ok(total)) = (step.parse::<u32>(), total.parse::<u32>()) else {
+            return false;
+        };
+
+        let Some(progress) = self.platforms.iter_mut().find(|p| p.platform == platform) else {
+            return false;
+        };
+        if step < progress.step {
+            return false;
+        }
+
+        progress.step = step;
+        progress.total = total;
+        progress.status = instruction.to_string();
+        true
+    }
+
+    // Marca todas as plataformas como concluídas ou com falha
+    pub fn finish(&mut self, success: bool) {
+        for progress in &mut self.platforms {
+            if success {
+                progress.step = progress.total.max(progress.step);
+                progress.status = "Concluído".to_string();
+            } else if progress.step < progress.total || progress.total == 0 {
+                progress.status = "Falhou".to_string();
+            }
+        }
+    }
+}
+
+// Executa o build chamando `on_line` para cada linha de saída
+pub async fn run_build(command: Command, mut on_line: impl FnMut(&str)) -> Result<()> {
+    let mut command = tokio::process::Command::from(command);
+    // Só o stderr é lido: um stdout em pipe sem leitor travaria o buildx ao encher o buffer
+    command.stdout(Stdio::null()).stderr(Stdio::piped());
+
+    let mut child = command
+        .spawn()
+        .context("Failed to execute docker buildx build command")?;
+
+    // buildx escreve o progresso no stderr
+    let stderr = child.stderr.take().context("Saída do buildx indisponível")?;
+    let mut lines = BufReader::new(stderr).lines();
+    let mut last_error = String::new();
+    while let Some(line) = lines.next_line().await? {
+        if line.contains("ERROR") || line.starts_with("error:") {
+            last_error = line.clone();
+        }
+        on_line(&line);
+    }
+
+    let status = child.wait().await.context("Falha ao aguardar o buildx")?;
+    if !status.success() {
+        return Err(anyhow::anyhow!(
+            "Build falhou: {}",
+            if last_error.is_empty() { status.to_string() } else { last_error }
+        ));
+    }
+
+    Ok(())
+}
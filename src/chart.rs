@@ -2,6 +2,8 @@
 use plotters::prelude::*;
 use slint::{Image, Rgb8Pixel, SharedPixelBuffer};
 
+use crate::deploy_history::DeploySeries;
+
 // Renderizador de gráficos com configurações customizáveis
 pub struct ChartRenderer {
     width: u32,
@@ -147,4 +149,146 @@ impl ChartRenderer {
             SharedPixelBuffer::<Rgb8Pixel>::clone_from_slice(&buffer, self.width, self.height);
         Image::from_rgb8(shared_buffer)
     }
+
+    // Renderiza o deploy atual com o marcador do recreate em x = 0 e o deploy anterior em
+    // cinza: o final dele antes do marcador e, sobreposto, o início dele na mesma idade
+    pub fn render_deploy_chart(&self, series: &DeploySeries, max_percentage: f32) -> Image {
+        let mut buffer = vec![0u8; (self.width * self.height * 3) as usize];
+
+        {
+            let backend = BitMapBackend::with_buffer(&mut buffer, (self.width, self.height))
+                .into_drawing_area();
+
+            backend.fill(&RGBColor(46, 48, 48)).unwrap(); // #2e3030
+
+            let all = || {
+                series
+                    .current
+                    .iter()
+                    .chain(&series.before)
+                    .chain(&series.overlay)
+            };
+            if all().next().is_some() {
+                let x_min = all().map(|(x, _)| *x).fold(0.0f32, f32::min);
+                let x_max = all().map(|(x, _)| *x).fold(1.0f32, f32::max);
+                let max_value = all().map(|(_, y)| *y).fold(0.0f32, f32::max);
+                // Próximo múltiplo de 10 acima do maior valor, como no gráfico ao vivo
+                let y_max = ((max_value / 10.0).ceil() * 10.0).clamp(5.0, max_percentage.max(5.0));
+
+                let mut chart = ChartBuilder::on(&backend)
+                    .margin(5)
+                    .x_label_area_size(20)
+                    .y_label_area_size(40)
+                    .build_cartesian_2d(x_min..x_max, 0f32..y_max)
+                    .unwrap();
+
+                chart
+                    .configure_mesh()
+                    .x_desc("")
+                    .y_desc("Valor %")
+                    .x_label_formatter(&|x| format!("{:+.0}min", x))
+                    .axis_style(&RGBColor(107, 114, 128)) // #6b7280 - gray
+                    .bold_line_style(&RGBColor(107, 114, 128).mix(0.3))
+                    .light_line_style(&RGBColor(107, 114, 128).mix(0.1))
+                    .label_style(("sans-serif", 12).into_font().color(&WHITE))
+                    .y_max_light_lines(4)
+                    .x_max_light_lines(6)
+                    .draw()
+                    .unwrap();
+
+                // Deploy anterior em cinza
+                let previous_color = RGBColor(156, 163, 175); // #9ca3af
+                for points in [&series.before, &series.overlay] {
+                    chart
+                        .draw_series(LineSeries::new(
+                            points.iter().copied(),
+                            previous_color.mix(0.8).stroke_width(1),
+                        ))
+                        .unwrap();
+                }
+
+                // Marcador do recreate
+                chart
+                    .draw_series(LineSeries::new(
+                        [(0.0, 0.0), (0.0, y_max)],
+                        RGBColor(245, 158, 11).stroke_width(2), // #f59e0b
+                    ))
+                    .unwrap();
+
+                let line_color =
+                    RGBColor(self.line_color[0], self.line_color[1], self.line_color[2]);
+                chart
+                    .draw_series(LineSeries::new(
+                        series.current.iter().copied(),
+                        line_color.stroke_width(2),
+                    ))
+                    .unwrap();
+            }
+
+            backend.present().unwrap();
+        }
+
+        let shared_buffer =
+            SharedPixelBuffer::<Rgb8Pixel>::clone_from_slice(&buffer, self.width, self.height);
+        Image::from_rgb8(shared_buffer)
+    }
+
+    // Renderiza sparkline compacta (sem eixos) para cards do dashboard
+    pub fn render_sparkline(&self, values: &[f32]) -> Image {
+        let mut buffer = vec![0u8; (self.width * self.height * 3) as usize];
+
+        {
+            let backend = BitMapBackend::with_buffer(&mut buffer, (self.width, self.height))
+                .into_drawing_area();
+
+            // Mesmo fundo do StatCard
+            backend.fill(&RGBColor(46, 48, 48)).unwrap(); // #2e3030
+
+            if values.len() > 1 {
+                let max_value = values.iter().cloned().fold(0.0f32, f32::max);
+                // Margem para a linha não encostar nas bordas
+                let y_max = (max_value * 1.2).max(1.0);
+
+                let mut chart = ChartBuilder::on(&backend)
+                    .margin(2)
+                    .build_cartesian_2d(0f32..(values.len() - 1) as f32, 0f32..y_max)
+                    .unwrap();
+
+                let line_color =
+                    RGBColor(self.line_color[0], self.line_color[1], self.line_color[2]);
+
+                chart
+                    .draw_series(LineSeries::new(
+                        values.iter().enumerate().map(|(i, v)| (i as f32, *v)),
+                        line_color.stroke_width(2),
+                    ))
+                    .unwrap();
+            }
+
+            backend.present().unwrap();
+        }
+
+        let shared_buffer =
+            SharedPixelBuffer::<Rgb8Pixel>::clone_from_slice(&buffer, self.width, self.height);
+        Image::from_rgb8(shared_buffer)
+    }
+}
+
+// Alternativa em texto de uma série para leitores de tela, ex:
+// "mínimo 2.1%, média 12.4%, máximo 40.0%, último 8.3% (60 amostras)"
+pub fn describe(values: &[f32], unit: &str) -> String {
+    let Some(last) = values.last() else {
+        return "Sem dados ainda".to_string();
+    };
+    let min = values.iter().cloned().fold(f32::INFINITY, f32::min);
+    let max = values.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+    let average = values.iter().sum::<f32>() / values.len() as f32;
+    format!(
+        "mínimo {:.1}{unit}, média {:.1}{unit}, máximo {:.1}{unit}, último {:.1}{unit} ({} amostras)",
+        min,
+        average,
+        max,
+        last,
+        values.len(),
+    )
 }
@@ -0,0 +1,562 @@
+// Stacks do Docker Compose (docker compose ls / ps)
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::PathBuf,
+};
+
+use crate::config::AppConfig;
+
+// Stack implantada
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComposeStack {
+    pub name: String,
+    pub status: String,
+    pub config_files: String,
+    // Arquivos nesta máquina (stack de arquivo): o compose roda sempre com o CLI local
+    pub local_files: bool,
+}
+
+impl ComposeStack {
+    // Argumentos -p/-f para recriar a stack a partir dos arquivos originais (após o
+    // `docker compose` ou `docker-compose`)
+    pub fn project_args(&self) -> Vec<String> {
+        let mut args = vec!["-p".to_string(), self.name.clone()];
+        for file in self.config_files.split(',').map(str::trim).filter(|f| !f.is_empty()) {
+            args.push("-f".to_string());
+            args.push(file.to_string());
+        }
+        args
+    }
+}
+
+// Stack ainda não implantada a partir de um arquivo compose local
+pub fn file_stack(path: &str, project: &str) -> Result<ComposeStack> {
+    // Caminhos colados de gerenciadores de arquivos podem vir com aspas ou file://
+    let path = path.trim().trim_matches(|c| c == '"' || c == '\'');
+    let path = path.strip_prefix("file://").unwrap_or(path);
+    if path.is_empty() {
+        return Err(anyhow::anyhow!("Informe o caminho do arquivo compose"));
+    }
+
+    let file = std::fs::canonicalize(path)
+        .with_context(|| format!("Arquivo '{}' não encontrado", path))?;
+    if !file.is_file() {
+        return Err(anyhow::anyhow!("'{}' não é um arquivo", path));
+    }
+    let is_yaml = file
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("yml") || ext.eq_ignore_ascii_case("yaml"));
+    if !is_yaml {
+        return Err(anyhow::anyhow!("O arquivo compose deve ser .yml ou .yaml"));
+    }
+
+    // Sem nome informado, usa o diretório do arquivo (mesmo padrão do compose)
+    let project = if project.trim().is_empty() {
+        file.parent()
+            .and_then(|dir| dir.file_name())
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default()
+    } else {
+        project.trim().to_string()
+    };
+    let project: String = project
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+        .collect();
+    if project.is_empty() {
+        return Err(anyhow::anyhow!("Nome de projeto inválido"));
+    }
+
+    Ok(ComposeStack {
+        name: project,
+        status: String::new(),
+        config_files: file.to_string_lossy().to_string(),
+        local_files: true,
+    })
+}
+
+// Container de um serviço, como retornado por `compose ps`
+#[derive(Debug, Clone, Deserialize)]
+pub struct ComposeContainer {
+    #[serde(rename = "Service", default)]
+    pub service: String,
+    #[serde(rename = "State", default)]
+    pub state: String,
+    #[serde(rename = "Health", default)]
+    pub health: String,
+}
+
+// Nível de convergência de uma célula da matriz
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HealthLevel {
+    Green,
+    Yellow,
+    Red,
+}
+
+impl HealthLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HealthLevel::Green => "green",
+            HealthLevel::Yellow => "yellow",
+            HealthLevel::Red => "red",
+        }
+    }
+}
+
+// Linha da matriz serviço × estado × health
+#[derive(Debug, Clone)]
+pub struct ServiceHealth {
+    pub service: String,
+    pub running: u32,
+    pub starting: u32,
+    pub stopped: u32,
+    pub healthy: u32,
+    pub unhealthy: u32,
+    pub health_starting: u32,
+}
+
+impl ServiceHealth {
+    // Cor da coluna de estado
+    pub fn state_level(&self) -> HealthLevel {
+        if self.stopped > 0 && self.running == 0 {
+            HealthLevel::Red
+        } else if self.stopped > 0 || self.starting > 0 {
+            HealthLevel::Yellow
+        } else {
+            HealthLevel::Green
+        }
+    }
+
+    // Cor da coluna de health (serviços sem healthcheck contam como verdes)
+    pub fn health_level(&self) -> HealthLevel {
+        if self.unhealthy > 0 {
+            HealthLevel::Red
+        } else if self.health_starting > 0 {
+            HealthLevel::Yellow
+        } else {
+            HealthLevel::Green
+        }
+    }
+
+    pub fn level(&self) -> HealthLevel {
+        self.state_level().max(self.health_level())
+    }
+
+    pub fn health_label(&self) -> String {
+        if self.healthy + self.unhealthy + self.health_starting == 0 {
+            "sem healthcheck".to_string()
+        } else if self.unhealthy > 0 {
+            format!("{} unhealthy", self.unhealthy)
+        } else if self.health_starting > 0 {
+            format!("{} iniciando", self.health_starting)
+        } else {
+            format!("{} healthy", self.healthy)
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ComposeLsEntry {
+    #[serde(rename = "Name", default)]
+    name: String,
+    #[serde(rename = "Status", default)]
+    status: String,
+    #[serde(rename = "ConfigFiles", default)]
+    config_files: String,
+}
+
+// Interpreta a saída de `docker compose ls --format json`
+pub fn parse_stacks(output: &str) -> Result<Vec<ComposeStack>> {
+    let entries: Vec<ComposeLsEntry> = parse_json_list(output)?;
+    let mut stacks: Vec<ComposeStack> = entries
+        .into_iter()
+        .map(|entry| ComposeStack {
+            name: entry.name,
+            status: entry.status,
+            config_files: entry.config_files,
+            local_files: false,
+        })
+        .collect();
+    stacks.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(stacks)
+}
+
+// Interpreta a saída de `docker compose ps --format json`
+pub fn parse_containers(output: &str) -> Result<Vec<ComposeContainer>> {
+    parse_json_list(output)
+}
+
+// Formatos do `docker ps` usados quando o compose não lista em JSON (v1 ou ausente): os
+// labels gravados pelo compose nos containers bastam para listar stacks e serviços
+pub const PS_STACKS_FORMAT: &str = "{{.Label \"com.docker.compose.project\"}}\t{{.State}}\t{{.Label \"com.docker.compose.project.config_files\"}}";
+pub const PS_SERVICES_FORMAT: &str =
+    "{{.Label \"com.docker.compose.service\"}}\t{{.State}}\t{{.Status}}";
+
+// Stacks a partir do `docker ps` com PS_STACKS_FORMAT, com o status no formato do
+// `compose ls`, ex: "running(2), exited(1)"
+pub fn parse_stacks_from_ps(output: &str) -> Vec<ComposeStack> {
+    let mut projects: BTreeMap<String, (BTreeMap<String, u32>, String)> = BTreeMap::new();
+    for line in output.lines() {
+        let mut fields = line.split('\t');
+        let (Some(name), Some(state)) = (fields.next(), fields.next()) else {
+            continue;
+        };
+        if name.trim().is_empty() {
+            continue;
+        }
+        let (states, config_files) = projects.entry(name.trim().to_string()).or_default();
+        *states.entry(state.trim().to_string()).or_default() += 1;
+        if config_files.is_empty() {
+            *config_files = fields.next().unwrap_or_default().trim().to_string();
+        }
+    }
+
+    projects
+        .into_iter()
+        .map(|(name, (states, config_files))| ComposeStack {
+            name,
+            status: states
+                .iter()
+                .map(|(state, count)| format!("{}({})", state, count))
+                .collect::<Vec<_>>()
+                .join(", "),
+            config_files,
+            local_files: false,
+        })
+        .collect()
+}
+
+// Containers da stack a partir do `docker ps` com PS_SERVICES_FORMAT; o health vem do
+// status, ex: "Up 2 hours (healthy)"
+pub fn parse_containers_from_ps(output: &str) -> Vec<ComposeContainer> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split('\t');
+            let service = fields.next()?.trim();
+            let state = fields.next()?.trim();
+            let status = fields.next().unwrap_or_default();
+            let health = if status.contains("(healthy)") {
+                "healthy"
+            } else if status.contains("(unhealthy)") {
+                "unhealthy"
+            } else if status.contains("(health: starting)") {
+                "starting"
+            } else {
+                ""
+            };
+            Some(ComposeContainer {
+                service: service.to_string(),
+                state: state.to_string(),
+                health: health.to_string(),
+            })
+        })
+        .collect()
+}
+
+// `config --images` não existe no v1: imagens lidas do `config` normalizado
+pub fn config_images(config: &str) -> Vec<String> {
+    config
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("image:"))
+        .map(|image| {
+            image
+                .trim()
+                .trim_matches(|c| c == '"' || c == '\'')
+                .to_string()
+        })
+        .filter(|image| !image.is_empty())
+        .collect()
+}
+
+// Binário do Compose usado nos comandos das stacks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComposeBinary {
+    // Plugin do CLI (`docker compose`)
+    Plugin,
+    // Executável separado (`docker-compose`, v1 em Python ou v2 standalone)
+    Standalone,
+}
+
+impl ComposeBinary {
+    // Programa e argumentos que antecedem o subcomando
+    pub fn program(self) -> (&'static str, &'static [&'static str]) {
+        match self {
+            ComposeBinary::Plugin => ("docker", &["compose"]),
+            ComposeBinary::Standalone => ("docker-compose", &[]),
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ComposeBinary::Plugin => "docker compose",
+            ComposeBinary::Standalone => "docker-compose",
+        }
+    }
+}
+
+// Compose detectado para o servidor
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComposeCli {
+    pub binary: ComposeBinary,
+    // Ex: "2.27.0" ou "1.29.2"
+    pub version: String,
+    // Executado no host do servidor via SSH em vez do CLI local
+    pub on_host: bool,
+}
+
+impl ComposeCli {
+    // v1 não tem `ls`, `ps --format json`, `config --images` nem `up --pull`
+    pub fn is_legacy(&self) -> bool {
+        self.version
+            .split('.')
+            .next()
+            .and_then(|major| major.parse::<u32>().ok())
+            .is_some_and(|major| major < 2)
+    }
+
+    // Ex: "docker compose 2.27.0 no servidor (SSH)" ou "docker-compose 1.29.2 local (legado)"
+    pub fn summary(&self) -> String {
+        format!(
+            "{} {} {}{}",
+            self.binary.label(),
+            self.version,
+            if self.on_host {
+                "no servidor (SSH)"
+            } else {
+                "local"
+            },
+            if self.is_legacy() { " (legado)" } else { "" }
+        )
+    }
+}
+
+// Saída de `version --short`: "2.27.0", "v2.27.0" ou "1.29.2"; sem --short, a primeira
+// palavra com número, ex: "docker-compose version 1.29.2, build 5becea4c"
+pub fn parse_version(output: &str) -> Option<String> {
+    let line = output
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())?;
+    line.split(|c: char| c.is_whitespace() || c == ',')
+        .map(|word| word.trim_start_matches('v'))
+        .find(|word| word.starts_with(|c: char| c.is_ascii_digit()))
+        .map(str::to_string)
+}
+
+// Versões antigas do compose retornam um array; as novas, um objeto por linha
+fn parse_json_list<T: for<'de> Deserialize<'de>>(output: &str) -> Result<Vec<T>> {
+    let trimmed = output.trim();
+    if trimmed.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    if trimmed.starts_with('[') {
+        return serde_json::from_str(trimmed).context("Resposta inválida do docker compose");
+    }
+
+    trimmed
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(line).context("Resposta inválida do docker compose"))
+        .collect()
+}
+
+// Agrupa containers por serviço para montar a matriz
+pub fn service_matrix(containers: &[ComposeContainer]) -> Vec<ServiceHealth> {
+    let mut services: Vec<ServiceHealth> = Vec::new();
+
+    for container in containers {
+        let index = match services.iter().position(|s| s.service == container.service) {
+            Some(index) => index,
+            None => {
+                services.push(ServiceHealth {
+                    service: container.service.clone(),
+                    running: 0,
+                    starting: 0,
+                    stopped: 0,
+                    healthy: 0,
+                    unhealthy: 0,
+                    health_starting: 0,
+                });
+                services.len() - 1
+            }
+        };
+        let service = &mut services[index];
+
+        match container.state.as_str() {
+            "running" => service.running += 1,
+            "restarting" | "created" | "paused" | "removing" => service.starting += 1,
+            _ => service.stopped += 1,
+        }
+
+        match container.health.as_str() {
+            "healthy" => service.healthy += 1,
+            "unhealthy" => service.unhealthy += 1,
+            "starting" => service.health_starting += 1,
+            _ => {}
+        }
+    }
+
+    services.sort_by(|a, b| a.service.cmp(&b.service));
+    services
+}
+
+// Ação que um redeploy aplicará a um serviço
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeAction {
+    Create,
+    Recreate,
+    Remove,
+    Unchanged,
+}
+
+impl ChangeAction {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ChangeAction::Create => "criar",
+            ChangeAction::Recreate => "recriar",
+            ChangeAction::Remove => "remover",
+            ChangeAction::Unchanged => "sem mudanças",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct StackChange {
+    pub service: String,
+    pub action: ChangeAction,
+}
+
+// Prévia do que um redeploy vai alterar
+#[derive(Debug, Clone, Default)]
+pub struct StackDiff {
+    pub changes: Vec<StackChange>,
+    pub images_to_pull: Vec<String>,
+    // Linhas alteradas do `compose config` desde o último deploy feito pela aplicação
+    pub config_diff: Vec<String>,
+    pub has_snapshot: bool,
+}
+
+impl StackDiff {
+    pub fn has_changes(&self) -> bool {
+        !self.images_to_pull.is_empty()
+            || !self.config_diff.is_empty()
+            || self
+                .changes
+                .iter()
+                .any(|change| change.action != ChangeAction::Unchanged)
+    }
+}
+
+// Interpreta `docker compose config --hash '*'` (linhas "serviço hash")
+pub fn parse_config_hashes(output: &str) -> HashMap<String, String> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            Some((parts.next()?.to_string(), parts.next()?.to_string()))
+        })
+        .collect()
+}
+
+// Compara hashes desejados (arquivos) com os dos containers em execução
+pub fn plan_changes(
+    desired: &HashMap<String, String>,
+    current: &HashMap<String, String>,
+) -> Vec<StackChange> {
+    let mut changes: Vec<StackChange> = desired
+        .iter()
+        .map(|(service, hash)| StackChange {
+            service: service.clone(),
+            action: match current.get(service) {
+                None => ChangeAction::Create,
+                Some(current_hash) if current_hash != hash => ChangeAction::Recreate,
+                Some(_) => ChangeAction::Unchanged,
+            },
+        })
+        .collect();
+
+    changes.extend(
+        current
+            .keys()
+            .filter(|service| !desired.contains_key(*service))
+            .map(|service| StackChange {
+                service: service.clone(),
+                action: ChangeAction::Remove,
+            }),
+    );
+
+    changes.sort_by(|a, b| a.service.cmp(&b.service));
+    changes
+}
+
+// Diff por linhas (LCS) retornando apenas linhas removidas "- " e adicionadas "+ "
+pub fn line_diff(old: &str, new: &str) -> Vec<String> {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+
+    // Arquivos muito grandes: evita matriz O(n*m) e informa só o tamanho
+    if old_lines.len() * new_lines.len() > 4_000_000 {
+        return vec![format!(
+            "~ configuração alterada ({} -> {} linhas)",
+            old_lines.len(),
+            new_lines.len()
+        )];
+    }
+
+    let n = old_lines.len();
+    let m = new_lines.len();
+    let mut lcs = vec![vec![0u32; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if old_lines[i] == new_lines[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if old_lines[i] == new_lines[j] {
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            diff.push(format!("- {}", old_lines[i]));
+            i += 1;
+        } else {
+            diff.push(format!("+ {}", new_lines[j]));
+            j += 1;
+        }
+    }
+    diff.extend(old_lines[i..].iter().map(|line| format!("- {}", line)));
+    diff.extend(new_lines[j..].iter().map(|line| format!("+ {}", line)));
+    diff
+}
+
+// Arquivo com o `compose config` do último deploy feito pela aplicação
+fn snapshot_path(context_name: &str, project: &str) -> PathBuf {
+    AppConfig::path()
+        .with_file_name("stacks")
+        .join(format!("{}-{}.yml", context_name, project))
+}
+
+pub fn load_snapshot(context_name: &str, project: &str) -> Option<String> {
+    std::fs::read_to_string(snapshot_path(context_name, project)).ok()
+}
+
+pub fn save_snapshot(context_name: &str, project: &str, config: &str) -> Result<()> {
+    let path = snapshot_path(context_name, project);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).context("Falha ao criar diretório de snapshots")?;
+    }
+    std::fs::write(path, config).context("Falha ao gravar snapshot da stack")
+}
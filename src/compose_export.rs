@@ -0,0 +1,294 @@
+// Exporta containers avulsos (dados do docker inspect) como docker-compose.yml
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::{collections::BTreeMap, path::Path};
+
+// Redes padrão do Docker, que não entram na seção "networks"
+const DEFAULT_NETWORKS: &[&str] = &["bridge", "host", "none"];
+
+// Container inspecionado e, se disponível, a imagem dele (para omitir valores herdados)
+pub struct ExportSource {
+    pub container: Value,
+    pub image: Option<Value>,
+}
+
+fn strings(value: &Value) -> Vec<String> {
+    value
+        .as_array()
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| item.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn text<'a>(value: &'a Value, pointer: &str) -> &'a str {
+    value.pointer(pointer).and_then(Value::as_str).unwrap_or_default()
+}
+
+// Escalar YAML seguro: strings JSON com aspas duplas também são YAML válido
+fn quote(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| "\"\"".to_string())
+}
+
+// Nome de serviço aceito pelo compose: minúsculas, dígitos, "-" e "_"
+fn service_name(container_name: &str) -> String {
+    let name: String = container_name
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '-' })
+        .collect();
+    let name = name.trim_matches('-').to_string();
+    if name.is_empty() { "service".to_string() } else { name }
+}
+
+// Seções de um serviço, já na ordem em que serão escritas
+#[derive(Default)]
+struct Service {
+    name: String,
+    scalars: Vec<(&'static str, String)>,
+    lists: Vec<(&'static str, Vec<String>)>,
+    maps: Vec<(&'static str, BTreeMap<String, String>)>,
+}
+
+fn to_service(source: &ExportSource) -> Service {
+    let container = &source.container;
+    let image = source.image.as_ref().unwrap_or(&Value::Null);
+    let container_name = text(container, "/Name").trim_start_matches('/');
+    let mut service = Service {
+        name: service_name(container_name),
+        ..Default::default()
+    };
+
+    service.scalars.push(("image", quote(text(container, "/Config/Image"))));
+    service.scalars.push(("container_name", quote(container_name)));
+
+    // Valores iguais aos da imagem são herdados e ficam de fora
+    let inherited = |field: &str| {
+        let pointer = format!("/Config/{}", field);
+        container.pointer(&pointer).filter(|value| {
+            !value.is_null() && image.pointer(&pointer) != Some(*value)
+        })
+    };
+    if let Some(entrypoint) = inherited("Entrypoint") {
+        service.lists.push(("entrypoint", strings(entrypoint).iter().map(|s| quote(s)).collect()));
+    }
+    if let Some(command) = inherited("Cmd") {
+        service.lists.push(("command", strings(command).iter().map(|s| quote(s)).collect()));
+    }
+    for (field, key) in [("WorkingDir", "working_dir"), ("User", "user")] {
+        if let Some(value) = inherited(field).and_then(Value::as_str).filter(|v| !v.is_empty()) {
+            service.scalars.push((key, quote(value)));
+        }
+    }
+
+    let restart = text(container, "/HostConfig/RestartPolicy/Name");
+    if !restart.is_empty() && restart != "no" {
+        service.scalars.push(("restart", quote(restart)));
+    }
+
+    let network_mode = text(container, "/HostConfig/NetworkMode");
+    if network_mode == "host" || network_mode == "none" || network_mode.starts_with("container:") {
+        service.scalars.push(("network_mode", quote(network_mode)));
+    }
+
+    let memory = container.pointer("/HostConfig/Memory").and_then(Value::as_u64).unwrap_or(0);
+    if memory > 0 {
+        service.scalars.push(("mem_limit", memory.to_string()));
+    }
+    let nano_cpus = container.pointer("/HostConfig/NanoCpus").and_then(Value::as_u64).unwrap_or(0);
+    if nano_cpus > 0 {
+        service.scalars.push(("cpus", (nano_cpus as f64 / 1e9).to_string()));
+    }
+
+    // Portas publicadas: "ip:host:container/proto"
+    let mut ports = Vec::new();
+    if let Some(bindings) = container.pointer("/HostConfig/PortBindings").and_then(Value::as_object) {
+        for (container_port, hosts) in bindings {
+            for host in hosts.as_array().into_iter().flatten() {
+                let host_ip = text(host, "/HostIp");
+                let host_port = text(host, "/HostPort");
+                let mapping = match (host_ip, host_port) {
+                    (_, "") => container_port.clone(),
+                    ("" | "0.0.0.0" | "::", port) => format!("{}:{}", port, container_port),
+                    (ip, port) => format!("{}:{}:{}", ip, port, container_port),
+                };
+                ports.push(quote(mapping.trim_end_matches("/tcp")));
+            }
+        }
+    }
+    ports.sort();
+    ports.dedup();
+    if !ports.is_empty() {
+        service.lists.push(("ports", ports));
+    }
+
+    // Variáveis definidas na imagem (PATH etc.) não são repetidas
+    let image_env = strings(image.pointer("/Config/Env").unwrap_or(&Value::Null));
+    let environment: BTreeMap<String, String> = strings(container.pointer("/Config/Env").unwrap_or(&Value::Null))
+        .into_iter()
+        .filter(|entry| !image_env.contains(entry))
+        .filter_map(|entry| {
+            let (key, value) = entry.split_once('=')?;
+            Some((key.to_string(), quote(value)))
+        })
+        .collect();
+    if !environment.is_empty() {
+        service.maps.push(("environment", environment));
+    }
+
+    let mut volumes = Vec::new();
+    for mount in container.pointer("/Mounts").and_then(Value::as_array).into_iter().flatten() {
+        let source = match text(mount, "/Type") {
+            "bind" => text(mount, "/Source"),
+            "volume" => text(mount, "/Name"),
+            _ => continue,
+        };
+        let mut volume = format!("{}:{}", source, text(mount, "/Destination"));
+        if mount.pointer("/RW").and_then(Value::as_bool) == Some(false) {
+            volume.push_str(":ro");
+        }
+        volumes.push(quote(&volume));
+    }
+    if !volumes.is_empty() {
+        service.lists.push(("volumes", volumes));
+    }
+
+    let networks: Vec<String> = container
+        .pointer("/NetworkSettings/Networks")
+        .and_then(Value::as_object)
+        .map(|networks| {
+            networks
+                .keys()
+                .filter(|name| !DEFAULT_NETWORKS.contains(&name.as_str()))
+                .map(|name| quote(name))
+                .collect()
+        })
+        .unwrap_or_default();
+    if !networks.is_empty() {
+        service.lists.push(("networks", networks));
+    }
+
+    // Labels do compose antigo e da imagem não são copiadas
+    let image_labels = image.pointer("/Config/Labels").and_then(Value::as_object);
+    let labels: BTreeMap<String, String> = container
+        .pointer("/Config/Labels")
+        .and_then(Value::as_object)
+        .map(|labels| {
+            labels
+                .iter()
+                .filter(|(key, _)| !key.starts_with("com.docker.compose."))
+                .filter(|(key, value)| image_labels.and_then(|image| image.get(*key)) != Some(*value))
+                .map(|(key, value)| (key.clone(), quote(value.as_str().unwrap_or_default())))
+                .collect()
+        })
+        .unwrap_or_default();
+    if !labels.is_empty() {
+        service.maps.push(("labels", labels));
+    }
+
+    service
+}
+
+// Gera o docker-compose.yml; volumes nomeados e redes são declarados como externos
+// para que o compose reaproveite os existentes em vez de criar novos
+pub fn to_compose_yaml(sources: &[ExportSource]) -> Result<String> {
+    if sources.is_empty() {
+        return Err(anyhow::anyhow!("Selecione ao menos um container"));
+    }
+
+    let mut yaml = String::from("services:\n");
+    let mut used_names: Vec<String> = Vec::new();
+    let mut external_volumes = Vec::new();
+    let mut external_networks = Vec::new();
+
+    for source in sources {
+        let mut service = to_service(source);
+
+        // Nomes repetidos após a normalização recebem sufixo
+        let base = service.name.clone();
+        let mut suffix = 2;
+        while used_names.contains(&service.name) {
+            service.name = format!("{}-{}", base, suffix);
+            suffix += 1;
+        }
+        used_names.push(service.name.clone());
+
+        yaml.push_str(&format!("  {}:\n", service.name));
+        for (key, value) in &service.scalars {
+            yaml.push_str(&format!("    {}: {}\n", key, value));
+        }
+        for (key, items) in &service.lists {
+            yaml.push_str(&format!("    {}:\n", key));
+            for item in items {
+                yaml.push_str(&format!("      - {}\n", item));
+            }
+        }
+        for (key, entries) in &service.maps {
+            yaml.push_str(&format!("    {}:\n", key));
+            for (name, value) in entries {
+                yaml.push_str(&format!("      {}: {}\n", quote(name), value));
+            }
+        }
+
+        for mount in source
+            .container
+            .pointer("/Mounts")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter(|mount| text(mount, "/Type") == "volume")
+        {
+            let name = text(mount, "/Name").to_string();
+            if !name.is_empty() && !external_volumes.contains(&name) {
+                external_volumes.push(name);
+            }
+        }
+        if let Some((_, networks)) = service.lists.iter().find(|(key, _)| *key == "networks") {
+            for network in networks {
+                if !external_networks.contains(network) {
+                    external_networks.push(network.clone());
+                }
+            }
+        }
+    }
+
+    if !external_volumes.is_empty() {
+        yaml.push_str("\nvolumes:\n");
+        for name in &external_volumes {
+            yaml.push_str(&format!("  {}:\n    external: true\n", quote(name)));
+        }
+    }
+    if !external_networks.is_empty() {
+        yaml.push_str("\nnetworks:\n");
+        for name in &external_networks {
+            yaml.push_str(&format!("  {}:\n    external: true\n", name));
+        }
+    }
+
+    Ok(yaml)
+}
+
+// Grava o arquivo sem sobrescrever um compose existente
+pub fn write_file(path: &str, yaml: &str) -> Result<()> {
+    let path = Path::new(path.trim());
+    if path.as_os_str().is_empty() {
+        return Err(anyhow::anyhow!("Informe o caminho do arquivo"));
+    }
+    if path.exists() {
+        return Err(anyhow::anyhow!("O arquivo {} já existe", path.display()));
+    }
+    if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent).context("Falha ao criar diretório de destino")?;
+    }
+    std::fs::write(path, yaml).context("Falha ao gravar o arquivo compose")
+}
+
+// Sugestão de destino: ~/docker-compose.yml
+pub fn default_path() -> String {
+    std::env::var_os("HOME")
+        .map(|home| Path::new(&home).join("docker-compose.yml").display().to_string())
+        .unwrap_or_else(|| "docker-compose.yml".to_string())
+}
@@ -0,0 +1,185 @@
+// Comparação lado a lado da configuração de dois containers (docker inspect), inclusive
+// de servidores diferentes: imagem, comando, ambiente, montagens, portas, redes e limites
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+// Seções na ordem exibida
+const SECTIONS: [&str; 7] = [
+    "Imagem",
+    "Comando",
+    "Ambiente",
+    "Montagens",
+    "Portas",
+    "Redes",
+    "Limites",
+];
+
+// Campos do HostConfig comparados na seção de limites
+const LIMITS: [(&str, &str); 10] = [
+    ("/HostConfig/Memory", "memória"),
+    ("/HostConfig/MemoryReservation", "reserva de memória"),
+    ("/HostConfig/MemorySwap", "swap"),
+    ("/HostConfig/NanoCpus", "CPUs"),
+    ("/HostConfig/CpuShares", "peso de CPU"),
+    ("/HostConfig/CpuQuota", "cota de CPU"),
+    ("/HostConfig/CpusetCpus", "cpuset"),
+    ("/HostConfig/PidsLimit", "limite de processos"),
+    ("/HostConfig/BlkioWeight", "peso de I/O"),
+    ("/HostConfig/RestartPolicy/Name", "política de reinício"),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffRow {
+    pub section: &'static str,
+    pub field: String,
+    // Vazio quando o campo não existe naquele container
+    pub left: String,
+    pub right: String,
+}
+
+impl DiffRow {
+    pub fn differs(&self) -> bool {
+        self.left != self.right
+    }
+}
+
+// Valor do campo como texto; 0, null e ausente contam como "não definido"
+fn text(container: &Value, pointer: &str) -> String {
+    match container.pointer(pointer) {
+        Some(Value::String(value)) => value.clone(),
+        Some(Value::Null) | None => String::new(),
+        Some(Value::Number(number)) if number.as_i64() == Some(0) => String::new(),
+        Some(value) => value.to_string(),
+    }
+}
+
+fn join(container: &Value, pointer: &str) -> String {
+    container
+        .pointer(pointer)
+        .and_then(Value::as_array)
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(Value::as_str)
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .unwrap_or_default()
+}
+
+// Campos de um container: (índice da seção, nome do campo) -> valor
+fn fields(container: &Value) -> BTreeMap<(usize, String), String> {
+    let mut fields = BTreeMap::new();
+    let mut set = |section: usize, field: &str, value: String| {
+        if !value.is_empty() {
+            fields.insert((section, field.to_string()), value);
+        }
+    };
+
+    set(0, "referência", text(container, "/Config/Image"));
+    let image_id = text(container, "/Image");
+    let image_id = image_id.strip_prefix("sha256:").unwrap_or(&image_id);
+    set(0, "ID", image_id[..image_id.len().min(12)].to_string());
+    set(0, "plataforma", text(container, "/Platform"));
+
+    set(1, "entrypoint", join(container, "/Config/Entrypoint"));
+    set(1, "cmd", join(container, "/Config/Cmd"));
+    set(1, "diretório", text(container, "/Config/WorkingDir"));
+    set(1, "usuário", text(container, "/Config/User"));
+
+    for variable in container
+        .pointer("/Config/Env")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+    {
+        let (name, value) = variable.split_once('=').unwrap_or((variable, ""));
+        set(2, name, value.to_string());
+    }
+
+    for mount in container
+        .get("Mounts")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        // Volumes nomeados pelo nome; bind mounts pelo caminho no host
+        let source = match text(mount, "/Type").as_str() {
+            "volume" => text(mount, "/Name"),
+            _ => text(mount, "/Source"),
+        };
+        let mode = if mount.get("RW").and_then(Value::as_bool) == Some(false) {
+            "ro"
+        } else {
+            "rw"
+        };
+        set(
+            3,
+            &text(mount, "/Destination"),
+            format!("{} ({}, {})", source, text(mount, "/Type"), mode),
+        );
+    }
+
+    if let Some(bindings) = container
+        .pointer("/HostConfig/PortBindings")
+        .and_then(Value::as_object)
+    {
+        for (port, hosts) in bindings {
+            let hosts: Vec<String> = hosts
+                .as_array()
+                .into_iter()
+                .flatten()
+                .map(|host| match text(host, "/HostIp").as_str() {
+                    "" => text(host, "/HostPort"),
+                    ip => format!("{}:{}", ip, text(host, "/HostPort")),
+                })
+                .collect();
+            let value = if hosts.is_empty() {
+                "não publicada".to_string()
+            } else {
+                hosts.join(", ")
+            };
+            set(4, port, value);
+        }
+    }
+
+    set(5, "modo", text(container, "/HostConfig/NetworkMode"));
+    if let Some(networks) = container
+        .pointer("/NetworkSettings/Networks")
+        .and_then(Value::as_object)
+    {
+        for (name, endpoint) in networks {
+            let aliases = join(endpoint, "/Aliases");
+            let value = if aliases.is_empty() {
+                "conectado".to_string()
+            } else {
+                format!("aliases: {}", aliases)
+            };
+            set(5, name, value);
+        }
+    }
+
+    for (pointer, name) in LIMITS {
+        set(6, name, text(container, pointer));
+    }
+    fields
+}
+
+// Linhas de todos os campos presentes em pelo menos um dos containers, por seção
+pub fn compare(left: &Value, right: &Value) -> Vec<DiffRow> {
+    let mut left = fields(left);
+    let right = fields(right);
+    let mut keys: Vec<(usize, String)> = left.keys().chain(right.keys()).cloned().collect();
+    keys.sort();
+    keys.dedup();
+
+    keys.into_iter()
+        .map(|key| DiffRow {
+            section: SECTIONS[key.0],
+            left: left.remove(&key).unwrap_or_default(),
+            right: right.get(&key).cloned().unwrap_or_default(),
+            field: key.1,
+        })
+        .collect()
+}
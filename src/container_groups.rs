@@ -0,0 +1,242 @@
+// Grupos de containers com ordem de inicialização (aproxima o depends_on do compose)
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+
+// Grupo salvo na configuração; spec tem uma linha por container:
+//   db
+//   api: db, cache
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ContainerGroup {
+    pub name: String,
+    // Servidor (contexto) onde os containers existem
+    pub server: String,
+    pub spec: String,
+}
+
+impl ContainerGroup {
+    pub fn new(name: &str, server: &str, spec: &str) -> Result<Self> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(anyhow::anyhow!("Informe o nome do grupo"));
+        }
+        let group = Self {
+            name: name.to_string(),
+            server: server.to_string(),
+            spec: spec.trim().to_string(),
+        };
+        group.start_order()?;
+        Ok(group)
+    }
+
+    // Etapas de inicialização; containers da mesma etapa sobem juntos
+    pub fn start_order(&self) -> Result<Vec<Vec<String>>> {
+        start_order(&parse_spec(&self.spec)?)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GroupMember {
+    pub container: String,
+    pub depends_on: Vec<String>,
+}
+
+// Interpreta "container" ou "container: dep1, dep2" (linhas vazias e # são ignoradas)
+pub fn parse_spec(spec: &str) -> Result<Vec<GroupMember>> {
+    let mut members: Vec<GroupMember> = Vec::new();
+
+    for line in spec.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (container, depends_on) = line.split_once(':').unwrap_or((line, ""));
+        let container = container.trim();
+        if container.is_empty() || container.contains(char::is_whitespace) {
+            return Err(anyhow::anyhow!("Linha inválida: '{}'", line));
+        }
+        if members.iter().any(|member| member.container == container) {
+            return Err(anyhow::anyhow!("Container '{}' declarado duas vezes", container));
+        }
+
+        members.push(GroupMember {
+            container: container.to_string(),
+            depends_on: depends_on
+                .split(',')
+                .map(str::trim)
+                .filter(|dependency| !dependency.is_empty())
+                .map(str::to_string)
+                .collect(),
+        });
+    }
+
+    if members.is_empty() {
+        return Err(anyhow::anyhow!("Informe ao menos um container"));
+    }
+    Ok(members)
+}
+
+// Ordena em etapas (Kahn); falha em dependência não declarada ou ciclo
+pub fn start_order(members: &[GroupMember]) -> Result<Vec<Vec<String>>> {
+    let mut pending: HashMap<&str, BTreeSet<&str>> = HashMap::new();
+    for member in members {
+        for dependency in &member.depends_on {
+            if dependency == &member.container {
+                return Err(anyhow::anyhow!("'{}' depende de si mesmo", member.container));
+            }
+            if !members.iter().any(|other| &other.container == dependency) {
+                return Err(anyhow::anyhow!(
+                    "'{}' depende de '{}', que não está no grupo",
+                    member.container,
+                    dependency
+                ));
+            }
+        }
+        pending.insert(
+            &member.container,
+            member.depends_on.iter().map(String::as_str).collect(),
+        );
+    }
+
+    let mut levels = Vec::new();
+    while !pending.is_empty() {
+        // Mantém a ordem de declaração dentro de cada etapa
+        let ready: Vec<String> = members
+            .iter()
+            .map(|member| member.container.as_str())
+            .filter(|container| pending.get(container).is_some_and(BTreeSet::is_empty))
+            .map(str::to_string)
+            .collect();
+
+        if ready.is_empty() {
+            let mut cycle: Vec<&str> = pending.keys().copied().collect();
+            cycle.sort();
+            return Err(anyhow::anyhow!(
+                "Dependência circular entre: {}",
+                cycle.join(", ")
+            ));
+        }
+
+        for container in &ready {
+            pending.remove(container.as_str());
+        }
+        for dependencies in pending.values_mut() {
+            for container in &ready {
+                dependencies.remove(container.as_str());
+            }
+        }
+        levels.push(ready);
+    }
+
+    Ok(levels)
+}
+
+// Ex: "db, cache → api → web"
+pub fn order_summary(levels: &[Vec<String>]) -> String {
+    levels
+        .iter()
+        .map(|level| level.join(", "))
+        .collect::<Vec<_>>()
+        .join(" → ")
+}
+
+// Situação de um container durante a inicialização do grupo
+#[derive(Debug, Clone, PartialEq)]
+pub enum Readiness {
+    Ready,
+    Waiting(String),
+    Failed(String),
+}
+
+impl Readiness {
+    // Recebe "<State.Status> <State.Health.Status>"; sem healthcheck, rodando já basta
+    pub fn from_inspect(output: &str) -> Self {
+        let mut parts = output.split_whitespace();
+        let status = parts.next().unwrap_or_default();
+        let health = parts.next().unwrap_or_default();
+
+        match (status, health) {
+            ("running", "" | "healthy") => Readiness::Ready,
+            ("running", "unhealthy") => Readiness::Failed("healthcheck falhou".to_string()),
+            ("running", health) => Readiness::Waiting(format!("healthcheck: {}", health)),
+            ("exited" | "dead", _) => Readiness::Failed(format!("container encerrou ({})", status)),
+            (status, _) => Readiness::Waiting(status.to_string()),
+        }
+    }
+}
+
+// Seletor de labels, ex: "env=staging,app=web"; "chave" sozinha exige só a presença da label.
+// Todas as condições precisam valer (mesma semântica do filtro label= do docker)
+#[derive(Debug, Clone, PartialEq)]
+pub struct LabelSelector {
+    conditions: Vec<(String, Option<String>)>,
+}
+
+impl LabelSelector {
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut conditions = Vec::new();
+        for part in text.split(',').map(str::trim).filter(|part| !part.is_empty()) {
+            let (key, value) = match part.split_once('=') {
+                Some((key, value)) => (key.trim(), Some(value.trim().to_string())),
+                None => (part, None),
+            };
+            if key.is_empty() || key.contains(char::is_whitespace) {
+                return Err(anyhow::anyhow!("Condição inválida: '{}'", part));
+            }
+            conditions.push((key.to_string(), value));
+        }
+        if conditions.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Informe ao menos uma label (ex: env=staging,app=web)"
+            ));
+        }
+        Ok(Self { conditions })
+    }
+
+    // Valores para `docker ps --filter label=...`
+    pub fn filters(&self) -> Vec<String> {
+        self.conditions
+            .iter()
+            .map(|(key, value)| match value {
+                Some(value) => format!("{}={}", key, value),
+                None => key.clone(),
+            })
+            .collect()
+    }
+}
+
+// Ação aplicada de uma vez a todos os containers do seletor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectorAction {
+    Start,
+    Stop,
+    Restart,
+    // Um por vez, aguardando cada um ficar pronto; para na primeira falha
+    RollingRestart,
+    PullRecreate,
+}
+
+impl SelectorAction {
+    // Valor vindo da interface
+    pub fn from_ui(value: &str) -> Option<Self> {
+        match value {
+            "start" => Some(Self::Start),
+            "stop" => Some(Self::Stop),
+            "restart" => Some(Self::Restart),
+            "rolling-restart" => Some(Self::RollingRestart),
+            "pull-recreate" => Some(Self::PullRecreate),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Start => "Iniciar",
+            Self::Stop => "Parar",
+            Self::Restart => "Reiniciar",
+            Self::RollingRestart => "Reinício gradual",
+            Self::PullRecreate => "Pull e recriar",
+        }
+    }
+}
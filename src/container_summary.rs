@@ -0,0 +1,145 @@
+// Resumo em Markdown de um container (dados do docker inspect) para colar em runbooks
+use serde_json::Value;
+
+use crate::compose_export::ExportSource;
+
+fn text<'a>(value: &'a Value, pointer: &str) -> &'a str {
+    value
+        .pointer(pointer)
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+}
+
+fn strings(value: Option<&Value>) -> Vec<String> {
+    value
+        .and_then(Value::as_array)
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| item.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// Código inline do Markdown; crases no valor exigem delimitador maior
+fn code(value: &str) -> String {
+    if value.contains('`') {
+        format!("`` {} ``", value)
+    } else {
+        format!("`{}`", value)
+    }
+}
+
+fn push_list(lines: &mut Vec<String>, title: &str, items: &[String]) {
+    if items.is_empty() {
+        lines.push(format!("- **{}:** nenhuma", title));
+        return;
+    }
+    lines.push(format!("- **{}:**", title));
+    lines.extend(items.iter().map(|item| format!("  - {}", item)));
+}
+
+pub fn to_markdown(source: &ExportSource) -> String {
+    let container = &source.container;
+    let image = source.image.as_ref().unwrap_or(&Value::Null);
+    let name = text(container, "/Name").trim_start_matches('/');
+
+    let mut lines = vec![
+        format!("### {}", name),
+        String::new(),
+        format!("- **Imagem:** {}", code(text(container, "/Config/Image"))),
+    ];
+
+    let restart = match text(container, "/HostConfig/RestartPolicy/Name") {
+        "" => "no",
+        policy => policy,
+    };
+    let max_retries = container
+        .pointer("/HostConfig/RestartPolicy/MaximumRetryCount")
+        .and_then(Value::as_u64)
+        .unwrap_or(0);
+    if restart == "on-failure" && max_retries > 0 {
+        lines.push(format!(
+            "- **Reinício:** {}",
+            code(&format!("{}:{}", restart, max_retries))
+        ));
+    } else {
+        lines.push(format!("- **Reinício:** {}", code(restart)));
+    }
+
+    // Portas publicadas, e as apenas expostas quando não há publicação
+    let mut ports = Vec::new();
+    if let Some(bindings) = container
+        .pointer("/HostConfig/PortBindings")
+        .and_then(Value::as_object)
+    {
+        for (container_port, hosts) in bindings {
+            for host in hosts.as_array().into_iter().flatten() {
+                let mapping = match (text(host, "/HostIp"), text(host, "/HostPort")) {
+                    (_, "") => container_port.clone(),
+                    ("" | "0.0.0.0" | "::", port) => format!("{} → {}", port, container_port),
+                    (ip, port) => format!("{}:{} → {}", ip, port, container_port),
+                };
+                ports.push(code(&mapping));
+            }
+        }
+    }
+    let exposed = container
+        .pointer("/Config/ExposedPorts")
+        .and_then(Value::as_object)
+        .filter(|_| ports.is_empty());
+    for port in exposed.into_iter().flat_map(|exposed| exposed.keys()) {
+        ports.push(format!("{} (exposta, não publicada)", code(port)));
+    }
+    ports.sort();
+    ports.dedup();
+    push_list(&mut lines, "Portas", &ports);
+
+    let mounts: Vec<String> = container
+        .pointer("/Mounts")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .map(|mount| {
+            let kind = text(mount, "/Type");
+            let source = match kind {
+                "volume" => text(mount, "/Name"),
+                "tmpfs" => "tmpfs",
+                _ => text(mount, "/Source"),
+            };
+            let read_only = mount.pointer("/RW").and_then(Value::as_bool) == Some(false);
+            format!(
+                "{} → {} ({}{})",
+                code(source),
+                code(text(mount, "/Destination")),
+                kind,
+                if read_only { ", somente leitura" } else { "" }
+            )
+        })
+        .collect();
+    push_list(&mut lines, "Montagens", &mounts);
+
+    // Só as chaves: valores podem conter segredos. Variáveis herdadas da imagem ficam de fora
+    let image_env = strings(image.pointer("/Config/Env"));
+    let mut env_keys: Vec<String> = strings(container.pointer("/Config/Env"))
+        .into_iter()
+        .filter(|entry| !image_env.contains(entry))
+        .map(|entry| {
+            let key = entry.split_once('=').map_or(entry.as_str(), |(key, _)| key);
+            code(key)
+        })
+        .collect();
+    env_keys.sort();
+    env_keys.dedup();
+    if env_keys.is_empty() {
+        lines.push("- **Variáveis de ambiente:** nenhuma".to_string());
+    } else {
+        lines.push(format!(
+            "- **Variáveis de ambiente:** {}",
+            env_keys.join(", ")
+        ));
+    }
+
+    lines.join("\n")
+}
@@ -0,0 +1,199 @@
+// Credenciais de registries (Docker Hub, GHCR, privados) guardadas no keyring do sistema
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::config::AppConfig;
+
+const KEYRING_SERVICE: &str = "docker-ui-registry";
+pub const DOCKER_HUB_SERVER: &str = "docker.io";
+
+// Metadados de uma credencial salva (a senha nunca vai para o config.json)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegistryCredential {
+    pub name: String,
+    pub server: String,
+    pub username: String,
+}
+
+impl RegistryCredential {
+    // Conta no keyring pelo nome, que é único entre as credenciais salvas
+    fn keyring_account(&self) -> String {
+        format!("credential:{}", self.name)
+    }
+
+    fn keyring_entry(&self) -> Result<keyring::Entry> {
+        keyring::Entry::new(KEYRING_SERVICE, &self.keyring_account())
+            .context("Falha ao acessar o keyring")
+    }
+
+    // Lê a senha/token do keyring
+    pub fn password(&self) -> Result<String> {
+        self.keyring_entry()?
+            .get_password()
+            .context(format!("Senha da credencial '{}' não encontrada no keyring", self.name))
+    }
+
+    // Indica se a credencial atende o registry informado
+    pub fn matches_server(&self, server: &str) -> bool {
+        normalize_server(&self.server) == normalize_server(server)
+    }
+}
+
+// Credencial escolhida para uma operação (pull, push, verificação de digest)
+#[derive(Debug, Clone, PartialEq)]
+pub enum CredentialSelection {
+    // Usa a primeira credencial salva para o registry da imagem
+    Auto,
+    // Operação sem autenticação
+    Anonymous,
+    // Credencial específica pelo nome
+    Named(String),
+}
+
+impl CredentialSelection {
+    // Converte o valor vindo da interface ("auto", "none" ou nome)
+    pub fn from_ui(value: &str) -> Self {
+        match value.trim() {
+            "" | "auto" => Self::Auto,
+            "none" => Self::Anonymous,
+            name => Self::Named(name.to_string()),
+        }
+    }
+}
+
+// Normaliza endereços equivalentes do Docker Hub
+fn normalize_server(server: &str) -> String {
+    let server = server
+        .trim()
+        .trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .trim_end_matches('/')
+        .trim_end_matches("/v1")
+        .to_lowercase();
+
+    match server.as_str() {
+        "" | "index.docker.io" | "registry-1.docker.io" | "docker.io" => {
+            DOCKER_HUB_SERVER.to_string()
+        }
+        _ => server,
+    }
+}
+
+// Extrai o registry de uma referência de imagem (ghcr.io/org/app:tag -> ghcr.io)
+pub fn registry_for_image(image: &str) -> String {
+    match image.split_once('/') {
+        Some((first, _))
+            if first.contains('.') || first.contains(':') || first == "localhost" =>
+        {
+            normalize_server(first)
+        }
+        _ => DOCKER_HUB_SERVER.to_string(),
+    }
+}
+
+// Lista as credenciais salvas
+pub fn list() -> Vec<RegistryCredential> {
+    AppConfig::load().registries
+}
+
+// Salva (ou substitui) uma credencial: metadados no config, senha no keyring
+pub fn save(credential: RegistryCredential, password: &str) -> Result<()> {
+    if credential.name.trim().is_empty() || credential.username.trim().is_empty() {
+        return Err(anyhow::anyhow!("Nome e usuário da credencial são obrigatórios"));
+    }
+
+    let credential = RegistryCredential {
+        server: normalize_server(&credential.server),
+        ..credential
+    };
+
+    credential
+        .keyring_entry()?
+        .set_password(password)
+        .context("Falha ao gravar senha no keyring")?;
+
+    AppConfig::update(|config| {
+        config.registries.retain(|existing| existing.name != credential.name);
+        config.registries.push(credential);
+        Ok(())
+    })?;
+    Ok(())
+}
+
+// Remove uma credencial do config e do keyring
+pub fn remove(name: &str) -> Result<()> {
+    AppConfig::update(|config| {
+        let Some(position) = config.registries.iter().position(|c| c.name == name) else {
+            return Ok(());
+        };
+        let credential = config.registries.remove(position);
+        if let Ok(entry) = credential.keyring_entry() {
+            let _ = entry.delete_credential();
+        }
+        Ok(())
+    })?;
+    Ok(())
+}
+
+// Resolve a credencial (com senha) para a imagem conforme a seleção
+pub fn resolve(
+    selection: &CredentialSelection,
+    image: &str,
+) -> Result<Option<(RegistryCredential, String)>> {
+    let credential = match selection {
+        CredentialSelection::Anonymous => return Ok(None),
+        CredentialSelection::Auto => {
+            let server = registry_for_image(image);
+            match list().into_iter().find(|c| c.matches_server(&server)) {
+                Some(credential) => credential,
+                None => return Ok(None),
+            }
+        }
+        CredentialSelection::Named(name) => list()
+            .into_iter()
+            .find(|c| &c.name == name)
+            .ok_or_else(|| anyhow::anyhow!("Credencial '{}' não encontrada", name))?,
+    };
+
+    let password = credential.password()?;
+    Ok(Some((credential, password)))
+}
+
+// Usuário e senha salvos para o Docker Hub, se houver
+pub fn docker_hub_login() -> Option<(String, String)> {
+    resolve(&CredentialSelection::Auto, "library/alpine")
+        .ok()
+        .flatten()
+        .map(|(credential, password)| (credential.username, password))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn credential(name: &str, server: &str, username: &str) -> RegistryCredential {
+        RegistryCredential {
+            name: name.to_string(),
+            server: server.to_string(),
+            username: username.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_keyring_account_is_per_credential() {
+        // Mesmo usuário e registry (ex: token de leitura e de escrita) não dividem a senha
+        let read = credential("ghcr-leitura", "ghcr.io", "ana");
+        let write = credential("ghcr-escrita", "ghcr.io", "ana");
+        assert_ne!(read.keyring_account(), write.keyring_account());
+        assert_eq!(read.keyring_account(), "credential:ghcr-leitura");
+    }
+
+    #[test]
+    fn test_matches_server() {
+        let hub = credential("hub", "https://index.docker.io/v1/", "ana");
+        assert!(hub.matches_server("docker.io"));
+        assert!(hub.matches_server("registry-1.docker.io"));
+        assert!(!hub.matches_server("ghcr.io"));
+        assert!(credential("ghcr", "GHCR.io/", "ana").matches_server("ghcr.io"));
+    }
+}
@@ -0,0 +1,121 @@
+// Dashboard personalizado: widgets escolhidos e ordenados pelo usuário, salvos por servidor
+use anyhow::{Result, bail};
+use serde::{Deserialize, Serialize};
+
+use crate::compose::{HealthLevel, ServiceHealth};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum DashboardWidget {
+    CpuChart,
+    MemoryChart,
+    ContainerMemory { container: String },
+    StackHealth { project: String },
+    DiskUsage,
+    EventFeed,
+}
+
+impl DashboardWidget {
+    // Layout inicial ao personalizar: o mesmo conteúdo do dashboard padrão
+    pub fn defaults() -> Vec<Self> {
+        vec![DashboardWidget::CpuChart, DashboardWidget::MemoryChart]
+    }
+
+    // Widget a partir do tipo e do alvo (container ou stack) vindos da interface
+    pub fn from_ui(kind: &str, target: &str) -> Result<Self> {
+        let target = target.trim();
+        let widget = match kind {
+            "cpu-chart" => DashboardWidget::CpuChart,
+            "memory-chart" => DashboardWidget::MemoryChart,
+            "disk-usage" => DashboardWidget::DiskUsage,
+            "event-feed" => DashboardWidget::EventFeed,
+            "container-memory" | "stack-health" if target.is_empty() => {
+                bail!("Escolha o container ou a stack do widget")
+            }
+            "container-memory" => DashboardWidget::ContainerMemory {
+                container: target.to_string(),
+            },
+            "stack-health" => DashboardWidget::StackHealth {
+                project: target.to_string(),
+            },
+            _ => bail!("Widget desconhecido: {}", kind),
+        };
+        Ok(widget)
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            DashboardWidget::CpuChart => "cpu-chart",
+            DashboardWidget::MemoryChart => "memory-chart",
+            DashboardWidget::ContainerMemory { .. } => "container-memory",
+            DashboardWidget::StackHealth { .. } => "stack-health",
+            DashboardWidget::DiskUsage => "disk-usage",
+            DashboardWidget::EventFeed => "event-feed",
+        }
+    }
+
+    pub fn target(&self) -> &str {
+        match self {
+            DashboardWidget::ContainerMemory { container } => container,
+            DashboardWidget::StackHealth { project } => project,
+            _ => "",
+        }
+    }
+
+    pub fn title(&self) -> String {
+        match self {
+            DashboardWidget::CpuChart => "Uso de CPU".to_string(),
+            DashboardWidget::MemoryChart => "Uso de Memória".to_string(),
+            DashboardWidget::ContainerMemory { container } => format!("Memória de {}", container),
+            DashboardWidget::StackHealth { project } => format!("Stack {}", project),
+            DashboardWidget::DiskUsage => "Disco do data-root".to_string(),
+            DashboardWidget::EventFeed => "Eventos de containers".to_string(),
+        }
+    }
+}
+
+// Adiciona o widget ao final; o mesmo widget duas vezes não faz sentido
+pub fn add(layout: &mut Vec<DashboardWidget>, widget: DashboardWidget) -> Result<()> {
+    if layout.contains(&widget) {
+        bail!("'{}' já está no dashboard", widget.title());
+    }
+    layout.push(widget);
+    Ok(())
+}
+
+// Move o widget de `from` para a posição `to` (arrastar ou botões ↑/↓)
+pub fn move_widget(layout: &mut Vec<DashboardWidget>, from: usize, to: usize) {
+    if from >= layout.len() || from == to {
+        return;
+    }
+    let widget = layout.remove(from);
+    layout.insert(to.min(layout.len()), widget);
+}
+
+// Resumo da stack para o widget: pior nível entre os serviços
+pub fn stack_summary(services: &[ServiceHealth]) -> (HealthLevel, String) {
+    if services.is_empty() {
+        return (HealthLevel::Red, "Nenhum container da stack".to_string());
+    }
+    let level = services
+        .iter()
+        .map(ServiceHealth::level)
+        .max()
+        .unwrap_or(HealthLevel::Green);
+    let problems: Vec<&str> = services
+        .iter()
+        .filter(|service| service.level() != HealthLevel::Green)
+        .map(|service| service.service.as_str())
+        .collect();
+    let summary = if problems.is_empty() {
+        format!("{} serviço(s) rodando e saudáveis", services.len())
+    } else {
+        format!(
+            "{} de {} serviço(s) com problema: {}",
+            problems.len(),
+            services.len(),
+            problems.join(", ")
+        )
+    };
+    (level, summary)
+}
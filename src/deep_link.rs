@@ -0,0 +1,85 @@
+// Links diretos para os detalhes de um container, vindos da linha de comando ou de URL:
+//   docker-ui-app open --server prod --container api
+//   docker-ui-app docker-ui://open?server=prod&container=api
+use anyhow::{Result, anyhow, bail};
+
+use crate::docker::ContainerInfo;
+use crate::util::{parse_flags, percent_decode};
+
+const URL_PREFIX: &str = "docker-ui://open";
+
+pub const USAGE: &str = "Uso: docker-ui-app open [--server <servidor>] --container <nome|id>";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeepLink {
+    // Contexto Docker; None mantém o servidor atual
+    pub server: Option<String>,
+    // Nome ou ID (completo ou prefixo) do container
+    pub container: String,
+}
+
+impl DeepLink {
+    // Argumentos da linha de comando, sem o nome do executável
+    pub fn from_args(args: &[String]) -> Result<Option<Self>> {
+        match args.first().map(String::as_str) {
+            None => Ok(None),
+            Some("open") => Self::from_open_args(&args[1..]).map(Some),
+            Some(arg) if arg.starts_with(URL_PREFIX) => Self::from_url(arg).map(Some),
+            Some(arg) => bail!("Argumento desconhecido: {}", arg),
+        }
+    }
+
+    fn from_open_args(args: &[String]) -> Result<Self> {
+        let mut server = None;
+        let mut container = None;
+        for (flag, value) in parse_flags(args, &["--server", "--container"])? {
+            match flag {
+                "--server" => server = Some(value),
+                _ => container = Some(value),
+            }
+        }
+        Self::build(server, container)
+    }
+
+    fn from_url(url: &str) -> Result<Self> {
+        let query = url[URL_PREFIX.len()..].trim_start_matches(['/', '?']);
+        let mut server = None;
+        let mut container = None;
+        // Nos parâmetros da URL, '+' é espaço
+        let decode = |value: &str| percent_decode(&value.replace('+', " "));
+        for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            match key {
+                "server" => server = Some(decode(value)),
+                "container" => container = Some(decode(value)),
+                _ => {}
+            }
+        }
+        Self::build(server, container)
+    }
+
+    fn build(server: Option<String>, container: Option<String>) -> Result<Self> {
+        let container = container
+            .map(|container| container.trim().to_string())
+            .filter(|container| !container.is_empty())
+            .ok_or_else(|| anyhow!("Informe o container com --container"))?;
+        Ok(Self {
+            server: server
+                .map(|server| server.trim().to_string())
+                .filter(|server| !server.is_empty()),
+            container,
+        })
+    }
+
+    // Nome exato tem prioridade sobre prefixo de ID
+    pub fn find<'a>(&self, containers: &'a [ContainerInfo]) -> Option<&'a ContainerInfo> {
+        containers
+            .iter()
+            .find(|container| container.name == self.container)
+            .or_else(|| {
+                containers
+                    .iter()
+                    .find(|container| container.id.starts_with(&self.container))
+            })
+    }
+}
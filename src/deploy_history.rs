@@ -0,0 +1,283 @@
+// Histórico de CPU/memória por nome de container que sobrevive ao recreate: quando o id
+// muda (novo deploy), a série atual vira a anterior e pode ser sobreposta à nova para
+// conferir se a nova versão da imagem não piorou o consumo
+use chrono::{DateTime, Local, TimeZone};
+use std::{
+    collections::{HashMap, VecDeque},
+    time::{Duration, Instant},
+};
+
+// Intervalo mínimo entre amostras do mesmo container
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(10);
+// Duas horas por deploy com amostras de 10s
+const MAX_SAMPLES: usize = 720;
+// Containers que sumiram há mais tempo que isso são esquecidos (o recreate leva segundos)
+const FORGET_AFTER: Duration = Duration::from_secs(60 * 60);
+
+#[derive(Debug, Clone, Copy)]
+struct DeploySample {
+    // Segundos desde a criação do container
+    age: f32,
+    cpu: f32,
+    memory: f32,
+}
+
+#[derive(Debug, Clone)]
+struct Deployment {
+    container_id: String,
+    image: String,
+    started: DateTime<Local>,
+    samples: VecDeque<DeploySample>,
+}
+
+impl Deployment {
+    fn new(container_id: &str, image: &str, created: i64) -> Self {
+        Self {
+            container_id: container_id.to_string(),
+            image: image.to_string(),
+            started: Local
+                .timestamp_opt(created, 0)
+                .single()
+                .unwrap_or_else(Local::now),
+            samples: VecDeque::new(),
+        }
+    }
+
+    // Duração coberta pelas amostras, em segundos desde a criação
+    fn span(&self) -> f32 {
+        self.samples.back().map_or(0.0, |sample| sample.age)
+    }
+}
+
+struct History {
+    current: Deployment,
+    previous: Option<Deployment>,
+    last_sample: Instant,
+    // Incrementa a cada amostra; a interface só redesenha quando muda
+    revision: u64,
+}
+
+// Métrica exibida no gráfico de comparação
+#[derive(Debug, Clone, Copy)]
+pub enum DeployMetric {
+    Cpu,
+    Memory,
+}
+
+impl DeployMetric {
+    fn value(self, sample: &DeploySample) -> f32 {
+        match self {
+            DeployMetric::Cpu => sample.cpu,
+            DeployMetric::Memory => sample.memory,
+        }
+    }
+}
+
+// Cópia do histórico de um container para desenhar fora do lock do DockerManager
+#[derive(Debug, Clone)]
+pub struct DeployComparison {
+    current: Deployment,
+    previous: Option<Deployment>,
+    pub revision: u64,
+}
+
+// Séries em minutos relativos ao último deploy (negativo = antes do recreate)
+pub struct DeploySeries {
+    pub current: Vec<(f32, f32)>,
+    // Final do deploy anterior, antes do marcador
+    pub before: Vec<(f32, f32)>,
+    // Deploy anterior na mesma idade do atual, para sobrepor
+    pub overlay: Vec<(f32, f32)>,
+}
+
+impl DeployComparison {
+    pub fn series(&self, metric: DeployMetric) -> DeploySeries {
+        let minutes = |seconds: f32| seconds / 60.0;
+        let current: Vec<(f32, f32)> = self
+            .current
+            .samples
+            .iter()
+            .map(|sample| (minutes(sample.age), metric.value(sample)))
+            .collect();
+        let Some(previous) = &self.previous else {
+            return DeploySeries {
+                current,
+                before: Vec::new(),
+                overlay: Vec::new(),
+            };
+        };
+
+        // Antes do marcador mostra o mesmo tanto de tempo que já passou desde o deploy
+        let window = self.current.span().max(5.0 * 60.0);
+        let offset = (previous.started - self.current.started).num_seconds() as f32;
+        let before = previous
+            .samples
+            .iter()
+            .map(|sample| (minutes(offset + sample.age), metric.value(sample)))
+            .filter(|(x, _)| *x <= 0.0 && *x >= -minutes(window))
+            .collect();
+        let overlay = previous
+            .samples
+            .iter()
+            .filter(|sample| sample.age <= window)
+            .map(|sample| (minutes(sample.age), metric.value(sample)))
+            .collect();
+        DeploySeries {
+            current,
+            before,
+            overlay,
+        }
+    }
+
+    // Últimas amostras do deploy atual (widget de memória do dashboard)
+    pub fn recent(&self, metric: DeployMetric, count: usize) -> Vec<f32> {
+        let samples = &self.current.samples;
+        samples
+            .iter()
+            .skip(samples.len().saturating_sub(count))
+            .map(|sample| metric.value(sample))
+            .collect()
+    }
+
+    // Ex: "Deploy em 16/10 14:32 · nginx:1.25 → nginx:1.27"
+    pub fn marker(&self) -> String {
+        let time = self.current.started.format("%d/%m %H:%M");
+        match &self.previous {
+            Some(previous) if previous.image != self.current.image => format!(
+                "Deploy em {} · {} → {}",
+                time, previous.image, self.current.image
+            ),
+            Some(_) => format!("Deploy em {} · mesma imagem {}", time, self.current.image),
+            None => format!("Criado em {} · {}", time, self.current.image),
+        }
+    }
+
+    // Médias do deploy atual contra o anterior na mesma idade, ex:
+    // "Primeiros 12min: CPU 8.2% (antes 6.1%, +2.1) · memória 31.0% (antes 30.2%, +0.8)"
+    pub fn summary(&self) -> String {
+        let Some(previous) = &self.previous else {
+            return "Nenhum recreate observado desde que o servidor foi aberto: a comparação aparece a partir do próximo deploy".to_string();
+        };
+        let span = self.current.span();
+        if self.current.samples.is_empty() {
+            return "Coletando amostras do deploy atual...".to_string();
+        }
+        let same_age: Vec<DeploySample> = previous
+            .samples
+            .iter()
+            .filter(|sample| sample.age <= span)
+            .copied()
+            .collect();
+        if same_age.is_empty() {
+            return "O deploy anterior não tem amostras da mesma idade para comparar".to_string();
+        }
+
+        let average = |samples: &[DeploySample], metric: DeployMetric| {
+            samples
+                .iter()
+                .map(|sample| metric.value(sample))
+                .sum::<f32>()
+                / samples.len() as f32
+        };
+        let current: Vec<DeploySample> = self.current.samples.iter().copied().collect();
+        let describe = |label: &str, metric: DeployMetric| {
+            let now = average(&current, metric);
+            let before = average(&same_age, metric);
+            format!(
+                "{} {:.1}% (antes {:.1}%, {:+.1})",
+                label,
+                now,
+                before,
+                now - before
+            )
+        };
+        format!(
+            "Primeiros {}: {} · {}",
+            format_span(span),
+            describe("CPU", DeployMetric::Cpu),
+            describe("memória", DeployMetric::Memory)
+        )
+    }
+}
+
+// Amostras por nome de container, guardando o deploy atual e o anterior
+#[derive(Default)]
+pub struct DeployHistoryTracker {
+    history: HashMap<String, History>,
+}
+
+impl DeployHistoryTracker {
+    pub fn record(
+        &mut self,
+        name: &str,
+        container_id: &str,
+        image: &str,
+        created: i64,
+        cpu: f32,
+        memory: f32,
+    ) {
+        let now = Instant::now();
+        let history = self
+            .history
+            .entry(name.to_string())
+            .or_insert_with(|| History {
+                current: Deployment::new(container_id, image, created),
+                previous: None,
+                last_sample: now - SAMPLE_INTERVAL,
+                revision: 0,
+            });
+
+        // Id novo com o mesmo nome: recreate (compose up, redeploy)
+        if history.current.container_id != container_id {
+            let current = Deployment::new(container_id, image, created);
+            history.previous = Some(std::mem::replace(&mut history.current, current));
+            history.last_sample = now - SAMPLE_INTERVAL;
+        }
+        if now.duration_since(history.last_sample) < SAMPLE_INTERVAL {
+            return;
+        }
+        history.last_sample = now;
+        history.revision += 1;
+
+        let age = (Local::now() - history.current.started)
+            .num_seconds()
+            .max(0) as f32;
+        let samples = &mut history.current.samples;
+        samples.push_back(DeploySample { age, cpu, memory });
+        if samples.len() > MAX_SAMPLES {
+            samples.pop_front();
+        }
+    }
+
+    // Esquece containers removidos há tempo demais para um recreate
+    pub fn forget_stale(&mut self) {
+        let now = Instant::now();
+        self.history
+            .retain(|_, history| now.duration_since(history.last_sample) < FORGET_AFTER);
+    }
+
+    pub fn container_count(&self) -> usize {
+        self.history.len()
+    }
+
+    pub fn comparison(&self, name: &str) -> Option<DeployComparison> {
+        let history = self.history.get(name)?;
+        Some(DeployComparison {
+            current: history.current.clone(),
+            previous: history.previous.clone(),
+            revision: history.revision,
+        })
+    }
+}
+
+// Ex: "45s", "12min", "1h 30min"
+fn format_span(seconds: f32) -> String {
+    let seconds = seconds as u64;
+    if seconds < 60 {
+        format!("{}s", seconds)
+    } else if seconds < 3600 {
+        format!("{}min", seconds / 60)
+    } else {
+        format!("{}h {}min", seconds / 3600, (seconds / 60) % 60)
+    }
+}
@@ -0,0 +1,85 @@
+// Espaço livre no sistema de arquivos do data-root do Docker (`df` local ou via SSH), com
+// aviso quando o uso passa do limite configurado
+use anyhow::{Context, Result, bail};
+
+// Limite padrão de uso, em porcentagem
+pub const DEFAULT_THRESHOLD: u8 = 85;
+// O aviso só volta a disparar depois que o uso cai esta margem abaixo do limite
+const REARM_MARGIN: f64 = 5.0;
+
+#[derive(Debug, Clone, Default)]
+pub struct DiskUsage {
+    pub total: u64,
+    pub used: u64,
+    pub available: u64,
+    // Ponto de montagem que contém o data-root
+    pub mount: String,
+}
+
+impl DiskUsage {
+    pub fn percent(&self) -> f64 {
+        // Como o df: usado / (usado + disponível), ignorando o espaço reservado ao root
+        let usable = self.used + self.available;
+        if usable == 0 {
+            return 0.0;
+        }
+        self.used as f64 / usable as f64 * 100.0
+    }
+}
+
+// Saída de `df -Pk <caminho>`: cabeçalho e uma linha com blocos de 1K
+pub fn parse_df(output: &str) -> Result<DiskUsage> {
+    let line = output
+        .lines()
+        .skip(1)
+        .find(|line| !line.trim().is_empty())
+        .context("Saída do df sem dados")?;
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    if fields.len() < 6 {
+        bail!("Saída do df inesperada: {}", line.trim());
+    }
+    let kib = |value: &str| -> Result<u64> {
+        value
+            .parse::<u64>()
+            .map(|blocks| blocks * 1024)
+            .with_context(|| format!("Valor inválido no df: {}", value))
+    };
+    Ok(DiskUsage {
+        total: kib(fields[1])?,
+        used: kib(fields[2])?,
+        available: kib(fields[3])?,
+        // O ponto de montagem pode ter espaços
+        mount: fields[5..].join(" "),
+    })
+}
+
+// Limite efetivo (0 na configuração = padrão)
+pub fn threshold(configured: u8) -> u8 {
+    if configured == 0 {
+        DEFAULT_THRESHOLD
+    } else {
+        configured.min(99)
+    }
+}
+
+// Estado do aviso de um servidor: avisa uma vez ao cruzar o limite
+#[derive(Debug, Default)]
+pub struct DiskWatch {
+    warned: bool,
+}
+
+impl DiskWatch {
+    // true quando o uso acabou de passar do limite
+    pub fn crossed(&mut self, percent: f64, threshold: u8) -> bool {
+        let threshold = threshold as f64;
+        if percent >= threshold {
+            let crossed = !self.warned;
+            self.warned = true;
+            return crossed;
+        }
+        if percent < threshold - REARM_MARGIN {
+            self.warned = false;
+        }
+        false
+    }
+}
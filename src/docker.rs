@@ -1,7 +1,32 @@
 // Imports para gerenciamento do Docker
 use anyhow::{Context, Result};
+use crate::build::{self, BuildRequest, BuilderSetup};
+use crate::compose::{self, ComposeBinary, ComposeCli, ComposeContainer, ComposeStack, StackDiff};
+use crate::compose_export::ExportSource;
+use crate::config::AppConfig;
+use crate::container_groups::{LabelSelector, Readiness};
+use crate::credentials::{self, CredentialSelection};
+use crate::format;
+use crate::image_provenance::ImageProvenance;
+use crate::ip_allocation::{self, SubnetUsage};
+use crate::deploy_history::{DeployComparison, DeployHistoryTracker};
+use crate::memory_trend::{MemoryTrend, MemoryTrendTracker};
+use crate::metrics_source::{MetricsFetcher, MetricsHistory, MetricsSource};
+use crate::network_create::NetworkCreateRequest;
+use crate::pending_changes;
+use crate::prune::{self, PruneTarget};
+use crate::pull_estimate::{self, ImageReference, PullEstimate};
+use crate::registry;
+use crate::resource_limits::{self, DeviceRate, IoLimits};
+use crate::host_address::HostAddress;
+use crate::ssh::SshClient;
+use crate::session_recording::{self, SessionEvent};
+use crate::snapshot::{ServerSnapshot, SnapshotRecorder};
+use crate::swarm::{self, SwarmNode};
+use crate::volume_create::VolumeCreateRequest;
+use crate::volume_migration;
 use bollard::{
-    Docker,
+    API_DEFAULT_VERSION, Docker,
     models::ContainerCreateBody,
     models::{ContainerStatsResponse, ImageSummary},
     query_parameters::CreateContainerOptions,
@@ -14,16 +39,21 @@ use chrono;
 use futures_util::TryStreamExt;
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     fmt,
-    process::Command,
+    io::Write,
+    process::{Command, Stdio},
+    sync::OnceLock,
     time::{SystemTime, UNIX_EPOCH},
 };
 
 // Informações básicas de um container
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContainerInfo {
     pub id: String,
+    // Contexto (servidor) de onde o container foi listado
+    #[serde(default)]
+    pub server: String,
     pub name: String,
     pub image: String,
     pub state: String,
@@ -32,7 +62,7 @@ pub struct ContainerInfo {
     pub created: i64,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImageInfo {
     pub id: String,
     pub tags: Vec<String>,
@@ -41,7 +71,7 @@ pub struct ImageInfo {
     pub in_use: bool,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NetworkInfo {
     pub id: String,
     pub name: String,
@@ -52,7 +82,7 @@ pub struct NetworkInfo {
     pub is_system: bool,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VolumeInfo {
     pub name: String,
     pub driver: String,
@@ -61,6 +91,20 @@ pub struct VolumeInfo {
     pub containers_count: i32,
 }
 
+// Entrada do cache de build (docker system df -v)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildCacheEntry {
+    pub id: String,
+    pub cache_type: String,
+    pub size: String,
+    pub created_since: String,
+    pub last_used_since: String,
+    pub usage_count: i32,
+    pub in_use: bool,
+    pub shared: bool,
+    pub description: String,
+}
+
 // Status possíveis do Docker
 #[derive(Debug, Serialize, Deserialize)]
 pub enum DockerStatus {
@@ -81,6 +125,58 @@ impl fmt::Display for DockerStatus {
     }
 }
 
+// Falhas de pull que a interface trata de forma própria
+#[derive(Debug)]
+pub enum PullError {
+    // Docker Hub recusou o pull pelo limite de pulls; `retry_after` é a renovação estimada
+    // da cota no host do daemon, quando já consultada
+    RateLimited {
+        image: String,
+        retry_after: Option<chrono::DateTime<chrono::Local>>,
+    },
+}
+
+impl fmt::Display for PullError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PullError::RateLimited { image, retry_after } => {
+                write!(f, "Limite de pulls do Docker Hub atingido ao baixar '{}'. ", image)?;
+                match retry_after {
+                    Some(time) => write!(f, "Tente novamente às {}. ", time.format("%H:%M"))?,
+                    None => write!(f, "Tente novamente mais tarde. ")?,
+                }
+                write!(f, "Faça login para aumentar o limite.")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PullError {}
+
+// Erro do daemon durante o pull; o limite do Docker Hub (HTTP 429 ou "toomanyrequests" no
+// stream de progresso) vira PullError::RateLimited para quem chamou reconhecê-lo
+fn pull_error(image_name: &str, error: bollard::errors::Error) -> anyhow::Error {
+    let too_many_requests = matches!(
+        error,
+        bollard::errors::Error::DockerResponseServerError {
+            status_code: 429,
+            ..
+        }
+    );
+    if too_many_requests || registry::is_rate_limit_error(&error.to_string()) {
+        return PullError::RateLimited {
+            image: image_name.to_string(),
+            retry_after: None,
+        }
+        .into();
+    }
+    anyhow::anyhow!("Falha ao fazer pull da imagem '{}': {}", image_name, error)
+}
+
+// Deltas sem atualização há mais que isso são descartados (ex: detalhes de um container
+// aberto uma vez com a coleta geral desligada pela economia de banda)
+const PREVIOUS_STATS_MAX_AGE_SECS: u64 = 300;
+
 // Cache para estatísticas anteriores (necessário para cálculo de delta)
 #[derive(Debug, Clone)]
 #[allow(dead_code)] // Alguns campos podem ser usados no futuro
@@ -94,14 +190,272 @@ struct PreviousStats {
     block_write: u64,
 }
 
+// Containers em cada cache do gerenciador (painel de diagnóstico)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CacheSizes {
+    pub previous_stats: usize,
+    pub memory_trends: usize,
+    pub deploy_history: usize,
+}
+
+// Configuração de um contexto Docker (servidor local ou remoto)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DockerContextConfig {
+    pub name: String,
+    pub host: String,
+    pub description: String,
+}
+
+// Entrada retornada por `docker context ls --format '{{json .}}'`
+#[derive(Debug, Deserialize)]
+struct DockerContextEntry {
+    #[serde(rename = "Name")]
+    name: String,
+    #[serde(rename = "Description", default)]
+    description: String,
+    #[serde(rename = "DockerEndpoint", default)]
+    docker_endpoint: String,
+    #[serde(rename = "Current", default)]
+    current: bool,
+}
+
+impl DockerContextConfig {
+    // Contexto local padrão (socket unix)
+    pub fn local_default() -> Self {
+        Self {
+            name: "default".to_string(),
+            host: "unix:///var/run/docker.sock".to_string(),
+            description: "Docker local".to_string(),
+        }
+    }
+
+    // Indica se o daemon está em outra máquina
+    pub fn is_remote(&self) -> bool {
+        !(self.host.starts_with("unix://") || self.host.starts_with("npipe://"))
+    }
+
+    // Host exibido no cabeçalho (sem esquema e usuário)
+    pub fn display_host(&self) -> String {
+        if !self.is_remote() {
+            return "localhost".to_string();
+        }
+        match self.address() {
+            Some(address) => address.authority(),
+            None => self.authority().to_string(),
+        }
+    }
+
+    // Host usado nos links das portas publicadas (IPv6 entre colchetes)
+    pub fn url_host(&self) -> String {
+        if !self.is_remote() {
+            return "localhost".to_string();
+        }
+        self.address()
+            .map(|address| address.url_host())
+            .unwrap_or_else(|| self.authority().to_string())
+    }
+
+    fn address(&self) -> Option<HostAddress> {
+        HostAddress::parse(self.authority()).ok()
+    }
+
+    // Confere o endpoint do formulário de servidores; ssh:// e tcp:// exigem host e porta
+    // válidos (IPv6 entre colchetes quando há porta)
+    pub fn validate_host(host: &str) -> Result<()> {
+        let host = host.trim();
+        let Some((scheme, rest)) = host.split_once("://") else {
+            return Err(anyhow::anyhow!(
+                "Informe o esquema do endpoint: ssh://, tcp://, unix:// ou npipe://"
+            ));
+        };
+        match scheme {
+            "ssh" | "tcp" => {
+                let context = Self {
+                    name: String::new(),
+                    host: host.to_string(),
+                    description: String::new(),
+                };
+                HostAddress::parse(context.authority())?;
+                Ok(())
+            }
+            "unix" | "npipe" if rest.is_empty() => {
+                Err(anyhow::anyhow!("Informe o caminho do socket em {}", host))
+            }
+            "unix" | "npipe" => Ok(()),
+            _ => Err(anyhow::anyhow!("Esquema não suportado: {}://", scheme)),
+        }
+    }
+
+    // Cria o contexto no Docker CLI ou atualiza o que já tem esse nome
+    pub fn save(&self) -> Result<()> {
+        if self.name.trim().is_empty() {
+            return Err(anyhow::anyhow!("Informe o nome do servidor"));
+        }
+        Self::validate_host(&self.host)?;
+
+        let exists = Self::list_entries()
+            .iter()
+            .any(|entry| entry.name == self.name);
+        let output = Command::new("docker")
+            .args(["context", if exists { "update" } else { "create" }])
+            .arg(self.name.trim())
+            .arg("--docker")
+            .arg(format!("host={}", self.host.trim()))
+            .arg("--description")
+            .arg(self.description.trim())
+            .output()
+            .context("Falha ao executar docker context")?;
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "{}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        Ok(())
+    }
+
+    // Endpoint sem esquema, usuário e caminho
+    fn authority(&self) -> &str {
+        let without_scheme = self
+            .host
+            .split_once("://")
+            .map(|(_, rest)| rest)
+            .unwrap_or(&self.host);
+        let without_path = without_scheme
+            .split_once('/')
+            .map(|(authority, _)| authority)
+            .unwrap_or(without_scheme);
+        without_path
+            .rsplit_once('@')
+            .map(|(_, host)| host)
+            .unwrap_or(without_path)
+    }
+
+    // Lista contextos configurados no Docker CLI
+    pub fn list_available() -> Vec<Self> {
+        Self::list_entries()
+            .into_iter()
+            .map(|entry| Self {
+                name: entry.name,
+                host: entry.docker_endpoint,
+                description: entry.description,
+            })
+            .collect()
+    }
+
+    // Contexto ativo: DOCKER_HOST tem prioridade sobre o contexto atual do CLI
+    pub fn current() -> Self {
+        if let Ok(host) = std::env::var("DOCKER_HOST") {
+            if !host.trim().is_empty() {
+                return Self {
+                    name: "DOCKER_HOST".to_string(),
+                    host,
+                    description: "Definido pela variável DOCKER_HOST".to_string(),
+                };
+            }
+        }
+
+        Self::list_entries()
+            .into_iter()
+            .find(|entry| entry.current)
+            .map(|entry| Self {
+                name: entry.name,
+                host: entry.docker_endpoint,
+                description: entry.description,
+            })
+            .unwrap_or_else(Self::local_default)
+    }
+
+    fn list_entries() -> Vec<DockerContextEntry> {
+        let output = Command::new("docker")
+            .args(["context", "ls", "--format", "{{json .}}"])
+            .output();
+
+        match output {
+            Ok(output) if output.status.success() => String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .filter_map(|line| serde_json::from_str::<DockerContextEntry>(line).ok())
+                .filter(|entry| !entry.docker_endpoint.is_empty())
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
 // Gerenciador principal do Docker
 pub struct DockerManager {
     docker: Docker,
     previous_stats: HashMap<String, PreviousStats>,
+    context: DockerContextConfig,
+    // Endpoint efetivo usado pelo CLI (socket do túnel em contextos SSH)
+    cli_host: String,
+    // Mantém o túnel SSH aberto enquanto o gerenciador existir
+    ssh_client: Option<SshClient>,
+    // Fuso horário e diferença de relógio do servidor, detectados ao conectar
+    remote_clock: Option<RemoteClock>,
+    // Último estado conhecido, exibido quando o servidor está inacessível
+    snapshot: std::sync::Mutex<SnapshotRecorder>,
+    // Amostras de memória por container para estimar vazamentos
+    memory_trends: MemoryTrendTracker,
+    // CPU/memória por nome de container, do deploy atual e do anterior ao último recreate
+    deploy_history: DeployHistoryTracker,
+    // Prometheus configurado para o servidor; substitui o docker stats nos gráficos
+    metrics: Option<MetricsFetcher>,
+    // Compose detectado no primeiro uso das stacks (None = nenhum disponível)
+    compose: OnceLock<Option<ComposeCli>>,
+}
+
+// Fuso horário do servidor e diferença do seu relógio em relação ao local
+#[derive(Debug, Clone, Copy)]
+pub struct RemoteClock {
+    pub offset: chrono::FixedOffset,
+    pub skew_secs: i64,
+}
+
+impl RemoteClock {
+    // SystemTime do `docker info` vem em RFC 3339 com o offset do servidor
+    fn from_system_time(system_time: &str) -> Option<Self> {
+        let remote_time = chrono::DateTime::parse_from_rfc3339(system_time).ok()?;
+        let skew = remote_time.with_timezone(&chrono::Utc) - chrono::Utc::now();
+        Some(RemoteClock {
+            offset: *remote_time.offset(),
+            skew_secs: skew.num_seconds(),
+        })
+    }
+
+    // Ex: "UTC+02:00 · relógio adiantado 42s"
+    pub fn summary(&self) -> String {
+        let zone = format!("UTC{}", self.offset);
+        if self.skew_secs >= 2 {
+            format!("{} · relógio adiantado {}s", zone, self.skew_secs)
+        } else if self.skew_secs <= -2 {
+            format!("{} · relógio atrasado {}s", zone, -self.skew_secs)
+        } else {
+            zone
+        }
+    }
+}
+
+// Fuso usado para exibir os timestamps dos logs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogTimeMode {
+    Remote,
+    Local,
+    Utc,
+}
+
+impl LogTimeMode {
+    pub fn from_ui(value: &str) -> Self {
+        match value {
+            "local" => LogTimeMode::Local,
+            "utc" => LogTimeMode::Utc,
+            _ => LogTimeMode::Remote,
+        }
+    }
 }
 
 // Informações gerais do sistema Docker
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DockerInfo {
     pub version: String,
     pub containers: i64,
@@ -110,6 +464,16 @@ pub struct DockerInfo {
     pub containers_stopped: i64,
     pub images: i64,
     pub architecture: String,
+    // Fuso e diferença de relógio do servidor (vazio se não detectado)
+    pub server_clock: String,
+    // Storage driver (ex: overlay2) e diretório de dados do daemon
+    #[serde(default)]
+    pub storage_driver: String,
+    #[serde(default)]
+    pub data_root: String,
+    // registry-mirrors do daemon.json do servidor
+    #[serde(default)]
+    pub registry_mirrors: Vec<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -158,6 +522,56 @@ pub struct CreateContainerRequest {
     pub environment: Vec<EnvVar>,
     pub command: Option<String>,
     pub restart_policy: String,
+    // Credencial usada no pull: "auto", "none" ou nome salvo
+    pub registry_credential: String,
+    // Cria mesmo se a arquitetura da imagem não for a do servidor
+    pub allow_arch_mismatch: bool,
+    pub pull_policy: PullPolicy,
+    // CPUs e nós de memória permitidos ("0-3,8"); vazio = sem restrição
+    pub cpuset_cpus: String,
+    pub cpuset_mems: String,
+    // Throttling de I/O: peso (10-1000) e "dispositivo:taxa" de leitura/escrita
+    pub blkio_weight: String,
+    pub device_read_bps: String,
+    pub device_write_bps: String,
+}
+
+// Quando baixar imagens ao criar containers e implantar stacks
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PullPolicy {
+    Always,
+    #[default]
+    IfNotPresent,
+    Never,
+}
+
+impl PullPolicy {
+    // Valores usados pela interface: "always", "if-not-present" ou "never"
+    pub fn from_ui(value: &str) -> Self {
+        match value {
+            "always" => PullPolicy::Always,
+            "never" => PullPolicy::Never,
+            _ => PullPolicy::IfNotPresent,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PullPolicy::Always => "always",
+            PullPolicy::IfNotPresent => "if-not-present",
+            PullPolicy::Never => "never",
+        }
+    }
+
+    // Valor equivalente de `docker compose up --pull`
+    fn compose_flag(&self) -> &'static str {
+        match self {
+            PullPolicy::Always => "always",
+            PullPolicy::IfNotPresent => "missing",
+            PullPolicy::Never => "never",
+        }
+    }
 }
 
 // Mapeamento de portas
@@ -184,17 +598,114 @@ pub struct EnvVar {
 }
 
 impl DockerManager {
-    // Cria nova instância conectando ao Docker daemon
+    // Cria nova instância conectando ao Docker daemon do contexto ativo
     pub async fn new() -> Result<Self> {
-        let docker = Docker::connect_with_socket_defaults()
-            .context("Falha ao conectar com Docker daemon")?;
+        Self::with_context(DockerContextConfig::current()).await
+    }
+
+    // Cria instância conectada a um contexto específico
+    pub async fn with_context(context: DockerContextConfig) -> Result<Self> {
+        let mut ssh_client = None;
+
+        let (docker, cli_host) = if context.host.starts_with("ssh://") {
+            // Daemons via SSH são acessados por um túnel para o socket remoto
+            let config = AppConfig::load();
+            let mut client = SshClient::new(
+                &context.name,
+                &context.host,
+                config.proxy_for_server(&context.name),
+                config.ssh_options_for(&context.name),
+            )?;
+            let socket_path = client.open_tunnel().await?;
+            let socket = socket_path.to_string_lossy().to_string();
+            let docker = Docker::connect_with_unix(&socket, 120, API_DEFAULT_VERSION)
+                .context("Falha ao conectar com Docker daemon via SSH")?;
+            ssh_client = Some(client);
+            (docker, format!("unix://{}", socket))
+        } else if context.host.starts_with("tcp://") || context.host.starts_with("http") {
+            let docker = Docker::connect_with_http(&context.host, 120, API_DEFAULT_VERSION)
+                .context("Falha ao conectar com Docker daemon remoto")?;
+            (docker, context.host.clone())
+        } else if let Some(path) = context.host.strip_prefix("unix://") {
+            let docker = Docker::connect_with_unix(path, 120, API_DEFAULT_VERSION)
+                .context("Falha ao conectar com Docker daemon")?;
+            (docker, context.host.clone())
+        } else {
+            let docker = Docker::connect_with_socket_defaults()
+                .context("Falha ao conectar com Docker daemon")?;
+            (docker, context.host.clone())
+        };
+
+        let remote_clock = docker
+            .info()
+            .await
+            .ok()
+            .and_then(|info| info.system_time)
+            .and_then(|system_time| RemoteClock::from_system_time(&system_time));
+
+        let config = AppConfig::load();
+        let metrics = config
+            .metrics_sources
+            .get(&context.name)
+            .map(|source| MetricsFetcher::new(source.clone(), config.global_proxy()));
 
         Ok(DockerManager {
             docker,
             previous_stats: HashMap::new(),
+            snapshot: std::sync::Mutex::new(SnapshotRecorder::new(&context.name)),
+            memory_trends: MemoryTrendTracker::default(),
+            deploy_history: DeployHistoryTracker::default(),
+            metrics,
+            context,
+            cli_host,
+            ssh_client,
+            remote_clock,
+            compose: OnceLock::new(),
         })
     }
 
+    // Contexto ao qual este gerenciador está conectado
+    pub fn context(&self) -> &DockerContextConfig {
+        &self.context
+    }
+
+    // Refaz a conexão com o mesmo servidor (túnel SSH, cache de CPU e tendências)
+    pub async fn reconnect(&mut self) -> Result<()> {
+        // Fecha o túnel antigo antes de abrir outro no mesmo socket
+        self.ssh_client = None;
+        *self = Self::with_context(self.context.clone()).await?;
+        Ok(())
+    }
+
+    // Conecta a outro servidor pelo nome do contexto
+    pub async fn for_context_name(name: &str) -> Result<Self> {
+        let context = DockerContextConfig::list_available()
+            .into_iter()
+            .find(|context| context.name == name)
+            .ok_or_else(|| anyhow::anyhow!("Servidor '{}' não encontrado", name))?;
+        Self::with_context(context).await
+    }
+
+    // Atualiza o snapshot em disco do servidor
+    fn record_snapshot(&self, update: impl FnOnce(&mut ServerSnapshot)) {
+        if let Ok(mut recorder) = self.snapshot.lock() {
+            recorder.record(update);
+        }
+    }
+
+    // Comando do Docker CLI apontando para o mesmo daemon da API
+    fn cli(&self) -> Command {
+        self.cli_program("docker")
+    }
+
+    // Programa local (docker ou docker-compose) apontando para o mesmo daemon da API
+    fn cli_program(&self, program: &str) -> Command {
+        let mut command = Command::new(program);
+        command.env_remove("DOCKER_CONTEXT");
+        command.env("DOCKER_HOST", &self.cli_host);
+        command
+    }
+
     // Verifica se Docker daemon está respondendo
     // pub async fn is_docker_running(&self) -> Result<bool> {
     //     match self.docker.ping().await {
@@ -208,7 +719,7 @@ impl DockerManager {
 
     // Verifica status do Docker via linha de comando
     pub fn check_docker_status(&self) -> DockerStatus {
-        let docker_version = Command::new("docker").arg("--version").output();
+        let docker_version = self.cli().arg("--version").output();
 
         match docker_version {
             Ok(output) => {
@@ -221,7 +732,7 @@ impl DockerManager {
             }
         }
 
-        let docker_info = Command::new("docker").arg("info").output();
+        let docker_info = self.cli().arg("info").output();
 
         match docker_info {
             Ok(output) => {
@@ -267,7 +778,7 @@ impl DockerManager {
             .await
             .context("Falha ao obter informações do Docker")?;
 
-        Ok(DockerInfo {
+        let docker_info = DockerInfo {
             version: version.version.unwrap_or_default(),
             containers: info.containers.unwrap_or(0),
             containers_paused: info.containers_paused.unwrap_or(0),
@@ -275,7 +786,20 @@ impl DockerManager {
             containers_stopped: info.containers_stopped.unwrap_or(0),
             images: info.images.unwrap_or(0),
             architecture: version.arch.unwrap_or_default(),
-        })
+            server_clock: self
+                .remote_clock
+                .map(|clock| clock.summary())
+                .unwrap_or_default(),
+            storage_driver: info.driver.unwrap_or_default(),
+            data_root: info.docker_root_dir.unwrap_or_default(),
+            registry_mirrors: info
+                .registry_config
+                .and_then(|config| config.mirrors)
+                .unwrap_or_default(),
+        };
+
+        self.record_snapshot(|snapshot| snapshot.info = Some(docker_info.clone()));
+        Ok(docker_info)
     }
 
     // Lista todos os containers (ativos e parados)
@@ -293,6 +817,7 @@ impl DockerManager {
             .into_iter()
             .map(|container| ContainerInfo {
                 id: container.id.unwrap_or_default(),
+                server: self.context.name.clone(),
                 name: container
                     .names
                     .unwrap_or_default()
@@ -314,12 +839,69 @@ impl DockerManager {
             })
             .collect();
 
+        self.record_snapshot(|snapshot| snapshot.containers = container_infos.clone());
         Ok(container_infos)
     }
 
+    // `docker container inspect` completo dos containers, em uma só chamada
+    pub async fn inspect_containers(&self, ids: &[String]) -> Result<Vec<serde_json::Value>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let output = self
+            .cli()
+            .args(["container", "inspect"])
+            .args(ids)
+            .output()
+            .context("Falha ao executar docker container inspect")?;
+        // Um container removido no meio da chamada só some da lista
+        let containers: Vec<serde_json::Value> =
+            serde_json::from_slice(&output.stdout).unwrap_or_default();
+        if containers.is_empty() && !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "Falha ao inspecionar containers: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        Ok(containers)
+    }
+
+    // ID atual de cada imagem local por referência normalizada (ex: "nginx:latest")
+    pub async fn image_ids_by_reference(&self) -> Result<HashMap<String, String>> {
+        let output = self
+            .cli()
+            .args([
+                "image",
+                "ls",
+                "--no-trunc",
+                "--format",
+                "{{.Repository}}:{{.Tag}}\t{{.ID}}",
+            ])
+            .output()
+            .context("Falha ao executar docker image ls")?;
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "Falha ao listar imagens: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| line.split_once('\t'))
+            .filter(|(reference, _)| !reference.contains("<none>"))
+            .map(|(reference, id)| {
+                (
+                    pending_changes::normalize_reference(reference),
+                    id.trim().to_string(),
+                )
+            })
+            .collect())
+    }
+
     // Inicia um container
     pub async fn start_container(&self, container_name: &str) -> Result<()> {
-        let output = Command::new("docker")
+        let output = self
+            .cli()
             .args(&["start", container_name])
             .output()
             .context("Failed to execute docker start command")?;
@@ -335,6 +917,73 @@ impl DockerManager {
         Ok(())
     }
 
+    // Estado e health do container para a inicialização de grupos
+    pub async fn container_readiness(&self, container_name: &str) -> Result<Readiness> {
+        let output = self
+            .cli()
+            .args(&[
+                "inspect",
+                "--format",
+                "{{.State.Status}} {{if .State.Health}}{{.State.Health.Status}}{{end}}",
+                container_name,
+            ])
+            .output()
+            .context("Failed to execute docker inspect command")?;
+
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "Container '{}' não encontrado: {}",
+                container_name,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        Ok(Readiness::from_inspect(&String::from_utf8_lossy(&output.stdout)))
+    }
+
+    // Dados de inspect dos containers (e das imagens deles) para exportar como compose
+    pub async fn inspect_for_export(&self, container_names: &[String]) -> Result<Vec<ExportSource>> {
+        let output = self
+            .cli()
+            .arg("inspect")
+            .args(container_names)
+            .output()
+            .context("Failed to execute docker inspect command")?;
+
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "Falha ao inspecionar containers: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        let containers: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout)
+            .context("Resposta inválida do docker inspect")?;
+
+        let mut sources = Vec::new();
+        for container in containers {
+            // Sem a imagem local, os valores herdados dela também são exportados
+            let image = container
+                .pointer("/Image")
+                .and_then(|image| image.as_str())
+                .and_then(|image_id| self.image_inspect(image_id));
+            sources.push(ExportSource { container, image });
+        }
+
+        Ok(sources)
+    }
+
+    // `docker image inspect` de uma imagem local; None se ela não existir mais
+    fn image_inspect(&self, image_id: &str) -> Option<serde_json::Value> {
+        self.cli()
+            .args(["image", "inspect", image_id])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .and_then(|output| serde_json::from_slice::<Vec<serde_json::Value>>(&output.stdout).ok())
+            .and_then(|images| images.into_iter().next())
+    }
+
     // Lista todas as imagens
     pub async fn list_images(&self) -> Result<Vec<ImageInfo>> {
         let images = self
@@ -367,12 +1016,14 @@ impl DockerManager {
             tag_a.cmp(&tag_b)
         });
 
+        self.record_snapshot(|snapshot| snapshot.images = image_infos.clone());
         Ok(image_infos)
     }
 
     // deleta uma imagem
     pub async fn remove_image(&self, image_id: &str) -> Result<()> {
-        let output = Command::new("docker")
+        let output = self
+            .cli()
             .args(&["rmi", image_id])
             .output()
             .context("Failed to execute docker rmi command")?;
@@ -462,12 +1113,93 @@ impl DockerManager {
         // Ordena por nome para manter ordem consistente
         network_infos.sort_by(|a, b| a.name.cmp(&b.name));
 
+        self.record_snapshot(|snapshot| snapshot.networks = network_infos.clone());
         Ok(network_infos)
     }
 
+    // Endereços em uso e faixas livres de cada sub-rede da network
+    pub async fn network_ip_usage(&self, network_id: &str) -> Result<Vec<SubnetUsage>> {
+        let output = self
+            .cli()
+            .args(["network", "inspect", "--format", "{{json .}}", network_id])
+            .output()
+            .context("Falha ao executar docker network inspect")?;
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "Falha ao inspecionar network: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        let network: serde_json::Value = serde_json::from_slice(&output.stdout)
+            .context("Resposta inválida do docker network inspect")?;
+        Ok(ip_allocation::from_inspect(&network))
+    }
+
+    // Cria uma network (bridge, macvlan ou ipvlan) a partir do formulário
+    pub async fn create_network(&self, request: &NetworkCreateRequest) -> Result<()> {
+        request.validate()?;
+        let output = self
+            .cli()
+            .args(request.args())
+            .output()
+            .context("Falha ao executar docker network create")?;
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "Falha ao criar network: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        Ok(())
+    }
+
+    // Comando executado no host do daemon, localmente ou via SSH; None quando o daemon
+    // é remoto sem SSH (ex: tcp://). Executado fora do lock do gerenciador
+    pub fn host_command(&self, args: &[&str]) -> Option<Command> {
+        if let Some(client) = &self.ssh_client {
+            return Some(client.remote_command(args));
+        }
+        if self.context.is_remote() {
+            return None;
+        }
+        let (program, args) = args.split_first()?;
+        let mut command = Command::new(program);
+        command.args(args);
+        Some(command)
+    }
+
+    // Comandos da consulta da cota do Docker Hub no host do daemon, que é quem faz os pulls.
+    // O proxy global vale só para a máquina local. None quando o daemon é remoto sem SSH
+    pub fn hub_rate_limit_commands(&self) -> Option<(Command, Command)> {
+        let proxy = if self.context.is_remote() {
+            None
+        } else {
+            AppConfig::load().global_proxy()
+        };
+        let (token, headers) = registry::hub_rate_limit_args(proxy.as_ref());
+        let token: Vec<&str> = token.iter().map(String::as_str).collect();
+        let headers: Vec<&str> = headers.iter().map(String::as_str).collect();
+        Some((self.host_command(&token)?, self.host_command(&headers)?))
+    }
+
+    // `df` do sistema de arquivos que contém o data-root do daemon
+    pub fn data_root_usage_command(&self, data_root: &str) -> Option<Command> {
+        self.host_command(&["df", "-Pk", data_root])
+    }
+
+    // Comando que lista as interfaces do host do daemon
+    pub fn host_interfaces_command(&self) -> Result<Command> {
+        self.host_command(&["ip", "-o", "link", "show"]).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Interfaces só podem ser listadas em servidores locais ou via SSH; informe a interface pai manualmente"
+            )
+        })
+    }
+
     // Remove uma network
     pub async fn remove_network(&self, network_id: &str) -> Result<()> {
-        let output = Command::new("docker")
+        let output = self
+            .cli()
             .args(&["network", "rm", network_id])
             .output()
             .context("Failed to execute docker network rm command")?;
@@ -551,12 +1283,111 @@ impl DockerManager {
         // Ordena por nome para manter ordem consistente
         volume_infos.sort_by(|a, b| a.name.cmp(&b.name));
 
+        self.record_snapshot(|snapshot| snapshot.volumes = volume_infos.clone());
         Ok(volume_infos)
     }
 
+    // Cria um volume local ou montado de um compartilhamento NFS/CIFS
+    pub async fn create_volume(&self, request: &VolumeCreateRequest) -> Result<()> {
+        use bollard::models::VolumeCreateOptions;
+
+        request.validate()?;
+        // Pela API: as opções de montagem podem conter a senha CIFS
+        let driver_opts = request.driver_opts();
+        let options = VolumeCreateOptions {
+            name: Some(request.name.trim().to_string()),
+            driver: Some("local".to_string()),
+            driver_opts: (!driver_opts.is_empty()).then_some(driver_opts),
+            ..Default::default()
+        };
+        self.docker
+            .create_volume(options)
+            .await
+            .map_err(|e| anyhow::anyhow!("Falha ao criar volume: {}", e))?;
+        Ok(())
+    }
+
+    // Indica se o volume existe no servidor
+    pub async fn volume_exists(&self, volume_name: &str) -> Result<bool> {
+        let output = self
+            .cli()
+            .args(["volume", "inspect", volume_name])
+            .output()
+            .context("Falha ao executar docker volume inspect")?;
+        if output.status.success() {
+            return Ok(true);
+        }
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if stderr.to_lowercase().contains("no such volume") {
+            return Ok(false);
+        }
+        Err(anyhow::anyhow!(
+            "Falha ao inspecionar volume: {}",
+            stderr.trim()
+        ))
+    }
+
+    // Conteúdo do volume em tar na saída padrão (executado fora do lock do gerenciador)
+    pub fn volume_archive_command(&self, volume_name: &str) -> Command {
+        let mount = format!("{}:/volume:ro", volume_name);
+        let mut archive = self.cli();
+        archive.args([
+            "run",
+            "--rm",
+            "-v",
+            mount.as_str(),
+            volume_migration::HELPER_IMAGE,
+            "tar",
+            "-C",
+            "/volume",
+            "-cf",
+            "-",
+            ".",
+        ]);
+        archive
+    }
+
+    // Extrai no volume o tar recebido pela entrada padrão (executado fora do lock do gerenciador)
+    pub fn volume_restore_command(&self, volume_name: &str) -> Command {
+        let mount = format!("{}:/volume", volume_name);
+        let mut restore = self.cli();
+        restore.args([
+            "run",
+            "--rm",
+            "-i",
+            "-v",
+            mount.as_str(),
+            volume_migration::HELPER_IMAGE,
+            "tar",
+            "-C",
+            "/volume",
+            "-xf",
+            "-",
+        ]);
+        restore
+    }
+
+    // Checksum do conteúdo do volume (executado fora do lock do gerenciador)
+    pub fn volume_checksum_command(&self, volume_name: &str) -> Command {
+        let mount = format!("{}:/volume:ro", volume_name);
+        let mut checksum = self.cli();
+        checksum.args([
+            "run",
+            "--rm",
+            "-v",
+            mount.as_str(),
+            volume_migration::HELPER_IMAGE,
+            "sh",
+            "-c",
+            volume_migration::CHECKSUM_SCRIPT,
+        ]);
+        checksum
+    }
+
     // Remove um volume
     pub async fn remove_volume(&self, volume_name: &str) -> Result<()> {
-        let output = Command::new("docker")
+        let output = self
+            .cli()
             .args(&["volume", "rm", volume_name])
             .output()
             .context("Failed to execute docker volume rm command")?;
@@ -580,9 +1411,611 @@ impl DockerManager {
         Ok(())
     }
 
+    // Comando buildx apontando para o daemon ativo (executado fora do lock do gerenciador);
+    // builds multiplataforma usam o builder docker-container do servidor
+    pub fn buildx_command(&self, request: &BuildRequest) -> Command {
+        let builder = request
+            .is_multi_platform()
+            .then(|| build::builder_name(&self.context.name));
+        let mut command = self.cli();
+        command.args(request.args(builder.as_deref()));
+        command
+    }
+
+    // Comandos para criar o builder docker-container do servidor ativo
+    pub fn buildx_builder_setup(&self) -> BuilderSetup {
+        let name = build::builder_name(&self.context.name);
+        let mut inspect = self.cli();
+        inspect.args(["buildx", "inspect", &name]);
+        let mut remove = self.cli();
+        remove.args(["buildx", "rm", &name]);
+        let mut create = self.cli();
+        create.args(["buildx", "create", "--name", &name, "--driver", "docker-container"]);
+        create.arg(&self.cli_host);
+        BuilderSetup {
+            name,
+            endpoint: self.cli_host.clone(),
+            inspect,
+            remove,
+            create,
+        }
+    }
+
+    // Cliente da API para streams longos (ex: gravação de sessão, console exec) fora do lock do gerenciador
+    pub fn api_client(&self) -> Docker {
+        self.docker.clone()
+    }
+
+    // Eventos dos containers em JSON, a partir de agora (executado fora do lock do gerenciador)
+    pub fn container_events_command(&self, containers: &[String]) -> Command {
+        let mut events = self.cli();
+        events.args(["events", "--format", "{{json .}}", "--filter", "type=container"]);
+        for container in containers {
+            events.arg("--filter").arg(format!("container={}", container));
+        }
+        events
+    }
+
+    // Eventos de containers no intervalo [since, until) em segundos Unix; com --until o
+    // comando termina sozinho (feed de eventos do dashboard)
+    pub async fn container_events_between(
+        &self,
+        since: i64,
+        until: i64,
+    ) -> Result<Vec<SessionEvent>> {
+        let output = self
+            .cli()
+            .args(["events", "--format", "{{json .}}", "--filter", "type=container"])
+            .arg("--since")
+            .arg(since.to_string())
+            .arg("--until")
+            .arg(until.to_string())
+            .output()
+            .context("Falha ao executar docker events")?;
+
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "Falha ao ler eventos: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(session_recording::parse_event)
+            .collect())
+    }
+
+    // Logs novos do container com timestamp, sem o histórico (executado fora do lock do gerenciador)
+    pub fn follow_logs_command(&self, container_name: &str) -> Command {
+        let mut logs = self.cli();
+        logs.args(["logs", "--follow", "--timestamps", "--tail", "0", container_name]);
+        logs
+    }
+
+    // Histórico de camadas da imagem em bytes (executado fora do lock do gerenciador)
+    pub fn image_history_command(&self, image_id: &str) -> Command {
+        let mut history = self.cli();
+        history.args([
+            "image",
+            "history",
+            "--no-trunc",
+            "--human=false",
+            "--format",
+            "{{.Size}}\t{{json .CreatedBy}}",
+            image_id,
+        ]);
+        history
+    }
+
+    // Comando `docker load` apontando para o daemon ativo (local ou túnel SSH)
+    pub fn load_image_command(&self) -> Command {
+        let mut command = self.cli();
+        command.arg("load");
+        command
+    }
+
+    // Lista stacks do Compose implantadas no daemon ativo
+    pub async fn list_compose_stacks(&self) -> Result<Vec<ComposeStack>> {
+        // v1 não tem `ls` e sem compose não há binário: os labels dos containers bastam
+        let Some(compose) = self.compose_cli().filter(|compose| !compose.is_legacy()) else {
+            let output = self
+                .cli()
+                .args(&[
+                    "ps",
+                    "-a",
+                    "--filter",
+                    "label=com.docker.compose.project",
+                    "--format",
+                    compose::PS_STACKS_FORMAT,
+                ])
+                .output()
+                .context("Failed to execute docker ps command")?;
+            if !output.status.success() {
+                return Err(anyhow::anyhow!(
+                    "Falha ao listar stacks: {}",
+                    String::from_utf8_lossy(&output.stderr)
+                ));
+            }
+            return Ok(compose::parse_stacks_from_ps(&String::from_utf8_lossy(
+                &output.stdout,
+            )));
+        };
+
+        let output = self
+            .compose_command(compose, &["ls", "-a", "--format", "json"])?
+            .output()
+            .context("Failed to execute docker compose ls command")?;
+
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "Falha ao listar stacks: {}",
+                String::from_utf8_lossy(&output.stderr)
+            ));
+        }
+
+        compose::parse_stacks(&String::from_utf8_lossy(&output.stdout))
+    }
+
+    // Containers de uma stack com estado e health de cada serviço
+    pub async fn compose_containers(&self, project: &str) -> Result<Vec<ComposeContainer>> {
+        // v1 não tem `ps --format json`: serviço, estado e health pelos labels
+        let Some(compose) = self.compose_cli().filter(|compose| !compose.is_legacy()) else {
+            let project_filter = format!("label=com.docker.compose.project={}", project);
+            let output = self
+                .cli()
+                .args(&[
+                    "ps",
+                    "-a",
+                    "--filter",
+                    &project_filter,
+                    "--format",
+                    compose::PS_SERVICES_FORMAT,
+                ])
+                .output()
+                .context("Failed to execute docker ps command")?;
+            if !output.status.success() {
+                return Err(anyhow::anyhow!(
+                    "Falha ao consultar serviços da stack {}: {}",
+                    project,
+                    String::from_utf8_lossy(&output.stderr)
+                ));
+            }
+            return Ok(compose::parse_containers_from_ps(&String::from_utf8_lossy(
+                &output.stdout,
+            )));
+        };
+
+        let output = self
+            .compose_command(compose, &["-p", project, "ps", "-a", "--format", "json"])?
+            .output()
+            .context("Failed to execute docker compose ps command")?;
+
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "Falha ao consultar serviços da stack {}: {}",
+                project,
+                String::from_utf8_lossy(&output.stderr)
+            ));
+        }
+
+        compose::parse_containers(&String::from_utf8_lossy(&output.stdout))
+    }
+
+    // Compose usado nas stacks: no host via SSH (onde ficam os arquivos das stacks
+    // implantadas por lá), senão o CLI local apontando para o daemon
+    pub fn compose_cli(&self) -> Option<&ComposeCli> {
+        self.compose
+            .get_or_init(|| self.detect_compose(self.ssh_client.is_some()))
+            .as_ref()
+    }
+
+    // Esquece o compose detectado (ex: depois de instalar o plugin no servidor)
+    pub fn redetect_compose(&mut self) {
+        self.compose = OnceLock::new();
+    }
+
+    // Testa o plugin antes do standalone, primeiro no host (se permitido) e depois local
+    fn detect_compose(&self, on_host: bool) -> Option<ComposeCli> {
+        let binaries = [ComposeBinary::Plugin, ComposeBinary::Standalone];
+        let host = binaries.iter().filter(|_| on_host).map(|binary| (*binary, true));
+        let local = binaries.iter().map(|binary| (*binary, false));
+        host.chain(local).find_map(|(binary, on_host)| {
+            let candidate = ComposeCli {
+                binary,
+                version: String::new(),
+                on_host,
+            };
+            let output = self
+                .compose_command(&candidate, &["version", "--short"])
+                .ok()?
+                .output()
+                .ok()?;
+            if !output.status.success() {
+                return None;
+            }
+            let version = compose::parse_version(&String::from_utf8_lossy(&output.stdout))?;
+            Some(ComposeCli {
+                version,
+                ..candidate
+            })
+        })
+    }
+
+    // Comando do compose pelo binário e local detectados
+    fn compose_command(&self, compose: &ComposeCli, args: &[&str]) -> Result<Command> {
+        let (program, prefix) = compose.binary.program();
+        if compose.on_host {
+            let mut remote = vec![program];
+            remote.extend_from_slice(prefix);
+            remote.extend_from_slice(args);
+            let client = self
+                .ssh_client
+                .as_ref()
+                .context("Servidor sem SSH para executar o compose no host")?;
+            return Ok(client.remote_command(&remote));
+        }
+        let mut command = self.cli_program(program);
+        command.args(prefix).args(args);
+        Ok(command)
+    }
+
+    // Compose da stack; stacks de arquivo local rodam sempre com o CLI local
+    fn stack_compose(&self, stack: &ComposeStack) -> Result<ComposeCli> {
+        let compose = if stack.local_files && self.ssh_client.is_some() {
+            self.detect_compose(false)
+        } else {
+            self.compose_cli().cloned()
+        };
+        compose.ok_or_else(|| {
+            let hint = if self.ssh_client.is_some() && !stack.local_files {
+                "; use \"Instalar plugin do Compose\" na tela de stacks"
+            } else {
+                ""
+            };
+            anyhow::anyhow!(
+                "Nenhum Compose encontrado para o servidor '{}' (docker compose ou docker-compose){}",
+                self.context.name,
+                hint
+            )
+        })
+    }
+
+    // Executa um subcomando do compose para a stack e retorna o stdout
+    fn compose_output(&self, stack: &ComposeStack, extra: &[&str]) -> Result<String> {
+        let compose = self.stack_compose(stack)?;
+        let project_args = stack.project_args();
+        let args: Vec<&str> = project_args
+            .iter()
+            .map(String::as_str)
+            .chain(extra.iter().copied())
+            .collect();
+        let output = self
+            .compose_command(&compose, &args)?
+            .output()
+            .context("Failed to execute docker compose command")?;
+
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "Falha ao executar compose {} na stack {}: {}",
+                extra.join(" "),
+                stack.name,
+                String::from_utf8_lossy(&output.stderr)
+            ));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    }
+
+    // Prévia do redeploy: serviços a criar/recriar/remover, imagens a baixar e diff de config
+    pub async fn preview_stack_update(&self, stack: &ComposeStack) -> Result<StackDiff> {
+        let config = self.compose_output(stack, &["config"])?;
+        let desired = compose::parse_config_hashes(&self.compose_output(
+            stack,
+            &["config", "--hash", "*"],
+        )?);
+
+        // Hash aplicado pelo compose em cada container implantado
+        let project_filter = format!("label=com.docker.compose.project={}", stack.name);
+        let output = self
+            .cli()
+            .args(&[
+                "ps",
+                "-a",
+                "--filter",
+                &project_filter,
+                "--format",
+                "{{.Label \"com.docker.compose.service\"}} {{.Label \"com.docker.compose.config-hash\"}}",
+            ])
+            .output()
+            .context("Failed to execute docker ps command")?;
+        let current = compose::parse_config_hashes(&String::from_utf8_lossy(&output.stdout));
+
+        // Imagens referenciadas que ainda não existem no daemon (v1 não tem --images)
+        let local_images = self.list_images().await?;
+        let images = if self.stack_compose(stack)?.is_legacy() {
+            compose::config_images(&config)
+        } else {
+            self.compose_output(stack, &["config", "--images"])?
+                .lines()
+                .map(str::to_string)
+                .collect()
+        };
+        let images_to_pull = images
+            .iter()
+            .map(|image| image.trim())
+            .filter(|image| !image.is_empty())
+            .filter(|image| {
+                let short = image.trim_start_matches("docker.io/library/");
+                let short = short.trim_start_matches("docker.io/");
+                !local_images.iter().any(|local| {
+                    local
+                        .tags
+                        .iter()
+                        .any(|tag| tag == image || tag == short || *tag == format!("{}:latest", short))
+                })
+            })
+            .map(str::to_string)
+            .collect();
+
+        let snapshot = compose::load_snapshot(&self.context.name, &stack.name);
+        let config_diff = snapshot
+            .as_deref()
+            .map(|previous| compose::line_diff(previous, &config))
+            .unwrap_or_default();
+
+        Ok(StackDiff {
+            changes: compose::plan_changes(&desired, &current),
+            images_to_pull,
+            config_diff,
+            has_snapshot: snapshot.is_some(),
+        })
+    }
+
+    // Reimplanta a stack e guarda o config aplicado para o próximo diff
+    pub async fn deploy_stack(&self, stack: &ComposeStack, pull_policy: PullPolicy) -> Result<()> {
+        let config = self.compose_output(stack, &["config"])?;
+        // O compose baixaria direto do Hub: com mirror, o app baixa antes e o up só usa as
+        // imagens já presentes (as que falharem ficam para o compose, como "missing")
+        let pull_policy = if pull_policy != PullPolicy::Never
+            && AppConfig::load()
+                .registry_mirror_for(&self.context.name)
+                .is_some()
+        {
+            for image in compose::config_images(&config) {
+                if pull_policy == PullPolicy::IfNotPresent && self.image_exists(&image).await? {
+                    continue;
+                }
+                if let Err(e) = self.pull_image(&image, &CredentialSelection::Auto).await {
+                    eprintln!("Erro ao baixar {} pelo mirror: {}", image, e);
+                }
+            }
+            PullPolicy::IfNotPresent
+        } else {
+            pull_policy
+        };
+        if self.stack_compose(stack)?.is_legacy() {
+            // v1 não tem `up --pull`: "always" vira um pull antes; o up já baixa as ausentes
+            // e "never" não tem equivalente
+            if pull_policy == PullPolicy::Always {
+                self.compose_output(stack, &["pull"])?;
+            }
+            self.compose_output(stack, &["up", "-d", "--remove-orphans"])?;
+        } else {
+            self.compose_output(
+                stack,
+                &["up", "-d", "--remove-orphans", "--pull", pull_policy.compose_flag()],
+            )?;
+        }
+        compose::save_snapshot(&self.context.name, &stack.name, &config)
+    }
+
+    // Lista nós do swarm com labels e distribuição de tarefas (requer manager)
+    pub async fn list_swarm_nodes(&self) -> Result<Vec<SwarmNode>> {
+        let output = self
+            .cli()
+            .args(&["node", "ls", "--format", "{{json .}}"])
+            .output()
+            .context("Failed to execute docker node ls command")?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if stderr.contains("not a swarm manager") || stderr.contains("not part of a swarm") {
+                return Err(anyhow::anyhow!("Este servidor não é um manager de swarm"));
+            }
+            return Err(anyhow::anyhow!("Falha ao listar nós do swarm: {}", stderr));
+        }
+
+        let mut nodes = swarm::parse_nodes(&String::from_utf8_lossy(&output.stdout))?;
+        if nodes.is_empty() {
+            return Ok(nodes);
+        }
+        let ids: Vec<String> = nodes.iter().map(|node| node.id.clone()).collect();
+
+        // Labels de cada nó
+        let output = self
+            .cli()
+            .args(&["node", "inspect", "--format", "{{.ID}}\t{{json .Spec.Labels}}"])
+            .args(&ids)
+            .output()
+            .context("Failed to execute docker node inspect command")?;
+        for line in String::from_utf8_lossy(&output.stdout).lines() {
+            let Some((id, labels)) = line.split_once('\t') else {
+                continue;
+            };
+            if let Some(node) = nodes.iter_mut().find(|node| node.id == id) {
+                node.labels = serde_json::from_str(labels).unwrap_or_default();
+            }
+        }
+
+        // Tarefas em execução por nó (a coluna Node traz o hostname)
+        let output = self
+            .cli()
+            .args(&[
+                "node",
+                "ps",
+                "--filter",
+                "desired-state=running",
+                "--format",
+                "{{.Node}}\t{{.Name}}",
+            ])
+            .args(&ids)
+            .output()
+            .context("Failed to execute docker node ps command")?;
+        let distribution =
+            swarm::parse_task_distribution(&String::from_utf8_lossy(&output.stdout));
+        for node in &mut nodes {
+            if let Some(tasks) = distribution.get(&node.hostname) {
+                node.tasks = tasks.clone();
+            }
+        }
+
+        Ok(nodes)
+    }
+
+    // Altera a disponibilidade de um nó (active, pause, drain)
+    pub async fn set_node_availability(&self, node_id: &str, availability: &str) -> Result<()> {
+        let output = self
+            .cli()
+            .args(&["node", "update", "--availability", availability, node_id])
+            .output()
+            .context("Failed to execute docker node update command")?;
+
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "Falha ao alterar disponibilidade do nó: {}",
+                String::from_utf8_lossy(&output.stderr)
+            ));
+        }
+
+        Ok(())
+    }
+
+    // Adiciona (chave=valor) ou remove (chave) um label do nó
+    pub async fn update_node_label(&self, node_id: &str, label: &str, remove: bool) -> Result<()> {
+        let (key, value) = swarm::parse_label(label)?;
+        let label_arg = if remove {
+            key
+        } else {
+            format!("{}={}", key, value)
+        };
+
+        let output = self
+            .cli()
+            .args(&[
+                "node",
+                "update",
+                if remove { "--label-rm" } else { "--label-add" },
+                &label_arg,
+                node_id,
+            ])
+            .output()
+            .context("Failed to execute docker node update command")?;
+
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "Falha ao atualizar label do nó: {}",
+                String::from_utf8_lossy(&output.stderr)
+            ));
+        }
+
+        Ok(())
+    }
+
+    // Lista entradas do cache de build do daemon ativo
+    pub async fn list_build_cache(&self) -> Result<Vec<BuildCacheEntry>> {
+        let output = self
+            .cli()
+            .args(&["system", "df", "-v", "--format", "{{json .}}"])
+            .output()
+            .context("Failed to execute docker system df command")?;
+
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "Falha ao consultar cache de build: {}",
+                String::from_utf8_lossy(&output.stderr)
+            ));
+        }
+
+        let usage: serde_json::Value = serde_json::from_slice(&output.stdout)
+            .context("Resposta inválida do docker system df")?;
+
+        // O CLI formata alguns campos como texto; aceita ambos os formatos
+        let text = |value: &serde_json::Value| match value {
+            serde_json::Value::String(text) => text.clone(),
+            serde_json::Value::Null => String::new(),
+            other => other.to_string(),
+        };
+        let flag = |value: &serde_json::Value| match value {
+            serde_json::Value::Bool(flag) => *flag,
+            serde_json::Value::String(text) => text == "true",
+            _ => false,
+        };
+
+        let mut entries: Vec<BuildCacheEntry> = usage["BuildCache"]
+            .as_array()
+            .map(|entries| {
+                entries
+                    .iter()
+                    .map(|entry| BuildCacheEntry {
+                        id: text(&entry["ID"]),
+                        cache_type: text(&entry["CacheType"]),
+                        size: text(&entry["Size"]),
+                        created_since: text(&entry["CreatedSince"]),
+                        last_used_since: text(&entry["LastUsedSince"]),
+                        usage_count: text(&entry["UsageCount"]).parse().unwrap_or(0),
+                        in_use: flag(&entry["InUse"]),
+                        shared: flag(&entry["Shared"]),
+                        description: text(&entry["Description"]),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        // Ordena por ID para a lista não pular entre atualizações
+        entries.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(entries)
+    }
+
+    // Remove cache de build não usado há mais que `older_than` (ex: "24h"); None remove tudo
+    pub async fn prune_build_cache(&self, older_than: Option<&str>) -> Result<String> {
+        let mut args = vec!["builder", "prune", "-f", "-a"];
+        let filter;
+        if let Some(older_than) = older_than {
+            filter = format!("until={}", older_than);
+            args.push("--filter");
+            args.push(&filter);
+        }
+
+        let output = self
+            .cli()
+            .args(&args)
+            .output()
+            .context("Failed to execute docker builder prune command")?;
+
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "Falha ao limpar cache de build: {}",
+                String::from_utf8_lossy(&output.stderr)
+            ));
+        }
+
+        // Última linha: "Total:\t1.2GB"
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let reclaimed = stdout
+            .lines()
+            .rev()
+            .find_map(|line| line.strip_prefix("Total:"))
+            .map(|total| total.trim().to_string())
+            .unwrap_or_else(|| "0B".to_string());
+
+        Ok(reclaimed)
+    }
+
     // Para um container
     pub async fn stop_container(&self, container_name: &str) -> Result<()> {
-        let output = Command::new("docker")
+        let output = self
+            .cli()
             .args(&["stop", container_name])
             .output()
             .context("Failed to execute docker stop command")?;
@@ -600,7 +2033,8 @@ impl DockerManager {
 
     // Pausa um container
     pub async fn pause_container(&self, container_name: &str) -> Result<()> {
-        let output = Command::new("docker")
+        let output = self
+            .cli()
             .args(&["pause", container_name])
             .output()
             .context("Failed to execute docker pause command")?;
@@ -613,43 +2047,224 @@ impl DockerManager {
             ));
         }
 
-        Ok(())
+        Ok(())
+    }
+
+    // Despausa um container
+    pub async fn unpause_container(&self, container_name: &str) -> Result<()> {
+        let output = self
+            .cli()
+            .args(&["unpause", container_name])
+            .output()
+            .context("Failed to execute docker unpause command")?;
+
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "Failed to unpause container {}: {}",
+                container_name,
+                String::from_utf8_lossy(&output.stderr)
+            ));
+        }
+
+        Ok(())
+    }
+
+    // Estado atual do container (running, paused, exited...), lido no servidor
+    pub async fn container_state(&self, container_name: &str) -> Result<String> {
+        let output = self
+            .cli()
+            .args(["container", "inspect", "--format", "{{.State.Status}}", container_name])
+            .output()
+            .context("Falha ao executar docker container inspect")?;
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "Falha ao inspecionar container {}: {}",
+                container_name,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    // Deleta um container. Sem `force`, recusa containers ativos para não matar a
+    // carga em execução; com `force` (remoção forçada explícita) usa rm -f
+    pub async fn remove_container(&self, container_name: &str, force: bool) -> Result<()> {
+        if !force {
+            let state = self.container_state(container_name).await?;
+            if is_active_state(&state) {
+                return Err(anyhow::anyhow!(
+                    "o container {} está '{}'; confirme 'Parar e remover' para removê-lo",
+                    container_name,
+                    state
+                ));
+            }
+        }
+
+        let mut rm = self.cli();
+        rm.arg("rm");
+        if force {
+            rm.arg("-f");
+        }
+        let output = rm
+            .arg(container_name)
+            .output()
+            .context("Failed to execute docker rm command")?;
+
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "Failed to remove container {}: {}",
+                container_name,
+                String::from_utf8_lossy(&output.stderr)
+            ));
+        }
+
+        Ok(())
+    }
+
+    // "Parar e remover": docker stop respeita o tempo de parada do container (SIGTERM e só
+    // depois SIGKILL); o rm só roda com ele já parado
+    pub async fn stop_and_remove_container(&self, container_name: &str) -> Result<()> {
+        self.stop_container(container_name).await?;
+        self.remove_container(container_name, false).await
+    }
+
+    // Nomes dos containers (inclusive parados) com todas as labels do seletor
+    pub async fn containers_by_labels(&self, selector: &LabelSelector) -> Result<Vec<String>> {
+        let mut ps = self.cli();
+        ps.args(["ps", "-a", "--format", "{{.Names}}"]);
+        for filter in selector.filters() {
+            ps.arg("--filter").arg(format!("label={}", filter));
+        }
+        let output = ps.output().context("Falha ao executar docker ps")?;
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "Falha ao listar containers: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        let mut names: Vec<String> = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(str::to_string)
+            .collect();
+        names.sort();
+        Ok(names)
+    }
+
+    // Baixa de novo a imagem e recria o container com a mesma configuração
+    pub async fn pull_and_recreate_container(&self, container_name: &str) -> Result<()> {
+        let inspect = self
+            .inspect_containers(&[container_name.to_string()])
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("Container '{}' não encontrado", container_name))?;
+        let image = inspect
+            .pointer("/Config/Image")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+        if image.is_empty() || image.starts_with("sha256:") {
+            return Err(anyhow::anyhow!(
+                "'{}' foi criado a partir de um ID de imagem; não há tag para atualizar",
+                container_name
+            ));
+        }
+        let running = inspect
+            .pointer("/State/Running")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        // Imagem antiga, para separar o que o usuário definiu do que veio dela
+        let old_image = inspect
+            .get("Image")
+            .and_then(serde_json::Value::as_str)
+            .and_then(|image_id| self.image_inspect(image_id));
+        let body = recreate_body(&inspect, old_image.as_ref())?;
+
+        self.pull_image(&image, &CredentialSelection::Auto).await?;
+        self.replace_container(container_name, body, running).await
+    }
+
+    // Troca o container por um novo criado com `body`. O antigo fica renomeado até o novo
+    // ser criado (e iniciado, se estava rodando); em falha, é restaurado
+    async fn replace_container(
+        &self,
+        container_name: &str,
+        body: ContainerCreateBody,
+        running: bool,
+    ) -> Result<()> {
+        let backup = format!(
+            "{}-old-{}",
+            container_name,
+            chrono::Utc::now().format("%Y%m%d%H%M%S")
+        );
+        self.run_cli(&["rename", container_name, &backup])?;
+        if running {
+            let stopped = self.stop_container(&backup).await;
+            if let Err(e) = stopped {
+                self.run_cli(&["rename", &backup, container_name])?;
+                return Err(e);
+            }
+        }
+
+        let options = CreateContainerOptions {
+            name: Some(container_name.to_string()),
+            ..Default::default()
+        };
+        let created = self.docker.create_container(Some(options), body).await;
+        let started = match created {
+            Ok(_) if running => self.start_container(container_name).await,
+            Ok(_) => Ok(()),
+            Err(e) => Err(anyhow::anyhow!("Falha ao criar o novo container: {}", e)),
+        };
+        if let Err(e) = started {
+            // Desfaz: remove o novo (se criado) e devolve o nome ao antigo
+            let _ = self.run_cli(&["rm", "-f", container_name]);
+            self.run_cli(&["rename", &backup, container_name])?;
+            if running {
+                self.start_container(container_name).await?;
+            }
+            return Err(e);
+        }
+
+        self.run_cli(&["rm", &backup])
     }
 
-    // Despausa um container
-    pub async fn unpause_container(&self, container_name: &str) -> Result<()> {
-        let output = Command::new("docker")
-            .args(&["unpause", container_name])
+    // Executa um comando do docker sem saída relevante
+    fn run_cli(&self, args: &[&str]) -> Result<()> {
+        let output = self
+            .cli()
+            .args(args)
             .output()
-            .context("Failed to execute docker unpause command")?;
-
+            .with_context(|| format!("Falha ao executar docker {}", args.join(" ")))?;
         if !output.status.success() {
             return Err(anyhow::anyhow!(
-                "Failed to unpause container {}: {}",
-                container_name,
-                String::from_utf8_lossy(&output.stderr)
+                "docker {} falhou: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
             ));
         }
-
         Ok(())
     }
 
-    // deleta um container
-    pub async fn remove_container(&self, container_name: &str) -> Result<()> {
-        let output = Command::new("docker")
-            .args(&["rm", container_name])
+    // Remove containers parados ou imagens sem uso; retorna o espaço liberado
+    pub async fn prune(&self, target: PruneTarget) -> Result<String> {
+        let output = self
+            .cli()
+            .args(target.args())
             .output()
-            .context("Failed to execute docker unpause command")?;
+            .context("Failed to execute docker prune command")?;
 
         if !output.status.success() {
             return Err(anyhow::anyhow!(
-                "Failed to unpause container {}: {}",
-                container_name,
-                String::from_utf8_lossy(&output.stderr)
+                "Falha ao limpar {}: {}",
+                target.label(),
+                String::from_utf8_lossy(&output.stderr).trim()
             ));
         }
 
-        Ok(())
+        Ok(prune::reclaimed_space(&String::from_utf8_lossy(&output.stdout)))
     }
 
     // Lista apenas containers em execução
@@ -667,6 +2282,7 @@ impl DockerManager {
             .into_iter()
             .map(|container| ContainerInfo {
                 id: container.id.unwrap_or_default(),
+                server: self.context.name.clone(),
                 name: container
                     .names
                     .unwrap_or_default()
@@ -711,6 +2327,12 @@ impl DockerManager {
             .unwrap()
             .as_secs();
 
+        // Chaves dos caches por container: id na coleta geral e nome na tela de detalhes
+        let cache_keys: HashSet<String> = containers
+            .iter()
+            .flat_map(|container| [container.id.clone(), container.name.clone()])
+            .collect();
+
         // Itera por todos os containers coletando estatísticas
         for container in containers {
             if let Ok(Some(stats)) = self
@@ -749,6 +2371,17 @@ impl DockerManager {
                 let (network_rx, network_tx) = self.get_network_stats(&stats);
                 let (block_read, block_write) = self.get_block_stats(&stats);
 
+                self.memory_trends
+                    .record(&container.id, memory_usage, memory_limit);
+                self.deploy_history.record(
+                    &container.name,
+                    &container.id,
+                    &container.image,
+                    container.created,
+                    cpu_percentage as f32,
+                    memory_percentage as f32,
+                );
+
                 containers_stats.push(ContainerStats {
                     id: container.id.clone(),
                     name: container.name.clone(),
@@ -772,13 +2405,18 @@ impl DockerManager {
             }
         }
 
+        let running: HashSet<&str> = cache_keys.iter().map(String::as_str).collect();
+        self.memory_trends.retain(&running);
+        self.forget_stale_stats(Some(&running), current_time);
+        self.deploy_history.forget_stale();
+
         let memory_percentage = if total_memory_limit > 0 {
             (total_memory_usage as f64 / total_memory_limit as f64) * 100.0
         } else {
             0.0
         };
 
-        Ok(DockerSystemUsage {
+        let usage = DockerSystemUsage {
             cpu_online: online_cpu,
             cpu_usage: total_cpu,
             memory_usage: total_memory_usage,
@@ -789,7 +2427,24 @@ impl DockerManager {
             block_read_bytes: total_block_read,
             block_write_bytes: total_block_write,
             containers_stats,
-        })
+        };
+
+        // Snapshot guarda só os totais
+        self.record_snapshot(|snapshot| {
+            snapshot.usage = Some(DockerSystemUsage {
+                cpu_online: usage.cpu_online,
+                cpu_usage: usage.cpu_usage,
+                memory_usage: usage.memory_usage,
+                memory_limit: usage.memory_limit,
+                memory_percentage: usage.memory_percentage,
+                network_rx_bytes: usage.network_rx_bytes,
+                network_tx_bytes: usage.network_tx_bytes,
+                block_read_bytes: usage.block_read_bytes,
+                block_write_bytes: usage.block_write_bytes,
+                containers_stats: Vec::new(),
+            })
+        });
+        Ok(usage)
     }
 
     // Calcula CPU com cache de estatísticas anteriores
@@ -926,16 +2581,23 @@ impl DockerManager {
         }
     }
 
-    // Método para limpar cache antigo (executar periodicamente)
-    // pub fn cleanup_old_stats(&mut self, max_age_seconds: u64) {
-    //     let current_time = SystemTime::now()
-    //         .duration_since(UNIX_EPOCH)
-    //         .unwrap()
-    //         .as_secs();
+    // Descarta deltas de containers que não estão mais em execução (quando a lista é
+    // conhecida) e os não atualizados há mais de PREVIOUS_STATS_MAX_AGE_SECS
+    fn forget_stale_stats(&mut self, running: Option<&HashSet<&str>>, current_time: u64) {
+        self.previous_stats.retain(|key, stats| {
+            running.is_none_or(|running| running.contains(key.as_str()))
+                && current_time.saturating_sub(stats.timestamp) < PREVIOUS_STATS_MAX_AGE_SECS
+        });
+    }
 
-    //     self.previous_stats
-    //         .retain(|_, stats| current_time - stats.timestamp < max_age_seconds);
-    // }
+    // Containers em cada cache, para o painel de diagnóstico
+    pub fn cache_sizes(&self) -> CacheSizes {
+        CacheSizes {
+            previous_stats: self.previous_stats.len(),
+            memory_trends: self.memory_trends.container_count(),
+            deploy_history: self.deploy_history.container_count(),
+        }
+    }
 
     // Obtém estatísticas de rede (RX/TX)
     fn get_network_stats(&self, stats: &ContainerStatsResponse) -> (u64, u64) {
@@ -1082,123 +2744,204 @@ impl DockerManager {
         Ok(())
     }
 
-    // Obter logs de um container com paginação
-    pub async fn get_container_logs(
+    // Limites de I/O de bloco em vigor no container
+    pub async fn container_io_limits(&self, container_name: &str) -> Result<IoLimits> {
+        let inspect = self
+            .inspect_containers(&[container_name.to_string()])
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("Container '{}' não encontrado", container_name))?;
+        Ok(IoLimits {
+            // 0 = sem peso definido (padrão do daemon)
+            blkio_weight: inspect
+                .pointer("/HostConfig/BlkioWeight")
+                .and_then(serde_json::Value::as_u64)
+                .filter(|weight| *weight > 0)
+                .map(|weight| weight as u16),
+            device_read_bps: inspected_rates(&inspect, "/HostConfig/BlkioDeviceReadBps"),
+            device_write_bps: inspected_rates(&inspect, "/HostConfig/BlkioDeviceWriteBps"),
+        })
+    }
+
+    // Aplica novos limites de I/O. Em um container existente o daemon só altera o peso
+    // (docker update); taxas por dispositivo e a remoção do peso exigem recriá-lo com o novo
+    // HostConfig. Retorna se houve recriação; erro se o inspect não mostrar os valores pedidos
+    pub async fn update_container_io(&self, container_name: &str, limits: &IoLimits) -> Result<bool> {
+        use bollard::models::ContainerUpdateBody;
+
+        let current = self.container_io_limits(container_name).await?;
+        let recreate = limits.needs_recreate(&current);
+        if recreate {
+            let inspect = self
+                .inspect_containers(&[container_name.to_string()])
+                .await?
+                .into_iter()
+                .next()
+                .ok_or_else(|| anyhow::anyhow!("Container '{}' não encontrado", container_name))?;
+            let running = inspect
+                .pointer("/State/Running")
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(false);
+            let image = inspect
+                .get("Image")
+                .and_then(serde_json::Value::as_str)
+                .and_then(|image_id| self.image_inspect(image_id));
+            let body = recreate_body(&with_io_limits(&inspect, limits), image.as_ref())?;
+            self.replace_container(container_name, body, running).await?;
+        } else if limits.blkio_weight != current.blkio_weight {
+            let body = ContainerUpdateBody {
+                blkio_weight: limits.blkio_weight,
+                ..Default::default()
+            };
+            self.docker
+                .update_container(container_name, body)
+                .await
+                .context(format!(
+                    "Falha ao atualizar limites de I/O de {}",
+                    container_name
+                ))?;
+        }
+
+        let applied = self.container_io_limits(container_name).await?;
+        if !applied.same_as(limits) {
+            return Err(anyhow::anyhow!(
+                "O daemon não aplicou os limites de I/O pedidos a {}",
+                container_name
+            ));
+        }
+        Ok(recreate)
+    }
+
+    // Linhas de log a partir de um instante, com o timestamp de cada uma (gatilhos de log)
+    pub async fn get_container_log_lines_since(
+        &self,
+        container_name: &str,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<(chrono::DateTime<chrono::Utc>, String)>> {
+        self.get_container_log_window(container_name, Some(since), None, None)
+            .await
+    }
+
+    // Linhas de log de uma janela de tempo (--since/--until), com o timestamp de cada uma.
+    // O daemon só aceita segundos inteiros: a janela pedida cobre os limites e quem chama
+    // filtra pelo instante exato. `tail` limita às últimas linhas da janela
+    pub async fn get_container_log_window(
         &self,
         container_name: &str,
-        tail_lines: Option<String>,
-    ) -> Result<String> {
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        until: Option<chrono::DateTime<chrono::Utc>>,
+        tail: Option<usize>,
+    ) -> Result<Vec<(chrono::DateTime<chrono::Utc>, String)>> {
         use bollard::query_parameters::LogsOptions;
         use futures_util::StreamExt;
 
-        let logs_options = LogsOptions {
+        let mut logs_options = LogsOptions {
             stdout: true,
             stderr: true,
-            tail: tail_lines.unwrap_or_else(|| "50".to_string()), // Padrão: últimas 50 linhas
             timestamps: true,
             ..Default::default()
         };
+        if let Some(since) = since {
+            logs_options.since = since.timestamp() as i32;
+        }
+        if let Some(until) = until {
+            logs_options.until = until.timestamp() as i32 + 1;
+        }
+        if let Some(tail) = tail {
+            logs_options.tail = tail.to_string();
+        }
 
         let mut logs_stream = self.docker.logs(container_name, Some(logs_options));
-
         let mut logs = String::new();
         while let Some(log_result) = logs_stream.next().await {
-            match log_result {
-                Ok(log_output) => {
-                    logs.push_str(&log_output.to_string());
-                }
-                Err(_) => break,
-            }
+            let log_output = log_result
+                .context(format!("Falha ao ler logs do container: {}", container_name))?;
+            logs.push_str(&log_output.to_string());
         }
 
-        // Processa e formata os logs com timestamp
-        let formatted_logs = logs
+        Ok(logs
             .lines()
             .filter_map(|line| {
-                if line.len() > 30 {
-                    // Tenta extrair o timestamp (formato: 2023-01-01T00:00:00.000000000Z)
-                    let timestamp_str = &line[0..30];
-                    let message = if line.len() > 31 { &line[31..] } else { "" };
-
-                    // Parse do timestamp ISO 8601 usando chrono
-                    if let Ok(utc_time) = timestamp_str.parse::<chrono::DateTime<chrono::Utc>>() {
-                        let local_time = utc_time.with_timezone(&chrono::Local);
-                        let formatted_time = local_time.format("%H:%M:%S").to_string();
-                        Some(format!("[{}] {}", formatted_time, message))
-                    } else {
-                        // Se não conseguir parsear timestamp, retorna a linha original sem timestamp
-                        Some(message.to_string())
-                    }
-                } else {
-                    // Linha muito curta, provavelmente não tem timestamp
-                    Some(line.to_string())
-                }
+                let (timestamp, message) = line.split_once(' ').unwrap_or((line, ""));
+                let time = timestamp.parse::<chrono::DateTime<chrono::Utc>>().ok()?;
+                Some((time, message.to_string()))
             })
-            .collect::<Vec<String>>()
-            .join("\n");
+            .collect())
+    }
 
-        Ok(if formatted_logs.trim().is_empty() {
-            "Nenhum log disponível".to_string()
-        } else {
-            formatted_logs
-        })
+    // Hora de uma linha de log no fuso escolhido
+    pub fn format_log_time(
+        &self,
+        time: chrono::DateTime<chrono::Utc>,
+        time_mode: LogTimeMode,
+    ) -> String {
+        match (time_mode, self.remote_clock) {
+            (LogTimeMode::Utc, _) => time.format("%H:%M:%S").to_string(),
+            (LogTimeMode::Remote, Some(clock)) => time
+                .with_timezone(&clock.offset)
+                .format("%H:%M:%S")
+                .to_string(),
+            // Fuso remoto desconhecido: usa o local
+            _ => time
+                .with_timezone(&chrono::Local)
+                .format("%H:%M:%S")
+                .to_string(),
+        }
     }
 
-    // Obter logs anteriores de um container (para infinite scroll)
-    // pub async fn get_container_logs_before(
-    //     &self,
-    //     container_name: &str,
-    //     before_timestamp: &str,
-    //     lines: u32,
-    // ) -> Result<String> {
-    //     use bollard::query_parameters::LogsOptions;
-    //     use futures_util::StreamExt;
-
-    //     let logs_options = LogsOptions {
-    //         stdout: true,
-    //         stderr: true,
-    //         until: before_timestamp.parse::<i32>().unwrap_or(0), // Logs antes deste timestamp
-    //         tail: lines.to_string(),
-    //         timestamps: true,
-    //         ..Default::default()
-    //     };
-
-    //     let mut logs_stream = self.docker.logs(container_name, Some(logs_options));
-
-    //     let mut logs = String::new();
-    //     while let Some(log_result) = logs_stream.next().await {
-    //         match log_result {
-    //             Ok(log_output) => {
-    //                 logs.push_str(&log_output.to_string());
-    //             }
-    //             Err(_) => break,
-    //         }
-    //     }
+    // Instante de criação do container, limite para a paginação dos logs antigos
+    pub async fn container_created_at(
+        &self,
+        container_name: &str,
+    ) -> Result<chrono::DateTime<chrono::Utc>> {
+        let inspected = self
+            .inspect_containers(&[container_name.to_string()])
+            .await?;
+        inspected
+            .first()
+            .and_then(|container| container.get("Created"))
+            .and_then(serde_json::Value::as_str)
+            .and_then(|created| created.parse().ok())
+            .ok_or_else(|| anyhow::anyhow!("Data de criação do container indisponível"))
+    }
 
-    //     // Processa e formata os logs com timestamp
-    //     let formatted_logs = logs
-    //         .lines()
-    //         .filter_map(|line| {
-    //             if line.len() > 30 {
-    //                 let timestamp_str = &line[0..30];
-    //                 let message = if line.len() > 31 { &line[31..] } else { "" };
-
-    //                 if let Ok(utc_time) = timestamp_str.parse::<chrono::DateTime<chrono::Utc>>() {
-    //                     let local_time = utc_time.with_timezone(&chrono::Local);
-    //                     let formatted_time = local_time.format("%H:%M:%S").to_string();
-    //                     Some(format!("[{}] {}", formatted_time, message))
-    //                 } else {
-    //                     Some(message.to_string())
-    //                 }
-    //             } else {
-    //                 Some(line.to_string())
-    //             }
-    //         })
-    //         .collect::<Vec<String>>()
-    //         .join("\n");
-
-    //     Ok(formatted_logs)
-    // }
+    // Troca a fonte de métricas sem reconectar (configurações salvas)
+    pub fn set_metrics_source(&mut self, source: Option<MetricsSource>) {
+        self.metrics = source
+            .map(|source| MetricsFetcher::new(source, AppConfig::load().global_proxy()));
+    }
+
+    // Indica se os gráficos deste servidor vêm do Prometheus
+    pub fn has_metrics_source(&self) -> bool {
+        self.metrics.is_some()
+    }
+
+    // Histórico do host no Prometheus; None sem fonte configurada ou antes do próximo passo
+    pub fn host_metrics_history(&mut self) -> Option<Result<MetricsHistory>> {
+        self.metrics.as_mut()?.host_history()
+    }
+
+    // Histórico de um container (pelo nome, label "name" do cAdvisor)
+    pub fn container_metrics_history(
+        &mut self,
+        chart_key: &str,
+        container_name: &str,
+    ) -> Option<Result<MetricsHistory>> {
+        self.metrics
+            .as_mut()?
+            .container_history(chart_key, container_name)
+    }
+
+    // Deploy atual e anterior do container, pelo nome (None se ainda não foi amostrado)
+    pub fn deploy_comparison(&self, container_name: &str) -> Option<DeployComparison> {
+        self.deploy_history.comparison(container_name)
+    }
+
+    // Tendência de memória do container (None enquanto coleta histórico)
+    pub fn memory_trend(&self, container_id: &str) -> Option<MemoryTrend> {
+        self.memory_trends.trend(container_id)
+    }
 
     // Obter estatísticas de um container específico
     pub async fn get_single_container_stats(
@@ -1228,6 +2971,7 @@ impl DockerManager {
                         &stats,
                         current_time,
                     );
+                    self.forget_stale_stats(None, current_time);
                     let cpu_usage = cpu_calc.usage_cpu;
                     let cpu_online = cpu_calc.online_cpus;
 
@@ -1235,46 +2979,20 @@ impl DockerManager {
                     let memory_stats = stats.memory_stats.as_ref().cloned().unwrap_or_default();
                     let memory_usage = memory_stats.usage.unwrap_or(0);
                     let memory_limit = memory_stats.limit.unwrap_or(0);
+                    self.memory_trends
+                        .record(container_name, memory_usage, memory_limit);
 
-                    let memory_usage_mb = memory_usage as f64 / 1024.0 / 1024.0;
-                    let memory_limit_mb = memory_limit as f64 / 1024.0 / 1024.0;
                     let memory_percentage = if memory_limit > 0 {
                         (memory_usage as f64 / memory_limit as f64) * 100.0
                     } else {
                         0.0
                     };
-
-                    let memory_str = if memory_usage_mb >= 1024.0 || memory_limit_mb >= 1024.0 {
-                        let memory_usage_gb = memory_usage_mb / 1024.0;
-                        let memory_limit_gb = memory_limit_mb / 1024.0;
-
-                        if memory_usage_mb >= 1024.0 && memory_limit_mb >= 1024.0 {
-                            format!(
-                                "{:.1}% ({:.1} GB / {:.1} GB)",
-                                memory_percentage, memory_usage_gb, memory_limit_gb
-                            )
-                        } else if memory_usage_mb >= 1024.0 {
-                            format!(
-                                "{:.1}% ({:.1} GB / {:.0} MB)",
-                                memory_percentage, memory_usage_gb, memory_limit_mb
-                            )
-                        } else {
-                            format!(
-                                "{:.1}% ({:.0} MB / {:.1} GB)",
-                                memory_percentage, memory_usage_mb, memory_limit_gb
-                            )
-                        }
-                    } else {
-                        format!(
-                            "{:.1}% ({:.0} MB / {:.0} MB)",
-                            memory_percentage, memory_usage_mb, memory_limit_mb
-                        )
-                    };
+                    let memory_str = format::memory(memory_percentage, memory_usage, memory_limit);
 
                     // Calcula network
                     let (rx, tx) = self.get_network_stats(&stats);
-                    let rx_str = self.format_bytes_rate(rx);
-                    let tx_str = self.format_bytes_rate(tx);
+                    let rx_str = format::bytes_rate(rx);
+                    let tx_str = format::bytes_rate(tx);
 
                     Ok((cpu_usage, cpu_online, memory_str, rx_str, tx_str))
                 }
@@ -1285,17 +3003,6 @@ impl DockerManager {
         }
     }
 
-    // Função auxiliar para formatar bytes/s
-    fn format_bytes_rate(&self, bytes: u64) -> String {
-        if bytes < 1024 {
-            format!("{} B/s", bytes)
-        } else if bytes < 1024 * 1024 {
-            format!("{:.1} KB/s", bytes as f64 / 1024.0)
-        } else {
-            format!("{:.1} MB/s", bytes as f64 / 1024.0 / 1024.0)
-        }
-    }
-
     // Cria um novo container
     pub async fn create_container(&self, request: CreateContainerRequest) -> Result<String> {
         use bollard::models::{
@@ -1303,6 +3010,15 @@ impl DockerManager {
         };
         use std::collections::HashMap;
 
+        // Fixação em CPUs e nós NUMA, validada antes de qualquer pull
+        let cpuset_cpus = resource_limits::parse_cpuset(&request.cpuset_cpus, "cpuset-cpus")?;
+        let cpuset_mems = resource_limits::parse_cpuset(&request.cpuset_mems, "cpuset-mems")?;
+        let io_limits = IoLimits::parse(
+            &request.blkio_weight,
+            &request.device_read_bps,
+            &request.device_write_bps,
+        )?;
+
         // Verifica se o nome já existe
         if self.container_name_exists(&request.name).await? {
             return Err(anyhow::anyhow!(
@@ -1311,10 +3027,29 @@ impl DockerManager {
             ));
         }
 
-        // Verifica se a imagem existe localmente, se não, tenta fazer pull
-        if !self.image_exists(&request.image).await? {
-            // Aqui podemos adicionar callback de progresso no futuro
-            self.pull_image(&request.image).await?;
+        // Pull conforme a política: sempre, só se ausente ou nunca
+        let image_exists = self.image_exists(&request.image).await?;
+        let should_pull = match request.pull_policy {
+            PullPolicy::Always => true,
+            PullPolicy::IfNotPresent => !image_exists,
+            PullPolicy::Never if !image_exists => {
+                return Err(anyhow::anyhow!(
+                    "Imagem '{}' não existe no servidor e a política de pull é 'nunca'",
+                    request.image
+                ));
+            }
+            PullPolicy::Never => false,
+        };
+        if should_pull {
+            let selection = CredentialSelection::from_ui(&request.registry_credential);
+            self.pull_image(&request.image, &selection).await?;
+        }
+
+        // Avisa antes de criar se a imagem não é da arquitetura do servidor
+        if !request.allow_arch_mismatch {
+            if let Some(warning) = self.check_image_architecture(&request.image).await? {
+                return Err(anyhow::anyhow!("ARCH_MISMATCH:{}", warning));
+            }
         }
 
         // Configura mapeamento de portas
@@ -1389,6 +3124,11 @@ impl DockerManager {
                 port_bindings: Some(port_bindings),
                 mounts: Some(mounts),
                 restart_policy,
+                cpuset_cpus,
+                cpuset_mems,
+                blkio_weight: io_limits.blkio_weight,
+                blkio_device_read_bps: throttle_devices(&io_limits.device_read_bps),
+                blkio_device_write_bps: throttle_devices(&io_limits.device_write_bps),
                 ..Default::default()
             }),
             ..Default::default()
@@ -1414,6 +3154,119 @@ impl DockerManager {
         Ok(response.id)
     }
 
+    // Portas expostas pela imagem (EXPOSE), ex: ["80/tcp", "443/tcp"]
+    pub async fn image_exposed_ports(&self, image_name: &str) -> Result<Vec<String>> {
+        let image = self
+            .docker
+            .inspect_image(image_name)
+            .await
+            .context("Falha ao inspecionar imagem")?;
+        let mut ports: Vec<String> = image
+            .config
+            .and_then(|config| config.exposed_ports)
+            .map(|ports| ports.into_keys().collect())
+            .unwrap_or_default();
+        ports.sort_by_key(|port| {
+            port.split('/')
+                .next()
+                .and_then(|number| number.parse::<u16>().ok())
+                .unwrap_or(u16::MAX)
+        });
+        Ok(ports)
+    }
+
+    // Labels da imagem que o container está executando, pelo ID e não pela tag,
+    // que pode ter sido atualizada depois que o container foi criado
+    pub async fn container_image_provenance(&self, container_id: &str) -> Result<ImageProvenance> {
+        let output = self
+            .cli()
+            .args(["container", "inspect", "--format", "{{.Image}}", container_id])
+            .output()
+            .context("Falha ao executar docker container inspect")?;
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "Falha ao inspecionar container: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        let image_id = String::from_utf8_lossy(&output.stdout).trim().to_string();
+
+        let image = self
+            .docker
+            .inspect_image(&image_id)
+            .await
+            .context("Falha ao inspecionar imagem")?;
+        let labels = image
+            .config
+            .and_then(|config| config.labels)
+            .unwrap_or_default();
+        Ok(ImageProvenance::from_labels(&image_id, &labels))
+    }
+
+    // Compara a arquitetura da imagem com a do servidor; retorna aviso se diferirem
+    async fn check_image_architecture(&self, image_name: &str) -> Result<Option<String>> {
+        let image = self
+            .docker
+            .inspect_image(image_name)
+            .await
+            .context("Falha ao inspecionar imagem")?;
+        let server_arch = self.get_docker_info().await?.architecture;
+
+        let image_arch = normalize_arch(&image.architecture.unwrap_or_default());
+        if image_arch.is_empty() || server_arch.is_empty() {
+            return Ok(None);
+        }
+        if image_arch == normalize_arch(&server_arch) {
+            return Ok(None);
+        }
+
+        let image_platform = match image.variant.filter(|v| !v.is_empty()) {
+            Some(variant) => format!("{}/{}", image_arch, variant),
+            None => image_arch,
+        };
+        Ok(Some(format!(
+            "A imagem '{}' é {} mas o servidor é {}. O container pode falhar ao iniciar ou rodar sob emulação.",
+            image_name,
+            image_platform,
+            normalize_arch(&server_arch)
+        )))
+    }
+
+    // Quanto o pull vai baixar de fato: camadas da plataforma do servidor no registry menos
+    // as que já existem em alguma imagem do servidor
+    pub async fn estimate_pull(
+        &self,
+        image_name: &str,
+        selection: &CredentialSelection,
+    ) -> Result<PullEstimate> {
+        let reference = ImageReference::parse(image_name)?;
+        let arch = normalize_arch(&self.get_docker_info().await?.architecture);
+        let variant = if arch == "arm" { "v7" } else { "" };
+        let login = credentials::resolve(selection, image_name)?
+            .map(|(credential, password)| (credential.username, password));
+        let proxy = AppConfig::load().global_proxy();
+
+        let remote = tokio::task::spawn_blocking(move || {
+            pull_estimate::remote_layers(&reference, ("linux", &arch, variant), login, proxy)
+        })
+        .await
+        .map_err(|e| anyhow::anyhow!(e))??;
+
+        let mut local = HashSet::new();
+        for image in self.list_images().await? {
+            let Ok(inspect) = self.docker.inspect_image(&image.id).await else {
+                continue;
+            };
+            local.extend(
+                inspect
+                    .root_fs
+                    .and_then(|root_fs| root_fs.layers)
+                    .unwrap_or_default(),
+            );
+        }
+        Ok(PullEstimate::from_layers(&remote, &local))
+    }
+
     // Verifica se um container com o nome existe
     async fn container_name_exists(&self, name: &str) -> Result<bool> {
         let containers = self.list_containers().await?;
@@ -1430,8 +3283,53 @@ impl DockerManager {
         }))
     }
 
-    // Faz pull de uma imagem
-    async fn pull_image(&self, image_name: &str) -> Result<()> {
+    // Faz pull de uma imagem. Com mirror configurado no servidor, imagens do Hub vêm dele e
+    // recebem a tag original; se o mirror falhar, o pull vai direto ao Hub
+    async fn pull_image(&self, image_name: &str, selection: &CredentialSelection) -> Result<()> {
+        let mirrored = AppConfig::load()
+            .registry_mirror_for(&self.context.name)
+            .and_then(|mirror| registry::mirrored_image(image_name, &mirror));
+        let Some(mirrored) = mirrored else {
+            return self.pull_from_registry(image_name, selection).await;
+        };
+
+        // A credencial escolhida é do registry da imagem (Hub) e nunca vai para o mirror:
+        // nele só se usa uma credencial salva para o host do próprio mirror
+        let from_mirror = match self
+            .pull_from_registry(&mirrored, &CredentialSelection::Auto)
+            .await
+        {
+            Ok(()) => self.run_cli(&["tag", &mirrored, image_name]),
+            Err(e) => Err(e),
+        };
+        match from_mirror {
+            Ok(()) => {
+                // Só remove a tag do mirror; a imagem continua com a tag original
+                if let Err(e) = self.run_cli(&["rmi", &mirrored]) {
+                    eprintln!("Erro ao remover tag do mirror: {}", e);
+                }
+                Ok(())
+            }
+            Err(mirror_error) => self
+                .pull_from_registry(image_name, selection)
+                .await
+                .map_err(|e| {
+                    // O limite do Hub continua reconhecível por quem chamou
+                    if e.is::<PullError>() {
+                        e
+                    } else {
+                        anyhow::anyhow!("{} (mirror {}: {})", e, mirrored, mirror_error)
+                    }
+                }),
+        }
+    }
+
+    async fn pull_from_registry(
+        &self,
+        image_name: &str,
+        selection: &CredentialSelection,
+    ) -> Result<()> {
+        use bollard::auth::DockerCredentials;
         use bollard::query_parameters::CreateImageOptions;
         use futures_util::StreamExt;
 
@@ -1440,7 +3338,19 @@ impl DockerManager {
             ..Default::default()
         };
 
-        let mut stream = self.docker.create_image(Some(options), None, None);
+        // Credencial do keyring para o registry da imagem
+        let docker_credentials = credentials::resolve(selection, image_name)?.map(
+            |(credential, password)| DockerCredentials {
+                username: Some(credential.username),
+                password: Some(password),
+                serveraddress: Some(credential.server),
+                ..Default::default()
+            },
+        );
+
+        let mut stream = self
+            .docker
+            .create_image(Some(options), None, docker_credentials);
 
         while let Some(result) = stream.next().await {
             match result {
@@ -1448,15 +3358,458 @@ impl DockerManager {
                     // Pull em progresso
                 }
                 Err(e) => {
-                    return Err(anyhow::anyhow!(
-                        "Falha ao fazer pull da imagem '{}': {}",
-                        image_name,
-                        e
-                    ));
+                    // Docker Hub limita pulls anônimos; o horário para tentar de novo é
+                    // consultado por quem chamou, sem o gerenciador bloqueado
+                    return Err(pull_error(image_name, e));
                 }
             }
         }
 
         Ok(())
     }
+
+    // Faz login em um registry no daemon ativo (credenciais ficam no credential store do Docker)
+    pub async fn registry_login(&self, server: &str, username: &str, password: &str) -> Result<()> {
+        let mut args = vec!["login", "-u", username, "--password-stdin"];
+        if !server.trim().is_empty() {
+            args.push(server);
+        }
+
+        let mut child = self
+            .cli()
+            .args(&args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .context("Failed to execute docker login command")?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(password.as_bytes())
+                .context("Falha ao enviar senha para docker login")?;
+        }
+
+        let output = child
+            .wait_with_output()
+            .context("Failed to execute docker login command")?;
+
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "Falha no login em {}: {}",
+                if server.is_empty() { "Docker Hub" } else { server },
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+// Estados em que o container ainda executa algo; remover exige confirmação e docker stop
+pub fn is_active_state(state: &str) -> bool {
+    matches!(state, "running" | "paused" | "restarting")
+}
+
+// Converte nomes de arquitetura do kernel para os nomes do Docker (x86_64 -> amd64)
+fn normalize_arch(arch: &str) -> String {
+    match arch.trim().to_lowercase().as_str() {
+        "x86_64" | "x86-64" => "amd64".to_string(),
+        "aarch64" | "arm64/v8" => "arm64".to_string(),
+        "i386" | "i686" => "386".to_string(),
+        "armv7l" | "armhf" => "arm".to_string(),
+        other => other.to_string(),
+    }
+}
+
+// Corpo de criação equivalente ao container inspecionado. Do Config ficam só os valores
+// definidos pelo usuário: os iguais aos da imagem antiga (Env, Cmd, Labels, WorkingDir...)
+// saem para os padrões da nova imagem valerem. HostConfig tem os mesmos campos da API de
+// criação, mais os volumes anônimos do antigo; das redes ficam só os dados de configuração
+// (aliases, IP fixo, links)
+fn recreate_body(
+    inspect: &serde_json::Value,
+    image: Option<&serde_json::Value>,
+) -> Result<ContainerCreateBody> {
+    use serde_json::{Map, Value};
+
+    let mut body = inspect.get("Config").cloned().unwrap_or_default();
+    let Some(config) = body.as_object_mut() else {
+        return Err(anyhow::anyhow!("Configuração do container indisponível"));
+    };
+    if let Some(image_config) = image
+        .and_then(|image| image.get("Config"))
+        .and_then(Value::as_object)
+    {
+        drop_inherited(config, image_config);
+    }
+    // Hostname gerado a partir do ID antigo não deve ser herdado
+    let id = inspect.get("Id").and_then(Value::as_str).unwrap_or_default();
+    let hostname = config.get("Hostname").and_then(Value::as_str).unwrap_or_default();
+    if !hostname.is_empty() && id.starts_with(hostname) {
+        config.remove("Hostname");
+    }
+    if let Some(host_config) = inspect.get("HostConfig") {
+        let mut host_config = host_config.clone();
+        carry_anonymous_volumes(inspect, &mut host_config);
+        config.insert("HostConfig".to_string(), host_config);
+    }
+
+    let endpoints: Map<String, Value> = inspect
+        .pointer("/NetworkSettings/Networks")
+        .and_then(Value::as_object)
+        .map(|networks| {
+            networks
+                .iter()
+                .map(|(network, endpoint)| {
+                    let settings: Map<String, Value> = ["Aliases", "IPAMConfig", "Links", "DriverOpts"]
+                        .iter()
+                        .filter_map(|key| {
+                            endpoint
+                                .get(*key)
+                                .filter(|value| !value.is_null())
+                                .map(|value| (key.to_string(), value.clone()))
+                        })
+                        .collect();
+                    (network.clone(), Value::Object(settings))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    if !endpoints.is_empty() {
+        config.insert(
+            "NetworkingConfig".to_string(),
+            serde_json::json!({ "EndpointsConfig": endpoints }),
+        );
+    }
+
+    serde_json::from_value(body).context("Configuração do container incompatível com a recriação")
+}
+
+// Tira do Config do container o que veio da imagem: Env e os mapas (Labels, portas,
+// volumes) perdem só os itens iguais aos da imagem; os demais campos saem quando iguais
+fn drop_inherited(
+    config: &mut serde_json::Map<String, serde_json::Value>,
+    image_config: &serde_json::Map<String, serde_json::Value>,
+) {
+    let cmd = config.get("Cmd").cloned();
+    config.retain(|key, value| {
+        if key == "Image" {
+            return true;
+        }
+        let Some(inherited) = image_config.get(key) else {
+            return true;
+        };
+        if key == "Env"
+            && let (Some(items), Some(inherited)) = (value.as_array_mut(), inherited.as_array())
+        {
+            items.retain(|item| !inherited.contains(item));
+            return !items.is_empty();
+        }
+        if matches!(key.as_str(), "Labels" | "ExposedPorts" | "Volumes")
+            && let (Some(entries), Some(inherited)) = (value.as_object_mut(), inherited.as_object())
+        {
+            entries.retain(|name, entry| inherited.get(name) != Some(&*entry));
+            return !entries.is_empty();
+        }
+        *value != *inherited
+    });
+    // Com Entrypoint próprio o daemon não usa o Cmd da imagem: o do container é mantido
+    if config.contains_key("Entrypoint")
+        && !config.contains_key("Cmd")
+        && let Some(cmd) = cmd.filter(|cmd| !cmd.is_null())
+    {
+        config.insert("Cmd".to_string(), cmd);
+    }
+}
+
+// Volumes anônimos do container antigo são montados pelo nome no novo, como o compose faz;
+// sem isso o novo começaria vazio e os antigos ficariam órfãos
+fn carry_anonymous_volumes(inspect: &serde_json::Value, host_config: &mut serde_json::Value) {
+    use serde_json::{Value, json};
+
+    let Some(host_config) = host_config.as_object_mut() else {
+        return;
+    };
+    // Destinos de binds e volumes nomeados já declarados no HostConfig
+    let bind_targets: Vec<String> = host_config
+        .get("Binds")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .filter_map(|bind| bind.split(':').nth(1))
+        .map(str::to_string)
+        .collect();
+
+    let volumes = inspect
+        .get("Mounts")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter(|mount| mount.get("Type").and_then(Value::as_str) == Some("volume"));
+    for mount in volumes {
+        let (Some(name), Some(target)) = (
+            mount.get("Name").and_then(Value::as_str),
+            mount.get("Destination").and_then(Value::as_str),
+        ) else {
+            continue;
+        };
+        if bind_targets.iter().any(|bind| bind == target) {
+            continue;
+        }
+        let mounts = host_config.entry("Mounts").or_insert(Value::Null);
+        if !mounts.is_array() {
+            *mounts = json!([]);
+        }
+        let Some(mounts) = mounts.as_array_mut() else {
+            continue;
+        };
+        match mounts
+            .iter_mut()
+            .find(|declared| declared.get("Target").and_then(Value::as_str) == Some(target))
+        {
+            // --mount sem origem: o daemon criaria outro volume anônimo
+            Some(declared) => {
+                let has_source = declared
+                    .get("Source")
+                    .and_then(Value::as_str)
+                    .is_some_and(|source| !source.is_empty());
+                if !has_source && let Some(declared) = declared.as_object_mut() {
+                    declared.insert("Source".to_string(), json!(name));
+                }
+            }
+            None => mounts.push(json!({
+                "Type": "volume",
+                "Source": name,
+                "Target": target,
+                "ReadOnly": !mount.get("RW").and_then(Value::as_bool).unwrap_or(true),
+            })),
+        }
+    }
+}
+
+// Inspect com os limites de I/O trocados, para recriar o container com eles
+fn with_io_limits(inspect: &serde_json::Value, limits: &IoLimits) -> serde_json::Value {
+    use serde_json::{Value, json};
+
+    let rates = |rates: &[DeviceRate]| -> Value {
+        rates
+            .iter()
+            .map(|rate| json!({ "Path": rate.path, "Rate": rate.bytes_per_sec }))
+            .collect()
+    };
+    let mut inspect = inspect.clone();
+    if let Some(host_config) = inspect.get_mut("HostConfig").and_then(Value::as_object_mut) {
+        host_config.insert("BlkioWeight".to_string(), json!(limits.blkio_weight.unwrap_or(0)));
+        host_config.insert("BlkioDeviceReadBps".to_string(), rates(&limits.device_read_bps));
+        host_config.insert("BlkioDeviceWriteBps".to_string(), rates(&limits.device_write_bps));
+    }
+    inspect
+}
+
+// Taxas por dispositivo no formato da API; lista vazia = campo omitido
+fn throttle_devices(rates: &[DeviceRate]) -> Option<Vec<bollard::models::ThrottleDevice>> {
+    if rates.is_empty() {
+        return None;
+    }
+    Some(
+        rates
+            .iter()
+            .map(|rate| bollard::models::ThrottleDevice {
+                path: Some(rate.path.clone()),
+                rate: Some(rate.bytes_per_sec as i64),
+            })
+            .collect(),
+    )
+}
+
+// Lista [{Path, Rate}] de um `docker inspect`
+fn inspected_rates(inspect: &serde_json::Value, pointer: &str) -> Vec<DeviceRate> {
+    inspect
+        .pointer(pointer)
+        .and_then(serde_json::Value::as_array)
+        .map(|devices| {
+            devices
+                .iter()
+                .filter_map(|device| {
+                    Some(DeviceRate {
+                        path: device.get("Path")?.as_str()?.to_string(),
+                        bytes_per_sec: device.get("Rate")?.as_u64()?,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn inspected() -> serde_json::Value {
+        json!({
+            "Id": "0123456789abcdef",
+            "Config": {
+                "Hostname": "0123456789ab",
+                "Image": "app:latest",
+                "Env": ["PATH=/usr/bin", "APP_VERSION=1", "MODE=prod"],
+                "Cmd": ["serve"],
+                "WorkingDir": "/app",
+                "Labels": { "org.opencontainers.image.version": "1", "team": "infra" },
+            },
+            "HostConfig": {
+                "Binds": ["/srv/data:/data"],
+                "Mounts": [{ "Type": "volume", "Target": "/cache" }],
+            },
+            "Mounts": [
+                { "Type": "bind", "Source": "/srv/data", "Destination": "/data", "RW": true },
+                { "Type": "volume", "Name": "anon1", "Destination": "/cache", "RW": true },
+                { "Type": "volume", "Name": "anon2", "Destination": "/logs", "RW": false },
+            ],
+            "NetworkSettings": {
+                "Networks": {
+                    "backend": { "Aliases": ["api"], "IPAddress": "172.18.0.5", "NetworkID": "n1" },
+                },
+            },
+        })
+    }
+
+    fn old_image() -> serde_json::Value {
+        json!({
+            "Config": {
+                "Env": ["PATH=/usr/bin", "APP_VERSION=1"],
+                "Cmd": ["serve"],
+                "WorkingDir": "/app",
+                "Labels": { "org.opencontainers.image.version": "1" },
+            },
+        })
+    }
+
+    #[test]
+    fn test_recreate_body_drops_image_defaults() {
+        let body = recreate_body(&inspected(), Some(&old_image())).unwrap();
+        assert_eq!(body.image.as_deref(), Some("app:latest"));
+        assert_eq!(body.env, Some(vec!["MODE=prod".to_string()]));
+        assert_eq!(body.cmd, None);
+        assert_eq!(body.working_dir, None);
+        let labels = body.labels.unwrap();
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels.get("team").map(String::as_str), Some("infra"));
+        // Hostname derivado do ID antigo não é herdado
+        assert_eq!(body.hostname, None);
+    }
+
+    #[test]
+    fn test_recreate_body_without_image_keeps_config() {
+        let body = recreate_body(&inspected(), None).unwrap();
+        assert_eq!(body.env.map(|env| env.len()), Some(3));
+        assert_eq!(body.cmd, Some(vec!["serve".to_string()]));
+    }
+
+    #[test]
+    fn test_recreate_body_keeps_cmd_with_own_entrypoint() {
+        let mut inspect = inspected();
+        inspect["Config"]["Entrypoint"] = json!(["/entrypoint.sh"]);
+        let body = recreate_body(&inspect, Some(&old_image())).unwrap();
+        assert_eq!(body.entrypoint, Some(vec!["/entrypoint.sh".to_string()]));
+        assert_eq!(body.cmd, Some(vec!["serve".to_string()]));
+    }
+
+    #[test]
+    fn test_recreate_body_carries_anonymous_volumes() {
+        let body = recreate_body(&inspected(), None).unwrap();
+        let mounts = body.host_config.unwrap().mounts.unwrap();
+        let mount = |target: &str| {
+            mounts
+                .iter()
+                .find(|mount| mount.target.as_deref() == Some(target))
+                .cloned()
+        };
+        // Bind já declarado não vira mount
+        assert!(mount("/data").is_none());
+        // --mount sem origem recebe o volume antigo
+        assert_eq!(mount("/cache").unwrap().source.as_deref(), Some("anon1"));
+        let logs = mount("/logs").unwrap();
+        assert_eq!(logs.source.as_deref(), Some("anon2"));
+        assert_eq!(logs.read_only, Some(true));
+        assert_eq!(mounts.len(), 2);
+    }
+
+    #[test]
+    fn test_recreate_body_keeps_network_configuration_only() {
+        let body = recreate_body(&inspected(), None).unwrap();
+        let endpoints = body.networking_config.unwrap().endpoints_config.unwrap();
+        let backend = endpoints.get("backend").unwrap();
+        assert_eq!(backend.aliases, Some(vec!["api".to_string()]));
+        assert_eq!(backend.ip_address, None);
+        assert_eq!(backend.network_id, None);
+    }
+
+    #[test]
+    fn test_is_active_state() {
+        for state in ["running", "paused", "restarting"] {
+            assert!(is_active_state(state), "{}", state);
+        }
+        for state in ["exited", "created", "dead", "removing", ""] {
+            assert!(!is_active_state(state), "{}", state);
+        }
+    }
+
+    #[test]
+    fn test_pull_error_is_typed() {
+        use bollard::errors::Error;
+
+        let limited = [
+            Error::DockerResponseServerError {
+                status_code: 429,
+                message: "Too Many Requests".to_string(),
+            },
+            Error::DockerStreamError {
+                error: "toomanyrequests: You have reached your pull rate limit".to_string(),
+            },
+        ];
+        for error in limited {
+            let error = pull_error("nginx:latest", error);
+            assert!(matches!(
+                error.downcast_ref::<PullError>(),
+                Some(PullError::RateLimited { image, retry_after: None }) if image == "nginx:latest"
+            ));
+        }
+
+        let other = pull_error(
+            "nginx:latest",
+            Error::DockerResponseServerError {
+                status_code: 404,
+                message: "manifest unknown".to_string(),
+            },
+        );
+        assert!(other.downcast_ref::<PullError>().is_none());
+        assert!(other.to_string().contains("manifest unknown"));
+    }
+
+    #[test]
+    fn test_validate_host() {
+        for valid in [
+            "ssh://admin@[2001:db8::1]:2222",
+            "ssh://admin@servidor.local",
+            "tcp://10.0.0.5:2376",
+            "tcp://servidor.local:2375/",
+            "unix:///var/run/docker.sock",
+            "npipe:////./pipe/docker_engine",
+        ] {
+            assert!(DockerContextConfig::validate_host(valid).is_ok(), "{}", valid);
+        }
+        for invalid in [
+            "servidor.local",
+            "ssh://admin@",
+            "ssh://admin@[2001:db8::1",
+            "tcp://servidor:99999",
+            "unix://",
+            "http://servidor",
+        ] {
+            assert!(DockerContextConfig::validate_host(invalid).is_err(), "{}", invalid);
+        }
+    }
 }
@@ -0,0 +1,343 @@
+// Instalação do Docker em um servidor salvo (contexto ssh://) que ainda não tem o daemon:
+// script oficial (get.docker.com) ou pacotes da distribuição, executados via SSH com a
+// saída acompanhada linha a linha; também instala o plugin do Compose
+use anyhow::{Context, Result, bail};
+use std::collections::HashMap;
+use std::process::Stdio;
+use tokio::io::{AsyncBufReadExt, BufReader};
+
+use crate::config::AppConfig;
+use crate::docker::{DockerContextConfig, DockerManager};
+use crate::ssh::SshClient;
+
+// Lê o essencial do host: docker presente, usuário, sudo sem senha e /etc/os-release
+const PROBE_SCRIPT: &str = "\
+command -v docker >/dev/null 2>&1 && echo DOCKER=yes || echo DOCKER=no
+echo UID=$(id -u)
+echo USER=$(id -un)
+sudo -n true >/dev/null 2>&1 && echo SUDO=yes || echo SUDO=no
+cat /etc/os-release 2>/dev/null";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstallMethod {
+    ConvenienceScript,
+    DistroPackages,
+}
+
+impl InstallMethod {
+    pub fn from_ui(value: &str) -> Self {
+        match value {
+            "packages" => InstallMethod::DistroPackages,
+            _ => InstallMethod::ConvenienceScript,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            InstallMethod::ConvenienceScript => "script oficial",
+            InstallMethod::DistroPackages => "pacotes da distribuição",
+        }
+    }
+}
+
+// Gerenciador de pacotes da distribuição e o pacote do Docker nele
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PackageManager {
+    Apt,
+    Dnf,
+    Apk,
+    Pacman,
+    Zypper,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct HostProbe {
+    pub docker_installed: bool,
+    pub root: bool,
+    pub user: String,
+    pub passwordless_sudo: bool,
+    // PRETTY_NAME, ID e ID_LIKE do /etc/os-release
+    pub os_name: String,
+    os_id: String,
+    os_like: String,
+}
+
+impl HostProbe {
+    fn parse(output: &str) -> Self {
+        let values: HashMap<&str, String> = output
+            .lines()
+            .filter_map(|line| line.split_once('='))
+            .map(|(key, value)| (key.trim(), value.trim().trim_matches('"').to_string()))
+            .collect();
+        let value = |key: &str| values.get(key).cloned().unwrap_or_default();
+        Self {
+            docker_installed: value("DOCKER") == "yes",
+            root: value("UID") == "0",
+            user: value("USER"),
+            passwordless_sudo: value("SUDO") == "yes",
+            os_name: value("PRETTY_NAME"),
+            os_id: value("ID"),
+            os_like: value("ID_LIKE"),
+        }
+    }
+
+    fn package_manager(&self) -> Option<PackageManager> {
+        let ids: Vec<&str> = std::iter::once(self.os_id.as_str())
+            .chain(self.os_like.split_whitespace())
+            .collect();
+        let has = |names: &[&str]| ids.iter().any(|id| names.contains(id));
+        if has(&["debian", "ubuntu"]) {
+            Some(PackageManager::Apt)
+        } else if has(&["fedora"]) {
+            Some(PackageManager::Dnf)
+        } else if has(&["alpine"]) {
+            Some(PackageManager::Apk)
+        } else if has(&["arch"]) {
+            Some(PackageManager::Pacman)
+        } else if has(&["suse", "opensuse"]) {
+            Some(PackageManager::Zypper)
+        } else {
+            None
+        }
+    }
+
+    // Pode rodar comandos como root sem pedir senha (o ssh roda em BatchMode, sem terminal)
+    pub fn can_install(&self) -> bool {
+        self.root || self.passwordless_sudo
+    }
+
+    // Ex: "Ubuntu 24.04 LTS · usuário deploy com sudo sem senha · Docker não instalado"
+    pub fn summary(&self) -> String {
+        let os = if self.os_name.is_empty() {
+            "Sistema desconhecido"
+        } else {
+            &self.os_name
+        };
+        let user = if self.root {
+            "usuário root".to_string()
+        } else if self.passwordless_sudo {
+            format!("usuário {} com sudo sem senha", self.user)
+        } else {
+            format!("usuário {} sem sudo sem senha", self.user)
+        };
+        let docker = if self.docker_installed {
+            "Docker já instalado"
+        } else {
+            "Docker não instalado"
+        };
+        format!("{} · {} · {}", os, user, docker)
+    }
+}
+
+// Servidores salvos acessados por SSH, os únicos em que a instalação é oferecida
+pub fn ssh_servers() -> Vec<DockerContextConfig> {
+    DockerContextConfig::list_available()
+        .into_iter()
+        .filter(|context| context.host.starts_with("ssh://"))
+        .collect()
+}
+
+pub fn find_server(name: &str) -> Result<DockerContextConfig> {
+    ssh_servers()
+        .into_iter()
+        .find(|context| context.name == name)
+        .ok_or_else(|| anyhow::anyhow!("Servidor SSH '{}' não encontrado", name))
+}
+
+// Cliente SSH do servidor salvo, sem abrir o túnel para o daemon (que ainda não existe)
+fn ssh_client(context: &DockerContextConfig) -> Result<SshClient> {
+    if !context.host.starts_with("ssh://") {
+        bail!(
+            "O servidor '{}' não usa SSH ({}); a instalação só é feita em servidores ssh://",
+            context.name,
+            context.host
+        );
+    }
+    let config = AppConfig::load();
+    SshClient::new(
+        &context.name,
+        &context.host,
+        config.proxy_for_server(&context.name),
+        config.ssh_options_for(&context.name),
+    )
+}
+
+pub async fn probe(context: &DockerContextConfig) -> Result<HostProbe> {
+    let command = ssh_client(context)?.remote_command(&["sh", "-c", PROBE_SCRIPT]);
+    let output = tokio::process::Command::from(command)
+        .output()
+        .await
+        .context("Falha ao executar ssh")?;
+    if !output.status.success() {
+        bail!(
+            "Falha ao acessar {} via SSH: {}",
+            context.name,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(HostProbe::parse(&String::from_utf8_lossy(&output.stdout)))
+}
+
+// Script executado no host; os passos aparecem na saída com "==>"
+pub fn install_script(method: InstallMethod, probe: &HostProbe) -> Result<String> {
+    if !probe.can_install() {
+        bail!(
+            "O usuário {} não é root e o sudo pede senha. Libere o sudo sem senha (NOPASSWD) ou conecte como root",
+            probe.user
+        );
+    }
+    let sudo = if probe.root { "" } else { "sudo -n " };
+    let mut lines = vec!["set -e".to_string(), "exec 2>&1".to_string()];
+
+    match method {
+        InstallMethod::ConvenienceScript => {
+            // Diretório próprio (0700) do mktemp: um caminho fixo no /tmp poderia ser criado ou
+            // trocado por outro usuário antes do script rodar como root
+            lines.push("workdir=$(mktemp -d)".to_string());
+            lines.push("trap 'rm -rf \"$workdir\"' EXIT".to_string());
+            lines.push(
+                "[ -O \"$workdir\" ] || { echo 'Diretório temporário não pertence ao usuário'; exit 1; }"
+                    .to_string(),
+            );
+            lines.push("echo '==> Baixando o script oficial (get.docker.com)'".to_string());
+            lines.push(
+                "if command -v curl >/dev/null 2>&1; then curl -fsSL https://get.docker.com -o \"$workdir/get-docker.sh\"; \
+                 else wget -qO \"$workdir/get-docker.sh\" https://get.docker.com; fi"
+                    .to_string(),
+            );
+            lines.push("echo '==> Executando o script oficial'".to_string());
+            lines.push(format!("{}sh \"$workdir/get-docker.sh\"", sudo));
+        }
+        InstallMethod::DistroPackages => {
+            let Some(manager) = probe.package_manager() else {
+                bail!(
+                    "Distribuição sem pacote do Docker conhecido ({}); use o script oficial",
+                    probe.os_name
+                );
+            };
+            lines.push("echo '==> Instalando os pacotes da distribuição'".to_string());
+            match manager {
+                PackageManager::Apt => {
+                    lines.push(format!("{}apt-get update", sudo));
+                    lines.push(format!(
+                        "{}env DEBIAN_FRONTEND=noninteractive apt-get install -y docker.io",
+                        sudo
+                    ));
+                }
+                PackageManager::Dnf => {
+                    lines.push(format!("{}dnf install -y moby-engine", sudo));
+                }
+                PackageManager::Apk => {
+                    lines.push(format!("{}apk add docker", sudo));
+                    lines.push(format!("{}rc-update add docker default", sudo));
+                    lines.push(format!("{}service docker start", sudo));
+                }
+                PackageManager::Pacman => {
+                    lines.push(format!("{}pacman -Sy --noconfirm docker", sudo));
+                }
+                PackageManager::Zypper => {
+                    lines.push(format!("{}zypper --non-interactive install docker", sudo));
+                }
+            }
+        }
+    }
+
+    lines.push("echo '==> Iniciando o serviço'".to_string());
+    lines.push(format!(
+        "if command -v systemctl >/dev/null 2>&1; then {}systemctl enable --now docker; fi",
+        sudo
+    ));
+    // O túnel da aplicação usa o socket com o usuário do SSH: precisa do grupo docker
+    if !probe.root {
+        lines.push(format!(
+            "echo '==> Adicionando {} ao grupo docker'",
+            probe.user
+        ));
+        lines.push(format!(
+            "if command -v usermod >/dev/null 2>&1; then {sudo}usermod -aG docker \"$(id -un)\"; \
+             else {sudo}addgroup \"$(id -un)\" docker; fi",
+            sudo = sudo
+        ));
+    }
+    lines.push("echo '==> Concluído'".to_string());
+    Ok(lines.join("\n"))
+}
+
+// Plugin do Compose v2 para servidores só com o docker-compose legado ou sem compose:
+// binário dos releases oficiais no diretório de plugins do CLI
+pub fn compose_plugin_script(probe: &HostProbe) -> Result<String> {
+    if !probe.docker_installed {
+        bail!("O Docker não está instalado; instale-o antes do plugin do Compose");
+    }
+    if !probe.can_install() {
+        bail!(
+            "O usuário {} não é root e o sudo pede senha. Libere o sudo sem senha (NOPASSWD) ou conecte como root",
+            probe.user
+        );
+    }
+    let sudo = if probe.root { "" } else { "sudo -n " };
+    let plugin = "/usr/local/lib/docker/cli-plugins/docker-compose";
+    let lines = [
+        "set -e".to_string(),
+        "exec 2>&1".to_string(),
+        "echo '==> Baixando o plugin do Compose'".to_string(),
+        "URL=\"https://github.com/docker/compose/releases/latest/download/docker-compose-$(uname -s | tr '[:upper:]' '[:lower:]')-$(uname -m)\"".to_string(),
+        format!("{}mkdir -p /usr/local/lib/docker/cli-plugins", sudo),
+        format!(
+            "if command -v curl >/dev/null 2>&1; then {sudo}curl -fsSL \"$URL\" -o {plugin}; \
+             else {sudo}wget -qO {plugin} \"$URL\"; fi",
+            sudo = sudo,
+            plugin = plugin
+        ),
+        format!("{}chmod +x {}", sudo, plugin),
+        "docker compose version".to_string(),
+        "echo '==> Concluído'".to_string(),
+    ];
+    Ok(lines.join("\n"))
+}
+
+// Executa o script chamando `on_line` para cada linha de saída
+pub async fn run_install(
+    context: &DockerContextConfig,
+    script: &str,
+    mut on_line: impl FnMut(&str),
+) -> Result<()> {
+    let command = ssh_client(context)?.remote_command(&["sh", "-c", script]);
+    let mut command = tokio::process::Command::from(command);
+    command.stdout(Stdio::piped()).stderr(Stdio::piped());
+    let mut child = command.spawn().context("Falha ao executar ssh")?;
+
+    // O script junta stderr no stdout; o stderr do ssh só traz erros de conexão
+    let stdout = child.stdout.take().context("Saída do ssh indisponível")?;
+    let mut lines = BufReader::new(stdout).lines();
+    let mut last_line = String::new();
+    while let Some(line) = lines.next_line().await? {
+        if !line.trim().is_empty() {
+            last_line = line.clone();
+        }
+        on_line(&line);
+    }
+
+    let output = child
+        .wait_with_output()
+        .await
+        .context("Falha ao aguardar o ssh")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        bail!(
+            "Instalação falhou ({}): {}",
+            output.status,
+            if stderr.is_empty() { last_line } else { stderr }
+        );
+    }
+    Ok(())
+}
+
+// Conecta ao daemon recém-instalado pelo túnel de sempre e retorna a versão
+pub async fn verify(context: &DockerContextConfig) -> Result<String> {
+    let manager = DockerManager::with_context(context.clone())
+        .await
+        .context("O Docker foi instalado, mas o daemon não respondeu pelo túnel SSH")?;
+    let info = manager.get_docker_info().await?;
+    Ok(format!("Docker {} ({})", info.version, info.architecture))
+}
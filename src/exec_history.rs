@@ -0,0 +1,131 @@
+// Histórico de comandos do console exec por container, snippets salvos e snippets
+// adicionados por imagem (~/.config/docker-ui/exec_history.json)
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, path::PathBuf};
+
+use crate::config::AppConfig;
+use crate::exec_snippets;
+
+// Comandos guardados por container
+const MAX_HISTORY: usize = 200;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExecHistory {
+    // Chave: "servidor/container" (nome, que sobrevive à recriação); mais antigo primeiro
+    history: HashMap<String, Vec<String>>,
+    snippets: Vec<String>,
+    // Chave: `exec_snippets::image_key` (ex: "postgres"), valem para todo container da imagem
+    image_snippets: HashMap<String, Vec<String>>,
+}
+
+impl ExecHistory {
+    fn path() -> PathBuf {
+        AppConfig::path().with_file_name("exec_history.json")
+    }
+
+    // Arquivo ausente ou inválido resulta em histórico vazio
+    pub fn load() -> Self {
+        std::fs::read_to_string(Self::path())
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = Self::path();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).context("Falha ao criar diretório de configuração")?;
+        }
+        let content =
+            serde_json::to_string_pretty(self).context("Falha ao serializar histórico")?;
+        std::fs::write(&path, content).context("Falha ao gravar histórico de comandos")
+    }
+
+    pub fn commands(&self, server: &str, container: &str) -> &[String] {
+        self.history
+            .get(&format!("{}/{}", server, container))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    // Registra o comando; repetir o último não duplica a entrada
+    pub fn push(&mut self, server: &str, container: &str, command: &str) {
+        let commands = self
+            .history
+            .entry(format!("{}/{}", server, container))
+            .or_default();
+        if commands.last().is_some_and(|last| last == command) {
+            return;
+        }
+        commands.push(command.to_string());
+        if commands.len() > MAX_HISTORY {
+            commands.remove(0);
+        }
+    }
+
+    pub fn snippets(&self) -> &[String] {
+        &self.snippets
+    }
+
+    pub fn add_snippet(&mut self, command: &str) -> Result<()> {
+        let command = command.trim();
+        if command.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Digite o comando antes de salvar o snippet"
+            ));
+        }
+        if !self.snippets.iter().any(|snippet| snippet == command) {
+            self.snippets.push(command.to_string());
+        }
+        Ok(())
+    }
+
+    pub fn remove_snippet(&mut self, command: &str) {
+        self.snippets.retain(|snippet| snippet != command);
+    }
+
+    // Snippets da imagem: os embutidos primeiro, depois os do usuário (true = embutido)
+    pub fn image_snippets(&self, image: &str) -> Vec<(String, bool)> {
+        let mut snippets: Vec<(String, bool)> = exec_snippets::builtin(image)
+            .iter()
+            .map(|command| (command.to_string(), true))
+            .collect();
+        if let Some(custom) = self.image_snippets.get(&exec_snippets::image_key(image)) {
+            snippets.extend(custom.iter().map(|command| (command.clone(), false)));
+        }
+        snippets
+    }
+
+    pub fn add_image_snippet(&mut self, image: &str, command: &str) -> Result<()> {
+        let command = command.trim();
+        if command.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Digite o comando antes de salvar o snippet"
+            ));
+        }
+        let key = exec_snippets::image_key(image);
+        if key.is_empty() {
+            return Err(anyhow::anyhow!("Container sem imagem identificada"));
+        }
+        if exec_snippets::builtin(image).contains(&command) {
+            return Ok(());
+        }
+        let snippets = self.image_snippets.entry(key).or_default();
+        if !snippets.iter().any(|snippet| snippet == command) {
+            snippets.push(command.to_string());
+        }
+        Ok(())
+    }
+
+    pub fn remove_image_snippet(&mut self, image: &str, command: &str) {
+        let key = exec_snippets::image_key(image);
+        if let Some(snippets) = self.image_snippets.get_mut(&key) {
+            snippets.retain(|snippet| snippet != command);
+            if snippets.is_empty() {
+                self.image_snippets.remove(&key);
+            }
+        }
+    }
+}
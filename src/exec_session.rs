@@ -0,0 +1,346 @@
+// Execução de comandos do console pela API com um TTY de verdade: a saída chega enquanto o
+// processo roda e passa por um emulador de terminal (para ferramentas de tela cheia como htop),
+// o teclado do console vai para o stdin e o tamanho do console é repassado ao processo
+// (e reajustado durante a execução, gerando SIGWINCH no container)
+use anyhow::{Context, Result};
+use bollard::Docker;
+use bollard::exec::{CreateExecOptions, ResizeExecOptions, StartExecOptions, StartExecResults};
+use futures_util::StreamExt;
+use regex::Regex;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::mpsc;
+
+// Intervalo mínimo entre atualizações da tela do console durante a execução
+const SCREEN_REFRESH: Duration = Duration::from_millis(50);
+// Saída bruta guardada para o histórico do console; o início é descartado além disso
+const RAW_OUTPUT_LIMIT: usize = 4 * 1024 * 1024;
+
+// Tamanho do console em caracteres
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TerminalSize {
+    pub columns: u16,
+    pub rows: u16,
+}
+
+impl Default for TerminalSize {
+    fn default() -> Self {
+        Self {
+            columns: 80,
+            rows: 24,
+        }
+    }
+}
+
+impl TerminalSize {
+    // Valores vindos da interface; limita a faixa aceita pelo daemon
+    pub fn from_ui(columns: i32, rows: i32) -> Self {
+        Self {
+            columns: columns.clamp(20, 500) as u16,
+            rows: rows.clamp(5, 200) as u16,
+        }
+    }
+}
+
+// Exec em andamento: recebe a entrada do console e os redimensionamentos
+#[derive(Clone)]
+pub struct ActiveExec {
+    client: Docker,
+    exec_id: String,
+    input: mpsc::UnboundedSender<Vec<u8>>,
+    screen: Arc<Mutex<vt100::Parser>>,
+}
+
+impl ActiveExec {
+    // Linha digitada no console: Enter no TTY é "\r"
+    pub fn send_line(&self, line: &str) {
+        let _ = self.input.send(format!("{}\r", line).into_bytes());
+    }
+
+    // Tecla pressionada sobre a tela do console
+    pub fn send_key(&self, text: &str, control: bool) {
+        let application_cursor = self.screen.lock().unwrap().screen().application_cursor();
+        let bytes = key_bytes(text, control, application_cursor);
+        if !bytes.is_empty() {
+            let _ = self.input.send(bytes);
+        }
+    }
+
+    // Ctrl+C: o TTY entrega SIGINT ao processo em primeiro plano
+    pub fn interrupt(&self) {
+        let _ = self.input.send(vec![0x03]);
+    }
+
+    pub async fn resize(&self, size: TerminalSize) {
+        self.screen
+            .lock()
+            .unwrap()
+            .screen_mut()
+            .set_size(size.rows, size.columns);
+        resize(&self.client, &self.exec_id, size).await;
+    }
+
+    // Se o processo ainda roda no container (um exec não termina quando o cliente desconecta)
+    pub async fn is_running(&self) -> bool {
+        self.client
+            .inspect_exec(&self.exec_id)
+            .await
+            .ok()
+            .and_then(|inspect| inspect.running)
+            .unwrap_or(false)
+    }
+}
+
+// Tamanho atual do console e o exec em andamento; `interactive` indica que o usuário já
+// enviou entrada ao exec atual
+#[derive(Default)]
+pub struct ExecTerminal {
+    pub size: TerminalSize,
+    pub active: Option<ActiveExec>,
+    pub interactive: bool,
+}
+
+pub struct ExecOutcome {
+    pub output: String,
+    pub exit_code: Option<i64>,
+}
+
+// Executa `sh -c <command>` com TTY do tamanho informado. `on_start` recebe o exec para
+// entrada e redimensionamento; `on_screen` recebe a tela do terminal a cada atualização
+pub async fn run(
+    client: &Docker,
+    container: &str,
+    command: &str,
+    size: TerminalSize,
+    on_start: impl FnOnce(ActiveExec),
+    mut on_screen: impl FnMut(String),
+) -> Result<ExecOutcome> {
+    let exec = client
+        .create_exec(
+            container,
+            CreateExecOptions {
+                attach_stdin: Some(true),
+                attach_stdout: Some(true),
+                attach_stderr: Some(true),
+                tty: Some(true),
+                env: Some(vec![
+                    "TERM=xterm-256color".to_string(),
+                    format!("COLUMNS={}", size.columns),
+                    format!("LINES={}", size.rows),
+                ]),
+                cmd: Some(vec![
+                    "sh".to_string(),
+                    "-c".to_string(),
+                    command.to_string(),
+                ]),
+                ..Default::default()
+            },
+        )
+        .await
+        .context("Falha ao criar o exec")?;
+
+    let started = client
+        .start_exec(
+            &exec.id,
+            Some(StartExecOptions {
+                detach: false,
+                tty: true,
+                output_capacity: None,
+            }),
+        )
+        .await
+        .context("Falha ao iniciar o exec")?;
+    let StartExecResults::Attached {
+        output: mut stream,
+        mut input,
+    } = started
+    else {
+        return Err(anyhow::anyhow!("O daemon não anexou o exec ao console"));
+    };
+    // O daemon só aceita o tamanho depois do início
+    resize(client, &exec.id, size).await;
+
+    let (input_tx, mut input_rx) = mpsc::unbounded_channel::<Vec<u8>>();
+    let writer = tokio::spawn(async move {
+        while let Some(bytes) = input_rx.recv().await {
+            if input.write_all(&bytes).await.is_err() || input.flush().await.is_err() {
+                break;
+            }
+        }
+    });
+
+    let screen = Arc::new(Mutex::new(vt100::Parser::new(size.rows, size.columns, 0)));
+    on_start(ActiveExec {
+        client: client.clone(),
+        exec_id: exec.id.clone(),
+        input: input_tx,
+        screen: screen.clone(),
+    });
+
+    let mut raw = Vec::new();
+    let mut full_screen = false;
+    // Tela com mudanças ainda não enviadas: é enviada quando a saída para por SCREEN_REFRESH
+    let mut pending = false;
+    let mut last_refresh: Option<Instant> = None;
+    loop {
+        let next = if pending {
+            match tokio::time::timeout(SCREEN_REFRESH, stream.next()).await {
+                Ok(next) => next,
+                Err(_) => {
+                    pending = false;
+                    last_refresh = Some(Instant::now());
+                    on_screen(screen.lock().unwrap().screen().contents());
+                    continue;
+                }
+            }
+        } else {
+            stream.next().await
+        };
+        let Some(Ok(chunk)) = next else {
+            break;
+        };
+        let bytes = chunk.into_bytes();
+        raw.extend_from_slice(&bytes);
+        if raw.len() > RAW_OUTPUT_LIMIT {
+            raw.drain(..raw.len() - RAW_OUTPUT_LIMIT / 2);
+        }
+
+        let mut parser = screen.lock().unwrap();
+        parser.process(&bytes);
+        full_screen |= parser.screen().alternate_screen();
+        pending = last_refresh.is_some_and(|last| last.elapsed() < SCREEN_REFRESH);
+        if !pending {
+            last_refresh = Some(Instant::now());
+            on_screen(parser.screen().contents());
+        }
+    }
+    writer.abort();
+
+    let exit_code = client
+        .inspect_exec(&exec.id)
+        .await
+        .ok()
+        .and_then(|inspect| inspect.exit_code);
+    // Programas de tela cheia redesenham a tela o tempo todo: o histórico fica só com o que
+    // restou na tela ao sair, e não com cada quadro desenhado
+    let output = if full_screen {
+        screen.lock().unwrap().screen().contents()
+    } else {
+        plain_text(&String::from_utf8_lossy(&raw))
+    };
+    Ok(ExecOutcome { output, exit_code })
+}
+
+// Falhas são ignoradas: o exec pode ter terminado entre o redimensionamento e a chamada
+async fn resize(client: &Docker, exec_id: &str, size: TerminalSize) {
+    let _ = client
+        .resize_exec(
+            exec_id,
+            ResizeExecOptions {
+                width: size.columns,
+                height: size.rows,
+            },
+        )
+        .await;
+}
+
+// Bytes que um terminal xterm envia para a tecla (texto do evento de tecla do Slint, que usa
+// caracteres de uso privado para setas, Home/End, F1-F12 etc.)
+fn key_bytes(text: &str, control: bool, application_cursor: bool) -> Vec<u8> {
+    let mut chars = text.chars();
+    let (Some(key), None) = (chars.next(), chars.next()) else {
+        return text.as_bytes().to_vec();
+    };
+
+    // Ctrl+letra vira o caractere de controle (Ctrl+C = 0x03)
+    if control && key.is_ascii_alphabetic() {
+        return vec![key.to_ascii_lowercase() as u8 - b'a' + 1];
+    }
+
+    let cursor = |code: char| {
+        let prefix = if application_cursor { "\x1bO" } else { "\x1b[" };
+        format!("{}{}", prefix, code).into_bytes()
+    };
+    let sequence: &str = match key {
+        '\u{F700}' => return cursor('A'),
+        '\u{F701}' => return cursor('B'),
+        '\u{F703}' => return cursor('C'),
+        '\u{F702}' => return cursor('D'),
+        '\u{F729}' => return cursor('H'),
+        '\u{F72B}' => return cursor('F'),
+        '\n' => "\r",
+        '\u{8}' => "\x7f",
+        '\u{7f}' => "\x1b[3~",
+        '\u{19}' => "\x1b[Z",
+        '\u{F727}' => "\x1b[2~",
+        '\u{F72C}' => "\x1b[5~",
+        '\u{F72D}' => "\x1b[6~",
+        '\u{F704}' => "\x1bOP",
+        '\u{F705}' => "\x1bOQ",
+        '\u{F706}' => "\x1bOR",
+        '\u{F707}' => "\x1bOS",
+        '\u{F708}' => "\x1b[15~",
+        '\u{F709}' => "\x1b[17~",
+        '\u{F70A}' => "\x1b[18~",
+        '\u{F70B}' => "\x1b[19~",
+        '\u{F70C}' => "\x1b[20~",
+        '\u{F70D}' => "\x1b[21~",
+        '\u{F70E}' => "\x1b[23~",
+        '\u{F70F}' => "\x1b[24~",
+        // Modificadores sozinhos e demais teclas especiais não geram entrada
+        '\u{10}'..='\u{18}' | '\u{F700}'..='\u{F8FF}' => "",
+        _ => return text.as_bytes().to_vec(),
+    };
+    sequence.as_bytes().to_vec()
+}
+
+// O histórico do console guarda texto puro: remove cores e controles de cursor do TTY e os "\r"
+fn plain_text(output: &str) -> String {
+    static ANSI: OnceLock<Regex> = OnceLock::new();
+    let ansi = ANSI.get_or_init(|| {
+        Regex::new(
+            r"\x1b\[[0-?]*[ -/]*[@-~]|\x1b\][^\x07]*(\x07|\x1b\\)|\x1b[()][0-9A-Za-z]|\x1b[=>]",
+        )
+        .unwrap()
+    });
+    ansi.replace_all(output, "")
+        .replace("\r\n", "\n")
+        .replace('\r', "")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_bytes_control_and_text() {
+        assert_eq!(key_bytes("c", true, false), vec![0x03]);
+        assert_eq!(key_bytes("D", true, false), vec![0x04]);
+        assert_eq!(key_bytes("q", false, false), b"q".to_vec());
+        assert_eq!(key_bytes("ã", false, false), "ã".as_bytes().to_vec());
+        assert_eq!(key_bytes("\n", false, false), b"\r".to_vec());
+        assert_eq!(key_bytes("\u{8}", false, false), vec![0x7f]);
+    }
+
+    #[test]
+    fn test_key_bytes_special_keys() {
+        assert_eq!(key_bytes("\u{F700}", false, false), b"\x1b[A".to_vec());
+        // htop e vim ligam o modo de cursor da aplicação
+        assert_eq!(key_bytes("\u{F700}", false, true), b"\x1bOA".to_vec());
+        assert_eq!(key_bytes("\u{F702}", false, true), b"\x1bOD".to_vec());
+        assert_eq!(key_bytes("\u{F708}", false, false), b"\x1b[15~".to_vec());
+        assert_eq!(key_bytes("\u{F72D}", false, false), b"\x1b[6~".to_vec());
+        // Shift ou Control pressionados sozinhos
+        assert!(key_bytes("\u{10}", false, false).is_empty());
+        assert!(key_bytes("\u{11}", true, false).is_empty());
+    }
+
+    #[test]
+    fn test_plain_text_strips_tty_controls() {
+        assert_eq!(
+            plain_text("\x1b[1;32mok\x1b[0m\r\nfim\r"),
+            "ok\nfim".to_string()
+        );
+    }
+}
@@ -0,0 +1,111 @@
+// Snippets do console exec por imagem: biblioteca embutida para imagens comuns
+// (postgres, redis, mysql...) e os adicionados pelo usuário, guardados no ExecHistory
+//
+// Os comandos rodam em `sh -c` no container, então variáveis como $POSTGRES_USER
+// são expandidas com o ambiente do próprio container
+
+// Chave da imagem na biblioteca -> comandos sugeridos
+const BUILTIN: &[(&str, &[&str])] = &[
+    (
+        "postgres",
+        &[
+            "psql -U \"${POSTGRES_USER:-postgres}\" -d \"${POSTGRES_DB:-${POSTGRES_USER:-postgres}}\"",
+            "pg_isready",
+            "psql -U \"${POSTGRES_USER:-postgres}\" -c 'SELECT datname, pg_size_pretty(pg_database_size(datname)) FROM pg_database'",
+        ],
+    ),
+    (
+        "redis",
+        &[
+            "redis-cli",
+            "redis-cli info memory",
+            "redis-cli --scan --pattern '*' | head -50",
+        ],
+    ),
+    ("valkey", &["valkey-cli", "valkey-cli info memory"]),
+    (
+        "mysql",
+        &[
+            "mysql -u root -p\"$MYSQL_ROOT_PASSWORD\"",
+            "mysqladmin -u root -p\"$MYSQL_ROOT_PASSWORD\" status",
+        ],
+    ),
+    (
+        "mariadb",
+        &[
+            "mariadb -u root -p\"${MARIADB_ROOT_PASSWORD:-$MYSQL_ROOT_PASSWORD}\"",
+            "mariadb-admin -u root -p\"${MARIADB_ROOT_PASSWORD:-$MYSQL_ROOT_PASSWORD}\" status",
+        ],
+    ),
+    (
+        "mongo",
+        &[
+            "mongosh -u \"$MONGO_INITDB_ROOT_USERNAME\" -p \"$MONGO_INITDB_ROOT_PASSWORD\"",
+            "mongosh --quiet --eval 'db.adminCommand({ listDatabases: 1 })'",
+        ],
+    ),
+    (
+        "nginx",
+        &["nginx -t", "nginx -T | head -100", "nginx -s reload"],
+    ),
+    ("httpd", &["httpd -t", "apachectl -S"]),
+    (
+        "rabbitmq",
+        &[
+            "rabbitmqctl status",
+            "rabbitmqctl list_queues name messages consumers",
+        ],
+    ),
+    (
+        "elasticsearch",
+        &[
+            "curl -s localhost:9200/_cluster/health?pretty",
+            "curl -s localhost:9200/_cat/indices?v",
+        ],
+    ),
+    ("memcached", &["echo stats | nc localhost 11211"]),
+    ("node", &["node --version", "npm ls --depth=0"]),
+    ("python", &["python --version", "pip list"]),
+    ("php", &["php -v", "php -m"]),
+    ("alpine", &["apk info -v | sort", "cat /etc/os-release"]),
+    ("ubuntu", &["cat /etc/os-release", "dpkg -l | head -50"]),
+    ("debian", &["cat /etc/os-release", "dpkg -l | head -50"]),
+];
+
+// Outros nomes para as mesmas imagens (Bitnami, forks, imagens oficiais com sufixo)
+const ALIASES: &[(&str, &str)] = &[
+    ("postgresql", "postgres"),
+    ("postgis", "postgres"),
+    ("timescaledb", "postgres"),
+    ("redis-stack", "redis"),
+    ("redis-stack-server", "redis"),
+    ("mongodb", "mongo"),
+    ("nginx-unprivileged", "nginx"),
+    ("openresty", "nginx"),
+    ("apache", "httpd"),
+    ("percona-server", "mysql"),
+];
+
+// Nome da imagem sem registry, namespace, tag ou digest, ex:
+// "docker.io/bitnami/postgresql:16@sha256:..." -> "postgresql"
+pub fn image_key(image: &str) -> String {
+    let image = image.trim();
+    let image = image.split_once('@').map_or(image, |(name, _)| name);
+    let name = image.rsplit('/').next().unwrap_or(image);
+    let name = name.split_once(':').map_or(name, |(name, _)| name);
+    name.to_lowercase()
+}
+
+// Comandos embutidos para a imagem; vazio quando ela não está na biblioteca
+pub fn builtin(image: &str) -> &'static [&'static str] {
+    let key = image_key(image);
+    let key = ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == key)
+        .map_or(key.as_str(), |(_, target)| target);
+    BUILTIN
+        .iter()
+        .find(|(name, _)| *name == key)
+        .map(|(_, commands)| *commands)
+        .unwrap_or_default()
+}
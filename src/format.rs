@@ -0,0 +1,194 @@
+// Formatação de tamanhos, taxas e memória exibidos na interface
+// Separador decimal e unidades (1024 ou 1000) vêm das configurações
+use serde::{Deserialize, Serialize};
+use std::sync::RwLock;
+
+// Separador decimal; "auto" segue o idioma do sistema (LANG/LC_NUMERIC)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DecimalSeparator {
+    #[default]
+    Auto,
+    Point,
+    Comma,
+}
+
+impl DecimalSeparator {
+    pub fn from_ui(value: &str) -> Self {
+        match value {
+            "point" => DecimalSeparator::Point,
+            "comma" => DecimalSeparator::Comma,
+            _ => DecimalSeparator::Auto,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DecimalSeparator::Auto => "auto",
+            DecimalSeparator::Point => "point",
+            DecimalSeparator::Comma => "comma",
+        }
+    }
+
+    fn uses_comma(&self) -> bool {
+        match self {
+            DecimalSeparator::Point => false,
+            DecimalSeparator::Comma => true,
+            DecimalSeparator::Auto => system_uses_comma(),
+        }
+    }
+}
+
+// Binário: KiB/MiB (1024), como o docker stats; decimal: kB/MB (1000), como o docker images
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ByteUnits {
+    #[default]
+    Binary,
+    Decimal,
+}
+
+impl ByteUnits {
+    pub fn from_ui(value: &str) -> Self {
+        match value {
+            "decimal" => ByteUnits::Decimal,
+            _ => ByteUnits::Binary,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ByteUnits::Binary => "binary",
+            ByteUnits::Decimal => "decimal",
+        }
+    }
+
+    fn base(&self) -> f64 {
+        match self {
+            ByteUnits::Binary => 1024.0,
+            ByteUnits::Decimal => 1000.0,
+        }
+    }
+
+    fn labels(&self) -> [&'static str; 5] {
+        match self {
+            ByteUnits::Binary => ["B", "KiB", "MiB", "GiB", "TiB"],
+            ByteUnits::Decimal => ["B", "kB", "MB", "GB", "TB"],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FormatSettings {
+    pub separator: DecimalSeparator,
+    pub units: ByteUnits,
+}
+
+// Preferência em uso; lida de várias threads (coleta de stats, listas e interface)
+static SETTINGS: RwLock<FormatSettings> = RwLock::new(FormatSettings {
+    separator: DecimalSeparator::Auto,
+    units: ByteUnits::Binary,
+});
+
+// Aplica a preferência salva; chamado na inicialização e ao alterar as configurações
+pub fn apply(settings: FormatSettings) {
+    if let Ok(mut current) = SETTINGS.write() {
+        *current = settings;
+    }
+}
+
+fn settings() -> FormatSettings {
+    SETTINGS
+        .read()
+        .map(|settings| *settings)
+        .unwrap_or_default()
+}
+
+// Idiomas que usam vírgula decimal (pt, es, fr, de, it...)
+fn system_uses_comma() -> bool {
+    const COMMA_LANGUAGES: [&str; 10] =
+        ["pt", "es", "fr", "de", "it", "nl", "ru", "pl", "tr", "sv"];
+    let locale = ["LC_ALL", "LC_NUMERIC", "LANG"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.is_empty())
+        .unwrap_or_default();
+    let language = locale.split(['_', '.', '-']).next().unwrap_or_default();
+    COMMA_LANGUAGES.contains(&language)
+}
+
+// Número com separadores de milhar e decimal do idioma, ex: "1.234,5" ou "1,234.5"
+pub fn number(value: f64, decimals: usize) -> String {
+    let (decimal, thousands) = if settings().separator.uses_comma() {
+        (',', '.')
+    } else {
+        ('.', ',')
+    };
+
+    let formatted = format!("{:.*}", decimals, value.abs());
+    let (integer, fraction) = formatted.split_once('.').unwrap_or((&formatted, ""));
+
+    let mut grouped = String::new();
+    for (i, digit) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i) % 3 == 0 {
+            grouped.push(thousands);
+        }
+        grouped.push(digit);
+    }
+
+    let sign = if value < 0.0 && formatted.chars().any(|c| c.is_ascii_digit() && c != '0') {
+        "-"
+    } else {
+        ""
+    };
+    if fraction.is_empty() {
+        format!("{}{}", sign, grouped)
+    } else {
+        format!("{}{}{}{}", sign, grouped, decimal, fraction)
+    }
+}
+
+// Valor e unidade para um tamanho em bytes
+fn scale(bytes: f64, units: ByteUnits) -> (f64, &'static str) {
+    let labels = units.labels();
+    let mut value = bytes;
+    let mut index = 0;
+    while value >= units.base() && index < labels.len() - 1 {
+        value /= units.base();
+        index += 1;
+    }
+    (value, labels[index])
+}
+
+// Tamanho legível, ex: "1,50 GiB"
+pub fn bytes(bytes: u64) -> String {
+    sized(bytes as f64, 2)
+}
+
+// Taxa de transferência, ex: "12,3 KiB/s"
+pub fn bytes_rate(bytes_per_sec: u64) -> String {
+    format!("{}/s", sized(bytes_per_sec as f64, 1))
+}
+
+// Crescimento por hora, ex: "12,4 MiB/h"
+pub fn bytes_per_hour(bytes_per_hour: f64) -> String {
+    format!("{}/h", sized(bytes_per_hour, 1))
+}
+
+// Uso de memória com porcentagem, ex: "42,1% (512 MiB / 1,2 GiB)"
+pub fn memory(percentage: f64, usage: u64, limit: u64) -> String {
+    format!(
+        "{}% ({} / {})",
+        number(percentage, 1),
+        sized(usage as f64, 1),
+        sized(limit as f64, 1)
+    )
+}
+
+fn sized(bytes: f64, decimals: usize) -> String {
+    let (value, unit) = scale(bytes, settings().units);
+    // Bytes não têm fração
+    let decimals = if unit == "B" { 0 } else { decimals };
+    format!("{} {}", number(value, decimals), unit)
+}
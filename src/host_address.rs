@@ -0,0 +1,157 @@
+// Host e porta de endpoints de servidores (ssh://, tcp://), com suporte a literais IPv6:
+// "[2001:db8::1]:2222", "2001:db8::1" (sem porta) e "servidor.local:2375"
+use anyhow::{Result, bail};
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HostAddress {
+    // Sem colchetes, mesmo quando IPv6
+    pub host: String,
+    pub port: Option<u16>,
+}
+
+impl HostAddress {
+    // Faz parse de "host", "host:porta", "[ipv6]" ou "[ipv6]:porta"
+    pub fn parse(authority: &str) -> Result<Self> {
+        let authority = authority.trim().trim_end_matches('/');
+        let (host, port) = if let Some(rest) = authority.strip_prefix('[') {
+            let Some((host, after)) = rest.split_once(']') else {
+                bail!("IPv6 sem ']' de fechamento: {}", authority);
+            };
+            if host.parse::<Ipv6Addr>().is_err() {
+                bail!("Endereço IPv6 inválido: {}", host);
+            }
+            let port = match after {
+                "" => None,
+                _ => match after.strip_prefix(':') {
+                    Some(port) => Some(parse_port(port)?),
+                    None => bail!("Esperado ':porta' depois de [{}]", host),
+                },
+            };
+            (host.to_string(), port)
+        } else if authority.matches(':').count() > 1 {
+            // IPv6 sem colchetes não pode ter porta: os ':' seriam ambíguos
+            if authority.parse::<Ipv6Addr>().is_err() {
+                bail!(
+                    "Endereço IPv6 inválido: {} (use [endereço]:porta para informar a porta)",
+                    authority
+                );
+            }
+            (authority.to_string(), None)
+        } else {
+            match authority.split_once(':') {
+                Some((host, port)) => (host.to_string(), Some(parse_port(port)?)),
+                None => (authority.to_string(), None),
+            }
+        };
+
+        if host.is_empty() {
+            bail!("Host vazio em {}", authority);
+        }
+        if !host.contains(':') && !is_valid_hostname(&host) {
+            bail!("Host inválido: {}", host);
+        }
+        Ok(Self { host, port })
+    }
+
+    pub fn is_ipv6(&self) -> bool {
+        self.host.parse::<Ipv6Addr>().is_ok()
+    }
+
+    // Host para URLs, com colchetes quando IPv6
+    pub fn url_host(&self) -> String {
+        if self.is_ipv6() {
+            format!("[{}]", self.host)
+        } else {
+            self.host.clone()
+        }
+    }
+
+    // Forma de exibição, ex: "[2001:db8::1]:2222" ou "servidor.local"
+    pub fn authority(&self) -> String {
+        match self.port {
+            Some(port) => format!("{}:{}", self.url_host(), port),
+            None => self.url_host(),
+        }
+    }
+}
+
+fn parse_port(port: &str) -> Result<u16> {
+    match port.parse::<u16>() {
+        Ok(port) if port > 0 => Ok(port),
+        _ => bail!("Porta inválida: {}", port),
+    }
+}
+
+// Nome DNS (rótulos alfanuméricos com '-'; '_' aceito por hosts internos) ou IPv4
+fn is_valid_hostname(host: &str) -> bool {
+    if host.parse::<Ipv4Addr>().is_ok() {
+        return true;
+    }
+    host.len() <= 253
+        && host.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(authority: &str) -> (String, Option<u16>) {
+        let address = HostAddress::parse(authority).unwrap();
+        (address.host, address.port)
+    }
+
+    #[test]
+    fn test_parse_hostname_and_port() {
+        assert_eq!(parse("servidor.local"), ("servidor.local".to_string(), None));
+        assert_eq!(parse("servidor.local:2375/"), ("servidor.local".to_string(), Some(2375)));
+        assert_eq!(parse("10.0.0.5:22"), ("10.0.0.5".to_string(), Some(22)));
+        assert_eq!(parse("build_host"), ("build_host".to_string(), None));
+    }
+
+    #[test]
+    fn test_parse_ipv6() {
+        assert_eq!(parse("[2001:db8::1]:2222"), ("2001:db8::1".to_string(), Some(2222)));
+        assert_eq!(parse("[::1]"), ("::1".to_string(), None));
+        assert_eq!(parse("2001:db8::1"), ("2001:db8::1".to_string(), None));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for invalid in [
+            "",
+            ":2375",
+            "servidor:0",
+            "servidor:99999",
+            "servidor:abc",
+            "[2001:db8::1",
+            "[2001:db8::1]2222",
+            "[servidor]:22",
+            "2001:db8::zz",
+            "-servidor",
+            "servi dor",
+        ] {
+            assert!(HostAddress::parse(invalid).is_err(), "{} deveria ser inválido", invalid);
+        }
+    }
+
+    #[test]
+    fn test_authority_brackets_ipv6() {
+        let address = HostAddress::parse("[2001:db8::1]:2222").unwrap();
+        assert!(address.is_ipv6());
+        assert_eq!(address.url_host(), "[2001:db8::1]");
+        assert_eq!(address.authority(), "[2001:db8::1]:2222");
+
+        let address = HostAddress::parse("servidor.local").unwrap();
+        assert!(!address.is_ipv6());
+        assert_eq!(address.authority(), "servidor.local");
+    }
+}
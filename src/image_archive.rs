@@ -0,0 +1,104 @@
+// Carregamento de arquivos de imagem (.tar) com docker load
+use anyhow::{Context, Result};
+use std::{
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+// Formatos aceitos pelo docker load
+const ARCHIVE_EXTENSIONS: &[&str] = &[".tar", ".tar.gz", ".tgz", ".tar.bz2", ".tar.xz"];
+
+// Valida o caminho informado (aceita aspas e file:// de caminhos colados)
+pub fn archive_path(path: &str) -> Result<PathBuf> {
+    let path = path.trim().trim_matches(|c| c == '"' || c == '\'');
+    let path = path.strip_prefix("file://").unwrap_or(path);
+    if path.is_empty() {
+        return Err(anyhow::anyhow!("Informe o caminho do arquivo .tar"));
+    }
+
+    let file =
+        std::fs::canonicalize(path).with_context(|| format!("Arquivo '{}' não encontrado", path))?;
+    if !file.is_file() {
+        return Err(anyhow::anyhow!("'{}' não é um arquivo", path));
+    }
+
+    let name = file
+        .file_name()
+        .map(|name| name.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    if !ARCHIVE_EXTENSIONS.iter().any(|ext| name.ends_with(ext)) {
+        return Err(anyhow::anyhow!(
+            "Formato não suportado: use {}",
+            ARCHIVE_EXTENSIONS.join(", ")
+        ));
+    }
+
+    Ok(file)
+}
+
+// Envia o arquivo pelo stdin do `docker load`, informando bytes enviados / total
+pub async fn run_load(
+    command: Command,
+    file: &Path,
+    mut on_progress: impl FnMut(u64, u64),
+) -> Result<Vec<String>> {
+    let mut archive = tokio::fs::File::open(file)
+        .await
+        .context("Falha ao abrir o arquivo da imagem")?;
+    let total = archive.metadata().await.map(|meta| meta.len()).unwrap_or(0);
+
+    let mut command = tokio::process::Command::from(command);
+    command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let mut child = command
+        .spawn()
+        .context("Failed to execute docker load command")?;
+
+    let mut stdin = child.stdin.take().context("Entrada do docker load indisponível")?;
+    let mut buffer = vec![0u8; 256 * 1024];
+    let mut sent = 0u64;
+    loop {
+        let read = archive
+            .read(&mut buffer)
+            .await
+            .context("Falha ao ler o arquivo da imagem")?;
+        if read == 0 {
+            break;
+        }
+        // Se o docker load encerrar antes, o erro real vem no stderr abaixo
+        if stdin.write_all(&buffer[..read]).await.is_err() {
+            break;
+        }
+        sent += read as u64;
+        on_progress(sent, total);
+    }
+    drop(stdin);
+
+    let output = child
+        .wait_with_output()
+        .await
+        .context("Falha ao aguardar o docker load")?;
+    if !output.status.success() {
+        return Err(anyhow::anyhow!(
+            "Falha ao carregar imagem: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    Ok(parse_loaded_images(&String::from_utf8_lossy(&output.stdout)))
+}
+
+// Interpreta "Loaded image: nome:tag" e "Loaded image ID: sha256:..."
+pub fn parse_loaded_images(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .filter_map(|line| {
+            line.strip_prefix("Loaded image: ")
+                .or_else(|| line.strip_prefix("Loaded image ID: "))
+        })
+        .map(|image| image.trim().to_string())
+        .collect()
+}
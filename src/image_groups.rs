@@ -0,0 +1,148 @@
+// Agrupamento de imagens por repositório com o espaço próprio e o compartilhado de cada um
+use anyhow::{Result, anyhow};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+
+use crate::docker::ImageInfo;
+
+// Repositório exibido para imagens sem tag
+pub const UNTAGGED: &str = "<none>";
+
+// Camadas de uma imagem; a chave identifica a camada pelo histórico até ela,
+// então imagens com a mesma base têm as mesmas chaves nas primeiras camadas
+#[derive(Debug, Clone)]
+pub struct ImageLayers {
+    pub image: ImageInfo,
+    pub layers: Vec<(String, u64)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RepoGroup {
+    pub repository: String,
+    pub tags: Vec<String>,
+    pub image_count: usize,
+    // Camadas usadas só por imagens deste repositório
+    pub unique_size: u64,
+    // Camadas usadas também por outros repositórios
+    pub shared_size: u64,
+    pub in_use: bool,
+}
+
+// "registry:5000/app:1.0" -> "registry:5000/app"; "app@sha256:..." -> "app"
+pub fn repository(tag: &str) -> String {
+    let tag = tag.split('@').next().unwrap_or(tag);
+    if tag.is_empty() || tag.starts_with(UNTAGGED) {
+        return UNTAGGED.to_string();
+    }
+    let name_start = tag.rfind('/').map(|slash| slash + 1).unwrap_or(0);
+    match tag[name_start..].rfind(':') {
+        Some(colon) => tag[..name_start + colon].to_string(),
+        None => tag.to_string(),
+    }
+}
+
+// Saída de `docker image history --no-trunc --human=false --format '{{.Size}}\t{{json .CreatedBy}}'`,
+// da camada mais nova para a mais antiga
+pub fn parse_history(output: &str) -> Result<Vec<(String, u64)>> {
+    let mut entries = Vec::new();
+    for line in output.lines().filter(|line| !line.trim().is_empty()) {
+        let (size, created_by) = line
+            .split_once('\t')
+            .ok_or_else(|| anyhow!("Linha inesperada no histórico: {}", line))?;
+        let size = size
+            .trim()
+            .parse::<u64>()
+            .map_err(|_| anyhow!("Tamanho inválido no histórico: {}", size))?;
+        let created_by: String = serde_json::from_str(created_by).unwrap_or_default();
+        entries.push((created_by, size));
+    }
+    entries.reverse();
+
+    // Cada chave encadeia a anterior, como as camadas do Docker
+    let mut parent = 0u64;
+    Ok(entries
+        .into_iter()
+        .map(|(created_by, size)| {
+            let mut hasher = DefaultHasher::new();
+            parent.hash(&mut hasher);
+            created_by.hash(&mut hasher);
+            size.hash(&mut hasher);
+            parent = hasher.finish();
+            (format!("{:016x}", parent), size)
+        })
+        .filter(|(_, size)| *size > 0)
+        .collect())
+}
+
+fn repositories_of(image: &ImageInfo) -> Vec<String> {
+    let mut repositories: Vec<String> = image.tags.iter().map(|tag| repository(tag)).collect();
+    if repositories.is_empty() {
+        repositories.push(UNTAGGED.to_string());
+    }
+    repositories.sort();
+    repositories.dedup();
+    repositories
+}
+
+// Grupos ordenados pelo espaço próprio, do maior para o menor
+pub fn group(images: &[ImageLayers]) -> Vec<RepoGroup> {
+    // Repositórios que usam cada camada
+    let mut owners: HashMap<&str, HashSet<String>> = HashMap::new();
+    let mut sizes: HashMap<&str, u64> = HashMap::new();
+    for image in images {
+        for (key, size) in &image.layers {
+            owners
+                .entry(key)
+                .or_default()
+                .extend(repositories_of(&image.image));
+            sizes.insert(key, *size);
+        }
+    }
+
+    let mut repos: BTreeMap<String, (Vec<String>, usize, HashSet<&str>, bool)> = BTreeMap::new();
+    for image in images {
+        for repo in repositories_of(&image.image) {
+            let entry = repos.entry(repo.clone()).or_default();
+            entry.0.extend(
+                image
+                    .image
+                    .tags
+                    .iter()
+                    .filter(|tag| repository(tag) == repo)
+                    .cloned(),
+            );
+            entry.1 += 1;
+            entry
+                .2
+                .extend(image.layers.iter().map(|(key, _)| key.as_str()));
+            entry.3 |= image.image.in_use;
+        }
+    }
+
+    let mut groups: Vec<RepoGroup> = repos
+        .into_iter()
+        .map(|(repository, (mut tags, image_count, layers, in_use))| {
+            tags.sort();
+            let (mut unique_size, mut shared_size) = (0, 0);
+            for key in layers {
+                let size = sizes.get(key).copied().unwrap_or(0);
+                if owners.get(key).is_some_and(|owners| owners.len() > 1) {
+                    shared_size += size;
+                } else {
+                    unique_size += size;
+                }
+            }
+            RepoGroup {
+                repository,
+                tags,
+                image_count,
+                unique_size,
+                shared_size,
+                in_use,
+            }
+        })
+        .collect();
+    groups.sort_by(|a, b| b.unique_size.cmp(&a.unique_size));
+    groups
+}
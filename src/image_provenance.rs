@@ -0,0 +1,190 @@
+// Procedência da imagem de um container: labels OCI (source, version, revision...)
+// com links para o repositório e o commit, seguidas das demais labels
+use anyhow::{Result, bail};
+use std::collections::HashMap;
+
+// Label OCI, equivalente no padrão antigo label-schema e nome exibido
+const KNOWN_LABELS: [(&str, &str, &str); 9] = [
+    (SOURCE, "org.label-schema.vcs-url", "Código-fonte"),
+    (
+        "org.opencontainers.image.version",
+        "org.label-schema.version",
+        "Versão",
+    ),
+    (REVISION, "org.label-schema.vcs-ref", "Revisão"),
+    (
+        "org.opencontainers.image.created",
+        "org.label-schema.build-date",
+        "Criada em",
+    ),
+    (
+        "org.opencontainers.image.url",
+        "org.label-schema.url",
+        "Página",
+    ),
+    (
+        "org.opencontainers.image.documentation",
+        "org.label-schema.usage",
+        "Documentação",
+    ),
+    (
+        "org.opencontainers.image.title",
+        "org.label-schema.name",
+        "Título",
+    ),
+    (
+        "org.opencontainers.image.vendor",
+        "org.label-schema.vendor",
+        "Fornecedor",
+    ),
+    ("org.opencontainers.image.licenses", "", "Licença"),
+];
+
+const SOURCE: &str = "org.opencontainers.image.source";
+const REVISION: &str = "org.opencontainers.image.revision";
+
+#[derive(Debug, Clone)]
+pub struct ImageLabel {
+    // Nome amigável para labels OCI, a própria chave para as demais
+    pub name: String,
+    pub value: String,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImageProvenance {
+    // ID da imagem em execução (pode diferir da tag atual se ela foi atualizada)
+    pub image_id: String,
+    pub labels: Vec<ImageLabel>,
+    // Quantas labels OCI (ou label-schema) a imagem declara
+    pub known_count: usize,
+}
+
+impl ImageProvenance {
+    pub fn from_labels(image_id: &str, labels: &HashMap<String, String>) -> Self {
+        let get = |key: &str| {
+            labels
+                .get(key)
+                .map(|value| value.trim())
+                .filter(|value| !value.is_empty())
+        };
+
+        let mut used = Vec::new();
+        let mut known = Vec::new();
+        let mut source = None;
+        for (oci, legacy, name) in KNOWN_LABELS {
+            let (key, value) = match get(oci) {
+                Some(value) => (oci, value),
+                None => match get(legacy) {
+                    Some(value) => (legacy, value),
+                    None => continue,
+                },
+            };
+            used.push(key);
+            let url = match oci {
+                SOURCE => {
+                    source = repository_url(value);
+                    source.clone()
+                }
+                REVISION => source
+                    .as_deref()
+                    .and_then(|source| commit_url(source, value)),
+                _ => web_url(value),
+            };
+            known.push(ImageLabel {
+                name: name.to_string(),
+                value: value.to_string(),
+                url,
+            });
+        }
+
+        let mut others: Vec<ImageLabel> = labels
+            .iter()
+            .filter(|(key, _)| !used.contains(&key.as_str()))
+            .map(|(key, value)| ImageLabel {
+                name: key.clone(),
+                value: value.clone(),
+                url: web_url(value),
+            })
+            .collect();
+        others.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let known_count = known.len();
+        known.extend(others);
+        Self {
+            image_id: image_id.to_string(),
+            labels: known,
+            known_count,
+        }
+    }
+
+    // Ex: "sha256:3f4a1b2c9d8e · 4 labels OCI"
+    pub fn summary(&self) -> String {
+        let id = self
+            .image_id
+            .get(..19)
+            .unwrap_or(&self.image_id)
+            .to_string();
+        match self.known_count {
+            0 => format!("{} · a imagem não declara labels OCI de procedência", id),
+            1 => format!("{} · 1 label OCI", id),
+            count => format!("{} · {} labels OCI", id, count),
+        }
+    }
+}
+
+fn web_url(value: &str) -> Option<String> {
+    (value.starts_with("https://") || value.starts_with("http://")).then(|| value.to_string())
+}
+
+// "git@github.com:org/app.git" ou "git+https://github.com/org/app.git" -> "https://github.com/org/app"
+fn repository_url(source: &str) -> Option<String> {
+    let source = source.strip_prefix("git+").unwrap_or(source);
+    let url = match source.strip_prefix("git@") {
+        Some(rest) => {
+            let (host, path) = rest.split_once(':')?;
+            format!("https://{}/{}", host, path)
+        }
+        None => web_url(source)?,
+    };
+    Some(
+        url.trim_end_matches('/')
+            .trim_end_matches(".git")
+            .to_string(),
+    )
+}
+
+// Link do commit nos serviços conhecidos
+fn commit_url(source: &str, revision: &str) -> Option<String> {
+    if !revision.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let host = source.split('/').nth(2)?;
+    let path = match host {
+        "github.com" => "commit",
+        "gitlab.com" => "-/commit",
+        "bitbucket.org" => "commits",
+        _ => return None,
+    };
+    Some(format!("{}/{}/{}", source, path, revision))
+}
+
+// Abre o link no navegador padrão; só http(s), para não executar outros esquemas
+pub fn open_url(url: &str) -> Result<()> {
+    if web_url(url).is_none() {
+        bail!("Link não suportado: {}", url);
+    }
+    let opener = if cfg!(target_os = "macos") {
+        "open"
+    } else {
+        "xdg-open"
+    };
+    std::process::Command::new(opener)
+        .arg(url)
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .map_err(|e| anyhow::anyhow!("Falha ao abrir o navegador ({}): {}", opener, e))?;
+    Ok(())
+}
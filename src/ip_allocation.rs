@@ -0,0 +1,403 @@
+// Endereços IP de uma rede: quais containers ocupam cada IP e quais faixas estão livres
+use serde_json::Value;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+// Faixas livres exibidas por sub-rede; o restante é resumido
+const MAX_FREE_RANGES: usize = 20;
+
+#[derive(Debug, Clone)]
+pub struct IpAllocation {
+    pub ip: String,
+    // Nome do container ou "gateway"
+    pub owner: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct SubnetUsage {
+    pub subnet: String,
+    pub gateway: String,
+    // Faixa usada na alocação automática (IPRange), se diferente da sub-rede
+    pub ip_range: String,
+    // Endereços utilizáveis (sem rede e broadcast); None para IPv6
+    pub capacity: Option<u64>,
+    pub allocations: Vec<IpAllocation>,
+    pub free_count: u64,
+    // Ex: "172.18.0.5 – 172.18.0.254 (250)"
+    pub free_ranges: Vec<String>,
+}
+
+impl SubnetUsage {
+    // Fração da faixa de alocação em uso (0 a 1)
+    pub fn usage(&self) -> f32 {
+        match self.capacity {
+            Some(capacity) if capacity > 0 => {
+                1.0 - (self.free_count as f64 / capacity as f64) as f32
+            }
+            _ => 0.0,
+        }
+    }
+
+    // Ex: "3 de 65534 endereços em uso · 65531 livres"
+    pub fn summary(&self) -> String {
+        match self.capacity {
+            Some(capacity) => format!(
+                "{} de {} endereços em uso · {} livres",
+                capacity - self.free_count,
+                capacity,
+                self.free_count
+            ),
+            None => format!("{} endereços em uso (IPv6)", self.allocations.len()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Ipv4Net {
+    pub network: u32,
+    pub prefix: u32,
+}
+
+impl Ipv4Net {
+    pub fn parse(cidr: &str) -> Option<Self> {
+        let (address, prefix) = cidr.split_once('/')?;
+        let address: Ipv4Addr = address.parse().ok()?;
+        let prefix: u32 = prefix.parse().ok().filter(|prefix| *prefix <= 32)?;
+        let mask = if prefix == 0 {
+            0
+        } else {
+            u32::MAX << (32 - prefix)
+        };
+        Some(Self {
+            network: u32::from(address) & mask,
+            prefix,
+        })
+    }
+
+    // Primeiro e último endereço de host; /31 e /32 não reservam rede e broadcast
+    pub fn host_bounds(&self) -> (u32, u32) {
+        let size = 1u64 << (32 - self.prefix);
+        let last = (self.network as u64 + size - 1) as u32;
+        if self.prefix >= 31 {
+            (self.network, last)
+        } else {
+            (self.network + 1, last - 1)
+        }
+    }
+
+    // Rede inteira, incluindo endereço de rede e broadcast
+    pub fn contains(&self, ip: u32) -> bool {
+        let size = 1u64 << (32 - self.prefix);
+        ip as u64 >= self.network as u64 && (ip as u64) < self.network as u64 + size
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Ipv6Net {
+    network: u128,
+    prefix: u32,
+}
+
+impl Ipv6Net {
+    fn parse(cidr: &str) -> Option<Self> {
+        let (address, prefix) = cidr.split_once('/')?;
+        let address: Ipv6Addr = address.parse().ok()?;
+        let prefix: u32 = prefix.parse().ok().filter(|prefix| *prefix <= 128)?;
+        Some(Self {
+            network: u128::from(address) & Self::mask(prefix),
+            prefix,
+        })
+    }
+
+    fn mask(prefix: u32) -> u128 {
+        if prefix == 0 {
+            0
+        } else {
+            u128::MAX << (128 - prefix)
+        }
+    }
+
+    fn contains(&self, ip: &str) -> bool {
+        ip.parse::<Ipv6Addr>()
+            .is_ok_and(|ip| u128::from(ip) & Self::mask(self.prefix) == self.network)
+    }
+}
+
+fn strip_prefix_len(address: &str) -> &str {
+    address.split('/').next().unwrap_or(address)
+}
+
+fn format_range(start: u32, end: u32) -> String {
+    let count = end as u64 - start as u64 + 1;
+    if start == end {
+        format!("{} (1)", Ipv4Addr::from(start))
+    } else {
+        format!(
+            "{} – {} ({})",
+            Ipv4Addr::from(start),
+            Ipv4Addr::from(end),
+            count
+        )
+    }
+}
+
+// Containers (nome, endereço) conectados à rede, a partir do `docker network inspect`
+fn endpoints(network: &Value, key: &str) -> Vec<IpAllocation> {
+    network
+        .get("Containers")
+        .and_then(Value::as_object)
+        .map(|containers| {
+            containers
+                .values()
+                .filter_map(|endpoint| {
+                    let ip = endpoint.get(key).and_then(Value::as_str)?;
+                    let ip = strip_prefix_len(ip);
+                    if ip.is_empty() {
+                        return None;
+                    }
+                    Some(IpAllocation {
+                        ip: ip.to_string(),
+                        owner: endpoint
+                            .get("Name")
+                            .and_then(Value::as_str)
+                            .unwrap_or_default()
+                            .to_string(),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// Uma entrada por sub-rede configurada no IPAM da rede
+pub fn from_inspect(network: &Value) -> Vec<SubnetUsage> {
+    let ipv4 = endpoints(network, "IPv4Address");
+    let ipv6 = endpoints(network, "IPv6Address");
+
+    let configs = network
+        .pointer("/IPAM/Config")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+
+    configs
+        .iter()
+        .filter_map(|config| {
+            let subnet = config.get("Subnet").and_then(Value::as_str)?.to_string();
+            let field = |name: &str| {
+                config
+                    .get(name)
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string()
+            };
+            let gateway = strip_prefix_len(&field("Gateway")).to_string();
+            let ip_range = field("IPRange");
+            Some(match Ipv4Net::parse(&subnet) {
+                Some(net) => ipv4_usage(net, subnet, gateway, ip_range, &ipv4),
+                None => ipv6_usage(subnet, gateway, ip_range, &ipv6),
+            })
+        })
+        .collect()
+}
+
+// Sem faixas livres: só os endereços que pertencem à sub-rede (a rede pode ter mais de uma)
+fn ipv6_usage(
+    subnet: String,
+    gateway: String,
+    ip_range: String,
+    endpoints: &[IpAllocation],
+) -> SubnetUsage {
+    let net = Ipv6Net::parse(&subnet);
+    let in_subnet = |ip: &str| net.is_some_and(|net| net.contains(ip));
+
+    let mut allocations: Vec<IpAllocation> = endpoints
+        .iter()
+        .filter(|allocation| in_subnet(&allocation.ip))
+        .cloned()
+        .collect();
+    if in_subnet(&gateway) {
+        allocations.push(IpAllocation {
+            ip: gateway.clone(),
+            owner: "gateway".to_string(),
+        });
+    }
+    allocations.sort_by_key(|allocation| allocation.ip.parse::<Ipv6Addr>().map(u128::from).ok());
+    allocations.dedup_by(|a, b| a.ip == b.ip);
+
+    SubnetUsage {
+        subnet,
+        gateway,
+        ip_range,
+        capacity: None,
+        allocations,
+        free_count: 0,
+        free_ranges: Vec::new(),
+    }
+}
+
+fn ipv4_usage(
+    net: Ipv4Net,
+    subnet: String,
+    gateway: String,
+    ip_range: String,
+    endpoints: &[IpAllocation],
+) -> SubnetUsage {
+    let (first, last) = net.host_bounds();
+    let in_subnet = |ip: u32| ip >= first && ip <= last;
+
+    let mut allocations: Vec<(u32, IpAllocation)> = endpoints
+        .iter()
+        .filter_map(|allocation| {
+            let ip = u32::from(allocation.ip.parse::<Ipv4Addr>().ok()?);
+            in_subnet(ip).then(|| (ip, allocation.clone()))
+        })
+        .collect();
+    if let Ok(ip) = gateway.parse::<Ipv4Addr>() {
+        let ip = u32::from(ip);
+        if in_subnet(ip) {
+            allocations.push((
+                ip,
+                IpAllocation {
+                    ip: gateway.clone(),
+                    owner: "gateway".to_string(),
+                },
+            ));
+        }
+    }
+    allocations.sort_by_key(|(ip, _)| *ip);
+    allocations.dedup_by_key(|(ip, _)| *ip);
+
+    // O Docker só atribui endereços automáticos dentro do IPRange
+    let range = Ipv4Net::parse(&ip_range);
+    let (start, end) = match range {
+        Some(range) => {
+            let (range_first, range_last) = range.host_bounds();
+            (range_first.max(first), range_last.min(last))
+        }
+        None => (first, last),
+    };
+    let capacity = if end >= start {
+        end as u64 - start as u64 + 1
+    } else {
+        0
+    };
+
+    let mut free_ranges = Vec::new();
+    let mut free_count = 0u64;
+    let mut next = start as u64;
+    let used = allocations
+        .iter()
+        .map(|(ip, _)| *ip as u64)
+        .filter(|ip| *ip >= start as u64 && *ip <= end as u64);
+    for ip in used.chain(std::iter::once(end as u64 + 1)) {
+        if ip > next {
+            free_count += ip - next;
+            free_ranges.push(format_range(next as u32, (ip - 1) as u32));
+        }
+        next = ip + 1;
+    }
+    if free_ranges.len() > MAX_FREE_RANGES {
+        let hidden = free_ranges.len() - MAX_FREE_RANGES;
+        free_ranges.truncate(MAX_FREE_RANGES);
+        free_ranges.push(format!("... e mais {} faixas", hidden));
+    }
+
+    SubnetUsage {
+        subnet,
+        gateway,
+        ip_range: if range.is_some() {
+            ip_range
+        } else {
+            String::new()
+        },
+        capacity: Some(capacity),
+        allocations: allocations
+            .into_iter()
+            .map(|(_, allocation)| allocation)
+            .collect(),
+        free_count,
+        free_ranges,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn network() -> Value {
+        json!({
+            "IPAM": {
+                "Config": [
+                    { "Subnet": "172.18.0.0/16", "Gateway": "172.18.0.1" },
+                    { "Subnet": "fd00:1::/64", "Gateway": "fd00:1::1" },
+                    { "Subnet": "fd00:2::/64", "Gateway": "fd00:2::1" }
+                ]
+            },
+            "Containers": {
+                "a": {
+                    "Name": "web",
+                    "IPv4Address": "172.18.0.2/16",
+                    "IPv6Address": "fd00:1::2/64"
+                },
+                "b": {
+                    "Name": "db",
+                    "IPv4Address": "172.18.0.3/16",
+                    "IPv6Address": "fd00:2::3/64"
+                }
+            }
+        })
+    }
+
+    fn owners(usage: &SubnetUsage) -> Vec<(&str, &str)> {
+        usage
+            .allocations
+            .iter()
+            .map(|allocation| (allocation.ip.as_str(), allocation.owner.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_ipv4_free_ranges() {
+        let usages = from_inspect(&network());
+        let ipv4 = &usages[0];
+        assert_eq!(ipv4.capacity, Some(65534));
+        assert_eq!(ipv4.free_count, 65531);
+        assert_eq!(
+            ipv4.free_ranges,
+            vec!["172.18.0.4 – 172.18.255.254 (65531)"]
+        );
+        assert_eq!(
+            owners(ipv4),
+            vec![
+                ("172.18.0.1", "gateway"),
+                ("172.18.0.2", "web"),
+                ("172.18.0.3", "db")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ipv6_allocations_stay_in_their_subnet() {
+        let usages = from_inspect(&network());
+        assert_eq!(
+            owners(&usages[1]),
+            vec![("fd00:1::1", "gateway"), ("fd00:1::2", "web")]
+        );
+        assert_eq!(
+            owners(&usages[2]),
+            vec![("fd00:2::1", "gateway"), ("fd00:2::3", "db")]
+        );
+        assert_eq!(usages[1].capacity, None);
+    }
+
+    #[test]
+    fn test_ipv6_net_contains() {
+        let net = Ipv6Net::parse("fd00:1::/64").unwrap();
+        assert!(net.contains("fd00:1::ffff"));
+        assert!(!net.contains("fd00:1:0:1::1"));
+        assert!(!net.contains("172.18.0.2"));
+        assert!(Ipv6Net::parse("::/0").unwrap().contains("fd00:2::3"));
+        assert!(Ipv6Net::parse("fd00::/129").is_none());
+    }
+}
@@ -0,0 +1,25 @@
+// Modo quiosque para telas de NOC: abre em tela cheia no dashboard de um servidor, sem
+// navegação para outras telas nem ações que alteram o servidor
+//   docker-ui-app kiosk [--server prod]
+use anyhow::Result;
+
+use crate::util::parse_flags;
+
+pub const USAGE: &str = "Uso: docker-ui-app kiosk [--server <servidor>]";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KioskMode {
+    // Contexto Docker exibido; None usa o contexto ativo
+    pub server: Option<String>,
+}
+
+impl KioskMode {
+    // Argumentos do subcomando `kiosk`, sem o nome do subcomando
+    pub fn from_args(args: &[String]) -> Result<Self> {
+        let mut kiosk = Self::default();
+        for (_, value) in parse_flags(args, &["--server"])? {
+            kiosk.server = Some(value.trim().to_string()).filter(|server| !server.is_empty());
+        }
+        Ok(kiosk)
+    }
+}
@@ -0,0 +1,161 @@
+// Sonda de latência de uma porta publicada: mede conexão, primeiro byte e total com o curl,
+// desta máquina e no próprio servidor (via SSH). Se só a medição local é lenta, o problema
+// está no caminho até o host; se as duas são lentas, está na aplicação
+use anyhow::{Context, Result, bail};
+use std::process::Command;
+
+// Medições mantidas por rota no gráfico
+pub const MAX_SAMPLES: usize = 60;
+
+#[derive(Debug, Clone, Default)]
+pub struct ProbeSample {
+    pub connect_ms: f64,
+    pub first_byte_ms: f64,
+    pub total_ms: f64,
+    // Código HTTP; 0 quando a porta não fala HTTP
+    pub status: u16,
+}
+
+impl ProbeSample {
+    // Ex: "conexão 2 ms · 1º byte 48 ms · total 51 ms · HTTP 200"
+    pub fn label(&self) -> String {
+        let mut label = format!(
+            "conexão {:.0} ms · 1º byte {:.0} ms · total {:.0} ms",
+            self.connect_ms, self.first_byte_ms, self.total_ms
+        );
+        if self.status > 0 {
+            label.push_str(&format!(" · HTTP {}", self.status));
+        }
+        label
+    }
+}
+
+// Histórico de uma rota para o gráfico e a média
+#[derive(Debug, Default)]
+pub struct ProbeSeries {
+    pub samples: Vec<ProbeSample>,
+    pub failures: usize,
+    pub last_error: Option<String>,
+}
+
+impl ProbeSeries {
+    pub fn record(&mut self, result: Result<ProbeSample>) {
+        match result {
+            Ok(sample) => {
+                self.samples.push(sample);
+                if self.samples.len() > MAX_SAMPLES {
+                    self.samples.remove(0);
+                }
+                self.last_error = None;
+            }
+            Err(e) => {
+                self.failures += 1;
+                self.last_error = Some(e.to_string());
+            }
+        }
+    }
+
+    pub fn totals(&self) -> Vec<f32> {
+        self.samples
+            .iter()
+            .map(|sample| sample.total_ms as f32)
+            .collect()
+    }
+
+    // Última medição, média e falhas, ou o erro mais recente
+    pub fn summary(&self) -> String {
+        if let Some(error) = &self.last_error {
+            return format!("Falha: {}", error);
+        }
+        let Some(last) = self.samples.last() else {
+            return "Aguardando medição...".to_string();
+        };
+        let average = self
+            .samples
+            .iter()
+            .map(|sample| sample.total_ms)
+            .sum::<f64>()
+            / self.samples.len() as f64;
+        let mut summary = format!("{} · média {:.0} ms", last.label(), average);
+        if self.failures > 0 {
+            summary.push_str(&format!(" · {} falha(s)", self.failures));
+        }
+        summary
+    }
+}
+
+// URL sondada: host do servidor (ou 127.0.0.1 no próprio servidor), porta e caminho
+pub fn probe_url(host: &str, port: i32, path: &str) -> String {
+    let path = path.trim();
+    let path = if path.starts_with('/') {
+        path.to_string()
+    } else {
+        format!("/{}", path)
+    };
+    format!("http://{}:{}{}", host, port, path)
+}
+
+// Argumentos do curl: descarta o corpo e imprime só os tempos e o código HTTP
+pub fn curl_args(url: &str) -> Vec<String> {
+    [
+        "-s",
+        "-o",
+        "/dev/null",
+        "--max-time",
+        "10",
+        "-w",
+        "%{time_connect} %{time_starttransfer} %{time_total} %{http_code}",
+        url,
+    ]
+    .iter()
+    .map(|arg| arg.to_string())
+    .collect()
+}
+
+// Executa o curl (local ou via SSH) e lê os tempos
+pub async fn run(command: Command) -> Result<ProbeSample> {
+    let output = tokio::process::Command::from(command)
+        .output()
+        .await
+        .context("Falha ao executar curl")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let sample = parse_timings(&stdout);
+    // O curl sai com erro em timeouts e conexões recusadas; tempos zerados indicam o mesmo
+    match sample {
+        Some(sample) if output.status.success() && sample.total_ms > 0.0 => Ok(sample),
+        _ => bail!("{}", curl_error(output.status.code(), &output.stderr)),
+    }
+}
+
+fn parse_timings(stdout: &str) -> Option<ProbeSample> {
+    let mut fields = stdout.split_whitespace();
+    let mut next_ms = || -> Option<f64> { Some(fields.next()?.parse::<f64>().ok()? * 1000.0) };
+    let connect_ms = next_ms()?;
+    let first_byte_ms = next_ms()?;
+    let total_ms = next_ms()?;
+    let status = fields.next()?.parse().unwrap_or(0);
+    Some(ProbeSample {
+        connect_ms,
+        first_byte_ms,
+        total_ms,
+        status,
+    })
+}
+
+// Códigos de saída mais comuns do curl em mensagens legíveis
+fn curl_error(code: Option<i32>, stderr: &[u8]) -> String {
+    match code {
+        Some(7) => "conexão recusada".to_string(),
+        Some(28) => "tempo esgotado (10s)".to_string(),
+        Some(6) => "host não encontrado".to_string(),
+        Some(127) => "curl não encontrado".to_string(),
+        _ => {
+            let stderr = String::from_utf8_lossy(stderr).trim().to_string();
+            if stderr.is_empty() {
+                format!("curl saiu com código {}", code.unwrap_or(-1))
+            } else {
+                stderr
+            }
+        }
+    }
+}
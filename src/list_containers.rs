@@ -1,16 +1,30 @@
+use crate::bandwidth::{BandwidthMode, TickGate};
 use crate::docker::{ContainerInfo, DockerManager};
+use crate::log_triggers::LogTriggerState;
+use crate::pending_changes::{PendingChange, PendingTracker};
+use crate::ui::store::Screen;
 use slint::{Timer, TimerMode};
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 
 // Struct para dados dos containers no formato Slint
 #[derive(Clone, Debug)]
 pub struct SlintContainerData {
+    pub id: slint::SharedString,
+    pub server: slint::SharedString,
     pub name: slint::SharedString,
     pub image: slint::SharedString,
     pub status: slint::SharedString,
     pub ports: slint::SharedString,
     pub created: slint::SharedString,
+    // "restart", "recreate" ou vazio, com o motivo exibido na dica do selo
+    pub pending: slint::SharedString,
+    pub pending_reason: slint::SharedString,
+    // Selo dos padrões de log em contagem (ex: "ERROR: 12"), vazio sem padrões
+    pub log_watch: slint::SharedString,
+    pub log_watch_hits: i32,
 }
 
 impl From<&ContainerInfo> for SlintContainerData {
@@ -27,47 +41,112 @@ impl From<&ContainerInfo> for SlintContainerData {
         };
 
         Self {
+            id: container.id.clone().into(),
+            server: container.server.clone().into(),
             name: container.name.clone().into(),
             image: container.image.clone().into(),
             status: parse_container_status(&container.state, &container.status),
             ports: ports_str.into(),
             created: format_creation_time(container.created),
+            pending: Default::default(),
+            pending_reason: Default::default(),
+            log_watch: Default::default(),
+            log_watch_hits: 0,
         }
     }
 }
 
+// Verifica mudanças pendentes a cada 15 atualizações da lista (30s)
+const PENDING_CHECK_EVERY: u32 = 15;
+
 // Gerenciador da UI de containers
 pub struct ContainerUIManager {
     docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
     containers: Vec<ContainerInfo>,
     search_filter: String,
     status_filter: String,
+    pending_tracker: PendingTracker,
+    // Mudanças pendentes por ID do container
+    pending: HashMap<String, PendingChange>,
+    pending_ticks: u32,
+    // Ação executada: verifica de novo na próxima atualização
+    pending_stale: AtomicBool,
+    // Contagens dos gatilhos de log, lidas pelo watcher em segundo plano
+    log_triggers: Arc<std::sync::Mutex<LogTriggerState>>,
 }
 
 impl ContainerUIManager {
-    pub fn new(docker_manager: Arc<tokio::sync::Mutex<DockerManager>>) -> Self {
+    pub fn new(
+        docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+        log_triggers: Arc<std::sync::Mutex<LogTriggerState>>,
+    ) -> Self {
         Self {
             docker_manager,
             containers: Vec::new(),
             search_filter: String::new(),
             status_filter: "all".to_string(),
+            pending_tracker: PendingTracker::default(),
+            pending: HashMap::new(),
+            pending_ticks: 0,
+            pending_stale: AtomicBool::new(false),
+            log_triggers,
         }
     }
 
     // Atualiza a lista de containers
     pub async fn refresh_containers(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        let docker_manager = self.docker_manager.lock().await;
+        // Clona o Arc: refresh_pending precisa de &mut self com o lock mantido
+        let docker = self.docker_manager.clone();
+        let docker_manager = docker.lock().await;
         match docker_manager.list_containers().await {
             Ok(containers) => {
                 self.containers = containers;
+                let due = self.pending_ticks % PENDING_CHECK_EVERY == 0;
+                self.pending_ticks = self.pending_ticks.wrapping_add(1);
+                if due || self.pending_stale.swap(false, Ordering::Relaxed) {
+                    self.refresh_pending(&docker_manager).await;
+                }
                 Ok(())
             }
             Err(e) => Err(format!("Failed to refresh containers: {}", e).into()),
         }
     }
 
+    // Limites aplicados em execução pelo app: não há reinício pendente
+    pub fn accept_live_update(&mut self, container_id: &str) {
+        self.pending_tracker.accept_current(container_id);
+        self.pending.retain(|id, _| !id.starts_with(container_id));
+        self.pending_stale.store(true, Ordering::Relaxed);
+    }
+
+    // Compara a configuração e a imagem de cada container com o que está em execução
+    async fn refresh_pending(&mut self, docker_manager: &DockerManager) {
+        let ids: Vec<String> = self.containers.iter().map(|c| c.id.clone()).collect();
+        let inspected = match docker_manager.inspect_containers(&ids).await {
+            Ok(inspected) => inspected,
+            Err(e) => {
+                eprintln!("Falha ao verificar mudanças pendentes: {}", e);
+                return;
+            }
+        };
+        // Sem a lista de imagens, só a configuração é comparada
+        let images = docker_manager
+            .image_ids_by_reference()
+            .await
+            .unwrap_or_default();
+        self.pending = self
+            .pending_tracker
+            .update(&docker_manager.context().name, &inspected, &images);
+    }
+
     // Filtra containers baseado nos critérios atuais
     pub fn get_filtered_containers(&self) -> Vec<SlintContainerData> {
+        let watch_badges = self
+            .log_triggers
+            .lock()
+            .map(|state| state.watch_badges())
+            .unwrap_or_default();
+
         self.containers
             .iter()
             .filter(|container| {
@@ -97,10 +176,30 @@ impl ContainerUIManager {
 
                 matches_search && matches_status
             })
-            .map(SlintContainerData::from)
+            .map(|container| {
+                let mut data = SlintContainerData::from(container);
+                if let Some(change) = self.pending.get(&container.id) {
+                    data.pending = change.action.as_str().into();
+                    data.pending_reason = change.reason.clone().into();
+                }
+                // Gatilhos de log são salvos pelo nome do container
+                if let Some(badge) =
+                    watch_badges.get(&format!("{}/{}", container.server, container.name))
+                {
+                    data.log_watch = badge.label().into();
+                    data.log_watch_hits = badge.hits as i32;
+                }
+                data
+            })
             .collect()
     }
 
+    // Estado atual do container no servidor, antes de ações destrutivas
+    pub async fn container_state(&self, container_id: &str) -> anyhow::Result<String> {
+        let docker_manager = self.docker_manager.lock().await;
+        docker_manager.container_state(container_id).await
+    }
+
     // Atualiza filtro de busca
     pub fn set_search_filter(&mut self, search: String) {
         self.search_filter = search;
@@ -114,34 +213,43 @@ impl ContainerUIManager {
     // Executa ação em um container
     pub async fn execute_container_action(
         &self,
-        container_name: &str,
+        container_id: &str,
         action: &str,
     ) -> Result<(), Box<dyn std::error::Error>> {
         let docker_manager = self.docker_manager.lock().await;
+        self.pending_stale.store(true, Ordering::Relaxed);
 
         match action {
             "start" => docker_manager
-                .start_container(container_name)
+                .start_container(container_id)
                 .await
                 .map_err(|e| format!("Failed to start container: {}", e).into()),
             "stop" => docker_manager
-                .stop_container(container_name)
+                .stop_container(container_id)
                 .await
                 .map_err(|e| format!("Failed to stop container: {}", e).into()),
             "pause" => docker_manager
-                .pause_container(container_name)
+                .pause_container(container_id)
                 .await
                 .map_err(|e| format!("Failed to pause container: {}", e).into()),
             "unpause" => docker_manager
-                .unpause_container(container_name)
+                .unpause_container(container_id)
                 .await
                 .map_err(|e| format!("Failed to unpause container: {}", e).into()),
             "remove" => docker_manager
-                .remove_container(container_name)
+                .remove_container(container_id, false)
+                .await
+                .map_err(|e| format!("Failed to remove container: {}", e).into()),
+            "stop-remove" => docker_manager
+                .stop_and_remove_container(container_id)
+                .await
+                .map_err(|e| format!("Failed to remove container: {}", e).into()),
+            "force-remove" => docker_manager
+                .remove_container(container_id, true)
                 .await
                 .map_err(|e| format!("Failed to remove container: {}", e).into()),
             "restart" => docker_manager
-                .restart_container(container_name)
+                .restart_container(container_id)
                 .await
                 .map_err(|e| format!("Failed to restart container: {}", e).into()),
             _ => Err(format!("Unknown action: {}", action).into()),
@@ -199,14 +307,22 @@ fn format_creation_time(created: i64) -> slint::SharedString {
     }
 }
 
-// Configura timer para atualização automática da UI
+// Configura timer para atualização automática da UI, só com a lista ou os detalhes abertos
 pub fn setup_container_ui_timer(
     ui_manager: Arc<tokio::sync::Mutex<ContainerUIManager>>,
     update_callback: Arc<dyn Fn(Vec<SlintContainerData>) + Send + Sync>,
+    low_bandwidth: BandwidthMode,
 ) -> Timer {
     let timer = Timer::default();
+    // Economia de banda: a cada 10s em vez de 2s
+    let mut gate = TickGate::new(5);
 
     timer.start(TimerMode::Repeated, Duration::from_secs(2), move || {
+        // Telas que exibem a lista: containers e detalhes
+        let visible = [Screen::Containers.index(), Screen::ContainerDetails.index()];
+        if !low_bandwidth.is_visible(&visible) || !gate.should_run(&low_bandwidth) {
+            return;
+        }
         let ui_manager_clone = ui_manager.clone();
         let callback_clone = update_callback.clone();
 
@@ -1,4 +1,5 @@
 use crate::docker::{DockerManager, ImageInfo};
+use crate::format;
 use std::sync::Arc;
 
 // Struct para dados das imagens no formato Slint
@@ -16,7 +17,7 @@ impl From<&ImageInfo> for SlintImageData {
         Self {
             id: image.id.clone().into(),
             tag: image.tags.get(0).cloned().unwrap_or_default().into(),
-            size: format_size(image.size),
+            size: format::bytes(image.size.max(0) as u64).into(),
             created: format_creation_time(image.created),
             in_use: image.in_use,
         }
@@ -72,18 +73,6 @@ impl ImageUIManager {
     }
 }
 
-// Formata o tamanho do arquivo
-fn format_size(size: i64) -> slint::SharedString {
-    if size <= 0 {
-        return "0 B".into();
-    }
-    let units = ["B", "KB", "MB", "GB", "TB"];
-    let digit_groups = (size as f64).log10() / (1024_f64).log10();
-    let unit_index = digit_groups.floor() as i32;
-    let size_in_unit = size as f64 / 1024_f64.powi(unit_index);
-    format!("{:.2} {}", size_in_unit, units[unit_index as usize]).into()
-}
-
 // Formata o tempo de criação
 fn format_creation_time(created: i64) -> slint::SharedString {
     if created <= 0 {
@@ -0,0 +1,219 @@
+// Logs do container aberto paginados por janelas de tempo: as linhas novas chegam com
+// --since a partir da última recebida e os blocos antigos com --until a partir da mais
+// antiga, sem baixar de novo (via SSH) o que já está na tela
+use chrono::{DateTime, Duration, Utc};
+use std::collections::VecDeque;
+
+// Linhas do carregamento inicial
+pub const INITIAL_LINES: usize = 50;
+// Limite de linhas de cada bloco antigo (as mais recentes da janela)
+pub const OLDER_PAGE_LINES: usize = 1_000;
+// Linhas mantidas; ao passar, as mais antigas são descartadas
+const MAX_LINES: usize = 20_000;
+// Primeiro bloco antigo; cresce enquanto as janelas vierem vazias
+const FIRST_WINDOW_MINUTES: i64 = 15;
+const MAX_WINDOW_DAYS: i64 = 30;
+
+pub type LogLine = (DateTime<Utc>, String);
+
+#[derive(Debug, Default)]
+pub struct LogPages {
+    // Servidor/id do container ao qual as linhas pertencem
+    key: String,
+    lines: VecDeque<LogLine>,
+    loaded: bool,
+    // Início da janela mais antiga já buscada e timestamp da última linha recebida
+    oldest: Option<DateTime<Utc>>,
+    newest: Option<DateTime<Utc>>,
+    window: Option<Duration>,
+    created_at: Option<DateTime<Utc>>,
+    exhausted: bool,
+}
+
+// Próxima busca de linhas novas
+pub enum NewerRequest {
+    // Primeira carga: as últimas linhas, sem janela
+    Initial,
+    Since(DateTime<Utc>),
+}
+
+impl LogPages {
+    // Descarta as linhas ao trocar de container ou servidor
+    pub fn select(&mut self, key: &str) {
+        if self.key != key {
+            *self = Self {
+                key: key.to_string(),
+                ..Self::default()
+            };
+        }
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn newer_request(&self) -> NewerRequest {
+        match (self.loaded, self.newest) {
+            (true, Some(newest)) => NewerRequest::Since(newest),
+            // Sem nenhuma linha ainda: pede a partir do início da janela já coberta
+            (true, None) => NewerRequest::Since(self.oldest.unwrap_or_else(Utc::now)),
+            (false, _) => NewerRequest::Initial,
+        }
+    }
+
+    // Resultado da primeira carga (últimas `INITIAL_LINES` linhas) ou de uma busca --since
+    pub fn append_newer(&mut self, lines: Vec<LogLine>, requested_at: DateTime<Utc>) {
+        let initial = !self.loaded;
+        let newest = self.newest;
+        // A janela começa no segundo inteiro: o que já foi recebido vem de novo
+        let fresh = lines
+            .into_iter()
+            .filter(|(time, _)| newest.is_none_or(|newest| *time > newest));
+        self.lines.extend(fresh);
+        if let Some((time, _)) = self.lines.back() {
+            self.newest = Some(*time);
+        }
+        if initial {
+            self.loaded = true;
+            self.oldest = Some(self.lines.front().map_or(requested_at, |(time, _)| *time));
+            // Menos linhas que o pedido: o log inteiro já está carregado
+            self.exhausted = self.lines.len() < INITIAL_LINES;
+        }
+        if self.lines.len() > MAX_LINES {
+            self.lines.drain(..self.lines.len() - MAX_LINES);
+            self.oldest = self.lines.front().map(|(time, _)| *time);
+            self.exhausted = false;
+        }
+    }
+
+    pub fn can_load_older(&self) -> bool {
+        self.loaded && !self.exhausted
+    }
+
+    pub fn needs_created_at(&self) -> bool {
+        self.created_at.is_none()
+    }
+
+    pub fn set_created_at(&mut self, created_at: DateTime<Utc>) {
+        self.created_at = Some(created_at);
+    }
+
+    // Janela [since, until) do próximo bloco antigo
+    pub fn older_window(&self) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        // Com o limite de linhas atingido, um bloco antigo seria descartado ao chegar
+        if !self.can_load_older() || self.lines.len() >= MAX_LINES {
+            return None;
+        }
+        let until = self.oldest?;
+        let window = self
+            .window
+            .unwrap_or_else(|| Duration::minutes(FIRST_WINDOW_MINUTES));
+        let since = until - window;
+        let since = match self.created_at {
+            Some(created_at) if since < created_at => created_at,
+            _ => since,
+        };
+        Some((since, until))
+    }
+
+    // Coloca o bloco antigo antes das linhas atuais. Janela vazia dobra a próxima;
+    // chegar à criação do container encerra a paginação. Um bloco que veio cortado em
+    // `OLDER_PAGE_LINES` só cobre a janela a partir da sua primeira linha
+    pub fn prepend_older(&mut self, lines: Vec<LogLine>, since: DateTime<Utc>) {
+        let Some(until) = self.oldest else {
+            return;
+        };
+        let capped = lines.len() >= OLDER_PAGE_LINES;
+        let older: Vec<LogLine> = lines
+            .into_iter()
+            .filter(|(time, _)| *time >= since && *time < until)
+            .collect();
+        let window = self
+            .window
+            .unwrap_or_else(|| Duration::minutes(FIRST_WINDOW_MINUTES));
+        self.window = Some(if older.is_empty() {
+            (window * 2).min(Duration::days(MAX_WINDOW_DAYS))
+        } else {
+            window
+        });
+        let covered_since = match older.first() {
+            Some((time, _)) if capped => *time,
+            _ => since,
+        };
+        for line in older.into_iter().rev() {
+            self.lines.push_front(line);
+        }
+        self.oldest = Some(covered_since);
+        self.exhausted = self
+            .created_at
+            .is_some_and(|created_at| covered_since <= created_at);
+        if self.lines.len() > MAX_LINES {
+            self.lines.drain(..self.lines.len() - MAX_LINES);
+            self.oldest = self.lines.front().map(|(time, _)| *time);
+            self.exhausted = false;
+        }
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = &LogLine> {
+        self.lines.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Uma linha a cada `step` a partir de `start`
+    fn lines_every(start: DateTime<Utc>, count: usize, step: Duration) -> Vec<LogLine> {
+        (0..count)
+            .map(|i| (start + step * i as i32, format!("linha {}", i)))
+            .collect()
+    }
+
+    fn loaded_pages(now: DateTime<Utc>) -> LogPages {
+        let mut pages = LogPages::default();
+        pages.select("local/app");
+        pages.append_newer(lines_every(now, INITIAL_LINES, Duration::seconds(1)), now);
+        pages
+    }
+
+    #[test]
+    fn test_capped_older_page_keeps_uncovered_part_of_window() {
+        let now = Utc::now();
+        let mut pages = loaded_pages(now);
+        let (since, until) = pages.older_window().unwrap();
+
+        // As linhas mais recentes da janela, uma a cada 100ms
+        let first = until - Duration::milliseconds(100 * OLDER_PAGE_LINES as i64);
+        let lines = lines_every(first, OLDER_PAGE_LINES, Duration::milliseconds(100));
+        pages.prepend_older(lines, since);
+
+        assert_eq!(pages.line_count(), INITIAL_LINES + OLDER_PAGE_LINES);
+        // O próximo bloco termina na primeira linha recebida, não no início da janela
+        let (_, next_until) = pages.older_window().unwrap();
+        assert_eq!(next_until, first);
+    }
+
+    #[test]
+    fn test_prepend_older_trims_to_max_lines() {
+        let now = Utc::now();
+        let mut pages = loaded_pages(now);
+        let start = now - Duration::seconds(MAX_LINES as i64);
+        pages.lines = lines_every(start, MAX_LINES - 10, Duration::seconds(1)).into();
+        pages.oldest = Some(start);
+
+        let (since, _) = pages.older_window().unwrap();
+        pages.prepend_older(lines_every(since, 100, Duration::seconds(1)), since);
+
+        assert_eq!(pages.line_count(), MAX_LINES);
+        assert_eq!(pages.older_window(), None);
+    }
+}
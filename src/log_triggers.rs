@@ -0,0 +1,288 @@
+// Gatilhos de log: padrões por container que geram alerta, reinício ou webhook,
+// ou que só contam as ocorrências no selo da lista de containers
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    io::Write,
+    process::{Command, Stdio},
+    time::{Duration, Instant},
+};
+
+use crate::config::ProxySettings;
+
+// Intervalo mínimo entre duas ações do mesmo gatilho (evita loop de reinícios)
+const ACTION_COOLDOWN: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TriggerAction {
+    #[default]
+    Alert,
+    Restart,
+    Webhook,
+    // Só conta as ocorrências, sem alerta
+    Watch,
+}
+
+impl TriggerAction {
+    // Valores usados pela interface: "alert", "restart", "webhook" ou "watch"
+    pub fn from_ui(value: &str) -> Self {
+        match value {
+            "restart" => TriggerAction::Restart,
+            "webhook" => TriggerAction::Webhook,
+            "watch" => TriggerAction::Watch,
+            _ => TriggerAction::Alert,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            TriggerAction::Alert => "Alerta",
+            TriggerAction::Restart => "Reiniciar",
+            TriggerAction::Webhook => "Webhook",
+            TriggerAction::Watch => "Contador",
+        }
+    }
+}
+
+// Gatilho salvo na configuração
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LogTrigger {
+    pub id: String,
+    // Servidor (contexto) e nome do container; o id muda quando o container é recriado
+    pub server: String,
+    pub container: String,
+    pub pattern: String,
+    pub action: TriggerAction,
+    pub webhook_url: String,
+}
+
+impl LogTrigger {
+    pub fn new(
+        server: &str,
+        container: &str,
+        pattern: &str,
+        action: TriggerAction,
+        webhook_url: &str,
+    ) -> Result<Self> {
+        let pattern = pattern.trim();
+        if pattern.is_empty() {
+            return Err(anyhow::anyhow!("Informe o padrão (ex: panic|OOM|ERROR)"));
+        }
+        Regex::new(pattern).map_err(|e| anyhow::anyhow!("Padrão inválido: {}", e))?;
+
+        let webhook_url = webhook_url.trim();
+        if action == TriggerAction::Webhook
+            && !(webhook_url.starts_with("http://") || webhook_url.starts_with("https://"))
+        {
+            return Err(anyhow::anyhow!("Informe a URL do webhook (http:// ou https://)"));
+        }
+
+        Ok(Self {
+            id: Utc::now().timestamp_millis().to_string(),
+            server: server.to_string(),
+            container: container.to_string(),
+            pattern: pattern.to_string(),
+            action,
+            webhook_url: webhook_url.to_string(),
+        })
+    }
+}
+
+// Ocorrências de um gatilho desde que o app foi aberto
+#[derive(Debug, Clone, Default)]
+pub struct TriggerHits {
+    pub count: u32,
+    pub last_line: String,
+    last_action: Option<Instant>,
+}
+
+// Selo de contagem de um container: padrões observados e ocorrências somadas
+#[derive(Debug, Clone, Default)]
+pub struct WatchBadge {
+    pub patterns: Vec<String>,
+    pub hits: u32,
+}
+
+impl WatchBadge {
+    // Ex: "ERROR: 12" ou "3 padrões: 12"
+    pub fn label(&self) -> String {
+        match self.patterns.as_slice() {
+            [pattern] => format!("{}: {}", pattern, self.hits),
+            patterns => format!("{} padrões: {}", patterns.len(), self.hits),
+        }
+    }
+}
+
+// Ocorrência encontrada em uma leitura de logs
+pub struct TriggerMatch {
+    pub trigger: LogTrigger,
+    pub line: String,
+    // Falso enquanto o gatilho estiver no intervalo mínimo entre ações
+    pub run_action: bool,
+}
+
+// Gatilhos salvos, posição de leitura por container e ocorrências por gatilho
+#[derive(Default)]
+pub struct LogTriggerState {
+    // Cópia dos gatilhos do config, recarregada quando eles são salvos: a lista de
+    // containers consulta os selos a cada atualização sem ler o arquivo
+    triggers: Vec<LogTrigger>,
+    // Timestamp da última linha lida por "servidor/container"
+    last_seen: HashMap<String, DateTime<Utc>>,
+    hits: HashMap<String, TriggerHits>,
+    regexes: HashMap<String, Regex>,
+}
+
+impl LogTriggerState {
+    pub fn new(triggers: Vec<LogTrigger>) -> Self {
+        Self {
+            triggers,
+            ..Default::default()
+        }
+    }
+
+    pub fn triggers(&self) -> &[LogTrigger] {
+        &self.triggers
+    }
+
+    pub fn set_triggers(&mut self, triggers: Vec<LogTrigger>) {
+        self.triggers = triggers;
+    }
+
+    // Início da próxima leitura; na primeira vez parte de agora e ignora o histórico
+    pub fn read_from(&mut self, server: &str, container: &str) -> DateTime<Utc> {
+        *self
+            .last_seen
+            .entry(format!("{}/{}", server, container))
+            .or_insert_with(Utc::now)
+    }
+
+    // Confere as linhas novas (timestamp, mensagem) contra os gatilhos do container
+    pub fn scan(
+        &mut self,
+        server: &str,
+        container: &str,
+        triggers: &[LogTrigger],
+        lines: &[(DateTime<Utc>, String)],
+    ) -> Vec<TriggerMatch> {
+        let key = format!("{}/{}", server, container);
+        let last_seen = self.last_seen.get(&key).copied();
+        let new_lines: Vec<&(DateTime<Utc>, String)> = lines
+            .iter()
+            .filter(|(time, _)| last_seen.is_none_or(|last_seen| *time > last_seen))
+            .collect();
+        if let Some((time, _)) = new_lines.last() {
+            self.last_seen.insert(key, *time);
+        }
+
+        let mut matches = Vec::new();
+        for trigger in triggers {
+            if !self.regexes.contains_key(&trigger.pattern) {
+                let Ok(regex) = Regex::new(&trigger.pattern) else {
+                    continue;
+                };
+                self.regexes.insert(trigger.pattern.clone(), regex);
+            }
+            let regex = &self.regexes[&trigger.pattern];
+
+            let matched: Vec<&String> = new_lines
+                .iter()
+                .map(|(_, line)| line)
+                .filter(|line| regex.is_match(line))
+                .collect();
+            let Some(line) = matched.last() else {
+                continue;
+            };
+
+            let hits = self.hits.entry(trigger.id.clone()).or_default();
+            hits.count += matched.len() as u32;
+            hits.last_line = line.to_string();
+
+            // Uma ação por leitura, respeitando o intervalo mínimo
+            let run_action = hits
+                .last_action
+                .is_none_or(|last_action| last_action.elapsed() >= ACTION_COOLDOWN);
+            if run_action {
+                hits.last_action = Some(Instant::now());
+            }
+
+            matches.push(TriggerMatch {
+                trigger: trigger.clone(),
+                line: line.to_string(),
+                run_action,
+            });
+        }
+        matches
+    }
+
+    pub fn hits(&self, trigger_id: &str) -> Option<&TriggerHits> {
+        self.hits.get(trigger_id)
+    }
+
+    // Selos dos gatilhos de contagem por "servidor/container"
+    pub fn watch_badges(&self) -> HashMap<String, WatchBadge> {
+        let mut badges: HashMap<String, WatchBadge> = HashMap::new();
+        for trigger in self
+            .triggers
+            .iter()
+            .filter(|trigger| trigger.action == TriggerAction::Watch)
+        {
+            let badge = badges
+                .entry(format!("{}/{}", trigger.server, trigger.container))
+                .or_default();
+            badge.patterns.push(trigger.pattern.clone());
+            badge.hits += self.hits(&trigger.id).map(|hits| hits.count).unwrap_or(0);
+        }
+        badges
+    }
+}
+
+// Envia a ocorrência em JSON por POST (curl, respeitando o proxy)
+pub fn send_webhook(
+    trigger: &LogTrigger,
+    line: &str,
+    proxy: Option<ProxySettings>,
+) -> Result<()> {
+    let payload = serde_json::json!({
+        "server": trigger.server,
+        "container": trigger.container,
+        "pattern": trigger.pattern,
+        "line": line,
+        "matched_at": Utc::now().to_rfc3339(),
+    });
+
+    let mut child = Command::new("curl")
+        .args(proxy.map(|proxy| proxy.curl_args()).unwrap_or_default())
+        .args([
+            "-fsS",
+            "--max-time",
+            "10",
+            "-H",
+            "Content-Type: application/json",
+            "--data-binary",
+            "@-",
+            &trigger.webhook_url,
+        ])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("Failed to execute curl")?;
+    if let Some(mut stdin) = child.stdin.take() {
+        let _ = stdin.write_all(payload.to_string().as_bytes());
+    }
+
+    let output = child.wait_with_output().context("Failed to execute curl")?;
+    if !output.status.success() {
+        return Err(anyhow::anyhow!(
+            "Falha ao chamar webhook: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(())
+}
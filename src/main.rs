@@ -7,16 +7,71 @@ use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 // Módulos locais
+mod annotations;
+mod audit_log;
+mod bandwidth;
+mod build;
 mod chart;
+mod compose;
+mod compose_export;
+mod config;
+mod container_diff;
+mod container_groups;
+mod container_summary;
+mod credentials;
+mod dashboard_layout;
+mod deep_link;
+mod deploy_history;
+mod disk_watch;
 mod docker;
+mod docker_install;
+mod format;
+mod exec_history;
+mod exec_session;
+mod exec_snippets;
+mod host_address;
+mod image_archive;
+mod image_groups;
+mod image_provenance;
+mod ip_allocation;
+mod kiosk;
+mod latency_probe;
 mod list_containers;
 mod list_images;
 mod list_networks;
 mod list_volumes;
+mod log_pages;
+mod log_triggers;
+mod maintenance;
+mod memory_trend;
+mod metrics_source;
+mod network_create;
+mod pending_changes;
+mod prune;
+mod prune_schedule;
+mod pull_estimate;
+mod registry;
+mod resource_limits;
+mod resume;
+mod session_recording;
+mod session_replay;
+mod snapshot;
+mod ssh;
+mod stats_export;
+mod stats_sampling;
+mod swarm;
+mod task_queue;
 mod ui;
+mod util;
+mod volume_create;
+mod volume_migration;
 
 // Tipos do Docker e gráficos
+use bandwidth::{BandwidthMode, RedrawGate};
 use chart::{ChartPoint, ChartRenderer};
+use deep_link::DeepLink;
+use kiosk::KioskMode;
+use metrics_source::MetricsHistory;
 
 use crate::ui::{UiApp, setup_docker_ui};
 
@@ -26,6 +81,11 @@ struct AppState {
     chart_data: Arc<std::sync::Mutex<ChartData>>,
     cpu_chart_renderer: Arc<std::sync::Mutex<ChartRenderer>>,
     memory_chart_renderer: Arc<std::sync::Mutex<ChartRenderer>>,
+    container_counts: Arc<std::sync::Mutex<ContainerCountHistory>>,
+    running_sparkline_renderer: Arc<std::sync::Mutex<ChartRenderer>>,
+    stopped_sparkline_renderer: Arc<std::sync::Mutex<ChartRenderer>>,
+    // Modo de economia de banda do servidor ativo
+    low_bandwidth: BandwidthMode,
 }
 
 // Dados dos gráficos em tempo real
@@ -33,6 +93,7 @@ struct ChartData {
     cpu_points: VecDeque<ChartPoint>,
     memory_points: VecDeque<ChartPoint>,
     last_update: Instant,
+    redraw: RedrawGate,
 }
 
 impl ChartData {
@@ -41,6 +102,7 @@ impl ChartData {
             cpu_points: VecDeque::new(),
             memory_points: VecDeque::new(),
             last_update: Instant::now() - Duration::from_secs(2), // Força primeira atualização
+            redraw: RedrawGate::default(),
         }
     }
 
@@ -60,6 +122,20 @@ impl ChartData {
         self.last_update = Instant::now();
     }
 
+    // Substitui os pontos pelo histórico de uma fonte externa (Prometheus)
+    fn replace_history(&mut self, history: MetricsHistory) {
+        self.cpu_points = history.cpu.into();
+        self.memory_points = history.memory.into();
+        self.last_update = Instant::now();
+    }
+
+    // Limpa histórico (ex.: ao trocar de servidor)
+    fn clear(&mut self) {
+        self.cpu_points.clear();
+        self.memory_points.clear();
+        self.last_update = Instant::now() - Duration::from_secs(2);
+    }
+
     // Adiciona ponto de memória (max 60 pontos)
     fn add_memory_point(&mut self, value: f32) {
         let time = chrono::Local::now().format("%H:%M:%S").to_string();
@@ -72,24 +148,88 @@ impl ChartData {
     }
 }
 
+// Histórico de containers rodando/parados (amostras do DockerInfo)
+struct ContainerCountHistory {
+    running: VecDeque<f32>,
+    stopped: VecDeque<f32>,
+    last_update: Instant,
+}
+
+impl ContainerCountHistory {
+    fn new() -> Self {
+        Self {
+            running: VecDeque::new(),
+            stopped: VecDeque::new(),
+            last_update: Instant::now() - Duration::from_secs(10),
+        }
+    }
+
+    // Uma amostra a cada 5 segundos (60 pontos = últimos 5 minutos)
+    fn should_update(&self) -> bool {
+        self.last_update.elapsed().as_secs() >= 5
+    }
+
+    fn add_sample(&mut self, running: i64, stopped: i64) {
+        self.running.push_back(running as f32);
+        self.stopped.push_back(stopped as f32);
+
+        if self.running.len() > 60 {
+            self.running.pop_front();
+        }
+        if self.stopped.len() > 60 {
+            self.stopped.pop_front();
+        }
+        self.last_update = Instant::now();
+    }
+
+    fn clear(&mut self) {
+        self.running.clear();
+        self.stopped.clear();
+        self.last_update = Instant::now() - Duration::from_secs(10);
+    }
+}
+
 // Dados dos gráficos para container específico
 struct ContainerChartData {
+    // Servidor/id do container ao qual os pontos pertencem
+    key: String,
     cpu_points: VecDeque<ChartPoint>,
     memory_points: VecDeque<ChartPoint>,
     last_update: Instant,
+    redraw: RedrawGate,
+    // Revisão do histórico de deploys já desenhada no modo de comparação
+    deploy_revision: Option<u64>,
 }
 
 impl ContainerChartData {
     fn new() -> Self {
         Self {
+            key: String::new(),
             cpu_points: VecDeque::new(),
             memory_points: VecDeque::new(),
             last_update: Instant::now() - Duration::from_secs(2),
+            redraw: RedrawGate::default(),
+            deploy_revision: None,
+        }
+    }
+
+    // Descarta o histórico ao trocar de container ou servidor
+    fn select(&mut self, key: &str) {
+        if self.key != key {
+            *self = Self::new();
+            self.key = key.to_string();
         }
     }
 
+    // A resolução vem do coletor (stats_sampling); a folga absorve o jitter do timer
     fn should_update(&self) -> bool {
-        self.last_update.elapsed().as_millis() >= 500 // 500ms entre atualizações (mesmo que dashboard)
+        self.last_update.elapsed().as_millis() >= 400
+    }
+
+    fn replace_history(&mut self, history: MetricsHistory) {
+        self.cpu_points = history.cpu.into();
+        self.memory_points = history.memory.into();
+        self.last_update = Instant::now();
     }
 
     fn add_cpu_point(&mut self, value: f32) {
@@ -116,6 +256,52 @@ impl ContainerChartData {
 // Função principal assíncrona
 #[tokio::main]
 async fn main() -> Result<(), slint::PlatformError> {
+    // Link direto para um container (ex.: docker-ui-app open --server prod --container api)
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    // Estatísticas em JSON para scripts (docker-ui-app stats --server prod), sem abrir a janela
+    if args.first().map(String::as_str) == Some("stats") {
+        let result = match stats_export::StatsCommand::from_args(&args[1..]) {
+            Ok(command) => command.run().await,
+            Err(e) => {
+                eprintln!("{}\n{}", e, stats_export::USAGE);
+                std::process::exit(2);
+            }
+        };
+        if let Err(e) = result {
+            eprintln!("Erro ao coletar estatísticas: {}", e);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    // Modo quiosque para telas de NOC (docker-ui-app kiosk --server prod)
+    let kiosk = if args.first().map(String::as_str) == Some("kiosk") {
+        match KioskMode::from_args(&args[1..]) {
+            Ok(kiosk) => Some(kiosk),
+            Err(e) => {
+                eprintln!("{}\n{}", e, kiosk::USAGE);
+                std::process::exit(2);
+            }
+        }
+    } else {
+        None
+    };
+
+    let deep_link = match kiosk {
+        Some(_) => None,
+        None => match DeepLink::from_args(&args) {
+            Ok(deep_link) => deep_link,
+            Err(e) => {
+                eprintln!("{}\n{}", e, deep_link::USAGE);
+                std::process::exit(2);
+            }
+        },
+    };
+
+    // Separador decimal e unidades de tamanho salvos nas configurações
+    format::apply(config::AppConfig::load().format);
+
     // Cria janela da aplicação
     let ui = AppWindow::new()?;
 
@@ -134,10 +320,21 @@ async fn main() -> Result<(), slint::PlatformError> {
     let mut container_memory_chart_renderer = ChartRenderer::new(800, 256);
     container_memory_chart_renderer.set_line_color([16, 185, 129]);
 
+    // Sparklines dos cards de containers rodando (verde) e parados (vermelho)
+    let mut running_sparkline_renderer = ChartRenderer::new(120, 48);
+    running_sparkline_renderer.set_line_color([10, 217, 92]);
+
+    let mut stopped_sparkline_renderer = ChartRenderer::new(120, 48);
+    stopped_sparkline_renderer.set_line_color([250, 97, 56]);
+
     let app_state = AppState {
         chart_data: Arc::new(std::sync::Mutex::new(ChartData::new())),
         cpu_chart_renderer: Arc::new(std::sync::Mutex::new(cpu_chart_renderer)),
         memory_chart_renderer: Arc::new(std::sync::Mutex::new(memory_chart_renderer)),
+        container_counts: Arc::new(std::sync::Mutex::new(ContainerCountHistory::new())),
+        running_sparkline_renderer: Arc::new(std::sync::Mutex::new(running_sparkline_renderer)),
+        stopped_sparkline_renderer: Arc::new(std::sync::Mutex::new(stopped_sparkline_renderer)),
+        low_bandwidth: BandwidthMode::default(),
     };
 
     // Dados e renderizadores para gráficos de container
@@ -154,6 +351,8 @@ async fn main() -> Result<(), slint::PlatformError> {
         container_chart_data,
         container_cpu_renderer,
         container_memory_renderer,
+        deep_link,
+        kiosk,
     )
     .await;
 
@@ -0,0 +1,110 @@
+// Janelas de manutenção por servidor: horários recorrentes (ex: toda noite das 22h às 7h)
+// ou uma manutenção iniciada na hora, durante as quais os alertas ficam silenciados
+use anyhow::{Result, bail};
+use chrono::{DateTime, Datelike, Local, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+
+// Abreviações exibidas, na ordem de `num_days_from_sunday`
+pub const WEEKDAYS: [&str; 7] = ["Dom", "Seg", "Ter", "Qua", "Qui", "Sex", "Sáb"];
+
+// Horário recorrente, no fuso local
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MaintenanceWindow {
+    pub id: String,
+    // Dias em que a janela começa (0 = domingo); vazio = todos os dias
+    pub weekdays: Vec<u32>,
+    // "HH:MM"; quando o fim é menor que o início a janela passa da meia-noite
+    pub start: String,
+    pub end: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ServerMaintenance {
+    pub windows: Vec<MaintenanceWindow>,
+    // Manutenção iniciada manualmente, até este instante
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl MaintenanceWindow {
+    // `weekdays`: índices separados por vírgula vindos da interface (ex: "1,2,3,4,5")
+    pub fn new(weekdays: &str, start: &str, end: &str) -> Result<Self> {
+        let start = parse_time(start)?;
+        let end = parse_time(end)?;
+        if start == end {
+            bail!("Início e fim da janela não podem ser iguais");
+        }
+        let mut days: Vec<u32> = weekdays
+            .split(',')
+            .filter_map(|day| day.trim().parse().ok())
+            .filter(|day| *day < 7)
+            .collect();
+        days.sort_unstable();
+        days.dedup();
+
+        Ok(Self {
+            id: Utc::now().timestamp_millis().to_string(),
+            weekdays: days,
+            start: start.format("%H:%M").to_string(),
+            end: end.format("%H:%M").to_string(),
+        })
+    }
+
+    // Ex: "Seg, Ter, Qua 22:00–07:00" ou "Todos os dias 02:00–04:00"
+    pub fn label(&self) -> String {
+        let days = if self.weekdays.is_empty() || self.weekdays.len() == 7 {
+            "Todos os dias".to_string()
+        } else {
+            self.weekdays
+                .iter()
+                .filter_map(|day| WEEKDAYS.get(*day as usize).copied())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        format!("{} {}–{}", days, self.start, self.end)
+    }
+
+    fn starts_on(&self, weekday: u32) -> bool {
+        self.weekdays.is_empty() || self.weekdays.contains(&weekday)
+    }
+
+    pub fn contains(&self, now: DateTime<Local>) -> bool {
+        let (Ok(start), Ok(end)) = (parse_time(&self.start), parse_time(&self.end)) else {
+            return false;
+        };
+        let time = now.time();
+        let today = now.weekday().num_days_from_sunday();
+        if start < end {
+            return self.starts_on(today) && time >= start && time < end;
+        }
+        // Passa da meia-noite: a parte da madrugada pertence à janela do dia anterior
+        let yesterday = (today + 6) % 7;
+        (self.starts_on(today) && time >= start) || (self.starts_on(yesterday) && time < end)
+    }
+}
+
+impl ServerMaintenance {
+    // Motivo do silêncio em vigor, exibido no indicador do cabeçalho
+    pub fn active_reason(&self, now: DateTime<Local>) -> Option<String> {
+        if let Some(until) = self.until.filter(|until| *until > now.with_timezone(&Utc)) {
+            return Some(format!(
+                "Em manutenção até {}",
+                until.with_timezone(&Local).format("%H:%M")
+            ));
+        }
+        self.windows
+            .iter()
+            .find(|window| window.contains(now))
+            .map(|window| format!("Janela de manutenção {}", window.label()))
+    }
+
+    pub fn is_active(&self, now: DateTime<Local>) -> bool {
+        self.active_reason(now).is_some()
+    }
+}
+
+fn parse_time(value: &str) -> Result<NaiveTime> {
+    NaiveTime::parse_from_str(value.trim(), "%H:%M")
+        .map_err(|_| anyhow::anyhow!("Horário inválido '{}': use HH:MM", value.trim()))
+}
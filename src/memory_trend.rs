@@ -0,0 +1,142 @@
+// Tendência de memória por container para detectar vazamentos lentos
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    time::{Duration, Instant},
+};
+
+use crate::format;
+
+// Janela considerada no cálculo da inclinação
+const WINDOW: Duration = Duration::from_secs(30 * 60);
+// Intervalo mínimo entre amostras do mesmo container
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(10);
+// Histórico mínimo antes de estimar uma tendência
+const MIN_SPAN: Duration = Duration::from_secs(5 * 60);
+// Crescimento abaixo disso é tratado como estável
+const STABLE_BYTES_PER_HOUR: f64 = 1024.0 * 1024.0;
+
+#[derive(Debug, Clone, Copy)]
+pub struct MemoryTrend {
+    pub bytes_per_hour: f64,
+    // Tempo estimado até atingir o limite, se a memória estiver subindo
+    pub time_to_limit: Option<Duration>,
+}
+
+impl MemoryTrend {
+    pub fn is_growing(&self) -> bool {
+        self.bytes_per_hour >= STABLE_BYTES_PER_HOUR
+    }
+
+    // Ex: "↑ 12,4 MiB/h · limite em ~3h 20min"
+    pub fn summary(&self) -> String {
+        if !self.is_growing() {
+            return "Memória estável nos últimos 30 min".to_string();
+        }
+
+        let rate = format!("↑ {}", format::bytes_per_hour(self.bytes_per_hour));
+        match self.time_to_limit {
+            Some(remaining) => format!("{} · limite em ~{}", rate, format_remaining(remaining)),
+            None => rate,
+        }
+    }
+}
+
+#[derive(Default)]
+struct Series {
+    samples: VecDeque<(Instant, u64)>,
+    limit: u64,
+}
+
+// Amostras recentes de memória de cada container, por id
+#[derive(Default)]
+pub struct MemoryTrendTracker {
+    series: HashMap<String, Series>,
+}
+
+impl MemoryTrendTracker {
+    pub fn record(&mut self, container_id: &str, usage: u64, limit: u64) {
+        let now = Instant::now();
+        let series = self.series.entry(container_id.to_string()).or_default();
+        series.limit = limit;
+
+        if series
+            .samples
+            .back()
+            .is_some_and(|(time, _)| now.duration_since(*time) < SAMPLE_INTERVAL)
+        {
+            return;
+        }
+        series.samples.push_back((now, usage));
+
+        while series
+            .samples
+            .front()
+            .is_some_and(|(time, _)| now.duration_since(*time) > WINDOW)
+        {
+            series.samples.pop_front();
+        }
+    }
+
+    // Descarta containers que deixaram de estar em execução
+    pub fn retain(&mut self, running_ids: &HashSet<&str>) {
+        self.series.retain(|id, _| running_ids.contains(id.as_str()));
+    }
+
+    pub fn container_count(&self) -> usize {
+        self.series.len()
+    }
+
+    // Inclinação por regressão linear; None enquanto não houver histórico suficiente
+    pub fn trend(&self, container_id: &str) -> Option<MemoryTrend> {
+        let series = self.series.get(container_id)?;
+        let (first, _) = *series.samples.front()?;
+        let (last, last_usage) = *series.samples.back()?;
+        if last.duration_since(first) < MIN_SPAN {
+            return None;
+        }
+
+        let points: Vec<(f64, f64)> = series
+            .samples
+            .iter()
+            .map(|(time, usage)| (time.duration_since(first).as_secs_f64(), *usage as f64))
+            .collect();
+        let count = points.len() as f64;
+        let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / count;
+        let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / count;
+
+        let covariance: f64 = points
+            .iter()
+            .map(|(x, y)| (x - mean_x) * (y - mean_y))
+            .sum();
+        let variance: f64 = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+        if variance == 0.0 {
+            return None;
+        }
+
+        let bytes_per_hour = covariance / variance * 3600.0;
+        let time_to_limit = (bytes_per_hour >= STABLE_BYTES_PER_HOUR
+            && series.limit > last_usage)
+            .then(|| {
+                let hours = (series.limit - last_usage) as f64 / bytes_per_hour;
+                Duration::from_secs_f64(hours * 3600.0)
+            });
+
+        Some(MemoryTrend {
+            bytes_per_hour,
+            time_to_limit,
+        })
+    }
+}
+
+// Ex: "45min", "3h 20min", "2d 4h"
+fn format_remaining(remaining: Duration) -> String {
+    let minutes = remaining.as_secs() / 60;
+    let (days, hours, minutes) = (minutes / 1440, (minutes / 60) % 24, minutes % 60);
+    if days > 0 {
+        format!("{}d {}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {}min", hours, minutes)
+    } else {
+        format!("{}min", minutes.max(1))
+    }
+}
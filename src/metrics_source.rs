@@ -0,0 +1,229 @@
+// Histórico de métricas via Prometheus (cAdvisor) como alternativa ao docker stats nos gráficos
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    collections::HashMap,
+    process::Command,
+    time::{Duration, Instant},
+};
+
+use crate::chart::ChartPoint;
+use crate::config::ProxySettings;
+
+// Mesma quantidade de pontos dos gráficos ao vivo
+const POINTS: i64 = 60;
+// Resolução do histórico (15 min de janela)
+const STEP_SECS: i64 = 15;
+// Intervalo entre consultas; novos pontos só surgem a cada passo
+const REFRESH_INTERVAL: Duration = Duration::from_secs(STEP_SECS as u64);
+
+// Fonte externa configurada por servidor
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MetricsSource {
+    // Ex: http://prometheus:9090
+    pub prometheus_url: String,
+    // Filtro extra de labels quando o Prometheus coleta vários hosts, ex: instance="srv1:8080"
+    pub selector: String,
+}
+
+impl MetricsSource {
+    pub fn new(prometheus_url: &str, selector: &str) -> Result<Self> {
+        let prometheus_url = prometheus_url.trim().trim_end_matches('/');
+        if !(prometheus_url.starts_with("http://") || prometheus_url.starts_with("https://")) {
+            return Err(anyhow::anyhow!(
+                "URL do Prometheus deve começar com http:// ou https://"
+            ));
+        }
+        let selector = selector
+            .trim()
+            .trim_matches(|c| c == '{' || c == '}')
+            .trim();
+        if selector.contains('{') || selector.contains('}') {
+            return Err(anyhow::anyhow!(
+                "Seletor inválido: use apenas label=\"valor\", separados por vírgula"
+            ));
+        }
+        Ok(Self {
+            prometheus_url: prometheus_url.to_string(),
+            selector: selector.to_string(),
+        })
+    }
+
+    // Combina o seletor do servidor com os labels da consulta
+    fn labels(&self, extra: &str) -> String {
+        let labels: Vec<&str> = [extra, self.selector.as_str()]
+            .into_iter()
+            .filter(|labels| !labels.is_empty())
+            .collect();
+        format!("{{{}}}", labels.join(","))
+    }
+
+    // Soma de todos os containers do host: CPU em % de um núcleo (mesma escala do dashboard)
+    // e memória em % da memória da máquina
+    fn host_queries(&self) -> (String, String) {
+        let containers = self.labels("name!=\"\"");
+        let machine = self.labels("");
+        (
+            format!(
+                "sum(rate(container_cpu_usage_seconds_total{}[1m])) * 100",
+                containers
+            ),
+            format!(
+                "sum(container_memory_working_set_bytes{}) / scalar(sum(machine_memory_bytes{})) * 100",
+                containers, machine
+            ),
+        )
+    }
+
+    // Memória em % do limite do container, ou da máquina quando não há limite
+    fn container_queries(&self, container_name: &str) -> (String, String) {
+        let container = self.labels(&format!(
+            "name={}",
+            serde_json::to_string(container_name).unwrap_or_default()
+        ));
+        let machine = self.labels("");
+        (
+            format!(
+                "sum(rate(container_cpu_usage_seconds_total{}[1m])) * 100",
+                container
+            ),
+            format!(
+                "(sum(container_memory_working_set_bytes{c}) / (sum(container_spec_memory_limit_bytes{c}) > 0) \
+                 or sum(container_memory_working_set_bytes{c}) / scalar(sum(machine_memory_bytes{m}))) * 100",
+                c = container,
+                m = machine
+            ),
+        )
+    }
+
+    // Série de uma consulta nos últimos POINTS * STEP_SECS segundos
+    fn query_range(&self, query: &str, proxy: Option<&ProxySettings>) -> Result<Vec<ChartPoint>> {
+        let end = chrono::Utc::now().timestamp();
+        let start = end - POINTS * STEP_SECS;
+
+        let output = Command::new("curl")
+            .args(proxy.map(|proxy| proxy.curl_args()).unwrap_or_default())
+            .args(["-fsS", "--max-time", "10", "-G"])
+            .arg(format!("{}/api/v1/query_range", self.prometheus_url))
+            .args(["--data-urlencode", &format!("query={}", query)])
+            .args(["--data-urlencode", &format!("start={}", start)])
+            .args(["--data-urlencode", &format!("end={}", end)])
+            .args(["--data-urlencode", &format!("step={}", STEP_SECS)])
+            .output()
+            .context("Failed to execute curl")?;
+
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "Falha ao consultar o Prometheus: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        let response: Value =
+            serde_json::from_slice(&output.stdout).context("Resposta inválida do Prometheus")?;
+        if response["status"] != "success" {
+            return Err(anyhow::anyhow!(
+                "Prometheus recusou a consulta: {}",
+                response["error"].as_str().unwrap_or("erro desconhecido")
+            ));
+        }
+
+        // Sem dados (ex: container sem métricas no cAdvisor) resulta em série vazia
+        let points = response
+            .pointer("/data/result/0/values")
+            .and_then(Value::as_array)
+            .map(|values| {
+                values
+                    .iter()
+                    .filter_map(|pair| {
+                        let timestamp = pair.get(0)?.as_f64()? as i64;
+                        let value: f32 = pair.get(1)?.as_str()?.parse().ok()?;
+                        let time = chrono::DateTime::from_timestamp(timestamp, 0)?
+                            .with_timezone(&chrono::Local)
+                            .format("%H:%M:%S")
+                            .to_string();
+                        value.is_finite().then_some(ChartPoint { time, value })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(points)
+    }
+
+    fn history(
+        &self,
+        (cpu, memory): (String, String),
+        proxy: Option<&ProxySettings>,
+    ) -> Result<MetricsHistory> {
+        Ok(MetricsHistory {
+            cpu: self.query_range(&cpu, proxy)?,
+            memory: self.query_range(&memory, proxy)?,
+        })
+    }
+}
+
+// Pontos prontos para substituir os dos gráficos
+pub struct MetricsHistory {
+    pub cpu: Vec<ChartPoint>,
+    pub memory: Vec<ChartPoint>,
+}
+
+// Fonte do servidor ativo com controle do intervalo entre consultas
+pub struct MetricsFetcher {
+    source: MetricsSource,
+    proxy: Option<ProxySettings>,
+    // Última consulta por chave ("host" ou "servidor/id" do container)
+    last_fetch: HashMap<String, Instant>,
+}
+
+impl MetricsFetcher {
+    pub fn new(source: MetricsSource, proxy: Option<ProxySettings>) -> Self {
+        Self {
+            source,
+            proxy,
+            last_fetch: HashMap::new(),
+        }
+    }
+
+    // Consulta só quando há passo novo; chave diferente (outro container) consulta na hora
+    fn due(&mut self, key: &str) -> bool {
+        if key != "host" {
+            self.last_fetch
+                .retain(|known, _| known == "host" || known == key);
+        }
+        let due = self
+            .last_fetch
+            .get(key)
+            .is_none_or(|last| last.elapsed() >= REFRESH_INTERVAL);
+        if due {
+            self.last_fetch.insert(key.to_string(), Instant::now());
+        }
+        due
+    }
+
+    pub fn host_history(&mut self) -> Option<Result<MetricsHistory>> {
+        if !self.due("host") {
+            return None;
+        }
+        Some(
+            self.source
+                .history(self.source.host_queries(), self.proxy.as_ref()),
+        )
+    }
+
+    pub fn container_history(
+        &mut self,
+        key: &str,
+        container_name: &str,
+    ) -> Option<Result<MetricsHistory>> {
+        if !self.due(key) {
+            return None;
+        }
+        Some(self.source.history(
+            self.source.container_queries(container_name),
+            self.proxy.as_ref(),
+        ))
+    }
+}
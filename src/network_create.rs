@@ -0,0 +1,236 @@
+// Criação de networks com campos guiados para macvlan e ipvlan, que ligam os
+// containers direto à rede física por uma interface do host
+use anyhow::{Result, anyhow, bail};
+use std::net::Ipv4Addr;
+
+use crate::ip_allocation::Ipv4Net;
+
+// Interfaces criadas pelo próprio Docker ou por outras ferramentas de virtualização
+const SKIPPED_PREFIXES: [&str; 5] = ["docker", "br-", "veth", "virbr", "vxlan"];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NetworkDriver {
+    #[default]
+    Bridge,
+    Macvlan,
+    Ipvlan,
+}
+
+impl NetworkDriver {
+    pub fn from_ui(value: &str) -> Self {
+        match value {
+            "macvlan" => NetworkDriver::Macvlan,
+            "ipvlan" => NetworkDriver::Ipvlan,
+            _ => NetworkDriver::Bridge,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NetworkDriver::Bridge => "bridge",
+            NetworkDriver::Macvlan => "macvlan",
+            NetworkDriver::Ipvlan => "ipvlan",
+        }
+    }
+
+    // Drivers que precisam de interface pai e da sub-rede da rede física
+    pub fn needs_parent(&self) -> bool {
+        !matches!(self, NetworkDriver::Bridge)
+    }
+
+    // Valores de -o macvlan_mode / -o ipvlan_mode; o primeiro é o padrão do Docker
+    pub fn modes(&self) -> &'static [&'static str] {
+        match self {
+            NetworkDriver::Bridge => &[],
+            NetworkDriver::Macvlan => &["bridge", "vepa", "private", "passthru"],
+            NetworkDriver::Ipvlan => &["l2", "l3", "l3s"],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct NetworkCreateRequest {
+    pub name: String,
+    pub driver: NetworkDriver,
+    // Interface do host, ex: "eth0" ou "eth0.10" (subinterface VLAN criada pelo Docker)
+    pub parent: String,
+    pub subnet: String,
+    pub gateway: String,
+    pub ip_range: String,
+    // Vazio usa o modo padrão do driver
+    pub mode: String,
+}
+
+impl NetworkCreateRequest {
+    // Confere os campos antes de chamar o docker, apontando o que corrigir
+    pub fn validate(&self) -> Result<()> {
+        let name = self.name.trim();
+        if name.is_empty() {
+            bail!("Informe o nome da network");
+        }
+        if !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+        {
+            bail!("Nome inválido: use letras, números, '_', '.' ou '-'");
+        }
+
+        let driver = self.driver.as_str();
+        if self.driver.needs_parent() {
+            if self.parent.trim().is_empty() {
+                bail!(
+                    "Selecione a interface pai do host para a network {}",
+                    driver
+                );
+            }
+            if self.subnet.trim().is_empty() {
+                bail!(
+                    "Informe a sub-rede da rede física para {} (ex: 192.168.1.0/24)",
+                    driver
+                );
+            }
+        }
+
+        let mode = self.mode.trim();
+        if !mode.is_empty() && !self.driver.modes().contains(&mode) {
+            bail!(
+                "Modo '{}' inválido para {}; use {}",
+                mode,
+                driver,
+                self.driver.modes().join(", ")
+            );
+        }
+
+        let subnet = self.subnet.trim();
+        let gateway = self.gateway.trim();
+        let ip_range = self.ip_range.trim();
+        if subnet.is_empty() {
+            if !gateway.is_empty() || !ip_range.is_empty() {
+                bail!("Gateway e faixa de IPs exigem uma sub-rede");
+            }
+            return Ok(());
+        }
+
+        let net = parse_cidr(subnet, "Sub-rede")?;
+        if !gateway.is_empty() {
+            let ip: Ipv4Addr = gateway.parse().map_err(|_| {
+                anyhow!(
+                    "Gateway inválido: {} (use um IPv4, ex: 192.168.1.1)",
+                    gateway
+                )
+            })?;
+            let (first, last) = net.host_bounds();
+            let ip = u32::from(ip);
+            if ip < first || ip > last {
+                bail!(
+                    "O gateway {} não é um endereço de host da sub-rede {}",
+                    gateway,
+                    subnet
+                );
+            }
+        }
+        if !ip_range.is_empty() {
+            let range = parse_cidr(ip_range, "Faixa de IPs")?;
+            if range.prefix < net.prefix || !net.contains(range.network) {
+                bail!(
+                    "A faixa de IPs {} não está dentro da sub-rede {}",
+                    ip_range,
+                    subnet
+                );
+            }
+        }
+        Ok(())
+    }
+
+    // Argumentos do `docker network create`
+    pub fn args(&self) -> Vec<String> {
+        let mut args = vec![
+            "network".to_string(),
+            "create".to_string(),
+            "--driver".to_string(),
+            self.driver.as_str().to_string(),
+        ];
+        if self.driver.needs_parent() {
+            args.push("-o".to_string());
+            args.push(format!("parent={}", self.parent.trim()));
+        }
+        if !self.mode.trim().is_empty() {
+            args.push("-o".to_string());
+            args.push(format!(
+                "{}_mode={}",
+                self.driver.as_str(),
+                self.mode.trim()
+            ));
+        }
+        for (flag, value) in [
+            ("--subnet", &self.subnet),
+            ("--gateway", &self.gateway),
+            ("--ip-range", &self.ip_range),
+        ] {
+            if !value.trim().is_empty() {
+                args.push(flag.to_string());
+                args.push(value.trim().to_string());
+            }
+        }
+        args.push(self.name.trim().to_string());
+        args
+    }
+}
+
+// CIDR IPv4 sem bits de host, como o Docker exige
+fn parse_cidr(value: &str, label: &str) -> Result<Ipv4Net> {
+    let net = Ipv4Net::parse(value)
+        .ok_or_else(|| anyhow!("{} inválida: {} (ex: 192.168.1.0/24)", label, value))?;
+    let address = value.split('/').next().unwrap_or_default();
+    if address.parse::<Ipv4Addr>().map(u32::from).ok() != Some(net.network) {
+        bail!(
+            "{} {} tem bits de host; use {}/{}",
+            label,
+            value,
+            Ipv4Addr::from(net.network),
+            net.prefix
+        );
+    }
+    Ok(net)
+}
+
+#[derive(Debug, Clone)]
+pub struct HostInterface {
+    pub name: String,
+    pub mac: String,
+    // Link ativo (LOWER_UP)
+    pub up: bool,
+}
+
+// Saída de `ip -o link show`, ex:
+//   2: eth0: <BROADCAST,MULTICAST,UP,LOWER_UP> mtu 1500 ... state UP ...\    link/ether 52:54:00:12:34:56 brd ...
+// Só interfaces Ethernet físicas ou VLAN servem de interface pai; as ativas vêm primeiro
+pub fn parse_interfaces(output: &str) -> Vec<HostInterface> {
+    let mut interfaces: Vec<HostInterface> = output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, ": ");
+            let _index = fields.next()?;
+            // "eth0.10@eth0" -> "eth0.10"
+            let name = fields.next()?.split('@').next()?.trim().to_string();
+            let rest = fields.next()?;
+            if SKIPPED_PREFIXES
+                .iter()
+                .any(|prefix| name.starts_with(prefix))
+            {
+                return None;
+            }
+
+            let mut words = rest.split_whitespace();
+            words.find(|word| *word == "link/ether")?;
+            let mac = words.next().unwrap_or_default().to_string();
+            let up = rest
+                .split(['<', '>'])
+                .nth(1)
+                .is_some_and(|flags| flags.split(',').any(|flag| flag == "LOWER_UP"));
+            Some(HostInterface { name, mac, up })
+        })
+        .collect();
+    interfaces.sort_by(|a, b| b.up.cmp(&a.up).then_with(|| a.name.cmp(&b.name)));
+    interfaces
+}
@@ -0,0 +1,269 @@
+// Containers com mudanças que só valem depois de uma ação: configuração gravada que
+// o daemon só aplica no próximo início (reinício pendente) ou tag da imagem apontando
+// para uma imagem mais nova que a em execução (recriação pendente)
+use serde_json::Value;
+use std::collections::HashMap;
+
+// Campos do HostConfig acompanhados e o nome exibido no motivo. Memória, CPU, pids,
+// peso de I/O e política de reinício ficam de fora: o docker update os aplica na hora.
+// Os limites por dispositivo aceitos pela API de update só valem a partir do próximo início
+const TRACKED_SETTINGS: [(&str, &str); 4] = [
+    ("/HostConfig/BlkioDeviceReadBps", "leitura por dispositivo"),
+    ("/HostConfig/BlkioDeviceWriteBps", "escrita por dispositivo"),
+    ("/HostConfig/BlkioDeviceReadIOps", "IOPS de leitura por dispositivo"),
+    ("/HostConfig/BlkioDeviceWriteIOps", "IOPS de escrita por dispositivo"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PendingAction {
+    Restart,
+    Recreate,
+}
+
+impl PendingAction {
+    // Valor usado pela interface para escolher o selo
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PendingAction::Restart => "restart",
+            PendingAction::Recreate => "recreate",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingChange {
+    pub action: PendingAction,
+    pub reason: String,
+}
+
+// Configuração observada desde o último início do container
+#[derive(Debug, Clone)]
+struct Baseline {
+    started_at: String,
+    settings: Vec<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct PendingTracker {
+    // Chave: servidor/id do container
+    baselines: HashMap<String, Baseline>,
+}
+
+impl PendingTracker {
+    // `inspected`: saída do `docker container inspect`; `images`: referência -> ID da imagem local.
+    // A primeira observação de cada início é tomada como a configuração aplicada
+    pub fn update(
+        &mut self,
+        server: &str,
+        inspected: &[Value],
+        images: &HashMap<String, String>,
+    ) -> HashMap<String, PendingChange> {
+        let prefix = format!("{}/", server);
+        let mut seen = Vec::new();
+        let mut pending = HashMap::new();
+
+        for container in inspected {
+            let Some(id) = container.get("Id").and_then(Value::as_str) else {
+                continue;
+            };
+            let key = format!("{}{}", prefix, id);
+            seen.push(key.clone());
+
+            if let Some(change) = image_change(container, images) {
+                pending.insert(id.to_string(), change);
+                continue;
+            }
+
+            let running = container
+                .pointer("/State/Running")
+                .and_then(Value::as_bool)
+                .unwrap_or(false);
+            let started_at = text(container, "/State/StartedAt");
+            let settings: Vec<String> = TRACKED_SETTINGS
+                .iter()
+                .map(|(pointer, _)| text(container, pointer))
+                .collect();
+
+            let baseline = self.baselines.entry(key).or_insert_with(|| Baseline {
+                started_at: started_at.clone(),
+                settings: settings.clone(),
+            });
+            // Reiniciado (ou parado): a configuração atual passa a ser a aplicada
+            if !running || baseline.started_at != started_at {
+                *baseline = Baseline {
+                    started_at,
+                    settings,
+                };
+                continue;
+            }
+
+            let changed: Vec<&str> = TRACKED_SETTINGS
+                .iter()
+                .zip(baseline.settings.iter().zip(&settings))
+                .filter(|(_, (before, now))| before != now)
+                .map(|((_, name), _)| *name)
+                .collect();
+            if !changed.is_empty() {
+                pending.insert(
+                    id.to_string(),
+                    PendingChange {
+                        action: PendingAction::Restart,
+                        reason: format!("Alterado desde o último início: {}", changed.join(", ")),
+                    },
+                );
+            }
+        }
+
+        // Containers removidos deste servidor
+        self.baselines
+            .retain(|key, _| !key.starts_with(&prefix) || seen.contains(key));
+        pending
+    }
+
+    // Configuração alterada sem reinício (docker update): a próxima observação do
+    // container passa a ser a aplicada
+    pub fn accept_current(&mut self, container_id: &str) {
+        self.baselines.retain(|key, _| {
+            key.rsplit_once('/')
+                .is_none_or(|(_, id)| !id.starts_with(container_id))
+        });
+    }
+}
+
+// Valor do campo como texto, para comparar números, strings e null da mesma forma
+fn text(container: &Value, pointer: &str) -> String {
+    match container.pointer(pointer) {
+        Some(Value::String(value)) => value.clone(),
+        Some(Value::Null) | None => String::new(),
+        Some(value) => value.to_string(),
+    }
+}
+
+// "nginx" -> "nginx:latest"; "docker.io/library/nginx:1.27" -> "nginx:1.27"
+pub fn normalize_reference(reference: &str) -> String {
+    let reference = reference.strip_prefix("docker.io/").unwrap_or(reference);
+    let reference = reference.strip_prefix("library/").unwrap_or(reference);
+    let name_start = reference.rfind('/').map(|slash| slash + 1).unwrap_or(0);
+    if reference[name_start..].contains(':') {
+        reference.to_string()
+    } else {
+        format!("{}:latest", reference)
+    }
+}
+
+// A tag usada na criação aponta hoje para outra imagem local
+fn image_change(container: &Value, images: &HashMap<String, String>) -> Option<PendingChange> {
+    let reference = text(container, "/Config/Image");
+    let running_image = text(container, "/Image");
+    // Imagens fixadas por digest ou ID não mudam
+    if reference.is_empty() || reference.contains('@') || reference.starts_with("sha256:") {
+        return None;
+    }
+    let reference = normalize_reference(&reference);
+    let current = images.get(&reference)?;
+    if *current == running_image {
+        return None;
+    }
+    Some(PendingChange {
+        action: PendingAction::Recreate,
+        reason: format!(
+            "A imagem {} foi atualizada ({} → {})",
+            reference,
+            short_id(&running_image),
+            short_id(current)
+        ),
+    })
+}
+
+fn short_id(id: &str) -> &str {
+    let id = id.strip_prefix("sha256:").unwrap_or(id);
+    &id[..id.len().min(12)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn container(started_at: &str, read_bps: Value, weight: u64) -> Value {
+        json!({
+            "Id": "abc123",
+            "Image": "sha256:1111",
+            "Config": { "Image": "nginx" },
+            "State": { "Running": true, "StartedAt": started_at },
+            "HostConfig": {
+                "Memory": 0,
+                "BlkioWeight": weight,
+                "BlkioDeviceReadBps": read_bps,
+            },
+        })
+    }
+
+    fn images(id: &str) -> HashMap<String, String> {
+        HashMap::from([("nginx:latest".to_string(), id.to_string())])
+    }
+
+    #[test]
+    fn test_device_rate_change_needs_restart() {
+        let mut tracker = PendingTracker::default();
+        let first = container("t1", Value::Null, 500);
+        assert!(tracker.update("srv", &[first], &images("sha256:1111")).is_empty());
+
+        let rates = json!([{ "Path": "/dev/sda", "Rate": 1048576 }]);
+        let pending = tracker.update("srv", &[container("t1", rates, 500)], &images("sha256:1111"));
+        let change = pending.get("abc123").unwrap();
+        assert_eq!(change.action, PendingAction::Restart);
+        assert!(change.reason.contains("leitura por dispositivo"));
+    }
+
+    #[test]
+    fn test_live_settings_are_not_tracked() {
+        let mut tracker = PendingTracker::default();
+        tracker.update("srv", &[container("t1", Value::Null, 500)], &images("sha256:1111"));
+        let pending = tracker.update("srv", &[container("t1", Value::Null, 800)], &images("sha256:1111"));
+        assert!(pending.is_empty());
+    }
+
+    #[test]
+    fn test_restart_clears_pending() {
+        let mut tracker = PendingTracker::default();
+        let rates = json!([{ "Path": "/dev/sda", "Rate": 1048576 }]);
+        tracker.update("srv", &[container("t1", Value::Null, 500)], &images("sha256:1111"));
+        assert!(!tracker.update("srv", &[container("t1", rates.clone(), 500)], &images("sha256:1111")).is_empty());
+        assert!(tracker.update("srv", &[container("t2", rates, 500)], &images("sha256:1111")).is_empty());
+    }
+
+    #[test]
+    fn test_accept_current_clears_pending() {
+        let mut tracker = PendingTracker::default();
+        let rates = json!([{ "Path": "/dev/sda", "Rate": 1048576 }]);
+        tracker.update("srv", &[container("t1", Value::Null, 500)], &images("sha256:1111"));
+        tracker.accept_current("abc");
+        assert!(tracker.update("srv", &[container("t1", rates.clone(), 500)], &images("sha256:1111")).is_empty());
+        assert!(tracker.update("srv", &[container("t1", rates, 500)], &images("sha256:1111")).is_empty());
+    }
+
+    #[test]
+    fn test_updated_image_needs_recreate() {
+        let mut tracker = PendingTracker::default();
+        let pending = tracker.update("srv", &[container("t1", Value::Null, 500)], &images("sha256:2222"));
+        let change = pending.get("abc123").unwrap();
+        assert_eq!(change.action, PendingAction::Recreate);
+        assert!(change.reason.contains("nginx:latest"));
+    }
+
+    #[test]
+    fn test_pinned_image_is_ignored() {
+        let mut value = container("t1", Value::Null, 500);
+        value["Config"]["Image"] = json!("nginx@sha256:abcd");
+        assert_eq!(image_change(&value, &images("sha256:2222")), None);
+    }
+
+    #[test]
+    fn test_normalize_reference() {
+        assert_eq!(normalize_reference("nginx"), "nginx:latest");
+        assert_eq!(normalize_reference("docker.io/library/nginx:1.27"), "nginx:1.27");
+        assert_eq!(normalize_reference("registry:5000/app"), "registry:5000/app:latest");
+        assert_eq!(normalize_reference("ghcr.io/org/app:v1"), "ghcr.io/org/app:v1");
+    }
+}
@@ -0,0 +1,64 @@
+// Limpeza (prune) de containers e imagens, executada em vários servidores em paralelo
+use anyhow::Result;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PruneTarget {
+    // Containers parados
+    Containers,
+    // Imagens sem tag
+    DanglingImages,
+    // Todas as imagens sem container usando
+    UnusedImages,
+}
+
+impl PruneTarget {
+    pub fn from_ui(value: &str) -> Self {
+        match value {
+            "dangling-images" => PruneTarget::DanglingImages,
+            "unused-images" => PruneTarget::UnusedImages,
+            _ => PruneTarget::Containers,
+        }
+    }
+
+    // Valor usado pela interface e pelos agendamentos
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PruneTarget::Containers => "containers",
+            PruneTarget::DanglingImages => "dangling-images",
+            PruneTarget::UnusedImages => "unused-images",
+        }
+    }
+
+    pub fn args(&self) -> &'static [&'static str] {
+        match self {
+            PruneTarget::Containers => &["container", "prune", "-f"],
+            PruneTarget::DanglingImages => &["image", "prune", "-f"],
+            PruneTarget::UnusedImages => &["image", "prune", "-f", "-a"],
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            PruneTarget::Containers => "containers parados",
+            PruneTarget::DanglingImages => "imagens sem tag",
+            PruneTarget::UnusedImages => "imagens sem uso",
+        }
+    }
+}
+
+// Espaço liberado, da última linha da saída do CLI: "Total reclaimed space: 1.2GB"
+pub fn reclaimed_space(stdout: &str) -> String {
+    stdout
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("Total reclaimed space:"))
+        .map(|total| total.trim().to_string())
+        .unwrap_or_else(|| "0B".to_string())
+}
+
+// Linha exibida para o servidor ao fim da limpeza
+pub fn server_status(result: &Result<String>) -> String {
+    match result {
+        Ok(reclaimed) => format!("✓ {} liberados", reclaimed),
+        Err(e) => format!("✗ {}", e),
+    }
+}
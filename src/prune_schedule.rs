@@ -0,0 +1,71 @@
+// Limpeza agendada: as regras do assistente de limpeza rodam sozinhas, todo dia no
+// horário configurado, nos servidores escolhidos
+use anyhow::{Result, bail};
+use chrono::{DateTime, Local, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::prune::PruneTarget;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PruneSchedule {
+    pub id: String,
+    pub servers: Vec<String>,
+    // Valor de `PruneTarget::as_str`
+    pub target: String,
+    // "HH:MM", no fuso local
+    pub time: String,
+    pub last_run: Option<DateTime<Utc>>,
+}
+
+impl PruneSchedule {
+    pub fn new(servers: Vec<String>, target: PruneTarget, time: &str) -> Result<Self> {
+        if servers.is_empty() {
+            bail!("Selecione ao menos um servidor");
+        }
+        let time = NaiveTime::parse_from_str(time.trim(), "%H:%M")
+            .map_err(|_| anyhow::anyhow!("Horário inválido '{}': use HH:MM", time.trim()))?;
+        Ok(Self {
+            id: Utc::now().timestamp_millis().to_string(),
+            servers,
+            target: target.as_str().to_string(),
+            time: time.format("%H:%M").to_string(),
+            // Conta a partir de agora: o primeiro horário passado não dispara na hora
+            last_run: Some(Utc::now()),
+        })
+    }
+
+    pub fn target(&self) -> PruneTarget {
+        PruneTarget::from_ui(&self.target)
+    }
+
+    // Ex: "Todo dia 03:00 · imagens sem uso · prod, staging"
+    pub fn label(&self) -> String {
+        format!(
+            "Todo dia {} · {} · {}",
+            self.time,
+            self.target().label(),
+            self.servers.join(", ")
+        )
+    }
+
+    // Já passou do horário hoje e ainda não rodou desde então
+    pub fn is_due(&self, now: DateTime<Local>) -> bool {
+        let Ok(time) = NaiveTime::parse_from_str(&self.time, "%H:%M") else {
+            return false;
+        };
+        if now.time() < time {
+            return false;
+        }
+        let Some(scheduled) = now
+            .date_naive()
+            .and_time(time)
+            .and_local_timezone(Local)
+            .earliest()
+        else {
+            return false;
+        };
+        self.last_run
+            .is_none_or(|last_run| last_run < scheduled.with_timezone(&Utc))
+    }
+}
@@ -0,0 +1,310 @@
+// Estimativa do download de um pull: consulta o manifest no registry e desconta as camadas
+// que o servidor já tem, ex: "~120 MB de 650 MB a baixar"
+use anyhow::{Context, Result, bail};
+use serde_json::Value;
+use std::collections::HashSet;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use crate::config::ProxySettings;
+use crate::format;
+
+const MANIFEST_ACCEPT: &str = "application/vnd.docker.distribution.manifest.list.v2+json, \
+application/vnd.oci.image.index.v1+json, \
+application/vnd.docker.distribution.manifest.v2+json, \
+application/vnd.oci.image.manifest.v1+json";
+
+// Referência de imagem separada em registry, repositório e tag/digest
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageReference {
+    pub registry: String,
+    pub repository: String,
+    pub reference: String,
+}
+
+impl ImageReference {
+    pub fn parse(image: &str) -> Result<Self> {
+        let image = image.trim();
+        if image.is_empty() {
+            bail!("Informe a imagem");
+        }
+        let (name, reference) = match image.split_once('@') {
+            Some((name, digest)) => (name, digest.to_string()),
+            None => match image.rsplit_once(':') {
+                Some((name, tag)) if !tag.contains('/') => (name, tag.to_string()),
+                _ => (image, "latest".to_string()),
+            },
+        };
+        let (registry, repository) = match name.split_once('/') {
+            Some((first, rest))
+                if first.contains('.') || first.contains(':') || first == "localhost" =>
+            {
+                (first.to_string(), rest.to_string())
+            }
+            _ => ("docker.io".to_string(), name.to_string()),
+        };
+        let (registry, repository) = if registry == "docker.io" {
+            let repository = if repository.contains('/') {
+                repository
+            } else {
+                format!("library/{}", repository)
+            };
+            ("registry-1.docker.io".to_string(), repository)
+        } else {
+            (registry, repository)
+        };
+        Ok(Self {
+            registry,
+            repository,
+            reference,
+        })
+    }
+
+    fn url(&self, kind: &str, reference: &str) -> String {
+        // Registries locais costumam rodar sem TLS
+        let scheme = if self.registry.starts_with("localhost") || self.registry.starts_with("127.")
+        {
+            "http"
+        } else {
+            "https"
+        };
+        format!(
+            "{}://{}/v2/{}/{}/{}",
+            scheme, self.registry, self.repository, kind, reference
+        )
+    }
+}
+
+// Camada da imagem remota: diff ID (não comprimido, como o daemon guarda) e tamanho comprimido
+#[derive(Debug, Clone)]
+pub struct RemoteLayer {
+    pub diff_id: String,
+    pub size: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PullEstimate {
+    pub needed: u64,
+    pub total: u64,
+    pub layers: usize,
+    pub cached: usize,
+}
+
+impl PullEstimate {
+    pub fn from_layers(remote: &[RemoteLayer], local: &HashSet<String>) -> Self {
+        let mut estimate = Self {
+            layers: remote.len(),
+            ..Default::default()
+        };
+        for layer in remote {
+            estimate.total += layer.size;
+            if local.contains(&layer.diff_id) {
+                estimate.cached += 1;
+            } else {
+                estimate.needed += layer.size;
+            }
+        }
+        estimate
+    }
+
+    // Ex: "~120 MB de 650 MB a baixar (3 de 7 camadas já no servidor)"
+    pub fn label(&self) -> String {
+        if self.needed == 0 {
+            return format!(
+                "Nada a baixar: as {} camadas ({}) já estão no servidor",
+                self.layers,
+                format::bytes(self.total)
+            );
+        }
+        format!(
+            "~{} de {} a baixar ({} de {} camadas já no servidor)",
+            format::bytes(self.needed),
+            format::bytes(self.total),
+            self.cached,
+            self.layers
+        )
+    }
+}
+
+// Camadas da imagem para a plataforma do servidor (ex: "linux", "amd64", "")
+pub fn remote_layers(
+    reference: &ImageReference,
+    platform: (&str, &str, &str),
+    login: Option<(String, String)>,
+    proxy: Option<ProxySettings>,
+) -> Result<Vec<RemoteLayer>> {
+    let client = RegistryClient::connect(reference, login, proxy)?;
+
+    let mut manifest = client.get_json(
+        &reference.url("manifests", &reference.reference),
+        MANIFEST_ACCEPT,
+    )?;
+    // Imagem multi-plataforma: escolhe o manifest da plataforma do servidor
+    if let Some(manifests) = manifest["manifests"].as_array() {
+        let (os, arch, variant) = platform;
+        let entry = manifests
+            .iter()
+            .filter(|entry| {
+                entry["platform"]["os"] == os && entry["platform"]["architecture"] == arch
+            })
+            .find(|entry| {
+                variant.is_empty() || entry["platform"]["variant"].as_str().unwrap_or("") == variant
+            })
+            .ok_or_else(|| anyhow::anyhow!("A imagem não tem versão para {}/{}", os, arch))?;
+        let digest = entry["digest"]
+            .as_str()
+            .ok_or_else(|| anyhow::anyhow!("Manifest sem digest para {}/{}", os, arch))?;
+        manifest = client.get_json(&reference.url("manifests", digest), MANIFEST_ACCEPT)?;
+    }
+
+    let config_digest = manifest["config"]["digest"]
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("Manifest sem configuração da imagem"))?;
+    let config = client.get_json(&reference.url("blobs", config_digest), "*/*")?;
+
+    let diff_ids = config["rootfs"]["diff_ids"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default();
+    let layers = manifest["layers"].as_array().cloned().unwrap_or_default();
+    if diff_ids.len() != layers.len() {
+        bail!("Manifest e configuração da imagem não conferem");
+    }
+    Ok(layers
+        .iter()
+        .zip(diff_ids)
+        .map(|(layer, diff_id)| RemoteLayer {
+            diff_id: diff_id.as_str().unwrap_or_default().to_string(),
+            size: layer["size"].as_u64().unwrap_or(0),
+        })
+        .collect())
+}
+
+// Requisições ao registry com o token (anônimo ou da credencial salva) já obtido
+struct RegistryClient {
+    token: Option<String>,
+    proxy_args: Vec<String>,
+}
+
+impl RegistryClient {
+    fn connect(
+        reference: &ImageReference,
+        login: Option<(String, String)>,
+        proxy: Option<ProxySettings>,
+    ) -> Result<Self> {
+        let proxy_args = proxy.map(|proxy| proxy.curl_args()).unwrap_or_default();
+        // O desafio do /v2/ diz onde obter o token (Docker Hub, GHCR, Harbor...)
+        let probe = Command::new("curl")
+            .args(&proxy_args)
+            .args(["-sSI", "--max-time", "10"])
+            .arg(reference.url("manifests", &reference.reference))
+            .output()
+            .context("Failed to execute curl")?;
+        if !probe.status.success() {
+            bail!(
+                "Registry {} inacessível: {}",
+                reference.registry,
+                String::from_utf8_lossy(&probe.stderr).trim()
+            );
+        }
+        let headers = String::from_utf8_lossy(&probe.stdout);
+        let Some(challenge) = bearer_challenge(&headers) else {
+            return Ok(Self {
+                token: None,
+                proxy_args,
+            });
+        };
+
+        let mut url = format!(
+            "{}?scope=repository:{}:pull",
+            challenge.realm, reference.repository
+        );
+        if let Some(service) = challenge.service {
+            url.push_str(&format!("&service={}", service));
+        }
+        let mut child = Command::new("curl")
+            .args(&proxy_args)
+            .args(["-fsS", "--max-time", "10", "--config", "-"])
+            .arg(&url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .context("Failed to execute curl")?;
+        if let Some(mut stdin) = child.stdin.take() {
+            // Usuário/senha via stdin para não expor a senha na lista de processos
+            if let Some((username, password)) = &login {
+                let escape = |value: &str| value.replace('\\', "\\\\").replace('"', "\\\"");
+                let _ = writeln!(
+                    stdin,
+                    "user = \"{}:{}\"",
+                    escape(username),
+                    escape(password)
+                );
+            }
+        }
+        let output = child.wait_with_output().context("Failed to execute curl")?;
+        if !output.status.success() {
+            bail!(
+                "Falha ao obter token do registry: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        let json: Value =
+            serde_json::from_slice(&output.stdout).context("Resposta inválida do registry")?;
+        let token = json["token"]
+            .as_str()
+            .or_else(|| json["access_token"].as_str())
+            .map(str::to_string);
+        Ok(Self { token, proxy_args })
+    }
+
+    fn get_json(&self, url: &str, accept: &str) -> Result<Value> {
+        let mut command = Command::new("curl");
+        command
+            .args(&self.proxy_args)
+            // -L segue o redirecionamento dos blobs para o storage (sem repassar o token)
+            .args(["-fsSL", "--max-time", "20", "-H"])
+            .arg(format!("Accept: {}", accept));
+        if let Some(token) = &self.token {
+            command
+                .arg("-H")
+                .arg(format!("Authorization: Bearer {}", token));
+        }
+        let output = command
+            .arg(url)
+            .output()
+            .context("Failed to execute curl")?;
+        if !output.status.success() {
+            bail!(
+                "Falha ao consultar o registry: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        serde_json::from_slice(&output.stdout).context("Resposta inválida do registry")
+    }
+}
+
+struct BearerChallenge {
+    realm: String,
+    service: Option<String>,
+}
+
+// www-authenticate: Bearer realm="https://auth.docker.io/token",service="registry.docker.io"
+fn bearer_challenge(headers: &str) -> Option<BearerChallenge> {
+    let line = headers
+        .lines()
+        .find(|line| line.to_lowercase().starts_with("www-authenticate:"))?;
+    let value = line.split_once(':')?.1.trim();
+    let params = value.strip_prefix("Bearer ")?;
+    let param = |name: &str| {
+        params.split(',').find_map(|pair| {
+            let (key, value) = pair.trim().split_once('=')?;
+            (key == name).then(|| value.trim_matches('"').to_string())
+        })
+    };
+    Some(BearerChallenge {
+        realm: param("realm")?,
+        service: param("service"),
+    })
+}
@@ -0,0 +1,458 @@
+// Integração com registries (Docker Hub) via HTTP usando curl
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local};
+use crate::config::ProxySettings;
+use regex::Regex;
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+const HUB_AUTH_URL: &str = "https://auth.docker.io/token?service=registry.docker.io&scope=repository:ratelimitpreview/test:pull";
+const HUB_RATE_LIMIT_URL: &str =
+    "https://registry-1.docker.io/v2/ratelimitpreview/test/manifests/latest";
+
+// Cota de pulls do Docker Hub informada pelos headers ratelimit-*
+#[derive(Debug, Clone)]
+pub struct RateLimitStatus {
+    pub limit: Option<u32>,
+    pub remaining: Option<u32>,
+    pub window_secs: u64,
+    pub checked_at: DateTime<Local>,
+}
+
+impl RateLimitStatus {
+    // Indica se a cota acabou
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining == Some(0)
+    }
+
+    // Horário aproximado em que a janela da cota é renovada
+    pub fn retry_at(&self) -> DateTime<Local> {
+        self.checked_at + chrono::Duration::seconds(self.window_secs as i64)
+    }
+
+    // Texto curto para a interface
+    pub fn summary(&self) -> String {
+        match (self.remaining, self.limit) {
+            (Some(remaining), Some(limit)) => format!(
+                "Cota Docker Hub: {}/{} pulls (janela de {}h)",
+                remaining,
+                limit,
+                self.window_secs / 3600
+            ),
+            _ => "Cota Docker Hub: sem limite informado".to_string(),
+        }
+    }
+}
+
+// Verifica se uma mensagem de erro do daemon indica rate limit
+pub fn is_rate_limit_error(message: &str) -> bool {
+    let message = message.to_lowercase();
+    message.contains("toomanyrequests")
+        || message.contains("too many requests")
+        || message.contains("pull rate limit")
+        || message.contains("rate limit exceeded")
+}
+
+// Argumentos do curl das duas etapas da consulta da cota (token e HEAD do manifest). A
+// cota é do IP que faz os pulls, então os comandos rodam no host do daemon; usuário/senha
+// e o token vão pelo stdin, fora da lista de processos
+pub fn hub_rate_limit_args(proxy: Option<&ProxySettings>) -> (Vec<String>, Vec<String>) {
+    let proxy_args = proxy.map(|proxy| proxy.curl_args()).unwrap_or_default();
+    let curl = |args: &[&str]| -> Vec<String> {
+        std::iter::once("curl".to_string())
+            .chain(proxy_args.iter().cloned())
+            .chain(args.iter().map(|arg| arg.to_string()))
+            .collect()
+    };
+    (
+        curl(&["-fsS", "--max-time", "10", "--config", "-", HUB_AUTH_URL]),
+        curl(&["-fsSI", "--max-time", "10", "-H", "@-", HUB_RATE_LIMIT_URL]),
+    )
+}
+
+// Consulta a cota de pulls do Docker Hub (HEAD não consome cota) com os comandos de
+// hub_rate_limit_args; com credencial, retorna a cota da conta em vez da cota anônima
+pub fn check_hub_rate_limit(
+    mut token_command: Command,
+    mut headers_command: Command,
+    login: Option<(String, String)>,
+) -> Result<RateLimitStatus> {
+    token_command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    let mut child = token_command.spawn().context("Failed to execute curl")?;
+    if let Some(mut stdin) = child.stdin.take()
+        && let Some((username, password)) = &login
+    {
+        let escape = |value: &str| value.replace('\\', "\\\\").replace('"', "\\\"");
+        let _ = writeln!(stdin, "user = \"{}:{}\"", escape(username), escape(password));
+    }
+    let token_output = child.wait_with_output().context("Failed to execute curl")?;
+
+    if !token_output.status.success() {
+        return Err(anyhow::anyhow!(
+            "Falha ao obter token do Docker Hub: {}",
+            String::from_utf8_lossy(&token_output.stderr)
+        ));
+    }
+
+    let token_json: serde_json::Value = serde_json::from_slice(&token_output.stdout)
+        .context("Resposta inválida do servidor de autenticação")?;
+    let token = token_json["token"]
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("Token ausente na resposta do Docker Hub"))?;
+
+    headers_command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let mut child = headers_command.spawn().context("Failed to execute curl")?;
+    if let Some(mut stdin) = child.stdin.take() {
+        let _ = writeln!(stdin, "Authorization: Bearer {}", token);
+    }
+    let headers_output = child.wait_with_output().context("Failed to execute curl")?;
+
+    if !headers_output.status.success() {
+        return Err(anyhow::anyhow!(
+            "Falha ao consultar cota do Docker Hub: {}",
+            String::from_utf8_lossy(&headers_output.stderr)
+        ));
+    }
+
+    Ok(parse_rate_limit_headers(&String::from_utf8_lossy(
+        &headers_output.stdout,
+    )))
+}
+
+// Extrai ratelimit-limit / ratelimit-remaining (formato "100;w=21600")
+fn parse_rate_limit_headers(headers: &str) -> RateLimitStatus {
+    let mut limit = None;
+    let mut remaining = None;
+    let mut window_secs = 6 * 3600;
+
+    for line in headers.lines() {
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        let name = name.trim().to_lowercase();
+        if name != "ratelimit-limit" && name != "ratelimit-remaining" {
+            continue;
+        }
+
+        let mut parts = value.trim().split(';');
+        let amount = parts.next().and_then(|v| v.trim().parse::<u32>().ok());
+        for part in parts {
+            if let Some(window) = part.trim().strip_prefix("w=")
+                && let Ok(window) = window.parse::<u64>()
+            {
+                window_secs = window;
+            }
+        }
+
+        if name == "ratelimit-limit" {
+            limit = amount;
+        } else {
+            remaining = amount;
+        }
+    }
+
+    RateLimitStatus {
+        limit,
+        remaining,
+        window_secs,
+        checked_at: Local::now(),
+    }
+}
+
+// Descrição de um repositório do Docker Hub, com o README em texto puro e as variáveis
+// de ambiente e portas citadas nele
+#[derive(Debug, Clone, Default)]
+pub struct HubDescription {
+    pub repository: String,
+    pub summary: String,
+    pub readme: String,
+    pub env_vars: Vec<String>,
+    pub ports: Vec<String>,
+    pub stars: u64,
+    pub pulls: u64,
+}
+
+impl HubDescription {
+    // Ex: "library/nginx · ★ 20000 · 1000000000 pulls"
+    pub fn header(&self) -> String {
+        format!(
+            "{} · ★ {} · {} pulls",
+            self.repository, self.stars, self.pulls
+        )
+    }
+}
+
+// Limite do README exibido, em caracteres
+const README_MAX_CHARS: usize = 8000;
+
+// Repositório do Hub de uma referência de imagem ("nginx:1.25" -> "library/nginx");
+// None para imagens de outros registries (primeiro segmento com '.', ':' ou localhost)
+pub fn hub_repository(image: &str) -> Option<String> {
+    let image = image.trim();
+    let name = image.split('@').next().unwrap_or(image);
+    let name = match name.rsplit_once(':') {
+        Some((name, tag)) if !tag.contains('/') => name,
+        _ => name,
+    };
+    if name.is_empty() {
+        return None;
+    }
+    let mut parts: Vec<&str> = name.split('/').collect();
+    if parts.len() > 1 {
+        let first = parts[0];
+        if first.contains('.') || first.contains(':') || first == "localhost" {
+            if first != "docker.io" && first != "registry-1.docker.io" {
+                return None;
+            }
+            parts.remove(0);
+        }
+    }
+    match parts.as_slice() {
+        [repo] => Some(format!("library/{}", repo)),
+        [namespace, repo] => Some(format!("{}/{}", namespace, repo)),
+        _ => None,
+    }
+}
+
+// Normaliza o mirror digitado ("https://mirror.local:5000/" -> "mirror.local:5000");
+// o daemon resolve o esquema, e referências de imagem não aceitam caminho no host
+pub fn parse_mirror(mirror: &str) -> Result<String> {
+    let mirror = mirror.trim();
+    let host = mirror
+        .split_once("://")
+        .map(|(_, rest)| rest)
+        .unwrap_or(mirror)
+        .trim_end_matches('/');
+    if host.is_empty() || host.contains('/') || host.contains(char::is_whitespace) {
+        return Err(anyhow::anyhow!(
+            "Mirror inválido: '{}'. Use host[:porta], ex: mirror.empresa.local:5000",
+            mirror
+        ));
+    }
+    Ok(host.to_string())
+}
+
+// Referência equivalente no mirror para imagens do Hub ("nginx:1.25" ->
+// "mirror.local:5000/library/nginx:1.25"). None para outros registries, que o mirror
+// não espelha, e para referências por digest, que não podem ser retaggeadas
+pub fn mirrored_image(image: &str, mirror: &str) -> Option<String> {
+    let image = image.trim();
+    if image.contains('@') {
+        return None;
+    }
+    let repository = hub_repository(image)?;
+    let tag = match image.rsplit_once(':') {
+        Some((_, tag)) if !tag.contains('/') => tag,
+        _ => "latest",
+    };
+    let host = parse_mirror(mirror).ok()?;
+    Some(format!("{}/{}:{}", host, repository, tag))
+}
+
+// Busca a descrição do repositório na API pública do Hub (não consome cota de pulls)
+pub fn fetch_hub_description(
+    image: &str,
+    proxy: Option<ProxySettings>,
+) -> Result<HubDescription> {
+    let repository = hub_repository(image)
+        .ok_or_else(|| anyhow::anyhow!("'{}' não é uma imagem do Docker Hub", image.trim()))?;
+
+    let output = Command::new("curl")
+        .args(proxy.map(|proxy| proxy.curl_args()).unwrap_or_default())
+        .args(["-fsS", "--max-time", "10"])
+        .arg(format!(
+            "https://hub.docker.com/v2/repositories/{}/",
+            repository
+        ))
+        .output()
+        .context("Failed to execute curl")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if stderr.contains("404") {
+            return Err(anyhow::anyhow!(
+                "Repositório {} não encontrado no Docker Hub",
+                repository
+            ));
+        }
+        return Err(anyhow::anyhow!(
+            "Falha ao consultar o Docker Hub: {}",
+            stderr.trim()
+        ));
+    }
+
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).context("Resposta inválida do Docker Hub")?;
+    let full_description = json["full_description"].as_str().unwrap_or_default();
+    let mut readme = markdown_to_text(full_description);
+    if readme.chars().count() > README_MAX_CHARS {
+        readme = readme.chars().take(README_MAX_CHARS).collect();
+        readme.push_str("\n…");
+    }
+
+    Ok(HubDescription {
+        repository,
+        summary: json["description"].as_str().unwrap_or_default().to_string(),
+        readme,
+        env_vars: mentioned_env_vars(full_description),
+        ports: mentioned_ports(full_description),
+        stars: json["star_count"].as_u64().unwrap_or(0),
+        pulls: json["pull_count"].as_u64().unwrap_or(0),
+    })
+}
+
+// Markdown em texto legível: sem imagens, HTML, marcações de título e cercas de código
+fn markdown_to_text(markdown: &str) -> String {
+    let image = Regex::new(r"!\[[^\]]*\]\([^)]*\)").unwrap();
+    let link = Regex::new(r"\[([^\]]+)\]\([^)]*\)").unwrap();
+    let html = Regex::new(r"<[^>]+>").unwrap();
+
+    let mut lines = Vec::new();
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            continue;
+        }
+        let line = image.replace_all(line, "");
+        let line = link.replace_all(&line, "$1");
+        let line = html.replace_all(&line, "");
+        let line = line.trim_start_matches('#').trim_start().replace("**", "");
+        // Uma linha em branco entre parágrafos
+        if line.trim().is_empty() && lines.last().is_none_or(|last: &String| last.is_empty()) {
+            continue;
+        }
+        lines.push(line.trim_end().to_string());
+    }
+    lines.join("\n").trim().to_string()
+}
+
+// Variáveis citadas como `-e NOME=`, `--env NOME=` ou `NOME_COMPOSTO` entre crases
+fn mentioned_env_vars(markdown: &str) -> Vec<String> {
+    let flag = Regex::new(r#"(?:-e|--env)\s+"?([A-Z][A-Z0-9_]+)="#).unwrap();
+    let quoted = Regex::new(r"`([A-Z][A-Z0-9]*_[A-Z0-9_]+)`").unwrap();
+    let mut vars: Vec<String> = flag
+        .captures_iter(markdown)
+        .chain(quoted.captures_iter(markdown))
+        .map(|captures| captures[1].to_string())
+        .collect();
+    vars.sort();
+    vars.dedup();
+    vars.truncate(30);
+    vars
+}
+
+// Portas do container citadas em `-p host:container`, `EXPOSE` ou "porta/tcp"
+fn mentioned_ports(markdown: &str) -> Vec<String> {
+    let publish = Regex::new(r#"(?:-p|--publish)\s+"?(?:[\d.]+:)?\d+:(\d+)(/udp|/tcp)?"#).unwrap();
+    let expose = Regex::new(r"EXPOSE\s+(\d+)(/udp|/tcp)?").unwrap();
+    let protocol = Regex::new(r"\b(\d{2,5})/(tcp|udp)\b").unwrap();
+    let mut ports: Vec<String> = publish
+        .captures_iter(markdown)
+        .chain(expose.captures_iter(markdown))
+        .map(|captures| {
+            format!(
+                "{}{}",
+                &captures[1],
+                captures.get(2).map_or("", |protocol| protocol.as_str())
+            )
+        })
+        .chain(
+            protocol
+                .captures_iter(markdown)
+                .map(|captures| format!("{}/{}", &captures[1], &captures[2])),
+        )
+        .map(|port| port.trim_end_matches("/tcp").to_string())
+        .collect();
+    ports.sort_by_key(|port| {
+        let number = port
+            .split('/')
+            .next()
+            .and_then(|number| number.parse::<u32>().ok())
+            .unwrap_or(u32::MAX);
+        (number, port.clone())
+    });
+    ports.dedup();
+    ports.truncate(20);
+    ports
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rate_limit_headers() {
+        let headers = "HTTP/1.1 200 OK\r\n\
+                       content-type: application/json\r\n\
+                       RateLimit-Limit: 100;w=21600\r\n\
+                       ratelimit-remaining: 76;w=21600\r\n\
+                       docker-ratelimit-source: 203.0.113.7\r\n";
+        let status = parse_rate_limit_headers(headers);
+        assert_eq!(status.limit, Some(100));
+        assert_eq!(status.remaining, Some(76));
+        assert_eq!(status.window_secs, 21600);
+        assert!(!status.is_exhausted());
+        assert_eq!(status.summary(), "Cota Docker Hub: 76/100 pulls (janela de 6h)");
+    }
+
+    #[test]
+    fn test_parse_rate_limit_headers_exhausted_with_custom_window() {
+        let status =
+            parse_rate_limit_headers("ratelimit-limit: 200;w=3600\nratelimit-remaining: 0;w=3600\n");
+        assert_eq!(status.remaining, Some(0));
+        assert_eq!(status.window_secs, 3600);
+        assert!(status.is_exhausted());
+        assert_eq!(status.retry_at(), status.checked_at + chrono::Duration::seconds(3600));
+    }
+
+    #[test]
+    fn test_parse_rate_limit_headers_without_limit() {
+        // Contas pagas não recebem os headers de cota
+        let status = parse_rate_limit_headers("HTTP/1.1 200 OK\ncontent-length: 0\n");
+        assert_eq!(status.limit, None);
+        assert_eq!(status.remaining, None);
+        assert_eq!(status.window_secs, 6 * 3600);
+        assert!(!status.is_exhausted());
+        assert_eq!(status.summary(), "Cota Docker Hub: sem limite informado");
+
+        let status = parse_rate_limit_headers("ratelimit-limit: muitos;w=abc\n");
+        assert_eq!(status.limit, None);
+        assert_eq!(status.window_secs, 6 * 3600);
+    }
+
+    #[test]
+    fn test_is_rate_limit_error() {
+        assert!(is_rate_limit_error(
+            "toomanyrequests: You have reached your pull rate limit"
+        ));
+        assert!(is_rate_limit_error("429 Too Many Requests"));
+        assert!(!is_rate_limit_error("manifest unknown"));
+    }
+
+    #[test]
+    fn test_hub_rate_limit_args() {
+        let (token, headers) = hub_rate_limit_args(None);
+        assert_eq!(token.first().map(String::as_str), Some("curl"));
+        assert!(token.contains(&"--config".to_string()));
+        assert_eq!(token.last().map(String::as_str), Some(HUB_AUTH_URL));
+        // O token vai pelo stdin, não pela linha de comando
+        assert!(headers.windows(2).any(|pair| pair == ["-H", "@-"]));
+        assert_eq!(headers.last().map(String::as_str), Some(HUB_RATE_LIMIT_URL));
+
+        let proxy = ProxySettings {
+            url: "http://proxy:3128".to_string(),
+            no_proxy: String::new(),
+        };
+        let (token, headers) = hub_rate_limit_args(Some(&proxy));
+        assert_eq!(&token[1..3], ["-x", "http://proxy:3128"]);
+        assert_eq!(&headers[1..3], ["-x", "http://proxy:3128"]);
+    }
+}
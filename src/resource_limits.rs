@@ -0,0 +1,237 @@
+// Limites de recursos definidos na criação do container (seção "Avançado" do modal) e no
+// diálogo de recursos: fixação em CPUs e nós NUMA (cpuset-cpus / cpuset-mems) e
+// throttling de I/O de bloco (peso e taxa por dispositivo)
+use anyhow::{Result, bail};
+
+// Valida uma lista no formato do kernel ("0-3,8,10-11"); vazio = sem restrição
+pub fn parse_cpuset(value: &str, field: &str) -> Result<Option<String>> {
+    let value: String = value.chars().filter(|c| !c.is_whitespace()).collect();
+    if value.is_empty() {
+        return Ok(None);
+    }
+    for part in value.split(',') {
+        let (start, end) = part.split_once('-').unwrap_or((part, part));
+        match (start.parse::<u32>(), end.parse::<u32>()) {
+            (Ok(start), Ok(end)) if start <= end => {}
+            _ => bail!(
+                "{} inválido: '{}'. Use números e intervalos, ex: 0-3,8",
+                field,
+                part
+            ),
+        }
+    }
+    Ok(Some(value))
+}
+
+// Taxa máxima de leitura ou escrita em um dispositivo de bloco
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceRate {
+    pub path: String,
+    pub bytes_per_sec: u64,
+}
+
+// Throttling de I/O de bloco de um container
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IoLimits {
+    // Peso relativo entre containers (10 a 1000); None = padrão do daemon
+    pub blkio_weight: Option<u16>,
+    pub device_read_bps: Vec<DeviceRate>,
+    pub device_write_bps: Vec<DeviceRate>,
+}
+
+impl IoLimits {
+    // Campos como digitados no formulário; vazios = sem limite
+    pub fn parse(weight: &str, read_bps: &str, write_bps: &str) -> Result<Self> {
+        Ok(Self {
+            blkio_weight: parse_blkio_weight(weight)?,
+            device_read_bps: parse_device_rates(read_bps, "Leitura por dispositivo")?,
+            device_write_bps: parse_device_rates(write_bps, "Escrita por dispositivo")?,
+        })
+    }
+
+    // Mesmos limites, sem depender da ordem dos dispositivos
+    pub fn same_as(&self, other: &IoLimits) -> bool {
+        self.blkio_weight == other.blkio_weight
+            && same_rates(&self.device_read_bps, &other.device_read_bps)
+            && same_rates(&self.device_write_bps, &other.device_write_bps)
+    }
+
+    // O daemon só aplica o peso a um container existente: taxas por dispositivo diferentes
+    // e a remoção do peso só valem recriando o container
+    pub fn needs_recreate(&self, current: &IoLimits) -> bool {
+        (self.blkio_weight.is_none() && current.blkio_weight.is_some())
+            || !same_rates(&self.device_read_bps, &current.device_read_bps)
+            || !same_rates(&self.device_write_bps, &current.device_write_bps)
+    }
+}
+
+// Cada dispositivo aparece uma vez por lista (parse_device_rates garante)
+fn same_rates(a: &[DeviceRate], b: &[DeviceRate]) -> bool {
+    a.len() == b.len() && a.iter().all(|rate| b.contains(rate))
+}
+
+pub fn parse_blkio_weight(value: &str) -> Result<Option<u16>> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+    match value.parse::<u16>() {
+        Ok(weight) if (10..=1000).contains(&weight) => Ok(Some(weight)),
+        _ => bail!(
+            "Peso de I/O inválido: '{}'. Use um valor entre 10 e 1000",
+            value
+        ),
+    }
+}
+
+// Lista "dispositivo:taxa" separada por vírgulas, ex: "/dev/sda:10mb,/dev/sdb:512kb"
+pub fn parse_device_rates(value: &str, field: &str) -> Result<Vec<DeviceRate>> {
+    let mut rates: Vec<DeviceRate> = Vec::new();
+    for part in value
+        .split(',')
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+    {
+        let Some((path, rate)) = part.rsplit_once(':') else {
+            bail!(
+                "{} inválido: '{}'. Use dispositivo:taxa, ex: /dev/sda:10mb",
+                field,
+                part
+            );
+        };
+        let path = path.trim();
+        if !path.starts_with("/dev/") {
+            bail!("{}: '{}' não é um dispositivo em /dev", field, path);
+        }
+        let Some(bytes_per_sec) = parse_rate(rate).filter(|rate| *rate > 0) else {
+            bail!(
+                "{}: taxa inválida '{}'. Use bytes ou kb/mb/gb, ex: 10mb",
+                field,
+                rate.trim()
+            );
+        };
+        if rates.iter().any(|rate| rate.path == path) {
+            bail!("{}: '{}' aparece mais de uma vez", field, path);
+        }
+        rates.push(DeviceRate {
+            path: path.to_string(),
+            bytes_per_sec,
+        });
+    }
+    Ok(rates)
+}
+
+// Texto para o formulário, no mesmo formato aceito por parse_device_rates
+pub fn format_device_rates(rates: &[DeviceRate]) -> String {
+    rates
+        .iter()
+        .map(|rate| format!("{}:{}", rate.path, format_rate(rate.bytes_per_sec)))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+// Unidades binárias, como o docker run (10mb = 10 * 1024 * 1024)
+const RATE_UNITS: [(&str, u64); 4] = [
+    ("gb", 1024 * 1024 * 1024),
+    ("mb", 1024 * 1024),
+    ("kb", 1024),
+    ("b", 1),
+];
+
+fn parse_rate(value: &str) -> Option<u64> {
+    let value = value.trim().to_lowercase();
+    let digits = value.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+    let unit = &value[digits.len()..];
+    let multiplier = match unit {
+        "" => 1,
+        "k" => 1024,
+        "m" => 1024 * 1024,
+        "g" => 1024 * 1024 * 1024,
+        _ => RATE_UNITS
+            .iter()
+            .find(|(suffix, _)| *suffix == unit)
+            .map(|(_, multiplier)| *multiplier)?,
+    };
+    digits.trim().parse::<u64>().ok()?.checked_mul(multiplier)
+}
+
+fn format_rate(bytes_per_sec: u64) -> String {
+    RATE_UNITS
+        .iter()
+        .find(|(_, multiplier)| bytes_per_sec.is_multiple_of(*multiplier))
+        .map(|(suffix, multiplier)| format!("{}{}", bytes_per_sec / multiplier, suffix))
+        .unwrap_or_else(|| bytes_per_sec.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rate(path: &str, bytes_per_sec: u64) -> DeviceRate {
+        DeviceRate {
+            path: path.to_string(),
+            bytes_per_sec,
+        }
+    }
+
+    #[test]
+    fn test_parse_rate_units() {
+        assert_eq!(parse_rate("512"), Some(512));
+        assert_eq!(parse_rate("10b"), Some(10));
+        assert_eq!(parse_rate("512kb"), Some(512 * 1024));
+        assert_eq!(parse_rate("10M"), Some(10 * 1024 * 1024));
+        assert_eq!(parse_rate(" 1gb "), Some(1024 * 1024 * 1024));
+        assert_eq!(parse_rate("10tb"), None);
+        assert_eq!(parse_rate("mb"), None);
+        assert_eq!(parse_rate("99999999999999gb"), None);
+    }
+
+    #[test]
+    fn test_parse_device_rates() {
+        let rates = parse_device_rates("/dev/sda:10mb, /dev/sdb:512kb,", "Leitura").unwrap();
+        assert_eq!(rates, vec![rate("/dev/sda", 10 * 1024 * 1024), rate("/dev/sdb", 512 * 1024)]);
+        assert!(parse_device_rates("", "Leitura").unwrap().is_empty());
+        assert!(parse_device_rates("/dev/sda", "Leitura").is_err());
+        assert!(parse_device_rates("sda:10mb", "Leitura").is_err());
+        assert!(parse_device_rates("/dev/sda:0", "Leitura").is_err());
+        assert!(parse_device_rates("/dev/sda:1mb,/dev/sda:2mb", "Leitura").is_err());
+    }
+
+    #[test]
+    fn test_format_device_rates_round_trip() {
+        let rates = vec![rate("/dev/sda", 10 * 1024 * 1024), rate("/dev/sdb", 1500)];
+        let text = format_device_rates(&rates);
+        assert_eq!(text, "/dev/sda:10mb,/dev/sdb:1500b");
+        assert_eq!(parse_device_rates(&text, "Leitura").unwrap(), rates);
+    }
+
+    #[test]
+    fn test_parse_io_limits() {
+        let limits = IoLimits::parse(" 500 ", "/dev/sda:1mb", "").unwrap();
+        assert_eq!(limits.blkio_weight, Some(500));
+        assert_eq!(limits.device_read_bps, vec![rate("/dev/sda", 1024 * 1024)]);
+        assert!(limits.device_write_bps.is_empty());
+
+        assert_eq!(IoLimits::parse("", "", "").unwrap(), IoLimits::default());
+        assert!(IoLimits::parse("5", "", "").is_err());
+        assert!(IoLimits::parse("1001", "", "").is_err());
+    }
+
+    #[test]
+    fn test_needs_recreate() {
+        let current = IoLimits::parse("500", "/dev/sda:1mb,/dev/sdb:2mb", "").unwrap();
+
+        let weight_only = IoLimits::parse("800", "/dev/sdb:2mb,/dev/sda:1mb", "").unwrap();
+        assert!(!weight_only.needs_recreate(&current));
+        assert!(!weight_only.same_as(&current));
+
+        let cleared_weight = IoLimits::parse("", "/dev/sda:1mb,/dev/sdb:2mb", "").unwrap();
+        assert!(cleared_weight.needs_recreate(&current));
+
+        let cleared_rates = IoLimits::parse("500", "", "").unwrap();
+        assert!(cleared_rates.needs_recreate(&current));
+
+        let reordered = IoLimits::parse("500", "/dev/sdb:2mb,/dev/sda:1mb", "").unwrap();
+        assert!(reordered.same_as(&current));
+    }
+}
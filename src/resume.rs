@@ -0,0 +1,27 @@
+// Detecção de retomada após suspensão do sistema
+use std::time::{Duration, SystemTime};
+
+// Intervalo entre verificações do relógio
+const CHECK_INTERVAL: Duration = Duration::from_secs(5);
+// Atraso acima do esperado que indica suspensão (e não só carga alta)
+const RESUME_THRESHOLD: Duration = Duration::from_secs(30);
+
+// Enquanto o sistema está suspenso nenhum timer dispara, então o relógio de
+// parede avança bem mais que o intervalo entre duas verificações.
+// on_resume recebe o tempo aproximado em que o sistema ficou suspenso.
+pub fn spawn_watcher(on_resume: impl Fn(Duration) + Send + 'static) {
+    tokio::spawn(async move {
+        let mut last_check = SystemTime::now();
+        loop {
+            tokio::time::sleep(CHECK_INTERVAL).await;
+
+            let now = SystemTime::now();
+            let elapsed = now.duration_since(last_check).unwrap_or_default();
+            last_check = now;
+
+            if elapsed > CHECK_INTERVAL + RESUME_THRESHOLD {
+                on_resume(elapsed - CHECK_INTERVAL);
+            }
+        }
+    });
+}
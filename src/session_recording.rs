@@ -0,0 +1,467 @@
+// Gravação de sessão para postmortems: stats (a cada ~1s), eventos e logs dos containers
+// escolhidos, em um arquivo JSON Lines com um registro por linha e o instante da coleta
+use anyhow::{Context, Result, bail};
+use bollard::Docker;
+use bollard::models::ContainerStatsResponse;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWriteExt, BufReader};
+use tokio::sync::mpsc::{self, UnboundedSender};
+use tokio::task::JoinHandle;
+
+pub const FORMAT_VERSION: u32 = 1;
+
+// Duração máxima de uma gravação
+const MAX_DURATION: Duration = Duration::from_secs(24 * 60 * 60);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum Record {
+    Header(SessionHeader),
+    Stats(StatsSample),
+    Event(SessionEvent),
+    Log(LogLine),
+    End(SessionEnd),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionHeader {
+    pub version: u32,
+    pub server: String,
+    pub containers: Vec<String>,
+    pub started_at: DateTime<Utc>,
+    pub planned_secs: u64,
+}
+
+// Amostra bruta do stats da API; rede e disco são acumulados desde o início do container
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatsSample {
+    pub at: DateTime<Utc>,
+    pub container: String,
+    pub cpu_percent: f64,
+    pub memory_usage: u64,
+    pub memory_limit: u64,
+    pub network_rx: u64,
+    pub network_tx: u64,
+    pub block_read: u64,
+    pub block_write: u64,
+    pub pids: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionEvent {
+    pub at: DateTime<Utc>,
+    pub container: String,
+    // Ex: "die", "oom", "health_status: unhealthy"
+    pub action: String,
+    // Atributos relevantes, ex: "exitCode=137"
+    pub detail: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogLine {
+    pub at: DateTime<Utc>,
+    pub container: String,
+    pub stderr: bool,
+    pub line: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionEnd {
+    pub at: DateTime<Utc>,
+    pub records: u64,
+    pub stopped_early: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct RecordingRequest {
+    pub containers: Vec<String>,
+    pub duration: Duration,
+    pub path: String,
+}
+
+// Fontes obtidas antes para não segurar o lock do gerenciador durante a gravação
+pub struct RecordingSources {
+    pub server: String,
+    pub client: Docker,
+    pub events: Command,
+    // Container e comando `docker logs --follow`
+    pub logs: Vec<(String, Command)>,
+}
+
+pub struct RecordingProgress {
+    pub elapsed: Duration,
+    pub records: u64,
+}
+
+pub struct RecordingReport {
+    pub records: u64,
+    pub elapsed: Duration,
+    pub stopped_early: bool,
+}
+
+impl RecordingRequest {
+    pub fn validate(&self) -> Result<()> {
+        if self.containers.is_empty() {
+            bail!("Selecione ao menos um container");
+        }
+        if self.duration.is_zero() || self.duration > MAX_DURATION {
+            bail!("A duração deve ficar entre 1 minuto e 24 horas");
+        }
+        let path = Path::new(self.path.trim());
+        if path.as_os_str().is_empty() {
+            bail!("Informe o caminho do arquivo");
+        }
+        if path.exists() {
+            bail!("O arquivo {} já existe", path.display());
+        }
+        Ok(())
+    }
+}
+
+// Sugestão de destino: ~/docker-ui-sessions/sessao-AAAAMMDD-HHMMSS.jsonl
+pub fn default_path() -> String {
+    let name = format!(
+        "sessao-{}.jsonl",
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    );
+    std::env::var_os("HOME")
+        .map(|home| {
+            Path::new(&home)
+                .join("docker-ui-sessions")
+                .join(&name)
+                .display()
+                .to_string()
+        })
+        .unwrap_or(name)
+}
+
+// Grava até o fim da duração, até `stop` ser marcado ou até todas as fontes encerrarem
+pub async fn record(
+    sources: RecordingSources,
+    request: &RecordingRequest,
+    stop: Arc<AtomicBool>,
+    mut on_progress: impl FnMut(RecordingProgress),
+) -> Result<RecordingReport> {
+    request.validate()?;
+    let path = Path::new(request.path.trim());
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        tokio::fs::create_dir_all(parent)
+            .await
+            .context("Falha ao criar diretório de destino")?;
+    }
+    let file = tokio::fs::File::create(path)
+        .await
+        .context("Falha ao criar o arquivo da sessão")?;
+    let mut writer = tokio::io::BufWriter::new(file);
+
+    write_record(
+        &mut writer,
+        &Record::Header(SessionHeader {
+            version: FORMAT_VERSION,
+            server: sources.server,
+            containers: request.containers.clone(),
+            started_at: Utc::now(),
+            planned_secs: request.duration.as_secs(),
+        }),
+    )
+    .await?;
+
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    let mut tasks: Vec<JoinHandle<()>> = request
+        .containers
+        .iter()
+        .map(|container| {
+            tokio::spawn(stream_stats(
+                sources.client.clone(),
+                container.clone(),
+                sender.clone(),
+            ))
+        })
+        .collect();
+
+    // Processos encerrados ao sair (kill_on_drop)
+    let mut children = Vec::new();
+    let mut events = spawn(sources.events, false)?;
+    if let Some(stdout) = events.stdout.take() {
+        tasks.push(forward_lines(stdout, sender.clone(), |line| {
+            parse_event(line).map(Record::Event)
+        }));
+    }
+    children.push(events);
+    for (container, command) in sources.logs {
+        let mut logs = spawn(command, true)?;
+        if let Some(stdout) = logs.stdout.take() {
+            let container = container.clone();
+            tasks.push(forward_lines(stdout, sender.clone(), move |line| {
+                Some(parse_log(&container, line, false))
+            }));
+        }
+        if let Some(stderr) = logs.stderr.take() {
+            tasks.push(forward_lines(stderr, sender.clone(), move |line| {
+                Some(parse_log(&container, line, true))
+            }));
+        }
+        children.push(logs);
+    }
+    drop(sender);
+
+    let started = tokio::time::Instant::now();
+    let deadline = started + request.duration;
+    let mut ticker = tokio::time::interval(Duration::from_secs(1));
+    let mut records = 0u64;
+    let mut stopped_early = false;
+    let result: Result<()> = async {
+        loop {
+            tokio::select! {
+                record = receiver.recv() => match record {
+                    Some(record) => {
+                        write_record(&mut writer, &record).await?;
+                        records += 1;
+                    }
+                    // Containers removidos e streams encerrados
+                    None => {
+                        stopped_early = true;
+                        break;
+                    }
+                },
+                _ = ticker.tick() => {
+                    on_progress(RecordingProgress {
+                        elapsed: started.elapsed(),
+                        records,
+                    });
+                    if stop.load(Ordering::Relaxed) {
+                        stopped_early = true;
+                        break;
+                    }
+                }
+                _ = tokio::time::sleep_until(deadline) => break,
+            }
+        }
+        Ok(())
+    }
+    .await;
+
+    for task in &tasks {
+        task.abort();
+    }
+    drop(children);
+    result?;
+
+    write_record(
+        &mut writer,
+        &Record::End(SessionEnd {
+            at: Utc::now(),
+            records,
+            stopped_early,
+        }),
+    )
+    .await?;
+    writer
+        .flush()
+        .await
+        .context("Falha ao gravar o arquivo da sessão")?;
+
+    Ok(RecordingReport {
+        records,
+        elapsed: started.elapsed(),
+        stopped_early,
+    })
+}
+
+async fn write_record(
+    writer: &mut tokio::io::BufWriter<tokio::fs::File>,
+    record: &Record,
+) -> Result<()> {
+    let mut line = serde_json::to_vec(record)?;
+    line.push(b'\n');
+    writer
+        .write_all(&line)
+        .await
+        .context("Falha ao gravar o arquivo da sessão")
+}
+
+fn spawn(command: Command, with_stderr: bool) -> Result<tokio::process::Child> {
+    let mut command = tokio::process::Command::from(command);
+    command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(if with_stderr {
+            Stdio::piped()
+        } else {
+            Stdio::null()
+        })
+        .kill_on_drop(true);
+    command
+        .spawn()
+        .context("Falha ao iniciar a coleta da sessão")
+}
+
+fn forward_lines<R>(
+    reader: R,
+    sender: UnboundedSender<Record>,
+    parse: impl Fn(&str) -> Option<Record> + Send + 'static,
+) -> JoinHandle<()>
+where
+    R: AsyncRead + Unpin + Send + 'static,
+{
+    tokio::spawn(async move {
+        let mut lines = BufReader::new(reader).lines();
+        while let Ok(Some(line)) = lines.next_line().await {
+            let Some(record) = parse(&line) else {
+                continue;
+            };
+            if sender.send(record).is_err() {
+                break;
+            }
+        }
+    })
+}
+
+async fn stream_stats(client: Docker, container: String, sender: UnboundedSender<Record>) {
+    use bollard::query_parameters::StatsOptions;
+    use futures_util::StreamExt;
+
+    let mut stream = client.stats(
+        &container,
+        Some(StatsOptions {
+            stream: true,
+            one_shot: false,
+        }),
+    );
+    while let Some(Ok(stats)) = stream.next().await {
+        let sample = StatsSample::from_stats(&container, &stats);
+        if sender.send(Record::Stats(sample)).is_err() {
+            break;
+        }
+    }
+}
+
+impl StatsSample {
+    fn from_stats(container: &str, stats: &ContainerStatsResponse) -> Self {
+        // CPU pelo delta em relação à leitura anterior enviada pelo próprio daemon
+        let cpu_percent = match (&stats.cpu_stats, &stats.precpu_stats) {
+            (Some(cpu), Some(precpu)) => {
+                let total = cpu.cpu_usage.as_ref().and_then(|usage| usage.total_usage);
+                let previous = precpu
+                    .cpu_usage
+                    .as_ref()
+                    .and_then(|usage| usage.total_usage);
+                let cpu_delta = total.unwrap_or(0).saturating_sub(previous.unwrap_or(0));
+                let system_delta = cpu
+                    .system_cpu_usage
+                    .unwrap_or(0)
+                    .saturating_sub(precpu.system_cpu_usage.unwrap_or(0));
+                let online = cpu.online_cpus.unwrap_or(1).max(1) as f64;
+                if system_delta > 0 {
+                    cpu_delta as f64 / system_delta as f64 * online * 100.0
+                } else {
+                    0.0
+                }
+            }
+            _ => 0.0,
+        };
+
+        let (network_rx, network_tx) = stats
+            .networks
+            .iter()
+            .flat_map(|networks| networks.values())
+            .fold((0, 0), |(rx, tx), network| {
+                (
+                    rx + network.rx_bytes.unwrap_or(0),
+                    tx + network.tx_bytes.unwrap_or(0),
+                )
+            });
+
+        let (block_read, block_write) = stats
+            .blkio_stats
+            .iter()
+            .filter_map(|blkio| blkio.io_service_bytes_recursive.as_ref())
+            .flatten()
+            .fold((0, 0), |(read, write), entry| {
+                let value = entry.value.unwrap_or(0);
+                match entry.op.as_deref() {
+                    Some(op) if op.eq_ignore_ascii_case("read") => (read + value, write),
+                    Some(op) if op.eq_ignore_ascii_case("write") => (read, write + value),
+                    _ => (read, write),
+                }
+            });
+
+        let memory = stats.memory_stats.as_ref();
+        Self {
+            at: Utc::now(),
+            container: container.to_string(),
+            cpu_percent,
+            memory_usage: memory.and_then(|memory| memory.usage).unwrap_or(0),
+            memory_limit: memory.and_then(|memory| memory.limit).unwrap_or(0),
+            network_rx,
+            network_tx,
+            block_read,
+            block_write,
+            pids: stats
+                .pids_stats
+                .as_ref()
+                .and_then(|pids| pids.current)
+                .unwrap_or(0),
+        }
+    }
+}
+
+// Linha do `docker events --format '{{json .}}'` (também usada no feed de eventos do dashboard)
+pub fn parse_event(line: &str) -> Option<SessionEvent> {
+    let value: Value = serde_json::from_str(line).ok()?;
+    let action = value
+        .get("Action")
+        .or_else(|| value.get("status"))
+        .and_then(Value::as_str)?
+        .to_string();
+    let attributes = value.pointer("/Actor/Attributes");
+    let attribute = |key: &str| {
+        attributes
+            .and_then(|attributes| attributes.get(key))
+            .and_then(Value::as_str)
+    };
+    let detail = ["exitCode", "signal", "image"]
+        .iter()
+        .filter_map(|key| attribute(key).map(|value| format!("{}={}", key, value)))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let at = value
+        .get("timeNano")
+        .and_then(Value::as_i64)
+        .map(DateTime::from_timestamp_nanos)
+        .unwrap_or_else(Utc::now);
+
+    Some(SessionEvent {
+        at,
+        container: attribute("name").unwrap_or_default().to_string(),
+        action,
+        detail,
+    })
+}
+
+// Linha do `docker logs --timestamps`: "2024-05-01T12:00:00.123456789Z mensagem"
+fn parse_log(container: &str, line: &str, stderr: bool) -> Record {
+    let parsed = line.split_once(' ').and_then(|(time, message)| {
+        DateTime::parse_from_rfc3339(time)
+            .ok()
+            .map(|time| (time.with_timezone(&Utc), message))
+    });
+    let (at, line) = parsed.unwrap_or_else(|| (Utc::now(), line));
+    Record::Log(LogLine {
+        at,
+        container: container.to_string(),
+        stderr,
+        line: line.to_string(),
+    })
+}
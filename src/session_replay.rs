@@ -0,0 +1,213 @@
+// Reprodução de sessões gravadas: lê o arquivo JSON Lines da gravação e monta, para uma
+// posição da linha do tempo, os gráficos, eventos e logs daquele instante, sem conexão
+use crate::chart::ChartPoint;
+use crate::format;
+use crate::session_recording::{
+    FORMAT_VERSION, LogLine, Record, SessionEnd, SessionEvent, SessionHeader, StatsSample,
+};
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, Local, Utc};
+
+// Janela dos gráficos, em segundos antes do cursor
+const CHART_WINDOW_SECS: f64 = 60.0;
+// Linhas de log mostradas até o cursor
+const MAX_LOG_LINES: usize = 200;
+
+pub struct RecordedSession {
+    pub header: SessionHeader,
+    pub stats: Vec<StatsSample>,
+    pub events: Vec<SessionEvent>,
+    pub logs: Vec<LogLine>,
+    pub end: Option<SessionEnd>,
+    // Linhas ilegíveis (ex: gravação interrompida no meio de uma linha)
+    pub skipped: usize,
+}
+
+// Estado da sessão na posição do cursor, para um container
+pub struct ReplayFrame {
+    pub cpu: Vec<ChartPoint>,
+    pub memory: Vec<ChartPoint>,
+    pub cpu_label: String,
+    pub memory_label: String,
+    // Mais recentes primeiro
+    pub events: Vec<SessionEvent>,
+    pub logs: Vec<LogLine>,
+}
+
+pub async fn load(path: &str) -> Result<RecordedSession> {
+    let content = tokio::fs::read_to_string(path.trim())
+        .await
+        .with_context(|| format!("Falha ao ler {}", path.trim()))?;
+    parse(&content)
+}
+
+fn parse(content: &str) -> Result<RecordedSession> {
+    let mut lines = content.lines().filter(|line| !line.trim().is_empty());
+    let header = match lines.next().map(serde_json::from_str::<Record>) {
+        Some(Ok(Record::Header(header))) => header,
+        _ => bail!("O arquivo não é uma sessão gravada pelo Docker UI"),
+    };
+    if header.version > FORMAT_VERSION {
+        bail!(
+            "Sessão gravada em um formato mais novo (versão {})",
+            header.version
+        );
+    }
+
+    let mut session = RecordedSession {
+        header,
+        stats: Vec::new(),
+        events: Vec::new(),
+        logs: Vec::new(),
+        end: None,
+        skipped: 0,
+    };
+    for line in lines {
+        match serde_json::from_str::<Record>(line) {
+            Ok(Record::Stats(sample)) => session.stats.push(sample),
+            Ok(Record::Event(event)) => session.events.push(event),
+            Ok(Record::Log(log)) => session.logs.push(log),
+            Ok(Record::End(end)) => session.end = Some(end),
+            Ok(Record::Header(_)) | Err(_) => session.skipped += 1,
+        }
+    }
+    // Registros chegam de fontes paralelas; a linha do tempo precisa deles em ordem
+    session.stats.sort_by_key(|sample| sample.at);
+    session.events.sort_by_key(|event| event.at);
+    session.logs.sort_by_key(|log| log.at);
+    Ok(session)
+}
+
+impl RecordedSession {
+    // Segundos do início da gravação até o fim (ou até o último registro, se interrompida)
+    pub fn duration_secs(&self) -> f64 {
+        let last = self.end.as_ref().map(|end| end.at).into_iter().chain(
+            [
+                self.stats.last().map(|sample| sample.at),
+                self.events.last().map(|event| event.at),
+                self.logs.last().map(|log| log.at),
+            ]
+            .into_iter()
+            .flatten(),
+        );
+        last.map(|at| self.offset(at)).fold(0.0, f64::max).max(1.0)
+    }
+
+    // Ex: "servidor-prod · 16/10/2026 14:00 · 3 containers"
+    pub fn summary(&self) -> String {
+        let mut summary = format!(
+            "{} · {} · {} containers",
+            self.header.server,
+            self.header
+                .started_at
+                .with_timezone(&Local)
+                .format("%d/%m/%Y %H:%M:%S"),
+            self.header.containers.len()
+        );
+        if self.end.as_ref().is_some_and(|end| end.stopped_early) {
+            summary.push_str(" · encerrada antes do prazo");
+        }
+        if self.end.is_none() {
+            summary.push_str(" · gravação incompleta");
+        }
+        if self.skipped > 0 {
+            summary.push_str(&format!(" · {} linhas ignoradas", self.skipped));
+        }
+        summary
+    }
+
+    // Posição (0..1) dos eventos do container, para as marcas da linha do tempo
+    pub fn event_markers(&self, container: &str) -> Vec<f32> {
+        let duration = self.duration_secs();
+        self.events
+            .iter()
+            .filter(|event| event.container == container)
+            .map(|event| (self.offset(event.at) / duration).clamp(0.0, 1.0) as f32)
+            .collect()
+    }
+
+    pub fn frame(&self, container: &str, cursor_secs: f64) -> ReplayFrame {
+        let samples: Vec<&StatsSample> = self
+            .stats
+            .iter()
+            .filter(|sample| sample.container == container)
+            .filter(|sample| {
+                let offset = self.offset(sample.at);
+                offset <= cursor_secs && offset > cursor_secs - CHART_WINDOW_SECS
+            })
+            .collect();
+
+        let cpu = samples
+            .iter()
+            .map(|sample| ChartPoint {
+                time: clock(self.offset(sample.at)),
+                value: sample.cpu_percent as f32,
+            })
+            .collect();
+        let memory = samples
+            .iter()
+            .map(|sample| ChartPoint {
+                time: clock(self.offset(sample.at)),
+                value: memory_percent(sample) as f32,
+            })
+            .collect();
+        let (cpu_label, memory_label) = match samples.last() {
+            Some(sample) => (
+                format!("{}%", format::number(sample.cpu_percent, 1)),
+                format::memory(
+                    memory_percent(sample),
+                    sample.memory_usage,
+                    sample.memory_limit,
+                ),
+            ),
+            None => ("--".to_string(), "--".to_string()),
+        };
+
+        let events = self
+            .events
+            .iter()
+            .rev()
+            .filter(|event| event.container == container && self.offset(event.at) <= cursor_secs)
+            .cloned()
+            .collect();
+        let logs = self
+            .logs
+            .iter()
+            .rev()
+            .filter(|log| log.container == container && self.offset(log.at) <= cursor_secs)
+            .take(MAX_LOG_LINES)
+            .cloned()
+            .collect();
+
+        ReplayFrame {
+            cpu,
+            memory,
+            cpu_label,
+            memory_label,
+            events,
+            logs,
+        }
+    }
+
+    // Segundos desde o início da gravação
+    pub fn offset(&self, at: DateTime<Utc>) -> f64 {
+        (at - self.header.started_at).num_milliseconds() as f64 / 1000.0
+    }
+}
+
+// Posição na linha do tempo, ex: "02:15" ou "1:02:15"
+pub fn clock(secs: f64) -> String {
+    let secs = secs.max(0.0) as u64;
+    if secs >= 3600 {
+        format!("{}:{:02}:{:02}", secs / 3600, secs % 3600 / 60, secs % 60)
+    } else {
+        format!("{:02}:{:02}", secs / 60, secs % 60)
+    }
+}
+
+fn memory_percent(sample: &StatsSample) -> f64 {
+    if sample.memory_limit == 0 {
+        return 0.0;
+    }
+    sample.memory_usage as f64 / sample.memory_limit as f64 * 100.0
+}
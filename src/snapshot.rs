@@ -0,0 +1,95 @@
+// Último estado conhecido de cada servidor (~/.config/docker-ui/snapshots/<contexto>.json)
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use crate::config::AppConfig;
+use crate::docker::{ContainerInfo, DockerInfo, DockerSystemUsage, ImageInfo, NetworkInfo, VolumeInfo};
+
+// Intervalo mínimo entre gravações em disco
+const WRITE_INTERVAL: Duration = Duration::from_secs(30);
+
+// Listas e estatísticas da última conexão bem-sucedida
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ServerSnapshot {
+    // Unix timestamp da última atualização
+    pub saved_at: i64,
+    pub info: Option<DockerInfo>,
+    pub usage: Option<DockerSystemUsage>,
+    pub containers: Vec<ContainerInfo>,
+    pub images: Vec<ImageInfo>,
+    pub networks: Vec<NetworkInfo>,
+    pub volumes: Vec<VolumeInfo>,
+}
+
+impl ServerSnapshot {
+    // Ex: "16/10 14:32"
+    pub fn saved_at_label(&self) -> String {
+        chrono::DateTime::from_timestamp(self.saved_at, 0)
+            .map(|time| {
+                time.with_timezone(&chrono::Local)
+                    .format("%d/%m %H:%M")
+                    .to_string()
+            })
+            .unwrap_or_else(|| "data desconhecida".to_string())
+    }
+}
+
+fn snapshot_path(context_name: &str) -> PathBuf {
+    AppConfig::path()
+        .with_file_name("snapshots")
+        .join(format!("{}.json", context_name))
+}
+
+pub fn load(context_name: &str) -> Option<ServerSnapshot> {
+    let content = std::fs::read_to_string(snapshot_path(context_name)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn save(context_name: &str, snapshot: &ServerSnapshot) -> Result<()> {
+    let path = snapshot_path(context_name);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).context("Falha ao criar diretório de snapshots")?;
+    }
+    let content = serde_json::to_string(snapshot).context("Falha ao serializar snapshot")?;
+    std::fs::write(path, content).context("Falha ao gravar snapshot do servidor")
+}
+
+// Acumula os resultados de um servidor e grava no máximo a cada WRITE_INTERVAL
+pub struct SnapshotRecorder {
+    context_name: String,
+    snapshot: ServerSnapshot,
+    last_write: Option<Instant>,
+}
+
+impl SnapshotRecorder {
+    pub fn new(context_name: &str) -> Self {
+        Self {
+            context_name: context_name.to_string(),
+            // Parte do snapshot anterior para não apagar listas ainda não atualizadas
+            snapshot: load(context_name).unwrap_or_default(),
+            last_write: None,
+        }
+    }
+
+    pub fn record(&mut self, update: impl FnOnce(&mut ServerSnapshot)) {
+        update(&mut self.snapshot);
+        self.snapshot.saved_at = chrono::Utc::now().timestamp();
+
+        if self
+            .last_write
+            .is_some_and(|last_write| last_write.elapsed() < WRITE_INTERVAL)
+        {
+            return;
+        }
+        self.last_write = Some(Instant::now());
+
+        if let Err(e) = save(&self.context_name, &self.snapshot) {
+            eprintln!("Erro ao gravar snapshot: {}", e);
+        }
+    }
+}
@@ -0,0 +1,259 @@
+// Cliente SSH para alcançar daemons Docker remotos (contextos ssh://)
+use anyhow::{Context, Result};
+use crate::config::ProxySettings;
+use crate::host_address::HostAddress;
+use serde::{Deserialize, Serialize};
+use std::{
+    path::PathBuf,
+    process::{Child, Command, Stdio},
+    time::Duration,
+};
+
+// Destino SSH extraído de um endpoint ssh://user@host:port
+#[derive(Debug, Clone, PartialEq)]
+pub struct SshTarget {
+    pub user: Option<String>,
+    pub host: String,
+    pub port: Option<u16>,
+}
+
+impl SshTarget {
+    // Faz parse de um endpoint no formato ssh://[user@]host[:port] (IPv6 entre colchetes)
+    pub fn parse(endpoint: &str) -> Result<Self> {
+        let rest = endpoint
+            .strip_prefix("ssh://")
+            .ok_or_else(|| anyhow::anyhow!("Endpoint SSH inválido: {}", endpoint))?;
+        let rest = rest.trim_end_matches('/');
+
+        let (user, host_port) = match rest.rsplit_once('@') {
+            Some((user, host_port)) => (Some(user.to_string()), host_port),
+            None => (None, rest),
+        };
+
+        let HostAddress { host, port } = HostAddress::parse(host_port)
+            .with_context(|| format!("Endpoint SSH inválido: {}", endpoint))?;
+
+        Ok(Self { user, host, port })
+    }
+
+    // Destino no formato aceito pelo comando ssh: IPv6 vai sem colchetes, a porta vai em -p
+    pub fn destination(&self) -> String {
+        match &self.user {
+            Some(user) => format!("{}@{}", user, self.host),
+            None => self.host.clone(),
+        }
+    }
+}
+
+// Opções de transporte do ssh por servidor: compressão para links lentos e
+// cifras/kex para servidores antigos (listas do ssh, ex: "+aes128-cbc")
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SshOptions {
+    pub compression: bool,
+    pub ciphers: String,
+    pub kex: String,
+}
+
+impl SshOptions {
+    // Rejeita listas que o ssh não aceitaria (espaços, caracteres fora dos nomes de algoritmos)
+    pub fn validate(&self) -> Result<()> {
+        for (label, value) in [("Cifras", &self.ciphers), ("Kex", &self.kex)] {
+            let valid = value.chars().all(|c| {
+                c.is_ascii_alphanumeric() || matches!(c, '@' | '.' | ',' | '-' | '+' | '^')
+            });
+            if !valid {
+                anyhow::bail!(
+                    "{} inválidas: use nomes separados por vírgula, ex: +aes128-cbc",
+                    label
+                );
+            }
+        }
+        Ok(())
+    }
+
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    fn args(&self) -> Vec<String> {
+        let mut args = Vec::new();
+        if self.compression {
+            args.push("-o".to_string());
+            args.push("Compression=yes".to_string());
+        }
+        if !self.ciphers.is_empty() {
+            args.push("-o".to_string());
+            args.push(format!("Ciphers={}", self.ciphers));
+        }
+        if !self.kex.is_empty() {
+            args.push("-o".to_string());
+            args.push(format!("KexAlgorithms={}", self.kex));
+        }
+        args
+    }
+}
+
+// Cliente SSH que mantém um túnel para o socket do Docker remoto
+pub struct SshClient {
+    target: SshTarget,
+    socket_path: PathBuf,
+    // ProxyCommand pronto; None sem proxy ou com o host nas exceções
+    proxy_command: Option<String>,
+    options: SshOptions,
+    child: Option<Child>,
+}
+
+impl SshClient {
+    pub fn new(
+        context_name: &str,
+        endpoint: &str,
+        proxy: Option<ProxySettings>,
+        options: SshOptions,
+    ) -> Result<Self> {
+        let target = SshTarget::parse(endpoint)?;
+        let socket_path = std::env::temp_dir().join(format!(
+            "docker-ui-{}-{}.sock",
+            context_name,
+            std::process::id()
+        ));
+
+        let proxy_command = proxy
+            .filter(|proxy| !proxy.bypasses(&target.host))
+            .map(|proxy| proxy.ssh_proxy_command())
+            .transpose()?;
+
+        Ok(Self {
+            target,
+            socket_path,
+            proxy_command,
+            options,
+            child: None,
+        })
+    }
+
+    pub fn target(&self) -> &SshTarget {
+        &self.target
+    }
+
+    // Argumentos base do ssh (porta, proxy e opções de transporte)
+    fn base_args(&self) -> Vec<String> {
+        let mut args = vec!["-o".to_string(), "BatchMode=yes".to_string()];
+        args.extend(self.options.args());
+        if let Some(port) = self.target.port {
+            args.push("-p".to_string());
+            args.push(port.to_string());
+        }
+        if let Some(proxy_command) = &self.proxy_command {
+            args.push("-o".to_string());
+            args.push(format!("ProxyCommand={}", proxy_command));
+        }
+        args
+    }
+
+    // Comando executado diretamente no host remoto, ex: `ip -o link show`; cada argumento
+    // vai entre aspas porque o ssh junta tudo em uma linha para o shell remoto
+    pub fn remote_command(&self, remote: &[&str]) -> Command {
+        let mut command = Command::new("ssh");
+        command
+            .args(self.base_args())
+            .arg(self.target.destination())
+            .args(remote.iter().map(|arg| shell_quote(arg)))
+            .stdin(Stdio::null());
+        command
+    }
+
+    // Abre túnel local -> /var/run/docker.sock remoto e retorna o socket local
+    pub async fn open_tunnel(&mut self) -> Result<PathBuf> {
+        self.close_tunnel();
+        let _ = std::fs::remove_file(&self.socket_path);
+
+        let mut args = self.base_args();
+        args.extend([
+            "-nNT".to_string(),
+            "-o".to_string(),
+            "ExitOnForwardFailure=yes".to_string(),
+            "-o".to_string(),
+            "StreamLocalBindUnlink=yes".to_string(),
+            "-L".to_string(),
+            format!("{}:/var/run/docker.sock", self.socket_path.display()),
+            self.target.destination(),
+        ]);
+
+        let child = Command::new("ssh")
+            .args(&args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()
+            .context("Falha ao executar ssh")?;
+        self.child = Some(child);
+
+        // Aguarda o socket local aparecer (até 15 segundos)
+        for _ in 0..150 {
+            if self.socket_path.exists() {
+                return Ok(self.socket_path.clone());
+            }
+
+            if let Some(child) = self.child.as_mut() {
+                if let Ok(Some(status)) = child.try_wait() {
+                    let mut stderr = String::new();
+                    if let Some(mut err) = child.stderr.take() {
+                        use std::io::Read;
+                        let _ = err.read_to_string(&mut stderr);
+                    }
+                    self.child = None;
+                    return Err(anyhow::anyhow!(
+                        "Túnel SSH para {} encerrou ({}): {}",
+                        self.target.host,
+                        status,
+                        stderr.trim()
+                    ));
+                }
+            }
+
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+
+        self.close_tunnel();
+        Err(anyhow::anyhow!(
+            "Tempo esgotado ao abrir túnel SSH para {}",
+            self.target.host
+        ))
+    }
+
+    // Verifica se o processo do túnel ainda está vivo
+    pub fn is_alive(&mut self) -> bool {
+        match self.child.as_mut() {
+            Some(child) => matches!(child.try_wait(), Ok(None)),
+            None => false,
+        }
+    }
+
+    // Encerra o túnel e remove o socket local
+    pub fn close_tunnel(&mut self) {
+        if let Some(mut child) = self.child.take() {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+        let _ = std::fs::remove_file(&self.socket_path);
+    }
+}
+
+// Aspas simples para o shell remoto: 'a'\''b' representa a'b
+fn shell_quote(arg: &str) -> String {
+    if !arg.is_empty()
+        && arg
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '/' | ':' | '='))
+    {
+        return arg.to_string();
+    }
+    format!("'{}'", arg.replace('\'', "'\\''"))
+}
+
+impl Drop for SshClient {
+    fn drop(&mut self) {
+        self.close_tunnel();
+    }
+}
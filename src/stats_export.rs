@@ -0,0 +1,162 @@
+// Estatísticas em JSON com esquema estável para scripts externos. É o mesmo dado do
+// dashboard, exportado pela interface ou pela linha de comando:
+//   docker-ui-app stats [--server prod] [--output stats.json]
+// Os campos levam a unidade no nome; mudanças incompatíveis trocam SCHEMA.
+use anyhow::{Context, Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::docker::{ContainerStats, DockerManager, DockerSystemUsage};
+use crate::util::parse_flags;
+
+pub const SCHEMA: &str = "docker-ui/stats/v1";
+
+pub const USAGE: &str = "Uso: docker-ui-app stats [--server <servidor>] [--output <arquivo>]";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatsSnapshot {
+    pub schema: String,
+    // Contexto Docker de onde os dados vieram
+    pub server: String,
+    // RFC 3339 em UTC
+    pub collected_at: String,
+    pub host: HostStats,
+    pub containers: Vec<ContainerStatsEntry>,
+}
+
+// Totais dos containers em execução
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HostStats {
+    pub cpu_online: u64,
+    pub cpu_percent: f64,
+    pub memory_used_bytes: u64,
+    pub memory_limit_bytes: u64,
+    pub memory_percent: f64,
+    pub network_rx_bytes: u64,
+    pub network_tx_bytes: u64,
+    pub block_read_bytes: u64,
+    pub block_write_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContainerStatsEntry {
+    pub id: String,
+    pub name: String,
+    pub cpu_percent: f64,
+    pub memory_used_bytes: u64,
+    pub memory_limit_bytes: u64,
+    pub memory_percent: f64,
+    pub network_rx_bytes: u64,
+    pub network_tx_bytes: u64,
+    pub block_read_bytes: u64,
+    pub block_write_bytes: u64,
+}
+
+impl From<&ContainerStats> for ContainerStatsEntry {
+    fn from(stats: &ContainerStats) -> Self {
+        Self {
+            id: stats.id.clone(),
+            name: stats.name.clone(),
+            cpu_percent: stats.cpu_percentage,
+            memory_used_bytes: stats.memory_usage,
+            memory_limit_bytes: stats.memory_limit,
+            memory_percent: stats.memory_percentage,
+            network_rx_bytes: stats.network_rx,
+            network_tx_bytes: stats.network_tx,
+            block_read_bytes: stats.block_read,
+            block_write_bytes: stats.block_write,
+        }
+    }
+}
+
+impl StatsSnapshot {
+    pub fn new(server: &str, usage: &DockerSystemUsage) -> Self {
+        Self {
+            schema: SCHEMA.to_string(),
+            server: server.to_string(),
+            collected_at: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+            host: HostStats {
+                cpu_online: usage.cpu_online,
+                cpu_percent: usage.cpu_usage,
+                memory_used_bytes: usage.memory_usage,
+                memory_limit_bytes: usage.memory_limit,
+                memory_percent: usage.memory_percentage,
+                network_rx_bytes: usage.network_rx_bytes,
+                network_tx_bytes: usage.network_tx_bytes,
+                block_read_bytes: usage.block_read_bytes,
+                block_write_bytes: usage.block_write_bytes,
+            },
+            containers: usage
+                .containers_stats
+                .iter()
+                .map(ContainerStatsEntry::from)
+                .collect(),
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self).context("Falha ao serializar estatísticas")
+    }
+}
+
+// Grava o JSON; sobrescreve o arquivo para que scripts possam reler sempre o mesmo caminho
+pub fn write_file(path: &str, json: &str) -> Result<()> {
+    let path = Path::new(path.trim());
+    if path.as_os_str().is_empty() {
+        return Err(anyhow!("Informe o caminho do arquivo"));
+    }
+    if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent).context("Falha ao criar diretório de destino")?;
+    }
+    std::fs::write(path, format!("{}\n", json)).context("Falha ao gravar estatísticas")
+}
+
+// Sugestão de destino: ~/docker-ui-stats-<servidor>.json
+pub fn default_path(server: &str) -> String {
+    let file = format!("docker-ui-stats-{}.json", server);
+    std::env::var_os("HOME")
+        .map(|home| Path::new(&home).join(&file).display().to_string())
+        .unwrap_or(file)
+}
+
+// Argumentos do subcomando `stats`, sem o nome do subcomando
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StatsCommand {
+    // Contexto Docker; None usa o contexto ativo
+    pub server: Option<String>,
+    // Arquivo de saída; None escreve na saída padrão
+    pub output: Option<String>,
+}
+
+impl StatsCommand {
+    pub fn from_args(args: &[String]) -> Result<Self> {
+        let mut command = Self::default();
+        for (flag, value) in parse_flags(args, &["--server", "--output"])? {
+            match flag {
+                "--server" => command.server = Some(value),
+                _ => command.output = Some(value),
+            }
+        }
+        Ok(command)
+    }
+
+    // Coleta duas amostras: o percentual de CPU é o delta entre elas
+    pub async fn run(&self) -> Result<()> {
+        let mut manager = match &self.server {
+            Some(server) => DockerManager::for_context_name(server).await?,
+            None => DockerManager::new().await?,
+        };
+        manager.get_docker_system_usage().await?;
+        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+        let usage = manager.get_docker_system_usage().await?;
+
+        let json = StatsSnapshot::new(&manager.context().name, &usage).to_json()?;
+        match &self.output {
+            Some(path) => write_file(path, &json),
+            None => {
+                println!("{}", json);
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,58 @@
+// Resolução da amostragem de stats por tela: 500ms só para o container com os detalhes
+// abertos; a agregação de todos os containers (dashboard) cai para amostras lentas
+// enquanto outra tela estiver em uso, aliviando os daemons remotos
+use std::time::{Duration, Instant};
+
+use crate::bandwidth::BandwidthMode;
+use crate::ui::store::Screen;
+
+// Intervalo mínimo entre ticks dos timers que consultam o coletor
+pub const TICK: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatsConsumer {
+    // docker stats de todos os containers (totais e gráficos do dashboard)
+    Aggregate,
+    // Container com a tela de detalhes aberta
+    ContainerDetails,
+}
+
+// Intervalo de amostragem do consumidor com a tela atual; None = não amostra
+pub fn interval(consumer: StatsConsumer, screen: i32, mode: &BandwidthMode) -> Option<Duration> {
+    let interval = match (consumer, Screen::from_index(screen)) {
+        (StatsConsumer::ContainerDetails, Screen::ContainerDetails) => Duration::from_millis(500),
+        (StatsConsumer::ContainerDetails, _) => return None,
+        (StatsConsumer::Aggregate, Screen::Dashboard) => Duration::from_secs(1),
+        // Detalhes abertos: a agregação dá lugar à amostragem fina do container
+        (StatsConsumer::Aggregate, Screen::ContainerDetails) => Duration::from_secs(10),
+        (StatsConsumer::Aggregate, _) => Duration::from_secs(5),
+    };
+    // Economia de banda e movimento reduzido nunca amostram mais rápido que 5s
+    if mode.is_throttled() {
+        Some(interval.max(Duration::from_secs(5)))
+    } else {
+        Some(interval)
+    }
+}
+
+// Momento da última amostra de um consumidor
+#[derive(Debug, Default)]
+pub struct SampleClock {
+    last: Option<Instant>,
+}
+
+impl SampleClock {
+    pub fn due(&mut self, interval: Option<Duration>) -> bool {
+        let Some(interval) = interval else {
+            return false;
+        };
+        // Folga para o jitter do timer não pular uma amostra inteira
+        let due = self
+            .last
+            .is_none_or(|last| last.elapsed() + TICK / 4 >= interval);
+        if due {
+            self.last = Some(Instant::now());
+        }
+        due
+    }
+}
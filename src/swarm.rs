@@ -0,0 +1,124 @@
+// Nós de clusters Swarm (docker node ls / ps)
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+
+// Nó do swarm com tarefas em execução
+#[derive(Debug, Clone)]
+pub struct SwarmNode {
+    pub id: String,
+    pub hostname: String,
+    pub status: String,
+    pub availability: String,
+    pub manager_status: String,
+    pub engine_version: String,
+    pub is_self: bool,
+    pub labels: BTreeMap<String, String>,
+    // Tarefas em execução por serviço
+    pub tasks: BTreeMap<String, u32>,
+}
+
+impl SwarmNode {
+    pub fn role(&self) -> &'static str {
+        if self.manager_status.is_empty() {
+            "worker"
+        } else {
+            "manager"
+        }
+    }
+
+    pub fn task_count(&self) -> u32 {
+        self.tasks.values().sum()
+    }
+
+    // Ex: "web×3, api×1"
+    pub fn task_summary(&self) -> String {
+        let mut tasks: Vec<(&String, &u32)> = self.tasks.iter().collect();
+        tasks.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+        tasks
+            .iter()
+            .map(|(service, count)| format!("{}×{}", service, count))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct NodeLsEntry {
+    #[serde(rename = "ID", default)]
+    id: String,
+    #[serde(rename = "Hostname", default)]
+    hostname: String,
+    #[serde(rename = "Status", default)]
+    status: String,
+    #[serde(rename = "Availability", default)]
+    availability: String,
+    #[serde(rename = "ManagerStatus", default)]
+    manager_status: String,
+    #[serde(rename = "EngineVersion", default)]
+    engine_version: String,
+    #[serde(rename = "Self", default)]
+    is_self: bool,
+}
+
+// Interpreta `docker node ls --format '{{json .}}'` (um objeto por linha)
+pub fn parse_nodes(output: &str) -> Result<Vec<SwarmNode>> {
+    let mut nodes = output
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let entry: NodeLsEntry =
+                serde_json::from_str(line).context("Resposta inválida do docker node ls")?;
+            Ok(SwarmNode {
+                id: entry.id.trim_end_matches(" *").to_string(),
+                hostname: entry.hostname,
+                status: entry.status,
+                availability: entry.availability,
+                manager_status: entry.manager_status,
+                engine_version: entry.engine_version,
+                is_self: entry.is_self,
+                labels: BTreeMap::new(),
+                tasks: BTreeMap::new(),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // Managers primeiro, depois por hostname
+    nodes.sort_by(|a, b| a.role().cmp(b.role()).then(a.hostname.cmp(&b.hostname)));
+    Ok(nodes)
+}
+
+// Interpreta linhas "nó<TAB>nome-da-tarefa" de `docker node ps`; web.1 -> web
+pub fn parse_task_distribution(output: &str) -> HashMap<String, BTreeMap<String, u32>> {
+    let mut distribution: HashMap<String, BTreeMap<String, u32>> = HashMap::new();
+
+    for line in output.lines() {
+        let Some((node, task)) = line.split_once('\t') else {
+            continue;
+        };
+        // Linhas de histórico começam com "\_"
+        let task = task.trim().trim_start_matches("\\_").trim();
+        let service = task.rsplit_once('.').map(|(service, _)| service).unwrap_or(task);
+        if service.is_empty() {
+            continue;
+        }
+
+        *distribution
+            .entry(node.trim().to_string())
+            .or_default()
+            .entry(service.to_string())
+            .or_insert(0) += 1;
+    }
+
+    distribution
+}
+
+// Valida "chave=valor" para --label-add
+pub fn parse_label(input: &str) -> Result<(String, String)> {
+    let (key, value) = input.split_once('=').unwrap_or((input, ""));
+    let key = key.trim();
+    if key.is_empty() || key.contains(char::is_whitespace) {
+        return Err(anyhow::anyhow!("Label inválido: use chave=valor"));
+    }
+    Ok((key.to_string(), value.trim().to_string()))
+}
@@ -0,0 +1,301 @@
+// Fila persistente de operações longas (limpezas agendadas, migração de volumes) em
+// ~/.config/docker-ui/tasks.json: tarefas interrompidas pelo fechamento da aplicação
+// continuam registradas e podem ser retomadas ou repetidas na próxima execução
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, Local, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use crate::config::AppConfig;
+use crate::prune::PruneTarget;
+
+// Tarefas concluídas mantidas no histórico
+const MAX_FINISHED: usize = 30;
+
+// Serializa leitura e gravação do arquivo entre as tarefas em execução
+static STORE_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TaskKind {
+    Prune {
+        server: String,
+        // Valor de `PruneTarget::as_str`
+        target: String,
+    },
+    VolumeMigration {
+        source_server: String,
+        source_volume: String,
+        target_server: String,
+        target_volume: String,
+    },
+}
+
+impl TaskKind {
+    pub fn prune(server: &str, target: PruneTarget) -> Self {
+        TaskKind::Prune {
+            server: server.to_string(),
+            target: target.as_str().to_string(),
+        }
+    }
+
+    // Ex: "Limpeza de imagens sem uso em prod", "Migração de dados (prod) para dados (staging)"
+    pub fn label(&self) -> String {
+        match self {
+            TaskKind::Prune { server, target } => format!(
+                "Limpeza de {} em {}",
+                PruneTarget::from_ui(target).label(),
+                server
+            ),
+            TaskKind::VolumeMigration {
+                source_server,
+                source_volume,
+                target_server,
+                target_volume,
+            } => format!(
+                "Migração de {} ({}) para {} ({})",
+                source_volume, source_server, target_volume, target_server
+            ),
+        }
+    }
+
+    // Limpezas podem ser repetidas sem risco; a migração só com confirmação do usuário
+    pub fn resumes_automatically(&self) -> bool {
+        matches!(self, TaskKind::Prune { .. })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskState {
+    Pending,
+    Running,
+    Done,
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskRecord {
+    pub id: String,
+    #[serde(flatten)]
+    pub kind: TaskKind,
+    pub state: TaskState,
+    pub attempts: u32,
+    // Resultado da última execução (ex: "1.2GB liberados") ou o erro
+    #[serde(default)]
+    pub detail: String,
+    // A migração já criou o volume de destino: uma interrupção deixa dados pela metade
+    #[serde(default)]
+    pub partial_target: bool,
+    #[serde(default)]
+    pub interrupted: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TaskRecord {
+    pub fn is_finished(&self) -> bool {
+        matches!(self.state, TaskState::Done | TaskState::Failed)
+    }
+
+    // Ex: "16/10 03:00 · falhou (2 tentativas): conexão recusada"
+    pub fn status_label(&self) -> String {
+        let state = match self.state {
+            TaskState::Pending => "na fila".to_string(),
+            TaskState::Running => "em execução".to_string(),
+            TaskState::Done => "concluída".to_string(),
+            TaskState::Failed if self.interrupted => "interrompida".to_string(),
+            TaskState::Failed => "falhou".to_string(),
+        };
+        let attempts = if self.attempts > 1 {
+            format!(" ({} tentativas)", self.attempts)
+        } else {
+            String::new()
+        };
+        let detail = if self.detail.is_empty() {
+            String::new()
+        } else {
+            format!(": {}", self.detail)
+        };
+        format!(
+            "{} · {}{}{}",
+            self.updated_at.with_timezone(&Local).format("%d/%m %H:%M"),
+            state,
+            attempts,
+            detail
+        )
+    }
+}
+
+fn path() -> PathBuf {
+    AppConfig::path().with_file_name("tasks.json")
+}
+
+// Arquivo ausente é uma fila vazia; um arquivo inválido é movido para uma cópia antes,
+// para que a próxima gravação não apague as tarefas registradas nele
+fn read() -> Vec<TaskRecord> {
+    let path = path();
+    let Ok(content) = std::fs::read_to_string(&path) else {
+        return Vec::new();
+    };
+    match serde_json::from_str(&content) {
+        Ok(tasks) => tasks,
+        Err(e) => {
+            eprintln!("Fila de tarefas inválida em {}: {}", path.display(), e);
+            AppConfig::backup_invalid(&path);
+            Vec::new()
+        }
+    }
+}
+
+fn write(tasks: &[TaskRecord]) -> Result<()> {
+    let path = path();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).context("Falha ao criar diretório de configuração")?;
+    }
+    let content = serde_json::to_string_pretty(tasks).context("Falha ao serializar tarefas")?;
+    // Grava em arquivo temporário e renomeia para não corromper a fila se o app fechar no meio
+    let temp = path.with_extension("json.tmp");
+    std::fs::write(&temp, content).context("Falha ao gravar a fila de tarefas")?;
+    std::fs::rename(&temp, &path).context("Falha ao gravar a fila de tarefas")
+}
+
+// Lê, altera e grava a fila sob o lock
+fn modify<T>(change: impl FnOnce(&mut Vec<TaskRecord>) -> T) -> Result<T> {
+    let _guard = STORE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut tasks = read();
+    let result = change(&mut tasks);
+    write(&tasks)?;
+    Ok(result)
+}
+
+// Tarefas da mais recente para a mais antiga
+pub fn load() -> Vec<TaskRecord> {
+    let _guard = STORE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut tasks = read();
+    tasks.reverse();
+    tasks
+}
+
+pub fn get(id: &str) -> Option<TaskRecord> {
+    load().into_iter().find(|task| task.id == id)
+}
+
+pub fn enqueue(kind: TaskKind) -> Result<TaskRecord> {
+    let now = Utc::now();
+    let task = TaskRecord {
+        id: format!("{}-{}", now.timestamp_millis(), rand::random::<u16>()),
+        kind,
+        state: TaskState::Pending,
+        attempts: 0,
+        detail: String::new(),
+        partial_target: false,
+        interrupted: false,
+        created_at: now,
+        updated_at: now,
+    };
+    modify(|tasks| {
+        tasks.push(task.clone());
+        // Descarta as concluídas mais antigas; falhas ficam até serem repetidas ou removidas
+        let done = tasks
+            .iter()
+            .filter(|task| task.state == TaskState::Done)
+            .count();
+        let mut excess = done.saturating_sub(MAX_FINISHED);
+        tasks.retain(|task| {
+            if excess > 0 && task.state == TaskState::Done {
+                excess -= 1;
+                return false;
+            }
+            true
+        });
+    })?;
+    Ok(task)
+}
+
+fn update(id: &str, change: impl FnOnce(&mut TaskRecord)) -> Result<()> {
+    let found = modify(|tasks| match tasks.iter_mut().find(|task| task.id == id) {
+        Some(task) => {
+            change(task);
+            task.updated_at = Utc::now();
+            true
+        }
+        None => false,
+    })?;
+    if !found {
+        bail!("Tarefa {} não encontrada", id);
+    }
+    Ok(())
+}
+
+// Marca o início de uma execução; falha se a tarefa já estiver rodando. Verificação e
+// transição acontecem sob o mesmo lock, para que duas chamadas não iniciem a mesma tarefa
+pub fn start(id: &str) -> Result<()> {
+    modify(|tasks| {
+        let task = tasks
+            .iter_mut()
+            .find(|task| task.id == id)
+            .with_context(|| format!("Tarefa {} não encontrada", id))?;
+        if task.state == TaskState::Running {
+            bail!("A tarefa já está em execução");
+        }
+        task.state = TaskState::Running;
+        task.attempts += 1;
+        task.interrupted = false;
+        task.detail.clear();
+        task.updated_at = Utc::now();
+        Ok(())
+    })?
+}
+
+pub fn set_partial_target(id: &str, partial: bool) -> Result<()> {
+    update(id, |task| task.partial_target = partial)
+}
+
+pub fn finish(id: &str, result: &Result<String>) -> Result<()> {
+    update(id, |task| match result {
+        Ok(detail) => {
+            task.state = TaskState::Done;
+            task.detail = detail.clone();
+        }
+        Err(e) => {
+            task.state = TaskState::Failed;
+            task.detail = e.to_string();
+        }
+    })
+}
+
+pub fn remove(id: &str) -> Result<()> {
+    modify(|tasks| tasks.retain(|task| task.id != id || task.state == TaskState::Running))
+}
+
+// Remove o histórico de tarefas concluídas com sucesso
+pub fn clear_done() -> Result<()> {
+    modify(|tasks| tasks.retain(|task| task.state != TaskState::Done))
+}
+
+// Na abertura do app: tarefas que estavam na fila ou rodando foram interrompidas.
+// Retorna as que podem ser retomadas sozinhas e quantas ficaram como falha para o usuário
+pub fn recover_interrupted() -> Result<(Vec<TaskRecord>, usize)> {
+    modify(|tasks| {
+        let mut resumable = Vec::new();
+        let mut interrupted = 0;
+        for task in tasks.iter_mut() {
+            if task.is_finished() {
+                continue;
+            }
+            if task.kind.resumes_automatically() {
+                task.state = TaskState::Pending;
+                resumable.push(task.clone());
+            } else {
+                task.state = TaskState::Failed;
+                task.interrupted = true;
+                task.detail = "a aplicação foi fechada durante a execução".to_string();
+                task.updated_at = Utc::now();
+                interrupted += 1;
+            }
+        }
+        (resumable, interrupted)
+    })
+}
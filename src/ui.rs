@@ -1,18 +1,89 @@
 use crate::docker::{
-    ContainerInfo, CreateContainerRequest, DockerInfo, DockerManager, EnvVar, PortMapping,
-    VolumeMapping,
+    ContainerInfo, DockerContextConfig, DockerInfo, DockerManager, DockerStatus, PullPolicy,
 };
-use crate::list_containers::{ContainerUIManager, SlintContainerData, setup_container_ui_timer};
-use crate::list_images::{ImageUIManager, SlintImageData};
-use crate::list_networks::{NetworkUIManager, SlintNetworkData};
-use crate::list_volumes::{SlintVolumeData, VolumeUIManager};
-use crate::chart::ChartRenderer;
-use slint::{Timer, TimerMode, ToSharedString, Weak};
+use crate::list_containers::SlintContainerData;
+use crate::log_pages::LogPages;
+use crate::stats_sampling::{self, SampleClock, StatsConsumer};
+use crate::stats_export::{self, StatsSnapshot};
+use crate::list_images::SlintImageData;
+use crate::list_networks::SlintNetworkData;
+use crate::list_volumes::SlintVolumeData;
+use crate::config::{AppConfig, ProxySettings};
+use crate::annotations::{Annotation, AnnotationTarget, Annotations};
+use crate::audit_log::{self, AuditEntry};
+use crate::container_groups::LabelSelector;
+use crate::image_archive;
+use crate::image_provenance;
+use crate::bandwidth::{BandwidthMode, TickGate};
+use crate::log_triggers::{self, LogTrigger, LogTriggerState, TriggerAction, TriggerMatch};
+use crate::maintenance::{MaintenanceWindow, ServerMaintenance};
+use crate::metrics_source::MetricsSource;
+use crate::prune::{self, PruneTarget};
+use crate::prune_schedule::PruneSchedule;
+use crate::registry;
+use crate::resume;
+use crate::session_recording::{self, RecordingRequest, RecordingSources};
+use crate::session_replay::{self, RecordedSession};
+use crate::snapshot;
+use crate::ssh::SshOptions;
+use crate::task_queue::{self, TaskKind};
+use crate::credentials::{self, RegistryCredential};
+use crate::build::{
+    BuildProgress, BuildRequest, PlatformProgress, SUPPORTED_PLATFORMS, run_build,
+};
+use crate::chart::{self, ChartPoint, ChartRenderer};
+use crate::compose::{self, ComposeBinary, ComposeStack, HealthLevel, StackDiff};
+use crate::compose_export;
+use crate::exec_history::ExecHistory;
+use crate::exec_session::{self, ExecTerminal, TerminalSize};
+use crate::format::{self, ByteUnits, DecimalSeparator, FormatSettings};
+use crate::deep_link::DeepLink;
+use crate::kiosk::KioskMode;
+use crate::dashboard_layout::{self, DashboardWidget};
+use crate::deploy_history::{DeployComparison, DeployMetric};
+use crate::disk_watch::{self, DiskWatch};
+use crate::docker_install::{self, InstallMethod};
+use slint::{ComponentHandle, Model, Timer, TimerMode, ToSharedString, Weak};
+use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 
 // Tipos gerados pelo Slint são importados diretamente
-use crate::{AppWindow, ContainerData, ImageData, NetworkData, VolumeData, AppState, ContainerChartData};
+use crate::{
+    AppWindow, BuildCacheData, BuildPlatformProgress, ComposeExportItem,
+    DashboardWidgetData, ImageLabelData,
+    ImageSnippetData,
+    LogTriggerData, MaintenanceWindowData,
+    PruneScheduleData, PruneServerItem, ReplayEventData, ReplayLogData,
+    RegistryCredentialData, ServiceHealthData, StackChangeData, StackData, SwarmNodeData,
+    AppState, ContainerChartData,
+};
+
+mod containers_ui;
+mod controller;
+mod create_container_ui;
+mod images_ui;
+mod latency_probe_ui;
+mod logs_ui;
+mod networks_ui;
+pub mod store;
+mod tasks_ui;
+mod volumes_ui;
+
+use containers_ui::{
+    ContainersController, rolling_restart, to_container_data, to_tag_data,
+    update_container_groups, update_ui_containers_from_slint,
+};
+use controller::{ActionBus, ResourceController};
+use create_container_ui::setup_create_container_callbacks;
+use images_ui::{ImagesController, update_ui_images_from_slint};
+use latency_probe_ui::setup_latency_probe_callbacks;
+use logs_ui::{setup_container_logs_timer, setup_load_more_logs_callback};
+use networks_ui::{NetworksController, update_ui_networks_from_slint};
+use store::{Screen, StateChange, Transition, UiStore};
+use tasks_ui::setup_task_queue_callbacks;
+use volumes_ui::{VolumesController, update_ui_volumes_from_slint};
 
 pub struct UiApp {}
 
@@ -29,18 +100,71 @@ pub async fn setup_docker_ui(
     container_chart_data: Arc<std::sync::Mutex<ContainerChartData>>,
     container_cpu_renderer: Arc<std::sync::Mutex<ChartRenderer>>,
     container_memory_renderer: Arc<std::sync::Mutex<ChartRenderer>>,
+    deep_link: Option<DeepLink>,
+    kiosk: Option<KioskMode>,
 ) -> Timer {
         let ui = ui_weak.upgrade().unwrap();
 
         let timer = Timer::default();
+
+        // Exibe o contexto ativo antes mesmo de conectar
+        let initial_server = deep_link
+            .as_ref()
+            .and_then(|link| link.server.as_deref())
+            .or_else(|| kiosk.as_ref().and_then(|kiosk| kiosk.server.as_deref()));
+        let current_context = initial_context(&ui, initial_server);
+        update_context_header(&ui, &current_context);
+        update_available_contexts(&ui, &DockerContextConfig::list_available());
+        app_state
+            .low_bandwidth
+            .set_low(AppConfig::load().is_low_bandwidth(&current_context.name));
+        let reduced_motion = AppConfig::load().reduced_motion;
+        app_state.low_bandwidth.set_reduced_motion(reduced_motion);
+        ui.set_reduced_motion(reduced_motion);
+
+        // Modo quiosque: tela cheia no dashboard, sem navegação nem ações que alteram o servidor
+        if kiosk.is_some() {
+            ui.set_kiosk_mode(true);
+            ui.window().set_fullscreen(true);
+        }
+
+        // Estado de navegação; o histórico dos gráficos acompanha o container aberto
+        let store = UiStore::new(&current_context.name);
+        store.subscribe({
+            let container_chart_data = container_chart_data.clone();
+            move |_, change| {
+                let StateChange::ContainerSelected(selected) = change else {
+                    return;
+                };
+                if let Ok(mut chart_data) = container_chart_data.lock() {
+                    chart_data.select(&selected.key());
+                }
+            }
+        });
+
+        // Só a tela visível se atualiza sozinha
+        store.subscribe({
+            let low_bandwidth = app_state.low_bandwidth.clone();
+            move |_, change| {
+                if let StateChange::ScreenChanged { to, .. } = change {
+                    low_bandwidth.set_visible_screen(to.index());
+                }
+            }
+        });
+
         // Verifica se Docker está rodando
-        match DockerManager::new().await {
+        match DockerManager::with_context(current_context.clone()).await {
             Ok(docker_manager) => {
                 ui.set_docker_status("Verificando...".into());
 
                 let docker_status = docker_manager.check_docker_status();
                 ui.set_docker_status(docker_status.to_shared_string());
 
+                // Daemon fora do ar: mostra o último estado conhecido até reconectar
+                if !matches!(docker_status, DockerStatus::Running) {
+                    show_offline_snapshot(&ui, &current_context.name);
+                }
+
                 // Carrega informações do Docker
                 if let Ok(info) = docker_manager.get_docker_info().await {
                     update_docker_info(&ui, &info);
@@ -53,228 +177,219 @@ pub async fn setup_docker_ui(
                     let slint_containers: Vec<SlintContainerData> =
                         containers.iter().map(SlintContainerData::from).collect();
                     update_ui_containers_from_slint(&ui, &slint_containers);
+                    ui.set_containers_updated(controller::updated_label());
                 }
 
                 let ui_weak_timer = ui_weak.clone();
                 let chart_data_timer = app_state.chart_data.clone();
                 let cpu_chart_renderer_timer = app_state.cpu_chart_renderer.clone();
                 let memory_chart_renderer_timer = app_state.memory_chart_renderer.clone();
+                let container_counts_timer = app_state.container_counts.clone();
+                let running_sparkline_timer = app_state.running_sparkline_renderer.clone();
+                let stopped_sparkline_timer = app_state.stopped_sparkline_renderer.clone();
+                let low_bandwidth_timer = app_state.low_bandwidth.clone();
+                // Economia de banda: informações do daemon a cada 10s
+                let mut dashboard_gate = TickGate::new(10);
+                // docker stats de todos os containers, na resolução da tela aberta
+                let mut aggregate_clock = SampleClock::default();
 
                 // Cria uma única instância do DockerManager compartilhada entre atualizações
                 let docker_manager_shared = Arc::new(tokio::sync::Mutex::new(docker_manager));
 
-                // Configura gerenciador de containers UI
-                let container_ui_manager = Arc::new(tokio::sync::Mutex::new(
-                    ContainerUIManager::new(docker_manager_shared.clone()),
-                ));
-
-                let ui_weak_container = ui_weak.clone();
-                let container_timer = setup_container_ui_timer(
-                    container_ui_manager.clone(),
-                    Arc::new(move |containers| {
-                        if let Some(ui) = ui_weak_container.upgrade() {
-                            update_ui_containers_from_slint(&ui, &containers);
-
-                            // Se estivermos na tela de detalhes, atualiza o container selecionado
-                            if ui.get_current_screen() == 5 {
-                                let selected = ui.get_selected_container();
-                                if !selected.name.is_empty() {
-                                    // Procura o container atualizado na lista
-                                    if let Some(updated_container) =
-                                        containers.iter().find(|c| c.name == selected.name)
-                                    {
-                                        // Cria um novo ContainerData com os dados atualizados
-                                        ui.set_selected_container(ContainerData {
-                                            name: updated_container.name.clone(),
-                                            image: updated_container.image.clone(),
-                                            status: updated_container.status.clone(),
-                                            ports: updated_container.ports.clone(),
-                                            created: updated_container.created.clone(),
-                                        });
-                                    }
-                                }
-                            }
-                        }
-                    }),
+                // Ocorrências dos gatilhos de log, também exibidas na lista de containers
+                let log_trigger_state = Arc::new(std::sync::Mutex::new(LogTriggerState::new(AppConfig::load().log_triggers)));
+
+                // Telas de containers, imagens, redes e volumes, ligadas por um barramento de ações
+                let action_bus = ActionBus::default();
+                let controllers: Vec<Box<dyn ResourceController>> = vec![
+                    Box::new(ContainersController::new(
+                        docker_manager_shared.clone(),
+                        action_bus.clone(),
+                        store.clone(),
+                        log_trigger_state.clone(),
+                    )),
+                    Box::new(ImagesController::new(docker_manager_shared.clone(), action_bus.clone())),
+                    Box::new(NetworksController::new(
+                        docker_manager_shared.clone(),
+                        action_bus.clone(),
+                    )),
+                    Box::new(VolumesController::new(
+                        docker_manager_shared.clone(),
+                        action_bus.clone(),
+                    )),
+                ];
+                for controller in &controllers {
+                    controller.setup_callbacks(ui_weak.clone());
+                    controller.start_refresh(ui_weak.clone(), app_state.low_bandwidth.clone());
+                }
+
+                // Configura troca de servidor (contexto Docker)
+                setup_context_callbacks(
+                    ui_weak.clone(),
+                    docker_manager_shared.clone(),
+                    app_state.clone(),
+                    container_chart_data.clone(),
+                    store.clone(),
+                );
+
+                // Revalida a conexão ao retomar de suspensão
+                setup_resume_watcher(
+                    ui_weak.clone(),
+                    docker_manager_shared.clone(),
+                    app_state.clone(),
+                    container_chart_data.clone(),
                 );
 
-                // Mantém o timer vivo armazenando-o no contexto
-                std::mem::forget(container_timer);
+                // Configura modo de economia de banda por servidor
+                setup_low_bandwidth_callback(ui_weak.clone(), app_state.clone());
+                setup_reduced_motion_callback(ui_weak.clone(), app_state.clone());
 
-                // Configura callbacks de container
-                setup_container_callbacks(ui_weak.clone(), container_ui_manager.clone());
+                // Configura fonte de métricas (Prometheus) por servidor
+                setup_metrics_source_callbacks(
+                    ui_weak.clone(),
+                    docker_manager_shared.clone(),
+                    app_state.clone(),
+                    container_chart_data.clone(),
+                );
 
-                // Configura callback para carregar mais logs
-                setup_load_more_logs_callback(ui_weak.clone(), docker_manager_shared.clone());
+                // Configura o mirror do Docker Hub por servidor
+                setup_registry_mirror_callbacks(ui_weak.clone());
 
-                // Configura timer para logs de container
-                setup_container_logs_timer(ui_weak.clone(), docker_manager_shared.clone());
+                // Logs do container aberto, paginados por janelas de tempo
+                let log_pages = Arc::new(std::sync::Mutex::new(LogPages::default()));
 
-                // Configura timer para stats de container
-                setup_container_stats_timer(
+                // Configura callback para carregar logs anteriores
+                setup_load_more_logs_callback(
                     ui_weak.clone(),
                     docker_manager_shared.clone(),
-                    container_chart_data,
-                    container_cpu_renderer,
-                    container_memory_renderer,
+                    log_pages.clone(),
                 );
 
-                // Configura callbacks de criação de containers
-                setup_create_container_callbacks(ui_weak.clone(), docker_manager_shared.clone());
+                // Configura seletor de fuso dos logs
+                setup_log_timezone_callback(ui_weak.clone());
+
+                // Configura separador decimal e unidades de tamanho
+                setup_number_format_callback(ui_weak.clone());
+
+                // Configura notas e tags de containers e servidores
+                setup_annotation_callbacks(ui_weak.clone(), store.clone());
+
+                // Configura exportação de containers como docker-compose.yml
+                setup_compose_export_callbacks(ui_weak.clone(), docker_manager_shared.clone());
+
+                // Configura a gravação de sessão (stats, eventos e logs) para postmortems
+                setup_session_recording_callbacks(ui_weak.clone(), docker_manager_shared.clone());
+
+                // Configura o probe de latência das portas publicadas
+                setup_latency_probe_callbacks(ui_weak.clone(), docker_manager_shared.clone());
 
-                // Configura gerenciador de imagens UI
-                let image_ui_manager = Arc::new(tokio::sync::Mutex::new(ImageUIManager::new(
+                // Configura a reprodução de sessões gravadas (independente da conexão)
+                setup_session_replay_callbacks(ui_weak.clone());
+
+                // Configura a instalação do Docker em servidores SSH salvos
+                setup_docker_install_callbacks(ui_weak.clone());
+
+                // Configura o dashboard personalizado (widgets e layout por servidor)
+                setup_dashboard_layout_callbacks(
+                    ui_weak.clone(),
                     docker_manager_shared.clone(),
-                )));
+                    app_state.low_bandwidth.clone(),
+                );
 
-                // Configura callbacks de imagem
-                setup_image_callbacks(ui_weak.clone(), image_ui_manager.clone());
+                // Configura o console exec com histórico por container e snippets
+                setup_exec_callbacks(ui_weak.clone(), docker_manager_shared.clone(), &store);
 
-                // Configura timer para atualizar imagens a cada segundo
-                let ui_weak_images = ui_weak.clone();
-                let image_ui_manager_timer = image_ui_manager.clone();
-                tokio::spawn(async move {
-                    let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(1));
+                // Configura a procedência (labels OCI) da imagem do container aberto
+                setup_image_provenance_callbacks(ui_weak.clone(), docker_manager_shared.clone(), &store);
 
-                    loop {
-                        interval.tick().await;
+                // Configura a limpeza em vários servidores em paralelo
+                setup_multi_prune_callbacks(ui_weak.clone(), docker_manager_shared.clone());
 
-                        let mut manager = image_ui_manager_timer.lock().await;
-                        match manager.refresh_images().await {
-                            Ok(()) => {
-                                let images = manager.get_images();
-                                let ui_weak_clone = ui_weak_images.clone();
-                                slint::invoke_from_event_loop(move || {
-                                    if let Some(ui) = ui_weak_clone.upgrade() {
-                                        ui.set_image_list_error("".into());
-                                        update_ui_images_from_slint(&ui, &images);
-                                    }
-                                })
-                                .unwrap();
-                            }
-                            Err(e) => {
-                                let error_message = e.to_string();
-                                let ui_weak_clone = ui_weak_images.clone();
-                                slint::invoke_from_event_loop(move || {
-                                    if let Some(ui) = ui_weak_clone.upgrade() {
-                                        ui.set_image_list_error(error_message.into());
-                                    }
-                                })
-                                .unwrap();
-                            }
-                        }
-                    }
-                });
+                // Configura a fila persistente de tarefas e retoma as interrompidas
+                setup_task_queue_callbacks(ui_weak.clone(), docker_manager_shared.clone());
 
-                // Configura gerenciador de networks UI
-                let network_ui_manager = Arc::new(tokio::sync::Mutex::new(NetworkUIManager::new(
+                // Monitora o espaço livre do data-root e avisa ao passar do limite
+                setup_disk_watcher(
+                    ui_weak.clone(),
                     docker_manager_shared.clone(),
-                )));
+                    app_state.low_bandwidth.clone(),
+                );
 
-                // Configura callbacks de network
-                setup_network_callbacks(ui_weak.clone(), network_ui_manager.clone());
+                // Configura janelas de manutenção (alertas silenciados por servidor)
+                setup_maintenance_callbacks(ui_weak.clone());
 
-                // Configura timer para atualizar networks a cada segundo
-                let ui_weak_networks = ui_weak.clone();
-                let network_ui_manager_timer = network_ui_manager.clone();
-                tokio::spawn(async move {
-                    let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(1));
+                // Atualiza o painel de diagnóstico enquanto as configurações estão abertas
+                setup_diagnostics_timer(ui_weak.clone(), docker_manager_shared.clone());
 
-                    loop {
-                        interval.tick().await;
+                // Configura a exportação das estatísticas do dashboard em JSON
+                setup_stats_export_callbacks(ui_weak.clone(), docker_manager_shared.clone());
 
-                        let mut manager = network_ui_manager_timer.lock().await;
-                        match manager.refresh_networks().await {
-                            Ok(()) => {
-                                let networks = manager.get_networks();
-                                let ui_weak_clone = ui_weak_networks.clone();
-                                slint::invoke_from_event_loop(move || {
-                                    if let Some(ui) = ui_weak_clone.upgrade() {
-                                        ui.set_network_list_error("".into());
-                                        update_ui_networks_from_slint(&ui, &networks);
-                                    }
-                                })
-                                .unwrap();
-                            }
-                            Err(e) => {
-                                let error_message = e.to_string();
-                                let ui_weak_clone = ui_weak_networks.clone();
-                                slint::invoke_from_event_loop(move || {
-                                    if let Some(ui) = ui_weak_clone.upgrade() {
-                                        ui.set_network_list_error(error_message.into());
-                                    }
-                                })
-                                .unwrap();
-                            }
-                        }
-                    }
-                });
+                // Configura gatilhos de log (alertas, reinício, webhook ou contagem por padrão)
+                setup_log_trigger_callbacks(ui_weak.clone(), log_trigger_state.clone());
+                setup_log_trigger_watcher(
+                    ui_weak.clone(),
+                    docker_manager_shared.clone(),
+                    log_trigger_state,
+                    app_state.low_bandwidth.clone(),
+                );
 
-                // Configura gerenciador de volumes UI
-                let volume_ui_manager = Arc::new(tokio::sync::Mutex::new(VolumeUIManager::new(
+                // Configura timer para logs de container
+                setup_container_logs_timer(
+                    ui_weak.clone(),
                     docker_manager_shared.clone(),
-                )));
+                    log_pages,
+                    app_state.low_bandwidth.clone(),
+                );
 
-                // Configura callbacks de volume
-                setup_volume_callbacks(ui_weak.clone(), volume_ui_manager.clone());
+                // Configura timer para stats de container
+                setup_container_stats_timer(
+                    ui_weak.clone(),
+                    docker_manager_shared.clone(),
+                    container_chart_data,
+                    container_cpu_renderer,
+                    container_memory_renderer,
+                    app_state.low_bandwidth.clone(),
+                );
 
-                // Configura timer para atualizar volumes a cada segundo
-                let ui_weak_volumes = ui_weak.clone();
-                let volume_ui_manager_timer = volume_ui_manager.clone();
-                tokio::spawn(async move {
-                    let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(1));
+                // Configura callbacks de criação de containers
+                setup_create_container_callbacks(ui_weak.clone(), docker_manager_shared.clone());
 
-                    loop {
-                        interval.tick().await;
+                // Configura cota de pulls e login em registry
+                setup_registry_callbacks(ui_weak.clone(), docker_manager_shared.clone());
+                refresh_hub_rate_limit(ui_weak.clone(), docker_manager_shared.clone());
 
-                        let mut manager = volume_ui_manager_timer.lock().await;
-                        match manager.refresh_volumes().await {
-                            Ok(()) => {
-                                let volumes = manager.get_volumes();
-                                let ui_weak_clone = ui_weak_volumes.clone();
-                                slint::invoke_from_event_loop(move || {
-                                    if let Some(ui) = ui_weak_clone.upgrade() {
-                                        ui.set_volume_list_error("".into());
-                                        update_ui_volumes_from_slint(&ui, &volumes);
-                                    }
-                                })
-                                .unwrap();
-                            }
-                            Err(e) => {
-                                let error_message = e.to_string();
-                                let ui_weak_clone = ui_weak_volumes.clone();
-                                slint::invoke_from_event_loop(move || {
-                                    if let Some(ui) = ui_weak_clone.upgrade() {
-                                        ui.set_volume_list_error(error_message.into());
-                                    }
-                                })
-                                .unwrap();
-                            }
-                        }
-                    }
-                });
+                // Configura cache de build e carga de imagens a partir de arquivo
+                setup_build_cache_callbacks(ui_weak.clone(), docker_manager_shared.clone());
+                setup_load_archive_callbacks(ui_weak.clone(), docker_manager_shared.clone());
 
-                // Inicialização manual dos containers
-                let ui_weak_init = ui_weak.clone();
-                let container_ui_manager_init = container_ui_manager.clone();
-                tokio::spawn(async move {
-                    tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-                    {
-                        let mut manager = container_ui_manager_init.lock().await;
-                        if let Ok(()) = manager.refresh_containers().await {
-                            let filtered_containers = manager.get_filtered_containers();
-                            slint::invoke_from_event_loop(move || {
-                                if let Some(ui) = ui_weak_init.upgrade() {
-                                    update_ui_containers_from_slint(&ui, &filtered_containers);
-                                }
-                            })
-                            .unwrap();
-                        }
-                    }
-                });
+                // Configura tela de build
+                setup_build_callbacks(ui_weak.clone(), docker_manager_shared.clone());
+
+                // Configura tela de stacks do Compose
+                setup_stack_callbacks(ui_weak.clone(), docker_manager_shared.clone());
+
+                // Configura tela de nós do swarm
+                setup_swarm_callbacks(ui_weak.clone(), docker_manager_shared.clone());
+
+                // Abre o container pedido pelo link direto
+                if let Some(deep_link) = deep_link {
+                    open_deep_link(ui_weak.clone(), docker_manager_shared.clone(), store.clone(), deep_link);
+                }
 
                 // Timer para atualizar estatísticas a cada segundo
                 timer.start(TimerMode::Repeated, Duration::from_secs(1), move || {
+                    if !dashboard_gate.should_run(&low_bandwidth_timer) {
+                        return;
+                    }
+                    let screen = ui_weak_timer
+                        .upgrade()
+                        .map_or(Screen::Dashboard.index(), |ui| ui.get_current_screen());
+                    let sample_stats = aggregate_clock.due(stats_sampling::interval(
+                        StatsConsumer::Aggregate,
+                        screen,
+                        &low_bandwidth_timer,
+                    ));
+                    let low_bandwidth = low_bandwidth_timer.clone();
                     let ui_weak_clone = ui_weak_timer.clone();
                     let ui_weak_clone2 = ui_weak_timer.clone();
                     let chart_data_clone = chart_data_timer.clone();
@@ -282,6 +397,9 @@ pub async fn setup_docker_ui(
                     let memory_chart_renderer_clone = memory_chart_renderer_timer.clone();
                     let docker_manager_clone = docker_manager_shared.clone();
                     let docker_manager_clone2 = docker_manager_shared.clone();
+                    let container_counts_clone = container_counts_timer.clone();
+                    let running_sparkline_clone = running_sparkline_timer.clone();
+                    let stopped_sparkline_clone = stopped_sparkline_timer.clone();
 
                     // Task para informações gerais do Docker
                     tokio::spawn(async move {
@@ -290,6 +408,31 @@ pub async fn setup_docker_ui(
                             slint::invoke_from_event_loop(move || {
                                 if let Some(ui) = ui_weak_clone2.upgrade() {
                                     update_docker_info(&ui, &info);
+
+                                    // Amostra contagens para as sparklines do dashboard
+                                    if let Ok(mut counts) = container_counts_clone.lock() {
+                                        if counts.should_update() {
+                                            counts.add_sample(
+                                                info.containers_running,
+                                                info.containers_stopped,
+                                            );
+
+                                            if let Ok(renderer) = running_sparkline_clone.lock() {
+                                                ui.set_running_sparkline(
+                                                    renderer.render_sparkline(
+                                                        counts.running.make_contiguous(),
+                                                    ),
+                                                );
+                                            }
+                                            if let Ok(renderer) = stopped_sparkline_clone.lock() {
+                                                ui.set_stopped_sparkline(
+                                                    renderer.render_sparkline(
+                                                        counts.stopped.make_contiguous(),
+                                                    ),
+                                                );
+                                            }
+                                        }
+                                    }
                                 }
                             })
                             .unwrap();
@@ -298,7 +441,36 @@ pub async fn setup_docker_ui(
 
                     // Task principal para estatísticas do sistema - USANDO A MESMA INSTÂNCIA
                     tokio::spawn(async move {
+                        if !sample_stats {
+                            return;
+                        }
+                        // Economia de banda: sem docker stats de todos os containers
+                        if low_bandwidth.is_low() {
+                            slint::invoke_from_event_loop(move || {
+                                if let Some(ui) = ui_weak_clone.upgrade() {
+                                    ui.set_cpu_usage_str("Economia de banda".into());
+                                    ui.set_memory_percentage_str("Economia de banda".into());
+                                    ui.set_network_rx_str("RX -".into());
+                                    ui.set_network_tx_str("TX -".into());
+                                }
+                            })
+                            .unwrap();
+                            return;
+                        }
+
                         let mut docker_manager = docker_manager_clone.lock().await;
+
+                        // Com Prometheus configurado, os gráficos usam o histórico dele
+                        let external_charts = docker_manager.has_metrics_source();
+                        let history = match docker_manager.host_metrics_history() {
+                            Some(Ok(history)) => Some(history),
+                            Some(Err(e)) => {
+                                eprintln!("Error querying Prometheus: {}", e);
+                                None
+                            }
+                            None => None,
+                        };
+
                         match docker_manager.get_docker_system_usage().await {
                             Ok(stats) => {
                                 // Atualiza UI no thread principal
@@ -306,14 +478,14 @@ pub async fn setup_docker_ui(
                                     if let Some(ui) = ui_weak_clone.upgrade() {
                                         ui.set_cpu_usage_str(
                                             format!(
-                                                "{:.2}% | {}%",
-                                                stats.cpu_usage,
+                                                "{}% | {}%",
+                                                format::number(stats.cpu_usage, 2),
                                                 stats.cpu_online * 100
                                             )
                                             .into(),
                                         );
                                         ui.set_memory_percentage_str(
-                                            format_memory_display(
+                                            format::memory(
                                                 stats.memory_percentage,
                                                 stats.memory_usage,
                                                 stats.memory_limit,
@@ -321,23 +493,38 @@ pub async fn setup_docker_ui(
                                             .into(),
                                         );
                                         ui.set_network_rx_str(
-                                            format!("RX {}", format_bytes(stats.network_rx_bytes))
+                                            format!("RX {}", format::bytes(stats.network_rx_bytes))
                                                 .into(),
                                         );
                                         ui.set_network_tx_str(
-                                            format!("TX {}", format_bytes(stats.network_tx_bytes))
+                                            format!("TX {}", format::bytes(stats.network_tx_bytes))
                                                 .into(),
                                         );
 
                                         // Atualiza dados dos gráficos com throttling adequado
                                         if let Ok(mut chart_data_lock) = chart_data_clone.lock() {
-                                            if chart_data_lock.should_update() {
-                                                chart_data_lock
-                                                    .add_cpu_point(stats.cpu_usage as f32);
-                                                chart_data_lock.add_memory_point(
-                                                    stats.memory_percentage as f32,
-                                                );
-
+                                            let redraw = match history {
+                                                Some(history) => {
+                                                    chart_data_lock.replace_history(history);
+                                                    true
+                                                }
+                                                None if external_charts => false,
+                                                None if chart_data_lock.should_update() => {
+                                                    chart_data_lock
+                                                        .add_cpu_point(stats.cpu_usage as f32);
+                                                    chart_data_lock.add_memory_point(
+                                                        stats.memory_percentage as f32,
+                                                    );
+                                                    true
+                                                }
+                                                None => false,
+                                            };
+                                            // Movimento reduzido: redesenha só de tempos em tempos
+                                            if redraw
+                                                && chart_data_lock
+                                                    .redraw
+                                                    .should_redraw(&low_bandwidth)
+                                            {
                                                 // Renderiza gráfico CPU
                                                 let cpu_chart_renderer =
                                                     cpu_chart_renderer_clone.lock().unwrap();
@@ -349,6 +536,9 @@ pub async fn setup_docker_ui(
                                                         stats.cpu_online as f32 * 100.0,
                                                     );
                                                 ui.set_cpu_chart(cpu_chart);
+                                                ui.set_cpu_chart_description(
+                                                    chart_description(&chart_data_lock.cpu_points),
+                                                );
 
                                                 // Renderiza gráfico memória
                                                 let memory_chart_renderer =
@@ -361,6 +551,11 @@ pub async fn setup_docker_ui(
                                                         100.0,
                                                     );
                                                 ui.set_memory_chart(memory_chart);
+                                                ui.set_memory_chart_description(
+                                                    chart_description(
+                                                        &chart_data_lock.memory_points,
+                                                    ),
+                                                );
                                             }
                                         }
                                     }
@@ -376,15 +571,22 @@ pub async fn setup_docker_ui(
             }
             Err(_) => {
                 ui.set_docker_status("NotRunning".into());
+                show_offline_snapshot(&ui, &current_context.name);
             }
         }
 
         // Configura callbacks da interface
-        setup_callbacks(ui_weak, app_state.clone());
+        setup_callbacks(ui_weak, app_state.clone(), store);
 
     timer
 }
 
+// Texto de um gráfico de uso (em %) para leitores de tela
+fn chart_description(points: &std::collections::VecDeque<ChartPoint>) -> String {
+    let values: Vec<f32> = points.iter().map(|point| point.value).collect();
+    chart::describe(&values, "%")
+}
+
 // Atualiza informações do Docker na interface
 fn update_docker_info(ui: &AppWindow, info: &DockerInfo) {
     ui.set_total_containers(info.containers as i32);
@@ -393,682 +595,3854 @@ fn update_docker_info(ui: &AppWindow, info: &DockerInfo) {
     ui.set_paused_containers(info.containers_paused as i32);
     ui.set_total_images(info.images as i32);
     ui.set_docker_version(format!("{} | {}", info.version, info.architecture).into());
+    ui.set_server_clock(info.server_clock.clone().into());
+    ui.set_daemon_mirrors(info.registry_mirrors.join(", ").into());
+    // Informação nova do daemon: dados deixam de estar desatualizados
+    ui.set_offline_snapshot("".into());
 }
 
-// Atualiza lista de containers (não implementado)
-fn update_containers_list(_ui: &AppWindow, _containers: &[ContainerInfo]) {
-    // Funcionalidade não implementada ainda
-}
+// Preenche as telas com o último estado salvo do servidor, marcado como desatualizado
+fn show_offline_snapshot(ui: &AppWindow, context_name: &str) {
+    let Some(snapshot) = snapshot::load(context_name) else {
+        return;
+    };
 
-// Converte imagens para formato Slint e atualiza UI
-fn update_ui_images_from_slint(ui: &AppWindow, images: &[SlintImageData]) {
-    let slint_images: Vec<_> = images
-        .iter()
-        .map(|image| ImageData {
-            id: image.id.clone(),
-            tag: image.tag.clone(),
-            size: image.size.clone(),
-            created: image.created.clone(),
-            in_use: image.in_use,
-        })
-        .collect();
+    if let Some(info) = &snapshot.info {
+        update_docker_info(ui, info);
+    }
 
-    let slint_model: std::rc::Rc<slint::VecModel<ImageData>> =
-        std::rc::Rc::new(slint::VecModel::from(slint_images));
+    if let Some(usage) = &snapshot.usage {
+        ui.set_cpu_usage_str(
+            format!("{}% | {}%", format::number(usage.cpu_usage, 2), usage.cpu_online * 100).into(),
+        );
+        ui.set_memory_percentage_str(
+            format::memory(usage.memory_percentage, usage.memory_usage, usage.memory_limit)
+                .into(),
+        );
+        ui.set_network_rx_str(format!("RX {}", format::bytes(usage.network_rx_bytes)).into());
+        ui.set_network_tx_str(format!("TX {}", format::bytes(usage.network_tx_bytes)).into());
+    }
 
-    ui.set_images(slint_model.into());
-}
+    let containers: Vec<SlintContainerData> =
+        snapshot.containers.iter().map(SlintContainerData::from).collect();
+    update_ui_containers_from_slint(ui, &containers);
 
-// Converte networks para formato Slint e atualiza UI
-fn update_ui_networks_from_slint(ui: &AppWindow, networks: &[SlintNetworkData]) {
-    let slint_networks: Vec<_> = networks
-        .iter()
-        .map(|network| NetworkData {
-            id: network.id.clone(),
-            name: network.name.clone(),
-            driver: network.driver.clone(),
-            scope: network.scope.clone(),
-            created: network.created.clone(),
-            containers_count: network.containers_count,
-            is_system: network.is_system,
-        })
-        .collect();
+    let images: Vec<SlintImageData> = snapshot.images.iter().map(SlintImageData::from).collect();
+    update_ui_images_from_slint(ui, &images);
 
-    let slint_model: std::rc::Rc<slint::VecModel<NetworkData>> =
-        std::rc::Rc::new(slint::VecModel::from(slint_networks));
+    let networks: Vec<SlintNetworkData> =
+        snapshot.networks.iter().map(SlintNetworkData::from).collect();
+    update_ui_networks_from_slint(ui, &networks);
 
-    ui.set_networks(slint_model.into());
-}
+    let volumes: Vec<SlintVolumeData> =
+        snapshot.volumes.iter().map(SlintVolumeData::from).collect();
+    update_ui_volumes_from_slint(ui, &volumes);
 
-// Converte volumes para formato Slint e atualiza UI
-fn update_ui_volumes_from_slint(ui: &AppWindow, volumes: &[SlintVolumeData]) {
-    let slint_volumes: Vec<_> = volumes
-        .iter()
-        .map(|volume| VolumeData {
-            name: volume.name.clone(),
-            driver: volume.driver.clone(),
-            mountpoint: volume.mountpoint.clone(),
-            created: volume.created.clone(),
-            containers_count: volume.containers_count,
-        })
-        .collect();
+    ui.set_offline_snapshot(
+        format!(
+            "Servidor '{}' inacessível — exibindo dados desatualizados de {}",
+            context_name,
+            snapshot.saved_at_label()
+        )
+        .into(),
+    );
+}
 
-    let slint_model: std::rc::Rc<slint::VecModel<VolumeData>> =
-        std::rc::Rc::new(slint::VecModel::from(slint_volumes));
+// Atualiza cabeçalho com o servidor ativo
+fn update_context_header(ui: &AppWindow, context: &DockerContextConfig) {
+    ui.set_context_name(context.name.clone().into());
+    ui.set_context_host(context.display_host().into());
+    ui.set_context_url_host(context.url_host().into());
+    // O disco do servidor anterior não vale para o novo
+    ui.set_disk_summary("".into());
+    ui.set_disk_warning(false);
+    ui.set_context_is_remote(context.is_remote());
+    // Nem o horário da última leitura das telas
+    ui.set_containers_updated("".into());
+    ui.set_images_updated("".into());
+    ui.set_networks_updated("".into());
+    ui.set_volumes_updated("".into());
+    ui.set_stacks_updated("".into());
+    ui.set_swarm_updated("".into());
+    // Os widgets do servidor anterior também não
+    ui.set_dashboard_editing(false);
+    ui.set_dashboard_widgets(
+        std::rc::Rc::new(slint::VecModel::<DashboardWidgetData>::default()).into(),
+    );
+    let config = AppConfig::load();
+    update_proxy_settings(ui, &config, &context.name);
+    let metrics = config.metrics_sources.get(&context.name).cloned().unwrap_or_default();
+    ui.set_metrics_url(metrics.prometheus_url.into());
+    ui.set_metrics_selector(metrics.selector.into());
+    ui.set_registry_mirror(config.registry_mirror_for(&context.name).unwrap_or_default().into());
+    // Os mirrors do daemon anterior não valem para o novo; voltam no próximo docker info
+    ui.set_daemon_mirrors("".into());
+    ui.set_pull_policy(config.pull_policy_for(&context.name).as_str().into());
+    ui.set_low_bandwidth(config.is_low_bandwidth(&context.name));
+    update_maintenance(ui, &config, &context.name);
+    update_container_groups(ui, &config, &context.name);
+    update_dashboard_layout(ui, &config, &context.name);
+    update_server_annotation(ui, &Annotations::load(), &context.name);
+}
 
-    ui.set_volumes(slint_model.into());
+// Nota e tags do servidor ativo (cabeçalho e configurações)
+fn update_server_annotation(ui: &AppWindow, annotations: &Annotations, context_name: &str) {
+    let annotation = annotations.server(context_name).cloned().unwrap_or_default();
+    ui.set_server_note(annotation.note.into());
+    ui.set_server_tags(to_tag_data(&annotation.tags));
 }
 
-// Converte containers para formato Slint e atualiza UI
-fn update_ui_containers_from_slint(ui: &AppWindow, containers: &[SlintContainerData]) {
-    let slint_containers: Vec<_> = containers
-        .iter()
-        .map(|container| ContainerData {
-            name: container.name.clone(),
-            image: container.image.clone(),
-            status: container.status.clone(),
-            ports: container.ports.clone(),
-            created: container.created.clone(),
-        })
-        .collect();
+// Configura edição de notas e tags do container selecionado e do servidor ativo
+fn setup_annotation_callbacks(ui_weak: Weak<AppWindow>, store: UiStore) {
+    let Some(ui) = ui_weak.upgrade() else {
+        return;
+    };
+
+    // Aplica a alteração ao alvo do escopo e atualiza cabeçalho/detalhes
+    let apply = {
+        let ui_weak = ui_weak.clone();
+        move |scope: &str, change: &dyn Fn(&mut Annotation) -> anyhow::Result<()>| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            let selected = ui.get_selected_container();
+            let target = if scope == "server" {
+                AnnotationTarget::Server(ui.get_context_name().to_string())
+            } else {
+                AnnotationTarget::Container {
+                    server: selected.server.to_string(),
+                    id: selected.id.to_string(),
+                }
+            };
+
+            let mut annotations = Annotations::load();
+            match annotations.update(&target, |annotation| change(annotation)) {
+                Ok(()) => ui.set_annotation_error("".into()),
+                Err(e) => ui.set_annotation_error(e.to_string().into()),
+            }
+
+            match target {
+                AnnotationTarget::Server(name) => {
+                    update_server_annotation(&ui, &annotations, &name);
+                }
+                AnnotationTarget::Container { server, id } => {
+                    let annotation = annotations
+                        .container(&server, &id)
+                        .cloned()
+                        .unwrap_or_default();
+                    let mut selected = selected;
+                    selected.note = annotation.note.into();
+                    selected.tags = to_tag_data(&annotation.tags);
+                    store.dispatch(&ui, Transition::UpdateSelected(selected));
+                }
+            }
+        }
+    };
+    let apply = std::rc::Rc::new(apply);
+
+    ui.on_save_annotation_note({
+        let apply = apply.clone();
+        move |scope, note| {
+            apply(&scope, &|annotation: &mut Annotation| {
+                annotation.note = note.trim().to_string();
+                Ok(())
+            });
+        }
+    });
 
-    let slint_model: std::rc::Rc<slint::VecModel<ContainerData>> =
-        std::rc::Rc::new(slint::VecModel::from(slint_containers));
+    ui.on_add_annotation_tag({
+        let apply = apply.clone();
+        move |scope, name, color| {
+            apply(&scope, &|annotation: &mut Annotation| {
+                annotation.set_tag(&name, &color)
+            });
+        }
+    });
 
-    ui.set_containers(slint_model.into());
+    ui.on_remove_annotation_tag(move |scope, name| {
+        apply(&scope, &|annotation: &mut Annotation| {
+            annotation.remove_tag(&name);
+            Ok(())
+        });
+    });
 }
 
-// Configura callbacks específicos para containers
-fn setup_container_callbacks(
+// Configura exportação dos containers selecionados como docker-compose.yml
+fn setup_compose_export_callbacks(
     ui_weak: Weak<AppWindow>,
-    container_ui_manager: Arc<tokio::sync::Mutex<ContainerUIManager>>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
 ) {
-    let ui = ui_weak.upgrade().unwrap();
+    let Some(ui) = ui_weak.upgrade() else {
+        return;
+    };
 
-    // Callback para mudança na busca de containers
-    ui.on_search_changed({
+    ui.on_open_compose_export({
         let ui_weak = ui_weak.clone();
-        let container_manager = container_ui_manager.clone();
-        move |search_text| {
-            let ui_weak_clone = ui_weak.clone();
-            let container_manager_clone = container_manager.clone();
-            let search_string = search_text.to_string();
-
-            tokio::spawn(async move {
-                let mut manager = container_manager_clone.lock().await;
-                manager.set_search_filter(search_string);
-                let filtered_containers = manager.get_filtered_containers();
-                slint::invoke_from_event_loop(move || {
-                    if let Some(ui) = ui_weak_clone.upgrade() {
-                        update_ui_containers_from_slint(&ui, &filtered_containers);
-                    }
+        move || {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            let items: Vec<ComposeExportItem> = ui
+                .get_containers()
+                .iter()
+                .map(|container| ComposeExportItem {
+                    name: container.name,
+                    image: container.image,
+                    selected: false,
                 })
-                .unwrap();
-            });
+                .collect();
+            ui.set_compose_export_items(std::rc::Rc::new(slint::VecModel::from(items)).into());
+            ui.set_compose_export_count(0);
+            ui.set_compose_export_error("".into());
+            if ui.get_compose_export_path().is_empty() {
+                ui.set_compose_export_path(compose_export::default_path().into());
+            }
+            ui.set_show_compose_export(true);
         }
     });
 
-    // Callback para mudança no filtro de status
-    ui.on_filter_changed({
+    ui.on_toggle_compose_export({
         let ui_weak = ui_weak.clone();
-        let container_manager = container_ui_manager.clone();
-        move |status_filter| {
-            let ui_weak_clone = ui_weak.clone();
-            let container_manager_clone = container_manager.clone();
-            let status_string = status_filter.to_string();
-
-            tokio::spawn(async move {
-                let mut manager = container_manager_clone.lock().await;
-                manager.set_status_filter(status_string);
-                let filtered_containers = manager.get_filtered_containers();
-                slint::invoke_from_event_loop(move || {
-                    if let Some(ui) = ui_weak_clone.upgrade() {
-                        update_ui_containers_from_slint(&ui, &filtered_containers);
-                    }
-                })
-                .unwrap();
-            });
+        move |index| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            let items = ui.get_compose_export_items();
+            let Some(mut item) = items.row_data(index as usize) else {
+                return;
+            };
+            item.selected = !item.selected;
+            items.set_row_data(index as usize, item);
+            ui.set_compose_export_count(items.iter().filter(|item| item.selected).count() as i32);
         }
     });
 
-    // Callback para ações em containers
-    ui.on_container_action({
-        let ui_weak = ui_weak.clone();
-        let container_manager = container_ui_manager.clone();
-        move |container_name, action| {
-            let ui_weak_clone = ui_weak.clone();
-            let container_manager_clone = container_manager.clone();
-            let container_name_str = container_name.to_string();
-            let action_str = action.to_string();
-            let loading_key = format!("{}_{}", container_name_str, action_str);
+    ui.on_export_compose(move |path| {
+        let Some(ui) = ui_weak.upgrade() else {
+            return;
+        };
+        let names: Vec<String> = ui
+            .get_compose_export_items()
+            .iter()
+            .filter(|item| item.selected)
+            .map(|item| item.name.to_string())
+            .collect();
 
-            tokio::spawn(async move {
-                // Define o estado de loading
-                let ui_weak_loading = ui_weak_clone.clone();
-                let loading_key_clone = loading_key.clone();
-                slint::invoke_from_event_loop(move || {
-                    if let Some(ui) = ui_weak_loading.upgrade() {
-                        ui.set_container_loading(loading_key_clone.into());
-                        ui.set_container_error("".into());
-                        ui.set_container_success("".into());
-                    }
-                })
-                .unwrap();
+        ui.set_exporting_compose(true);
+        ui.set_compose_export_error("".into());
 
-                let (success, error_message) = {
-                    let manager = container_manager_clone.lock().await;
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        tokio::spawn(async move {
+            let result = async {
+                let sources = docker_manager.lock().await.inspect_for_export(&names).await?;
+                let yaml = compose_export::to_compose_yaml(&sources)?;
+                compose_export::write_file(&path, &yaml)
+            }
+            .await;
 
-                    // Executa a ação no container
-                    match manager
-                        .execute_container_action(&container_name_str, &action_str)
-                        .await
-                    {
-                        Ok(()) => (true, None),
-                        Err(e) => (false, Some(e.to_string())),
-                    }
+            slint::invoke_from_event_loop(move || {
+                let Some(ui) = ui_weak.upgrade() else {
+                    return;
                 };
-
-                // Limpa o loading e trata resultado
-                let ui_weak_result = ui_weak_clone.clone();
-                if success {
-                    let success_msg = match action_str.as_str() {
-                        "start" => {
-                            format!("Container '{}' iniciado com sucesso", container_name_str)
-                        }
-                        "stop" => format!("Container '{}' parado com sucesso", container_name_str),
-                        "pause" => {
-                            format!("Container '{}' pausado com sucesso", container_name_str)
-                        }
-                        "unpause" => {
-                            format!("Container '{}' despausado com sucesso", container_name_str)
-                        }
-                        "remove" => {
-                            format!("Container '{}' removido com sucesso", container_name_str)
-                        }
-                        _ => format!(
-                            "Ação '{}' executada com sucesso no container '{}'",
-                            action_str, container_name_str
-                        ),
-                    };
-
-                    slint::invoke_from_event_loop(move || {
-                        if let Some(ui) = ui_weak_result.upgrade() {
-                            ui.set_container_loading("".into());
-                            ui.set_notification_message(success_msg.into());
-                            ui.set_notification_is_error(false);
-                            ui.set_show_notification(true);
-                        }
-                    })
-                    .unwrap();
-
-                    // Timer para limpar mensagem de sucesso após 3 segundos
-                    let ui_weak_timer = ui_weak_clone.clone();
-                    tokio::spawn(async move {
-                        tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
-                        slint::invoke_from_event_loop(move || {
-                            if let Some(ui) = ui_weak_timer.upgrade() {
-                                ui.set_show_notification(false);
-                            }
-                        })
-                        .unwrap();
-                    });
-                } else if let Some(error) = error_message {
-                    let error_msg = format!(
-                        "Erro ao executar '{}' no container '{}': {}",
-                        action_str, container_name_str, error
-                    );
-                    slint::invoke_from_event_loop(move || {
-                        if let Some(ui) = ui_weak_result.upgrade() {
-                            ui.set_container_loading("".into());
-                            ui.set_notification_message(error_msg.into());
-                            ui.set_notification_is_error(true);
-                            ui.set_show_notification(true);
-                        }
-                    })
-                    .unwrap();
-                }
-
-                // Atualiza a lista imediatamente após a ação bem-sucedida
-                if success {
-                    let mut manager = container_manager_clone.lock().await;
-                    if let Ok(()) = manager.refresh_containers().await {
-                        let filtered_containers = manager.get_filtered_containers();
-                        let ui_weak_final = ui_weak_clone.clone();
-                        slint::invoke_from_event_loop(move || {
-                            if let Some(ui) = ui_weak_final.upgrade() {
-                                update_ui_containers_from_slint(&ui, &filtered_containers);
-                            }
-                        })
-                        .unwrap();
+                ui.set_exporting_compose(false);
+                match result {
+                    Ok(()) => {
+                        ui.set_show_compose_export(false);
+                        ui.set_notification_message(
+                            format!("{} container(s) exportado(s) para {}", names.len(), path).into(),
+                        );
+                        ui.set_notification_is_error(false);
+                        ui.set_show_notification(true);
                     }
+                    Err(e) => ui.set_compose_export_error(e.to_string().into()),
                 }
-            });
-        }
+            })
+            .unwrap();
+        });
     });
 }
 
-// Configura callbacks específicos para imagens
-fn setup_image_callbacks(
+// Configura a gravação de sessão dos containers selecionados (uma por vez)
+fn setup_session_recording_callbacks(
     ui_weak: Weak<AppWindow>,
-    image_ui_manager: Arc<tokio::sync::Mutex<ImageUIManager>>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
 ) {
-    let ui = ui_weak.upgrade().unwrap();
+    let Some(ui) = ui_weak.upgrade() else {
+        return;
+    };
+    // Marcado por "Parar"; a gravação confere a cada segundo
+    let stop = Arc::new(AtomicBool::new(false));
 
-    // Callback para refresh de imagens
-    ui.on_refresh_images_clicked({
+    ui.on_open_session_recording({
         let ui_weak = ui_weak.clone();
-        let image_manager = image_ui_manager.clone();
         move || {
-            let ui_weak_clone = ui_weak.clone();
-            let image_manager_clone = image_manager.clone();
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            // Durante a gravação o modal só mostra o andamento
+            if !ui.get_session_recording() {
+                let items: Vec<ComposeExportItem> = ui
+                    .get_containers()
+                    .iter()
+                    .map(|container| ComposeExportItem {
+                        name: container.name,
+                        image: container.image,
+                        selected: false,
+                    })
+                    .collect();
+                ui.set_session_items(std::rc::Rc::new(slint::VecModel::from(items)).into());
+                ui.set_session_count(0);
+                ui.set_session_error("".into());
+                ui.set_session_status("".into());
+                ui.set_session_path(session_recording::default_path().into());
+            }
+            ui.set_show_session_recording(true);
+        }
+    });
 
-            tokio::spawn(async move {
-                let mut manager = image_manager_clone.lock().await;
-                match manager.refresh_images().await {
-                    Ok(()) => {
-                        let images = manager.get_images();
-                        slint::invoke_from_event_loop(move || {
-                            if let Some(ui) = ui_weak_clone.upgrade() {
-                                ui.set_image_list_error("".into());
-                                update_ui_images_from_slint(&ui, &images);
-                            }
-                        })
-                        .unwrap();
-                    }
-                    Err(e) => {
-                        let error_message = e.to_string();
-                        slint::invoke_from_event_loop(move || {
-                            if let Some(ui) = ui_weak_clone.upgrade() {
-                                ui.set_image_list_error(error_message.into());
-                            }
-                        })
-                        .unwrap();
-                    }
-                }
-            });
+    ui.on_toggle_session_item({
+        let ui_weak = ui_weak.clone();
+        move |index| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            let items = ui.get_session_items();
+            let Some(mut item) = items.row_data(index as usize) else {
+                return;
+            };
+            item.selected = !item.selected;
+            items.set_row_data(index as usize, item);
+            ui.set_session_count(items.iter().filter(|item| item.selected).count() as i32);
+        }
+    });
+
+    ui.on_stop_session_recording({
+        let stop = stop.clone();
+        move || {
+            stop.store(true, Ordering::Relaxed);
         }
     });
 
-    // Callback para ações em imagens
-    ui.on_image_action({
+    ui.on_start_session_recording(move |path, minutes| {
+        let Some(ui) = ui_weak.upgrade() else {
+            return;
+        };
+        if ui.get_session_recording() {
+            return;
+        }
+        let request = RecordingRequest {
+            containers: ui
+                .get_session_items()
+                .iter()
+                .filter(|item| item.selected)
+                .map(|item| item.name.to_string())
+                .collect(),
+            duration: Duration::from_secs(minutes.max(1) as u64 * 60),
+            path: path.to_string(),
+        };
+        if let Err(e) = request.validate() {
+            ui.set_session_error(e.to_string().into());
+            return;
+        }
+
+        stop.store(false, Ordering::Relaxed);
+        ui.set_session_recording(true);
+        ui.set_session_error("".into());
+        ui.set_session_status("Iniciando gravação...".into());
+
         let ui_weak = ui_weak.clone();
-        let image_manager = image_ui_manager.clone();
-        move |image_id, action| {
-            let ui_weak_clone = ui_weak.clone();
-            let image_manager_clone = image_manager.clone();
-            let image_id_str = image_id.to_string();
-            let action_str = action.to_string();
-            let loading_key = format!("{}_{}", image_id_str, action_str);
+        let docker_manager = docker_manager.clone();
+        let stop = stop.clone();
+        tokio::spawn(async move {
+            let sources = {
+                let manager = docker_manager.lock().await;
+                RecordingSources {
+                    server: manager.context().name.clone(),
+                    client: manager.api_client(),
+                    events: manager.container_events_command(&request.containers),
+                    logs: request
+                        .containers
+                        .iter()
+                        .map(|name| (name.clone(), manager.follow_logs_command(name)))
+                        .collect(),
+                }
+            };
 
-            tokio::spawn(async move {
-                // Define o estado de loading
-                let ui_weak_loading = ui_weak_clone.clone();
-                let loading_key_clone = loading_key.clone();
+            let total = session_replay::clock(request.duration.as_secs_f64());
+            let progress_ui = ui_weak.clone();
+            let result = session_recording::record(sources, &request, stop, |progress| {
+                let status = format!(
+                    "Gravando {} / {} · {} registros",
+                    session_replay::clock(progress.elapsed.as_secs_f64()),
+                    total,
+                    progress.records
+                );
+                let ui_weak = progress_ui.clone();
                 slint::invoke_from_event_loop(move || {
-                    if let Some(ui) = ui_weak_loading.upgrade() {
-                        ui.set_image_loading(loading_key_clone.into());
+                    if let Some(ui) = ui_weak.upgrade() {
+                        ui.set_session_status(status.into());
                     }
                 })
                 .unwrap();
+            })
+            .await;
 
-                let manager = image_manager_clone.lock().await;
-
-                // Executa a ação na imagem
-                let result = manager
-                    .execute_image_action(&image_id_str, &action_str)
-                    .await;
-
-                let ui_weak_result = ui_weak_clone.clone();
+            slint::invoke_from_event_loop(move || {
+                let Some(ui) = ui_weak.upgrade() else {
+                    return;
+                };
+                ui.set_session_recording(false);
                 match result {
-                    Ok(success_message) => {
-                        slint::invoke_from_event_loop(move || {
-                            if let Some(ui) = ui_weak_result.upgrade() {
-                                ui.set_image_loading("".into());
-                                ui.set_notification_message(success_message.into());
-                                ui.set_notification_is_error(false);
-                                ui.set_show_notification(true);
-                            }
-                        })
-                        .unwrap();
-
-                        // Timer para limpar mensagem de sucesso após 3 segundos
-                        let ui_weak_timer = ui_weak_clone.clone();
-                        tokio::spawn(async move {
-                            tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
-                            slint::invoke_from_event_loop(move || {
-                                if let Some(ui) = ui_weak_timer.upgrade() {
-                                    ui.set_show_notification(false);
-                                }
-                            })
-                            .unwrap();
-                        });
+                    Ok(report) => {
+                        let message = format!(
+                            "Sessão gravada em {} ({} registros em {}{})",
+                            request.path.trim(),
+                            report.records,
+                            session_replay::clock(report.elapsed.as_secs_f64()),
+                            if report.stopped_early { ", encerrada antes do prazo" } else { "" }
+                        );
+                        ui.set_session_status(message.clone().into());
+                        ui.set_notification_message(message.into());
+                        ui.set_notification_is_error(false);
                     }
-                    Err(error_message) => {
-                        let error_message_clone = error_message.clone();
-                        slint::invoke_from_event_loop(move || {
-                            if let Some(ui) = ui_weak_result.upgrade() {
-                                ui.set_image_loading("".into());
-                                let formatted_error = if error_message_clone.starts_with("IN_USE:")
-                                {
-                                    error_message_clone
-                                        .strip_prefix("IN_USE:")
-                                        .unwrap_or(&error_message_clone)
-                                        .to_string()
-                                } else {
-                                    error_message_clone
-                                        .strip_prefix("OTHER_ERROR:")
-                                        .unwrap_or(&error_message_clone)
-                                        .to_string()
-                                };
-                                ui.set_notification_message(formatted_error.into());
-                                ui.set_notification_is_error(true);
-                                ui.set_show_notification(true);
-                            }
-                        })
-                        .unwrap();
+                    Err(e) => {
+                        ui.set_session_status("".into());
+                        ui.set_session_error(e.to_string().into());
+                        ui.set_notification_message(format!("Falha na gravação da sessão:\n{}", e).into());
+                        ui.set_notification_is_error(true);
                     }
                 }
+                ui.set_show_notification(true);
+            })
+            .unwrap();
+        });
+    });
+}
 
-                // Atualiza a lista imediatamente após a ação
-                drop(manager); // Libera o lock
-                let mut manager = image_manager_clone.lock().await;
-                if let Ok(()) = manager.refresh_images().await {
-                    let images = manager.get_images();
-                    let ui_weak_final = ui_weak_clone.clone();
-                    slint::invoke_from_event_loop(move || {
-                        if let Some(ui) = ui_weak_final.upgrade() {
-                            update_ui_images_from_slint(&ui, &images);
-                        }
-                    })
-                    .unwrap();
-                }
-            });
+// Sessão aberta na reprodução e posição do cursor
+#[derive(Default)]
+struct ReplayState {
+    session: Option<RecordedSession>,
+    container: String,
+    // Segundos desde o início da gravação
+    cursor: f64,
+}
+
+// Atualiza gráficos, eventos e logs da reprodução na posição do cursor
+fn render_session_replay(ui: &AppWindow, state: &ReplayState, renderers: &[ChartRenderer; 2]) {
+    let Some(session) = &state.session else {
+        return;
+    };
+    let duration = session.duration_secs();
+    let frame = session.frame(&state.container, state.cursor);
+
+    ui.set_replay_position((state.cursor / duration) as f32);
+    ui.set_replay_clock(
+        format!(
+            "{} / {}",
+            session_replay::clock(state.cursor),
+            session_replay::clock(duration)
+        )
+        .into(),
+    );
+    ui.set_replay_cpu_chart(renderers[0].render_line_chart(&frame.cpu, 100.0));
+    ui.set_replay_memory_chart(renderers[1].render_line_chart(&frame.memory, 100.0));
+    ui.set_replay_cpu(frame.cpu_label.into());
+    ui.set_replay_memory(frame.memory_label.into());
+
+    let events: Vec<ReplayEventData> = frame
+        .events
+        .iter()
+        .map(|event| ReplayEventData {
+            time: session_replay::clock(session.offset(event.at)).into(),
+            action: event.action.clone().into(),
+            detail: event.detail.clone().into(),
+        })
+        .collect();
+    ui.set_replay_events(std::rc::Rc::new(slint::VecModel::from(events)).into());
+    let logs: Vec<ReplayLogData> = frame
+        .logs
+        .iter()
+        .map(|log| ReplayLogData {
+            time: session_replay::clock(session.offset(log.at)).into(),
+            line: log.line.clone().into(),
+            stderr: log.stderr,
+        })
+        .collect();
+    ui.set_replay_logs(std::rc::Rc::new(slint::VecModel::from(logs)).into());
+}
+
+// Configura a instalação guiada do Docker via SSH; não usa a conexão ativa, só o contexto
+// salvo do servidor escolhido
+// Formulário de servidores: cria ou edita contextos do Docker CLI
+fn setup_server_form_callbacks(ui_weak: Weak<AppWindow>) {
+    let Some(ui) = ui_weak.upgrade() else {
+        return;
+    };
+
+    ui.on_new_server({
+        let ui_weak = ui_weak.clone();
+        move || {
+            if let Some(ui) = ui_weak.upgrade() {
+                ui.set_server_form_editing(false);
+                ui.set_server_form_name("".into());
+                ui.set_server_form_host("".into());
+                ui.set_server_form_description("".into());
+                ui.set_server_form_error("".into());
+            }
+        }
+    });
+
+    ui.on_edit_server({
+        let ui_weak = ui_weak.clone();
+        move |name| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            let Some(context) = DockerContextConfig::list_available()
+                .into_iter()
+                .find(|context| context.name == name.as_str())
+            else {
+                return;
+            };
+            ui.set_server_form_editing(true);
+            ui.set_server_form_name(context.name.into());
+            ui.set_server_form_host(context.host.into());
+            ui.set_server_form_description(context.description.into());
+            ui.set_server_form_error("".into());
+        }
+    });
+
+    // Endpoint inválido não chega ao docker context: o erro fica no formulário
+    ui.on_save_server(move |name, host, description| {
+        let Some(ui) = ui_weak.upgrade() else {
+            return;
+        };
+        let context = DockerContextConfig {
+            name: name.trim().to_string(),
+            host: host.trim().to_string(),
+            description: description.trim().to_string(),
+        };
+        match context.save() {
+            Ok(()) => {
+                ui.set_server_form_error("".into());
+                ui.set_server_form_editing(true);
+                update_available_contexts(&ui, &DockerContextConfig::list_available());
+                ui.set_notification_message(
+                    format!(
+                        "Servidor '{}' salvo. Se estiver ativo, o novo endpoint vale ao reconectar.",
+                        context.name
+                    )
+                    .into(),
+                );
+                ui.set_notification_is_error(false);
+                ui.set_show_notification(true);
+            }
+            Err(e) => ui.set_server_form_error(e.to_string().into()),
         }
     });
 }
 
-// Configura callbacks específicos para networks
-fn setup_network_callbacks(
-    ui_weak: Weak<AppWindow>,
-    network_ui_manager: Arc<tokio::sync::Mutex<NetworkUIManager>>,
-) {
-    let ui = ui_weak.upgrade().unwrap();
+fn setup_docker_install_callbacks(ui_weak: Weak<AppWindow>) {
+    let Some(ui) = ui_weak.upgrade() else {
+        return;
+    };
 
-    // Callback para refresh de networks
-    ui.on_refresh_networks_clicked({
+    ui.on_open_docker_install({
         let ui_weak = ui_weak.clone();
-        let network_manager = network_ui_manager.clone();
         move || {
-            let ui_weak_clone = ui_weak.clone();
-            let network_manager_clone = network_manager.clone();
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            let servers: Vec<slint::SharedString> = docker_install::ssh_servers()
+                .into_iter()
+                .map(|context| context.name.into())
+                .collect();
+            // Servidor ativo vem pré-selecionado quando é ssh://
+            let active = ui.get_context_name();
+            let server = servers
+                .iter()
+                .find(|name| **name == active)
+                .cloned()
+                .unwrap_or_default();
+            ui.set_install_servers(std::rc::Rc::new(slint::VecModel::from(servers)).into());
+            ui.set_install_server(server.clone());
+            ui.set_install_probe_summary("".into());
+            ui.set_install_can_install(false);
+            ui.set_install_output("".into());
+            ui.set_install_status("".into());
+            ui.set_install_error("".into());
+            ui.set_install_result("".into());
+            ui.set_show_docker_install(true);
+            if !server.is_empty() {
+                ui.invoke_probe_install_server(server);
+            }
+        }
+    });
 
+    ui.on_probe_install_server({
+        let ui_weak = ui_weak.clone();
+        move |server| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            ui.set_install_probing(true);
+            ui.set_install_can_install(false);
+            ui.set_install_probe_summary("".into());
+            ui.set_install_error("".into());
+            ui.set_install_result("".into());
+
+            let ui_weak = ui_weak.clone();
+            let server = server.to_string();
             tokio::spawn(async move {
-                let mut manager = network_manager_clone.lock().await;
-                match manager.refresh_networks().await {
-                    Ok(()) => {
-                        let networks = manager.get_networks();
-                        slint::invoke_from_event_loop(move || {
-                            if let Some(ui) = ui_weak_clone.upgrade() {
-                                ui.set_network_list_error("".into());
-                                update_ui_networks_from_slint(&ui, &networks);
-                            }
-                        })
-                        .unwrap();
+                let result = match docker_install::find_server(&server) {
+                    Ok(context) => docker_install::probe(&context).await,
+                    Err(e) => Err(e),
+                };
+                slint::invoke_from_event_loop(move || {
+                    let Some(ui) = ui_weak.upgrade() else {
+                        return;
+                    };
+                    // Outro servidor foi escolhido enquanto este era verificado
+                    if ui.get_install_server() != server.as_str() {
+                        return;
                     }
-                    Err(e) => {
-                        let error_message = e.to_string();
-                        slint::invoke_from_event_loop(move || {
-                            if let Some(ui) = ui_weak_clone.upgrade() {
-                                ui.set_network_list_error(error_message.into());
+                    ui.set_install_probing(false);
+                    match result {
+                        Ok(probe) => {
+                            ui.set_install_probe_summary(probe.summary().into());
+                            ui.set_install_can_install(
+                                probe.can_install() && !probe.docker_installed,
+                            );
+                            if !probe.can_install() {
+                                ui.set_install_error(
+                                    "Sem permissão para instalar: conecte como root ou libere o sudo sem senha (NOPASSWD) para este usuário".into(),
+                                );
                             }
-                        })
-                        .unwrap();
+                        }
+                        Err(e) => ui.set_install_error(e.to_string().into()),
                     }
-                }
+                })
+                .unwrap();
             });
         }
     });
 
-    // Callback para ações em networks
-    ui.on_network_action({
+    ui.on_start_docker_install({
         let ui_weak = ui_weak.clone();
-        let network_manager = network_ui_manager.clone();
-        move |network_id, action| {
-            let ui_weak_clone = ui_weak.clone();
-            let network_manager_clone = network_manager.clone();
-            let network_id_str = network_id.to_string();
-            let action_str = action.to_string();
-            let loading_key = format!("{}_{}", network_id_str, action_str);
-
+        move |server, method| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            ui.set_install_running(true);
+            ui.set_install_output("".into());
+            ui.set_install_status("Verificando o host...".into());
+            ui.set_install_error("".into());
+            ui.set_install_result("".into());
+
+            let ui_weak = ui_weak.clone();
+            let server = server.to_string();
+            let method = InstallMethod::from_ui(&method);
             tokio::spawn(async move {
-                // Define o estado de loading
-                let ui_weak_loading = ui_weak_clone.clone();
-                let loading_key_clone = loading_key.clone();
-                slint::invoke_from_event_loop(move || {
-                    if let Some(ui) = ui_weak_loading.upgrade() {
-                        ui.set_network_loading(loading_key_clone.into());
-                    }
-                })
-                .unwrap();
-
-                let manager = network_manager_clone.lock().await;
-
-                // Executa a ação na network
-                let result = manager
-                    .execute_network_action(&network_id_str, &action_str)
-                    .await;
-
-                let ui_weak_result = ui_weak_clone.clone();
-                match result {
-                    Ok(success_message) => {
-                        slint::invoke_from_event_loop(move || {
-                            if let Some(ui) = ui_weak_result.upgrade() {
-                                ui.set_network_loading("".into());
-                                ui.set_notification_message(success_message.into());
-                                ui.set_notification_is_error(false);
-                                ui.set_show_notification(true);
-                            }
-                        })
-                        .unwrap();
+                let set_status = |status: &str| {
+                    let ui_weak = ui_weak.clone();
+                    let status = status.to_string();
+                    slint::invoke_from_event_loop(move || {
+                        if let Some(ui) = ui_weak.upgrade() {
+                            ui.set_install_status(status.into());
+                        }
+                    })
+                    .unwrap();
+                };
 
-                        // Timer para limpar mensagem de sucesso após 3 segundos
-                        let ui_weak_timer = ui_weak_clone.clone();
-                        tokio::spawn(async move {
-                            tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
+                let result: anyhow::Result<String> = async {
+                    let context = docker_install::find_server(&server)?;
+                    // Verifica de novo: o host pode ter mudado desde a última verificação
+                    let probe = docker_install::probe(&context).await?;
+                    if probe.docker_installed {
+                        anyhow::bail!("O Docker já está instalado em {}", server);
+                    }
+                    let script = docker_install::install_script(method, &probe)?;
+
+                    set_status("Instalando...");
+                    let mut output = Vec::<String>::new();
+                    let mut last_refresh = std::time::Instant::now();
+                    let installed = docker_install::run_install(&context, &script, |line| {
+                        output.push(line.to_string());
+                        let overflow = output.len().saturating_sub(300);
+                        output.drain(..overflow);
+
+                        // Limita atualizações da interface a ~5 por segundo
+                        if last_refresh.elapsed() >= Duration::from_millis(200) {
+                            last_refresh = std::time::Instant::now();
+                            let text = output.join("\n");
+                            let ui_weak = ui_weak.clone();
                             slint::invoke_from_event_loop(move || {
-                                if let Some(ui) = ui_weak_timer.upgrade() {
-                                    ui.set_show_notification(false);
+                                if let Some(ui) = ui_weak.upgrade() {
+                                    ui.set_install_output(text.into());
                                 }
                             })
                             .unwrap();
-                        });
-                    }
-                    Err(error_message) => {
-                        let error_message_clone = error_message.clone();
-                        slint::invoke_from_event_loop(move || {
-                            if let Some(ui) = ui_weak_result.upgrade() {
-                                ui.set_network_loading("".into());
-                                let formatted_error = if error_message_clone.starts_with("IN_USE:")
-                                {
-                                    error_message_clone
-                                        .strip_prefix("IN_USE:")
-                                        .unwrap_or(&error_message_clone)
-                                        .to_string()
-                                } else {
-                                    error_message_clone
-                                        .strip_prefix("OTHER_ERROR:")
-                                        .unwrap_or(&error_message_clone)
-                                        .to_string()
-                                };
-                                ui.set_notification_message(formatted_error.into());
-                                ui.set_notification_is_error(true);
-                                ui.set_show_notification(true);
-                            }
-                        })
-                        .unwrap();
-                    }
-                }
-
-                // Atualiza a lista imediatamente após a ação
-                drop(manager); // Libera o lock
-                let mut manager = network_manager_clone.lock().await;
-                if let Ok(()) = manager.refresh_networks().await {
-                    let networks = manager.get_networks();
-                    let ui_weak_final = ui_weak_clone.clone();
+                        }
+                    })
+                    .await;
+                    // A saída final fica visível também quando a instalação falha
+                    let text = output.join("\n");
+                    let output_ui = ui_weak.clone();
                     slint::invoke_from_event_loop(move || {
-                        if let Some(ui) = ui_weak_final.upgrade() {
-                            update_ui_networks_from_slint(&ui, &networks);
+                        if let Some(ui) = output_ui.upgrade() {
+                            ui.set_install_output(text.into());
                         }
                     })
                     .unwrap();
+                    installed?;
+
+                    set_status("Conferindo a conexão com o daemon...");
+                    docker_install::verify(&context).await
+                }
+                .await;
+
+                // Instalar o Docker altera o host: fica registrado na auditoria
+                let (detail, success) = match &result {
+                    Ok(version) => (format!("{}: {}", method.label(), version), true),
+                    Err(e) => (format!("{}: {}", method.label(), e), false),
+                };
+                if let Err(e) = audit_log::append(&AuditEntry::new(
+                    &server,
+                    "docker.install",
+                    detail,
+                    success,
+                )) {
+                    eprintln!("Error writing audit log: {}", e);
                 }
+
+                slint::invoke_from_event_loop(move || {
+                    let Some(ui) = ui_weak.upgrade() else {
+                        return;
+                    };
+                    ui.set_install_running(false);
+                    ui.set_install_status("".into());
+                    match result {
+                        Ok(version) => {
+                            ui.set_install_can_install(false);
+                            ui.set_install_result(
+                                format!("{} respondendo em {}", version, server).into(),
+                            );
+                            ui.set_notification_message(
+                                format!("Docker instalado em '{}'", server).into(),
+                            );
+                            ui.set_notification_is_error(false);
+                            ui.set_show_notification(true);
+                        }
+                        Err(e) => ui.set_install_error(e.to_string().into()),
+                    }
+                })
+                .unwrap();
             });
         }
     });
 }
 
-// Configura callbacks específicos para volumes
-fn setup_volume_callbacks(
-    ui_weak: Weak<AppWindow>,
-    volume_ui_manager: Arc<tokio::sync::Mutex<VolumeUIManager>>,
-) {
-    let ui = ui_weak.upgrade().unwrap();
+// Linhas mantidas no widget de eventos do dashboard
+const EVENT_FEED_LINES: usize = 8;
+
+// Eventos de containers já exibidos no dashboard do servidor atual
+#[derive(Default)]
+struct EventFeed {
+    server: String,
+    // Início da próxima consulta (segundos Unix)
+    since: i64,
+    // Mais recente primeiro, ex: "14:32:05 api die (exitCode=137)"
+    lines: VecDeque<String>,
+}
 
-    // Callback para refresh de volumes
-    ui.on_refresh_volumes_clicked({
-        let ui_weak = ui_weak.clone();
-        let volume_manager = volume_ui_manager.clone();
-        move || {
-            let ui_weak_clone = ui_weak.clone();
-            let volume_manager_clone = volume_manager.clone();
+// Widgets do layout salvo do servidor, mantendo os valores já coletados
+fn update_dashboard_layout(ui: &AppWindow, config: &AppConfig, context_name: &str) {
+    let previous: Vec<DashboardWidgetData> = ui.get_dashboard_widgets().iter().collect();
+    let widgets: Vec<DashboardWidgetData> = config
+        .dashboard_layout_for(context_name)
+        .iter()
+        .map(|widget| {
+            previous
+                .iter()
+                .find(|data| data.kind == widget.kind() && data.target == widget.target())
+                .cloned()
+                .unwrap_or_else(|| DashboardWidgetData {
+                    kind: widget.kind().into(),
+                    target: widget.target().into(),
+                    title: widget.title().into(),
+                    ..Default::default()
+                })
+        })
+        .collect();
+    ui.set_dashboard_widgets(std::rc::Rc::new(slint::VecModel::from(widgets)).into());
+}
 
-            tokio::spawn(async move {
-                let mut manager = volume_manager_clone.lock().await;
-                match manager.refresh_volumes().await {
-                    Ok(()) => {
-                        let volumes = manager.get_volumes();
+// Coleta os valores dos widgets que dependem do servidor (stacks, eventos e memória de
+// containers); gráficos do sistema e disco vêm das propriedades do dashboard padrão
+fn refresh_dashboard_widgets(
+    ui: &AppWindow,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+    feed: Arc<std::sync::Mutex<EventFeed>>,
+) {
+    let layout: Vec<DashboardWidget> = ui
+        .get_dashboard_widgets()
+        .iter()
+        .filter_map(|data| DashboardWidget::from_ui(&data.kind, &data.target).ok())
+        .collect();
+    if layout.is_empty() {
+        return;
+    }
+    let server = ui.get_context_name().to_string();
+    let ui_weak = ui.as_weak();
+
+    tokio::spawn(async move {
+        let mut stacks: HashMap<String, (HealthLevel, String)> = HashMap::new();
+        let mut deploys: HashMap<String, Option<DeployComparison>> = HashMap::new();
+        let mut events = None;
+        {
+            let manager = docker_manager.lock().await;
+            for widget in &layout {
+                match widget {
+                    DashboardWidget::StackHealth { project } => {
+                        let summary = match manager.compose_containers(project).await {
+                            Ok(containers) => dashboard_layout::stack_summary(
+                                &compose::service_matrix(&containers),
+                            ),
+                            Err(e) => (HealthLevel::Red, e.to_string()),
+                        };
+                        stacks.insert(project.clone(), summary);
+                    }
+                    DashboardWidget::ContainerMemory { container } => {
+                        deploys.insert(container.clone(), manager.deploy_comparison(container));
+                    }
+                    DashboardWidget::EventFeed => {
+                        let now = chrono::Utc::now().timestamp();
+                        let since = {
+                            let mut feed = feed.lock().unwrap();
+                            // Servidor trocado: o feed recomeça com o último minuto
+                            if feed.server != server {
+                                *feed = EventFeed {
+                                    server: server.clone(),
+                                    since: now - 60,
+                                    lines: VecDeque::new(),
+                                };
+                            }
+                            feed.since
+                        };
+                        events = Some((now, manager.container_events_between(since, now).await));
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let (feed_lines, feed_error) = {
+            let mut feed = feed.lock().unwrap();
+            let mut error = None;
+            match events {
+                Some((now, Ok(found))) => {
+                    feed.since = now;
+                    for event in found {
+                        let detail = if event.detail.is_empty() {
+                            String::new()
+                        } else {
+                            format!(" ({})", event.detail)
+                        };
+                        feed.lines.push_front(format!(
+                            "{} {} {}{}",
+                            event.at.with_timezone(&chrono::Local).format("%H:%M:%S"),
+                            event.container,
+                            event.action,
+                            detail
+                        ));
+                    }
+                    feed.lines.truncate(EVENT_FEED_LINES);
+                }
+                Some((_, Err(e))) => error = Some(e.to_string()),
+                None => {}
+            }
+            (
+                feed.lines.iter().cloned().collect::<Vec<_>>().join("\n"),
+                error,
+            )
+        };
+
+        slint::invoke_from_event_loop(move || {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            // Servidor trocado durante a consulta
+            if ui.get_context_name() != server.as_str() {
+                return;
+            }
+            let mut renderer = ChartRenderer::new(520, 160);
+            renderer.set_line_color([16, 185, 129]);
+
+            let widgets: Vec<DashboardWidgetData> = ui
+                .get_dashboard_widgets()
+                .iter()
+                .map(|mut data| {
+                    let target = data.target.to_string();
+                    match data.kind.as_str() {
+                        "stack-health" => {
+                            if let Some((level, summary)) = stacks.get(&target) {
+                                data.level = level.as_str().into();
+                                data.value = match level {
+                                    HealthLevel::Green => "Saudável",
+                                    HealthLevel::Yellow => "Convergindo",
+                                    HealthLevel::Red => "Com problema",
+                                }
+                                .into();
+                                data.detail = summary.clone().into();
+                            }
+                        }
+                        "container-memory" => {
+                            if let Some(deploy) = deploys.get(&target) {
+                                match deploy {
+                                    Some(deploy) => {
+                                        let values = deploy.recent(DeployMetric::Memory, 60);
+                                        data.value = values
+                                            .last()
+                                            .map(|value| format!("{:.1}%", value))
+                                            .unwrap_or_default()
+                                            .into();
+                                        data.detail = deploy.marker().into();
+                                        data.chart = renderer.render_sparkline(&values);
+                                    }
+                                    None => {
+                                        data.value = "".into();
+                                        data.detail = "Sem amostras: o container não está em execução ou a economia de banda está ligada".into();
+                                        data.chart = renderer.render_sparkline(&[]);
+                                    }
+                                }
+                            }
+                        }
+                        "event-feed" => {
+                            data.detail = match &feed_error {
+                                Some(error) => format!("Falha ao ler eventos: {}", error),
+                                None => feed_lines.clone(),
+                            }
+                            .into();
+                        }
+                        _ => {}
+                    }
+                    data
+                })
+                .collect();
+            ui.set_dashboard_widgets(std::rc::Rc::new(slint::VecModel::from(widgets)).into());
+        })
+        .unwrap();
+    });
+}
+
+// Configura o dashboard personalizado: edição do layout do servidor ativo e atualização
+// periódica dos widgets
+fn setup_dashboard_layout_callbacks(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+    low_bandwidth: BandwidthMode,
+) {
+    let Some(ui) = ui_weak.upgrade() else {
+        return;
+    };
+    let feed: Arc<std::sync::Mutex<EventFeed>> = Default::default();
+
+    // Altera o layout do servidor ativo, salva e atualiza a tela
+    fn edit(
+        ui: &AppWindow,
+        change: impl FnOnce(&mut Vec<DashboardWidget>) -> anyhow::Result<()>,
+    ) {
+        let context_name = ui.get_context_name().to_string();
+        let result = AppConfig::update(|config| {
+            let mut layout = config.dashboard_layout_for(&context_name);
+            change(&mut layout)?;
+            if layout.is_empty() {
+                config.dashboard_layouts.remove(&context_name);
+            } else {
+                config
+                    .dashboard_layouts
+                    .insert(context_name.clone(), layout);
+            }
+            Ok(())
+        });
+        match result {
+            Ok(_) => ui.set_dashboard_layout_error("".into()),
+            Err(e) => ui.set_dashboard_layout_error(e.to_string().into()),
+        }
+        update_dashboard_layout(ui, &AppConfig::load(), &context_name);
+    }
+
+    ui.on_open_dashboard_editor({
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        let feed = feed.clone();
+        move || {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            // Começa do conteúdo do dashboard padrão
+            if AppConfig::load()
+                .dashboard_layout_for(&ui.get_context_name())
+                .is_empty()
+            {
+                edit(&ui, |layout| {
+                    *layout = DashboardWidget::defaults();
+                    Ok(())
+                });
+            }
+            ui.set_dashboard_layout_error("".into());
+            ui.set_dashboard_editing(true);
+            refresh_dashboard_widgets(&ui, docker_manager.clone(), feed.clone());
+
+            // Opções da paleta: containers em execução e stacks do Compose
+            let ui_weak = ui_weak.clone();
+            let docker_manager = docker_manager.clone();
+            tokio::spawn(async move {
+                let (containers, stacks) = {
+                    let manager = docker_manager.lock().await;
+                    (
+                        manager.list_containers().await.unwrap_or_default(),
+                        manager.list_compose_stacks().await.unwrap_or_default(),
+                    )
+                };
+                let mut containers: Vec<slint::SharedString> = containers
+                    .into_iter()
+                    .filter(|container| container.state == "running")
+                    .map(|container| container.name.into())
+                    .collect();
+                containers.sort();
+                let stacks: Vec<slint::SharedString> =
+                    stacks.into_iter().map(|stack| stack.name.into()).collect();
+
+                slint::invoke_from_event_loop(move || {
+                    if let Some(ui) = ui_weak.upgrade() {
+                        ui.set_dashboard_container_options(
+                            std::rc::Rc::new(slint::VecModel::from(containers)).into(),
+                        );
+                        ui.set_dashboard_stack_options(
+                            std::rc::Rc::new(slint::VecModel::from(stacks)).into(),
+                        );
+                    }
+                })
+                .unwrap();
+            });
+        }
+    });
+
+    ui.on_add_dashboard_widget({
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        let feed = feed.clone();
+        move |kind, target| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            edit(&ui, |layout| {
+                dashboard_layout::add(layout, DashboardWidget::from_ui(&kind, &target)?)
+            });
+            refresh_dashboard_widgets(&ui, docker_manager.clone(), feed.clone());
+        }
+    });
+
+    ui.on_remove_dashboard_widget({
+        let ui_weak = ui_weak.clone();
+        move |index| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            edit(&ui, |layout| {
+                if index >= 0 && (index as usize) < layout.len() {
+                    layout.remove(index as usize);
+                }
+                Ok(())
+            });
+        }
+    });
+
+    ui.on_move_dashboard_widget({
+        let ui_weak = ui_weak.clone();
+        move |from, to| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            if from < 0 || to < 0 {
+                return;
+            }
+            edit(&ui, |layout| {
+                dashboard_layout::move_widget(layout, from as usize, to as usize);
+                Ok(())
+            });
+        }
+    });
+
+    ui.on_reset_dashboard_layout({
+        let ui_weak = ui_weak.clone();
+        move || {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            edit(&ui, |layout| {
+                layout.clear();
+                Ok(())
+            });
+            ui.set_dashboard_editing(false);
+        }
+    });
+
+    let timer = Timer::default();
+    // Economia de banda: a cada 30 segundos
+    let mut gate = TickGate::new(6);
+    timer.start(TimerMode::Repeated, Duration::from_secs(5), move || {
+        let Some(ui) = ui_weak.upgrade() else {
+            return;
+        };
+        if ui.get_current_screen() != Screen::Dashboard.index()
+            || ui.get_dashboard_widgets().row_count() == 0
+        {
+            return;
+        }
+        if !gate.should_run(&low_bandwidth) {
+            return;
+        }
+        refresh_dashboard_widgets(&ui, docker_manager.clone(), feed.clone());
+    });
+
+    // Mantém o timer vivo
+    std::mem::forget(timer);
+}
+
+// Configura a reprodução de sessões gravadas; lê só o arquivo, sem usar a conexão
+fn setup_session_replay_callbacks(ui_weak: Weak<AppWindow>) {
+    let Some(ui) = ui_weak.upgrade() else {
+        return;
+    };
+    let state = Arc::new(std::sync::Mutex::new(ReplayState::default()));
+    let renderers = Arc::new({
+        let cpu = ChartRenderer::new(800, 180);
+        let mut memory = ChartRenderer::new(800, 180);
+        memory.set_line_color([16, 185, 129]);
+        [cpu, memory]
+    });
+    // Avança o cursor `replay-speed` segundos por segundo enquanto toca
+    let player = std::rc::Rc::new(Timer::default());
+
+    ui.on_load_session_replay({
+        let ui_weak = ui_weak.clone();
+        let state = state.clone();
+        let renderers = renderers.clone();
+        let player = player.clone();
+        move |path| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            player.stop();
+            ui.set_replay_playing(false);
+            ui.set_replay_loading(true);
+            ui.set_replay_error("".into());
+
+            let ui_weak = ui_weak.clone();
+            let state = state.clone();
+            let renderers = renderers.clone();
+            let path = path.to_string();
+            tokio::spawn(async move {
+                let result = session_replay::load(&path).await;
+                slint::invoke_from_event_loop(move || {
+                    let Some(ui) = ui_weak.upgrade() else {
+                        return;
+                    };
+                    ui.set_replay_loading(false);
+                    let session = match result {
+                        Ok(session) => session,
+                        Err(e) => {
+                            ui.set_replay_error(e.to_string().into());
+                            return;
+                        }
+                    };
+
+                    let containers: Vec<slint::SharedString> = session
+                        .header
+                        .containers
+                        .iter()
+                        .map(|name| name.into())
+                        .collect();
+                    let container = session.header.containers.first().cloned().unwrap_or_default();
+                    let markers = session.event_markers(&container);
+                    ui.set_replay_info(session.summary().into());
+                    ui.set_replay_containers(std::rc::Rc::new(slint::VecModel::from(containers)).into());
+                    ui.set_replay_container(container.as_str().into());
+                    ui.set_replay_markers(std::rc::Rc::new(slint::VecModel::from(markers)).into());
+                    ui.set_replay_loaded(true);
+
+                    let mut state = state.lock().unwrap();
+                    *state = ReplayState {
+                        session: Some(session),
+                        container,
+                        cursor: 0.0,
+                    };
+                    render_session_replay(&ui, &state, &renderers);
+                })
+                .unwrap();
+            });
+        }
+    });
+
+    ui.on_select_replay_container({
+        let ui_weak = ui_weak.clone();
+        let state = state.clone();
+        let renderers = renderers.clone();
+        move |name| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            let mut state = state.lock().unwrap();
+            let Some(session) = &state.session else {
+                return;
+            };
+            let markers = session.event_markers(&name);
+            ui.set_replay_markers(std::rc::Rc::new(slint::VecModel::from(markers)).into());
+            ui.set_replay_container(name.clone());
+            state.container = name.to_string();
+            render_session_replay(&ui, &state, &renderers);
+        }
+    });
+
+    ui.on_seek_session_replay({
+        let ui_weak = ui_weak.clone();
+        let state = state.clone();
+        let renderers = renderers.clone();
+        move |position| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            let mut state = state.lock().unwrap();
+            let Some(duration) = state.session.as_ref().map(RecordedSession::duration_secs) else {
+                return;
+            };
+            state.cursor = position.clamp(0.0, 1.0) as f64 * duration;
+            render_session_replay(&ui, &state, &renderers);
+        }
+    });
+
+    ui.on_set_replay_speed({
+        let ui_weak = ui_weak.clone();
+        move |speed| {
+            if let Some(ui) = ui_weak.upgrade() {
+                ui.set_replay_speed(speed.max(1));
+            }
+        }
+    });
+
+    ui.on_toggle_session_replay({
+        let ui_weak = ui_weak.clone();
+        let state = state.clone();
+        let player = player.clone();
+        move || {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            if player.running() {
+                player.stop();
+                ui.set_replay_playing(false);
+                return;
+            }
+            {
+                let mut state = state.lock().unwrap();
+                let Some(duration) = state.session.as_ref().map(RecordedSession::duration_secs) else {
+                    return;
+                };
+                // No fim, recomeça do início
+                if state.cursor >= duration {
+                    state.cursor = 0.0;
+                }
+            }
+
+            let ui_weak = ui_weak.clone();
+            let state = state.clone();
+            let renderers = renderers.clone();
+            let tick_player = std::rc::Rc::downgrade(&player);
+            player.start(TimerMode::Repeated, Duration::from_secs(1), move || {
+                let Some(ui) = ui_weak.upgrade() else {
+                    return;
+                };
+                let mut state = state.lock().unwrap();
+                let Some(duration) = state.session.as_ref().map(RecordedSession::duration_secs) else {
+                    return;
+                };
+                state.cursor = (state.cursor + ui.get_replay_speed() as f64).min(duration);
+                render_session_replay(&ui, &state, &renderers);
+                if state.cursor >= duration {
+                    if let Some(player) = tick_player.upgrade() {
+                        player.stop();
+                    }
+                    ui.set_replay_playing(false);
+                }
+            });
+            ui.set_replay_playing(true);
+        }
+    });
+
+    ui.on_close_session_replay(move || {
+        player.stop();
+        if let Some(ui) = ui_weak.upgrade() {
+            ui.set_replay_playing(false);
+        }
+    });
+}
+
+// Prune em um servidor (conexão ativa ou temporária)
+async fn prune_server(
+    docker_manager: &Arc<tokio::sync::Mutex<DockerManager>>,
+    server: &str,
+    target: PruneTarget,
+) -> anyhow::Result<String> {
+    {
+        let active = docker_manager.lock().await;
+        if active.context().name == server {
+            return active.prune(target).await;
+        }
+    }
+    DockerManager::for_context_name(server)
+        .await?
+        .prune(target)
+        .await
+}
+
+// Atualiza o resultado exibido para um servidor na limpeza multi-servidor
+fn set_prune_server_status(ui: &AppWindow, server: &str, status: String, failed: bool) {
+    let servers = ui.get_prune_servers();
+    let Some(index) = servers.iter().position(|item| item.name.as_str() == server) else {
+        return;
+    };
+    if let Some(mut item) = servers.row_data(index) {
+        item.status = status.into();
+        item.failed = failed;
+        servers.set_row_data(index, item);
+    }
+}
+
+// Registra o resultado de uma limpeza no log de auditoria
+fn audit_prune(
+    server: &str,
+    action: &str,
+    target: PruneTarget,
+    result: &anyhow::Result<String>,
+) {
+    let (detail, success) = match result {
+        Ok(reclaimed) => (format!("{}: {} liberados", target.label(), reclaimed), true),
+        Err(e) => (format!("{}: {}", target.label(), e), false),
+    };
+    if let Err(e) = audit_log::append(&AuditEntry::new(server, action, detail, success)) {
+        eprintln!("Error writing audit log: {}", e);
+    }
+}
+
+// Atualiza a lista de limpezas agendadas e as últimas entradas da auditoria
+fn update_prune_schedules(ui: &AppWindow, config: &AppConfig) {
+    let schedules: Vec<PruneScheduleData> = config
+        .prune_schedules
+        .iter()
+        .map(|schedule| PruneScheduleData {
+            id: schedule.id.clone().into(),
+            label: schedule.label().into(),
+            last_run: schedule
+                .last_run
+                .map(|at| {
+                    format!(
+                        "última: {}",
+                        at.with_timezone(&chrono::Local).format("%d/%m %H:%M")
+                    )
+                })
+                .unwrap_or_default()
+                .into(),
+        })
+        .collect();
+    let audit: Vec<slint::SharedString> = audit_log::recent(8)
+        .iter()
+        .map(|entry| entry.label().into())
+        .collect();
+    ui.set_prune_schedules(std::rc::Rc::new(slint::VecModel::from(schedules)).into());
+    ui.set_prune_audit_entries(std::rc::Rc::new(slint::VecModel::from(audit)).into());
+}
+
+// Roda as limpezas agendadas vencidas; o horário é marcado antes de rodar para não repetir
+fn run_due_prune_schedules(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+) {
+    let now = chrono::Local::now();
+    let is_due = |schedule: &PruneSchedule| schedule.is_due(now);
+    if !AppConfig::load().prune_schedules.iter().any(is_due) {
+        return;
+    }
+    let mut due: Vec<PruneSchedule> = Vec::new();
+    let result = AppConfig::update(|config| {
+        for schedule in config.prune_schedules.iter_mut() {
+            if is_due(schedule) {
+                due.push(schedule.clone());
+                schedule.last_run = Some(chrono::Utc::now());
+            }
+        }
+        Ok(())
+    });
+    let config = match result {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Error saving prune schedules: {}", e);
+            return;
+        }
+    };
+    if let Some(ui) = ui_weak.upgrade() {
+        update_prune_schedules(&ui, &config);
+    }
+
+    tokio::spawn(async move {
+        for schedule in due {
+            let target = schedule.target();
+            let mut failed = Vec::new();
+            for server in &schedule.servers {
+                // Cada servidor vira uma tarefa da fila: se o app fechar no meio, é retomada
+                let result = match task_queue::enqueue(TaskKind::prune(server, target)) {
+                    Ok(task) => {
+                        tasks_ui::run_prune_task(
+                            &ui_weak,
+                            &docker_manager,
+                            &task.id,
+                            server,
+                            target,
+                        )
+                        .await
+                    }
+                    Err(e) => Err(e),
+                };
+                audit_prune(server, "prune agendado", target, &result);
+                if result.is_err() {
+                    failed.push(server.clone());
+                }
+            }
+
+            let ui_weak = ui_weak.clone();
+            slint::invoke_from_event_loop(move || {
+                let Some(ui) = ui_weak.upgrade() else {
+                    return;
+                };
+                update_prune_schedules(&ui, &AppConfig::load());
+                let message = if failed.is_empty() {
+                    format!(
+                        "Limpeza agendada de {} concluída em {} servidor(es)",
+                        target.label(),
+                        schedule.servers.len()
+                    )
+                } else {
+                    format!(
+                        "Limpeza agendada de {} falhou em: {}",
+                        target.label(),
+                        failed.join(", ")
+                    )
+                };
+                ui.set_notification_message(message.into());
+                ui.set_notification_is_error(!failed.is_empty());
+                ui.set_show_notification(true);
+            })
+            .unwrap();
+        }
+    });
+}
+
+// Configura a limpeza de containers/imagens em vários servidores em paralelo
+fn setup_multi_prune_callbacks(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+) {
+    let Some(ui) = ui_weak.upgrade() else {
+        return;
+    };
+
+    ui.on_open_multi_prune({
+        let ui_weak = ui_weak.clone();
+        move || {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            // Servidor ativo vem pré-selecionado
+            let active = ui.get_context_name();
+            let servers: Vec<PruneServerItem> = ui
+                .get_available_contexts()
+                .iter()
+                .map(|name| PruneServerItem {
+                    selected: name == active,
+                    name,
+                    status: "".into(),
+                    failed: false,
+                })
+                .collect();
+            let selected = servers.iter().filter(|server| server.selected).count();
+            ui.set_prune_servers(std::rc::Rc::new(slint::VecModel::from(servers)).into());
+            ui.set_prune_selected_count(selected as i32);
+            ui.set_prune_schedule_error("".into());
+            update_prune_schedules(&ui, &AppConfig::load());
+            ui.set_show_multi_prune(true);
+        }
+    });
+
+    ui.on_toggle_prune_server({
+        let ui_weak = ui_weak.clone();
+        move |index| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            let servers = ui.get_prune_servers();
+            let Some(mut item) = servers.row_data(index as usize) else {
+                return;
+            };
+            item.selected = !item.selected;
+            servers.set_row_data(index as usize, item);
+            let selected = servers.iter().filter(|server| server.selected).count();
+            ui.set_prune_selected_count(selected as i32);
+        }
+    });
+
+    ui.on_add_prune_schedule({
+        let ui_weak = ui_weak.clone();
+        move |target, time| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            let servers: Vec<String> = ui
+                .get_prune_servers()
+                .iter()
+                .filter(|server| server.selected)
+                .map(|server| server.name.to_string())
+                .collect();
+            let target = PruneTarget::from_ui(&target);
+            let schedule = match PruneSchedule::new(servers, target, &time) {
+                Ok(schedule) => schedule,
+                Err(e) => {
+                    ui.set_prune_schedule_error(e.to_string().into());
+                    return;
+                }
+            };
+            let result = AppConfig::update(|config| {
+                config.prune_schedules.push(schedule);
+                Ok(())
+            });
+            let config = match result {
+                Ok(config) => config,
+                Err(e) => {
+                    ui.set_prune_schedule_error(format!("Falha ao salvar: {}", e).into());
+                    return;
+                }
+            };
+            ui.set_prune_schedule_error("".into());
+            update_prune_schedules(&ui, &config);
+        }
+    });
+
+    ui.on_remove_prune_schedule({
+        let ui_weak = ui_weak.clone();
+        move |id| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            let result = AppConfig::update(|config| {
+                config.prune_schedules.retain(|schedule| schedule.id != id.as_str());
+                Ok(())
+            });
+            let config = match result {
+                Ok(config) => config,
+                Err(e) => {
+                    ui.set_prune_schedule_error(format!("Falha ao salvar: {}", e).into());
+                    return;
+                }
+            };
+            update_prune_schedules(&ui, &config);
+        }
+    });
+
+    // Verifica os agendamentos a cada minuto
+    let timer = Timer::default();
+    timer.start(TimerMode::Repeated, Duration::from_secs(60), {
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        move || run_due_prune_schedules(ui_weak.clone(), docker_manager.clone())
+    });
+    std::mem::forget(timer);
+
+    ui.on_run_multi_prune(move |target| {
+        let Some(ui) = ui_weak.upgrade() else {
+            return;
+        };
+        let target = PruneTarget::from_ui(&target);
+        let servers: Vec<String> = ui
+            .get_prune_servers()
+            .iter()
+            .filter(|server| server.selected)
+            .map(|server| server.name.to_string())
+            .collect();
+        if servers.is_empty() {
+            return;
+        }
+        for server in &servers {
+            set_prune_server_status(&ui, server, "...".to_string(), false);
+        }
+        ui.set_pruning_servers(true);
+
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        tokio::spawn(async move {
+            let mut tasks = tokio::task::JoinSet::new();
+            for server in servers {
+                let docker_manager = docker_manager.clone();
+                tasks.spawn(async move {
+                    let result = prune_server(&docker_manager, &server, target).await;
+                    (server, result)
+                });
+            }
+
+            // Cada servidor é atualizado assim que termina
+            let mut succeeded = 0;
+            let mut failed = 0;
+            while let Some(joined) = tasks.join_next().await {
+                let Ok((server, result)) = joined else {
+                    failed += 1;
+                    continue;
+                };
+                if result.is_ok() {
+                    succeeded += 1;
+                } else {
+                    failed += 1;
+                }
+                audit_prune(&server, "prune", target, &result);
+                let status = prune::server_status(&result);
+                let is_error = result.is_err();
+                let ui_weak = ui_weak.clone();
+                slint::invoke_from_event_loop(move || {
+                    if let Some(ui) = ui_weak.upgrade() {
+                        set_prune_server_status(&ui, &server, status, is_error);
+                    }
+                })
+                .unwrap();
+            }
+
+            slint::invoke_from_event_loop(move || {
+                if let Some(ui) = ui_weak.upgrade() {
+                    ui.set_pruning_servers(false);
+                    ui.set_notification_message(
+                        format!(
+                            "Limpeza de {}: {} servidor(es) ok, {} com falha",
+                            target.label(),
+                            succeeded,
+                            failed
+                        )
+                        .into(),
+                    );
+                    ui.set_notification_is_error(failed > 0);
+                    ui.set_show_notification(true);
+                }
+            })
+            .unwrap();
+        });
+    });
+}
+
+// Labels OCI da imagem do container, no servidor ativo ou em uma conexão temporária
+async fn load_image_provenance(
+    docker_manager: &Arc<tokio::sync::Mutex<DockerManager>>,
+    server: &str,
+    container_id: &str,
+) -> anyhow::Result<image_provenance::ImageProvenance> {
+    {
+        let active = docker_manager.lock().await;
+        if server.is_empty() || active.context().name == server {
+            return active.container_image_provenance(container_id).await;
+        }
+    }
+    DockerManager::for_context_name(server)
+        .await?
+        .container_image_provenance(container_id)
+        .await
+}
+
+// Configura a procedência da imagem nos detalhes e a abertura dos links no navegador
+fn setup_image_provenance_callbacks(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+    store: &UiStore,
+) {
+    let Some(ui) = ui_weak.upgrade() else {
+        return;
+    };
+
+    store.subscribe({
+        let ui_weak = ui_weak.clone();
+        move |ui, change| {
+            let StateChange::ContainerSelected(selected) = change else {
+                return;
+            };
+            ui.set_image_labels(Default::default());
+            ui.set_image_provenance_summary("Carregando...".into());
+            ui.set_image_provenance_error("".into());
+
+            let ui_weak = ui_weak.clone();
+            let docker_manager = docker_manager.clone();
+            let selected = selected.clone();
+            tokio::spawn(async move {
+                let result =
+                    load_image_provenance(&docker_manager, &selected.server, &selected.id).await;
+
+                slint::invoke_from_event_loop(move || {
+                    let Some(ui) = ui_weak.upgrade() else {
+                        return;
+                    };
+                    // Outro container foi aberto enquanto carregava
+                    if ui.get_selected_container().id != selected.id.as_str() {
+                        return;
+                    }
+                    match result {
+                        Ok(provenance) => {
+                            ui.set_image_provenance_summary(provenance.summary().into());
+                            let labels: Vec<ImageLabelData> = provenance
+                                .labels
+                                .into_iter()
+                                .map(|label| ImageLabelData {
+                                    name: label.name.into(),
+                                    value: label.value.into(),
+                                    url: label.url.unwrap_or_default().into(),
+                                })
+                                .collect();
+                            ui.set_image_labels(
+                                std::rc::Rc::new(slint::VecModel::from(labels)).into(),
+                            );
+                        }
+                        Err(e) => {
+                            ui.set_image_provenance_summary("".into());
+                            ui.set_image_provenance_error(e.to_string().into());
+                        }
+                    }
+                })
+                .unwrap();
+            });
+        }
+    });
+
+    ui.on_open_link({
+        let ui_weak = ui_weak.clone();
+        move |url| {
+            let Err(e) = image_provenance::open_url(&url) else {
+                return;
+            };
+            if let Some(ui) = ui_weak.upgrade() {
+                ui.set_notification_message(e.to_string().into());
+                ui.set_notification_is_error(true);
+                ui.set_show_notification(true);
+            }
+        }
+    });
+}
+
+// Linhas mantidas na saída do console exec
+const EXEC_OUTPUT_LINES: usize = 5000;
+// Comandos sem nenhuma entrada do usuário recebem Ctrl+C após esse tempo, para não ficarem
+// esquecidos no container; depois que o usuário digita no programa, a sessão não expira
+const EXEC_TIMEOUT_SECS: u64 = 60;
+// Espera pelo fim do comando depois do Ctrl+C do tempo limite
+const EXEC_INTERRUPT_GRACE_SECS: u64 = 3;
+
+// Carrega o histórico de comandos do container selecionado, os snippets salvos e os da imagem
+fn update_exec_console(ui: &AppWindow) {
+    let selected = ui.get_selected_container();
+    let history = ExecHistory::load();
+    let commands: Vec<slint::SharedString> = history
+        .commands(&selected.server, &selected.name)
+        .iter()
+        .map(|command| command.into())
+        .collect();
+    let snippets: Vec<slint::SharedString> =
+        history.snippets().iter().map(|snippet| snippet.into()).collect();
+    ui.set_exec_history(std::rc::Rc::new(slint::VecModel::from(commands)).into());
+    ui.set_exec_snippets(std::rc::Rc::new(slint::VecModel::from(snippets)).into());
+    let image_snippets: Vec<ImageSnippetData> = history
+        .image_snippets(&selected.image)
+        .into_iter()
+        .map(|(command, builtin)| ImageSnippetData {
+            command: command.into(),
+            builtin,
+        })
+        .collect();
+    ui.set_exec_image_snippets(std::rc::Rc::new(slint::VecModel::from(image_snippets)).into());
+}
+
+// Acrescenta um bloco à saída do console, descartando as linhas mais antigas
+fn append_exec_output(ui: &AppWindow, block: &str) {
+    let current = ui.get_exec_output();
+    let combined = if current.is_empty() {
+        block.to_string()
+    } else {
+        format!("{}\n{}", current, block)
+    };
+    let lines: Vec<&str> = combined.lines().collect();
+    let overflow = lines.len().saturating_sub(EXEC_OUTPUT_LINES);
+    ui.set_exec_output(lines[overflow..].join("\n").into());
+    show_exec_match(ui, ui.get_exec_search_index());
+}
+
+// Ocorrências do termo na saída, sem diferenciar maiúsculas: byte inicial, final e linha
+fn find_in_exec_output(output: &str, query: &str) -> Vec<(usize, usize, usize)> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+    // Minúsculas só em ASCII para manter as posições em bytes da saída original
+    let haystack = output.to_ascii_lowercase();
+    let needle = query.to_ascii_lowercase();
+    let mut line = 0;
+    let mut counted = 0;
+    haystack
+        .match_indices(&needle)
+        .map(|(start, _)| {
+            line += output[counted..start].matches('\n').count();
+            counted = start;
+            (start, start + needle.len(), line)
+        })
+        .collect()
+}
+
+// Seleciona a ocorrência `index` (circular) da busca atual no console
+fn show_exec_match(ui: &AppWindow, index: i32) {
+    let matches = find_in_exec_output(&ui.get_exec_output(), &ui.get_exec_search_query());
+    ui.set_exec_search_count(matches.len() as i32);
+    if matches.is_empty() {
+        ui.set_exec_search_index(0);
+        ui.set_exec_match_line(-1);
+        return;
+    }
+    let index = index.rem_euclid(matches.len() as i32);
+    let (start, end, line) = matches[index as usize];
+    ui.set_exec_search_index(index);
+    ui.set_exec_match_line(line as i32);
+    ui.set_exec_match_start(start as i32);
+    ui.set_exec_match_end(end as i32);
+}
+
+// Configura o console exec: execução, histórico por container e snippets
+fn setup_exec_callbacks(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+    store: &UiStore,
+) {
+    let Some(ui) = ui_weak.upgrade() else {
+        return;
+    };
+    let terminal = Arc::new(std::sync::Mutex::new(ExecTerminal::default()));
+
+    // Cada container tem seu histórico; a saída anterior não é reaproveitada
+    store.subscribe(|ui, change| {
+        if let StateChange::ContainerSelected(_) = change {
+            ui.set_exec_output("".into());
+            ui.set_exec_error("".into());
+            show_exec_match(ui, 0);
+            update_exec_console(ui);
+        }
+    });
+
+    ui.on_run_exec({
+        let ui_weak = ui_weak.clone();
+        move |command| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            let selected = ui.get_selected_container();
+            let command = command.trim().to_string();
+            if selected.name.is_empty() || command.is_empty() {
+                return;
+            }
+
+            // Blocos colados de outros sistemas podem vir com "\r\n"
+            let command = command.replace("\r\n", "\n");
+            let mut history = ExecHistory::load();
+            history.push(&selected.server, &selected.name, &command);
+            match history.save() {
+                Ok(()) => ui.set_exec_error("".into()),
+                Err(e) => ui.set_exec_error(e.to_string().into()),
+            }
+            update_exec_console(&ui);
+            ui.set_exec_running(true);
+
+            let ui_weak = ui_weak.clone();
+            let docker_manager = docker_manager.clone();
+            let terminal = terminal.clone();
+            let container_name = selected.name.to_string();
+            tokio::spawn(async move {
+                // Cliente da API obtido antes; o gerenciador fica livre durante a execução
+                let client = docker_manager.lock().await.api_client();
+                let size = terminal.lock().unwrap().size;
+
+                let screen_ui = ui_weak.clone();
+                let run = exec_session::run(
+                    &client,
+                    &container_name,
+                    &command,
+                    size,
+                    |active| {
+                        let mut terminal = terminal.lock().unwrap();
+                        terminal.active = Some(active);
+                        terminal.interactive = false;
+                    },
+                    move |screen| {
+                        let ui_weak = screen_ui.clone();
+                        let _ = slint::invoke_from_event_loop(move || {
+                            if let Some(ui) = ui_weak.upgrade() {
+                                ui.set_exec_screen(screen.into());
+                            }
+                        });
+                    },
+                );
+                tokio::pin!(run);
+                let timeout = Duration::from_secs(EXEC_TIMEOUT_SECS);
+                let mut result = loop {
+                    match tokio::time::timeout(timeout, &mut run).await {
+                        Err(_) if terminal.lock().unwrap().interactive => continue,
+                        result => break result.ok(),
+                    }
+                };
+
+                // O exec não termina quando a conexão é largada: no tempo limite, o Ctrl+C pelo
+                // TTY interrompe o processo, e o console avisa se ele ignorou o sinal
+                let timed_out = result.is_none();
+                let active = terminal.lock().unwrap().active.take();
+                let mut still_running = false;
+                if timed_out && let Some(active) = active {
+                    active.interrupt();
+                    let grace = Duration::from_secs(EXEC_INTERRUPT_GRACE_SECS);
+                    result = tokio::time::timeout(grace, &mut run).await.ok();
+                    still_running = result.is_none() && active.is_running().await;
+                }
+
+                let block = match result {
+                    Some(Ok(outcome)) => format!(
+                        "$ {}\n{}\n[saída {}{}]",
+                        command,
+                        outcome.output.trim_end(),
+                        outcome
+                            .exit_code
+                            .map(|code| code.to_string())
+                            .unwrap_or_else(|| "?".to_string()),
+                        if timed_out {
+                            format!("; tempo limite de {}s excedido, Ctrl+C enviado", EXEC_TIMEOUT_SECS)
+                        } else {
+                            String::new()
+                        }
+                    ),
+                    Some(Err(e)) => format!("$ {}\nFalha ao executar docker exec: {:#}", command, e),
+                    None if still_running => format!(
+                        "$ {}\nTempo limite de {}s excedido; o comando ignorou o Ctrl+C e continua em execução no container",
+                        command, EXEC_TIMEOUT_SECS
+                    ),
+                    None => format!(
+                        "$ {}\nTempo limite de {}s excedido; comando interrompido",
+                        command, EXEC_TIMEOUT_SECS
+                    ),
+                };
+
+                slint::invoke_from_event_loop(move || {
+                    if let Some(ui) = ui_weak.upgrade() {
+                        ui.set_exec_running(false);
+                        ui.set_exec_screen("".into());
+                        append_exec_output(&ui, &block);
+                    }
+                })
+                .unwrap();
+            });
+        }
+    });
+
+    // Entrada para o processo em execução: linhas do campo de comando e teclas sobre a tela
+    ui.on_send_exec_input({
+        let terminal = terminal.clone();
+        move |line| {
+            let mut terminal = terminal.lock().unwrap();
+            terminal.interactive = true;
+            if let Some(active) = &terminal.active {
+                active.send_line(&line);
+            }
+        }
+    });
+
+    ui.on_send_exec_key({
+        let terminal = terminal.clone();
+        move |text, control| {
+            let mut terminal = terminal.lock().unwrap();
+            terminal.interactive = true;
+            if let Some(active) = &terminal.active {
+                active.send_key(&text, control);
+            }
+        }
+    });
+
+    ui.on_interrupt_exec({
+        let terminal = terminal.clone();
+        move || {
+            let mut terminal = terminal.lock().unwrap();
+            terminal.interactive = true;
+            if let Some(active) = &terminal.active {
+                active.interrupt();
+            }
+        }
+    });
+
+    ui.on_search_exec({
+        let ui_weak = ui_weak.clone();
+        move |_| {
+            if let Some(ui) = ui_weak.upgrade() {
+                show_exec_match(&ui, 0);
+            }
+        }
+    });
+
+    ui.on_search_exec_next({
+        let ui_weak = ui_weak.clone();
+        move || {
+            if let Some(ui) = ui_weak.upgrade() {
+                show_exec_match(&ui, ui.get_exec_search_index() + 1);
+            }
+        }
+    });
+
+    ui.on_search_exec_previous({
+        let ui_weak = ui_weak.clone();
+        move || {
+            if let Some(ui) = ui_weak.upgrade() {
+                show_exec_match(&ui, ui.get_exec_search_index() - 1);
+            }
+        }
+    });
+
+    // Console redimensionado: vale para os próximos comandos e para o que estiver em execução
+    ui.on_resize_exec({
+        let terminal = terminal.clone();
+        move |columns, rows| {
+            let size = TerminalSize::from_ui(columns, rows);
+            let mut terminal = terminal.lock().unwrap();
+            if terminal.size == size {
+                return;
+            }
+            terminal.size = size;
+            if let Some(active) = terminal.active.clone() {
+                tokio::spawn(async move {
+                    active.resize(size).await;
+                });
+            }
+        }
+    });
+
+    ui.on_save_exec_snippet({
+        let ui_weak = ui_weak.clone();
+        move |command| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            let mut history = ExecHistory::load();
+            match history.add_snippet(&command).and_then(|()| history.save()) {
+                Ok(()) => ui.set_exec_error("".into()),
+                Err(e) => ui.set_exec_error(e.to_string().into()),
+            }
+            update_exec_console(&ui);
+        }
+    });
+
+    ui.on_save_exec_image_snippet({
+        let ui_weak = ui_weak.clone();
+        move |command| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            let image = ui.get_selected_container().image;
+            let mut history = ExecHistory::load();
+            match history
+                .add_image_snippet(&image, &command)
+                .and_then(|()| history.save())
+            {
+                Ok(()) => ui.set_exec_error("".into()),
+                Err(e) => ui.set_exec_error(e.to_string().into()),
+            }
+            update_exec_console(&ui);
+        }
+    });
+
+    ui.on_remove_exec_image_snippet({
+        let ui_weak = ui_weak.clone();
+        move |command| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            let image = ui.get_selected_container().image;
+            let mut history = ExecHistory::load();
+            history.remove_image_snippet(&image, &command);
+            match history.save() {
+                Ok(()) => ui.set_exec_error("".into()),
+                Err(e) => ui.set_exec_error(e.to_string().into()),
+            }
+            update_exec_console(&ui);
+        }
+    });
+
+    ui.on_remove_exec_snippet(move |command| {
+        let Some(ui) = ui_weak.upgrade() else {
+            return;
+        };
+        let mut history = ExecHistory::load();
+        history.remove_snippet(&command);
+        match history.save() {
+            Ok(()) => ui.set_exec_error("".into()),
+            Err(e) => ui.set_exec_error(e.to_string().into()),
+        }
+        update_exec_console(&ui);
+    });
+}
+
+// Preenche a tela de configurações com o proxy global, o do servidor ativo e as opções SSH
+fn update_proxy_settings(ui: &AppWindow, config: &AppConfig, context_name: &str) {
+    let global = config.proxy.clone().unwrap_or_default();
+    ui.set_proxy_url(global.url.into());
+    ui.set_proxy_no_proxy(global.no_proxy.into());
+    ui.set_server_proxy_url(
+        config
+            .server_proxies
+            .get(context_name)
+            .map(|proxy| proxy.url.clone())
+            .unwrap_or_default()
+            .into(),
+    );
+
+    let ssh = config.ssh_options_for(context_name);
+    ui.set_ssh_compression(ssh.compression);
+    ui.set_ssh_ciphers(ssh.ciphers.into());
+    ui.set_ssh_kex(ssh.kex.into());
+}
+
+// Atualiza lista de servidores disponíveis no seletor
+fn update_available_contexts(ui: &AppWindow, contexts: &[DockerContextConfig]) {
+    let names: Vec<slint::SharedString> = contexts
+        .iter()
+        .map(|context| context.name.clone().into())
+        .collect();
+
+    let slint_model: std::rc::Rc<slint::VecModel<slint::SharedString>> =
+        std::rc::Rc::new(slint::VecModel::from(names));
+
+    ui.set_available_contexts(slint_model.into());
+}
+
+// Atualiza lista de containers (não implementado)
+fn update_containers_list(_ui: &AppWindow, _containers: &[ContainerInfo]) {
+    // Funcionalidade não implementada ainda
+}
+
+// Configura callback de troca de servidor sem sair da tela atual
+fn setup_context_callbacks(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+    app_state: AppState,
+    container_chart_data: Arc<std::sync::Mutex<ContainerChartData>>,
+    store: UiStore,
+) {
+    let ui = ui_weak.upgrade().unwrap();
+
+    ui.on_switch_context({
+        let ui_weak = ui_weak.clone();
+        move |context_name| {
+            let ui_weak_clone = ui_weak.clone();
+            let docker_manager_clone = docker_manager.clone();
+            let app_state_clone = app_state.clone();
+            let container_chart_data_clone = container_chart_data.clone();
+            let store_clone = store.clone();
+            let context_name_str = context_name.to_string();
+
+            if let Some(ui) = ui_weak_clone.upgrade() {
+                ui.set_switching_context(true);
+            }
+
+            tokio::spawn(async move {
+                let contexts = DockerContextConfig::list_available();
+                let Some(context) = contexts
+                    .iter()
+                    .find(|context| context.name == context_name_str)
+                    .cloned()
+                else {
+                    slint::invoke_from_event_loop(move || {
+                        if let Some(ui) = ui_weak_clone.upgrade() {
+                            ui.set_switching_context(false);
+                            ui.set_notification_message(
+                                format!("Servidor '{}' não encontrado", context_name_str).into(),
+                            );
+                            ui.set_notification_is_error(true);
+                            ui.set_show_notification(true);
+                        }
+                    })
+                    .unwrap();
+                    return;
+                };
+
+                match DockerManager::with_context(context.clone()).await {
+                    Ok(new_manager) => {
+                        let docker_status = new_manager.check_docker_status();
+                        let is_running = matches!(docker_status, DockerStatus::Running);
+                        let info = new_manager.get_docker_info().await.ok();
+
+                        // Substitui o gerenciador compartilhado por todas as telas
+                        *docker_manager_clone.lock().await = new_manager;
+
+                        // Históricos de gráficos pertencem ao servidor anterior
+                        clear_chart_history(&app_state_clone, &container_chart_data_clone);
+                        app_state_clone
+                            .low_bandwidth
+                            .set_low(AppConfig::load().is_low_bandwidth(&context.name));
+                        let quota_manager = docker_manager_clone.clone();
+
+                        slint::invoke_from_event_loop(move || {
+                            if let Some(ui) = ui_weak_clone.upgrade() {
+                                ui.set_switching_context(false);
+                                update_context_header(&ui, &context);
+                                update_available_contexts(&ui, &contexts);
+                                ui.set_docker_status(docker_status.to_shared_string());
+                                if let Some(info) = info {
+                                    update_docker_info(&ui, &info);
+                                }
+                                if !is_running {
+                                    show_offline_snapshot(&ui, &context.name);
+                                }
+
+                                store_clone.dispatch(&ui, Transition::SwitchServer(context.name.clone()));
+                                // A cota do Hub é do host que faz os pulls
+                                refresh_hub_rate_limit(ui.as_weak(), quota_manager);
+
+                                // Cache de build é por servidor
+                                ui.set_show_build_cache(false);
+                                ui.set_build_cache(
+                                    std::rc::Rc::new(slint::VecModel::<BuildCacheData>::default()).into(),
+                                );
+
+                                ui.set_notification_message(
+                                    format!("Conectado ao servidor '{}'", context.name).into(),
+                                );
+                                ui.set_notification_is_error(false);
+                                ui.set_show_notification(true);
+                            }
+                        })
+                        .unwrap();
+                    }
+                    Err(e) => {
+                        let mut error_message = e.to_string();
+                        // Servidor SSH sem o daemon: aponta para a instalação guiada
+                        if context.host.starts_with("ssh://") {
+                            error_message.push_str(
+                                "\nSe o Docker não estiver instalado, use Configurações > Instalar Docker.",
+                            );
+                        }
                         slint::invoke_from_event_loop(move || {
                             if let Some(ui) = ui_weak_clone.upgrade() {
-                                ui.set_volume_list_error("".into());
-                                update_ui_volumes_from_slint(&ui, &volumes);
+                                ui.set_switching_context(false);
+                                ui.set_notification_message(
+                                    format!(
+                                        "Falha ao conectar ao servidor '{}':\n{}",
+                                        context_name_str, error_message
+                                    )
+                                    .into(),
+                                );
+                                ui.set_notification_is_error(true);
+                                ui.set_show_notification(true);
+                            }
+                        })
+                        .unwrap();
+                    }
+                }
+            });
+        }
+    });
+}
+
+// Tempo máximo para a troca de servidor pedida por um link direto
+const DEEP_LINK_TIMEOUT_SECS: u64 = 30;
+
+// Contexto da inicialização: o servidor do link direto ou do modo quiosque, se existir,
+// ou o contexto atual
+fn initial_context(ui: &AppWindow, server: Option<&str>) -> DockerContextConfig {
+    let Some(server) = server else {
+        return DockerContextConfig::current();
+    };
+    match DockerContextConfig::list_available()
+        .into_iter()
+        .find(|context| context.name == server)
+    {
+        Some(context) => context,
+        None => {
+            ui.set_notification_message(format!("Servidor '{}' não encontrado", server).into());
+            ui.set_notification_is_error(true);
+            ui.set_show_notification(true);
+            DockerContextConfig::current()
+        }
+    }
+}
+
+// Abre os detalhes do container do link, trocando de servidor se necessário
+fn open_deep_link(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+    store: UiStore,
+    deep_link: DeepLink,
+) {
+    tokio::spawn(async move {
+        if let Some(server) = deep_link
+            .server
+            .clone()
+            .filter(|server| *server != store.state().server)
+        {
+            let ui_weak = ui_weak.clone();
+            let target = server.clone();
+            slint::invoke_from_event_loop(move || {
+                if let Some(ui) = ui_weak.upgrade() {
+                    ui.invoke_switch_context(target.into());
+                }
+            })
+            .unwrap();
+
+            // Falhas na troca já são notificadas pelo próprio callback
+            let waited = tokio::time::timeout(Duration::from_secs(DEEP_LINK_TIMEOUT_SECS), async {
+                while store.state().server != server {
+                    tokio::time::sleep(Duration::from_millis(200)).await;
+                }
+            })
+            .await;
+            if waited.is_err() {
+                return;
+            }
+        }
+
+        let result = docker_manager.lock().await.list_containers().await;
+        let server = store.state().server;
+        let found = result.map(|containers| deep_link.find(&containers).map(SlintContainerData::from));
+
+        slint::invoke_from_event_loop(move || {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            let message = match found {
+                Ok(Some(container)) => {
+                    let container = to_container_data(&container, &Annotations::load());
+                    store.dispatch(&ui, Transition::SelectContainer(container));
+                    return;
+                }
+                Ok(None) => format!(
+                    "Container '{}' não encontrado em '{}'",
+                    deep_link.container, server
+                ),
+                Err(e) => format!("Falha ao abrir o container '{}': {}", deep_link.container, e),
+            };
+            ui.set_notification_message(message.into());
+            ui.set_notification_is_error(true);
+            ui.set_show_notification(true);
+        })
+        .unwrap();
+    });
+}
+
+// Descarta os históricos dos gráficos (troca de servidor ou retomada após suspensão)
+fn clear_chart_history(
+    app_state: &AppState,
+    container_chart_data: &Arc<std::sync::Mutex<ContainerChartData>>,
+) {
+    if let Ok(mut chart_data) = app_state.chart_data.lock() {
+        chart_data.clear();
+    }
+    if let Ok(mut counts) = app_state.container_counts.lock() {
+        counts.clear();
+    }
+    if let Ok(mut container_chart_data) = container_chart_data.lock() {
+        *container_chart_data = ContainerChartData::new();
+    }
+}
+
+// Após suspender/retomar o sistema, túneis SSH e históricos ficam obsoletos:
+// reconecta ao servidor ativo e reinicia os gráficos antes que os erros se acumulem
+fn setup_resume_watcher(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+    app_state: AppState,
+    container_chart_data: Arc<std::sync::Mutex<ContainerChartData>>,
+) {
+    resume::spawn_watcher(move |suspended| {
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        let app_state = app_state.clone();
+        let container_chart_data = container_chart_data.clone();
+
+        tokio::spawn(async move {
+            let mut manager = docker_manager.lock().await;
+            let context_name = manager.context().name.clone();
+            let result = manager.reconnect().await;
+            let docker_status = manager.check_docker_status();
+            let info = match &result {
+                Ok(()) => manager.get_docker_info().await.ok(),
+                Err(_) => None,
+            };
+            drop(manager);
+
+            clear_chart_history(&app_state, &container_chart_data);
+
+            slint::invoke_from_event_loop(move || {
+                let Some(ui) = ui_weak.upgrade() else {
+                    return;
+                };
+                let minutes = (suspended.as_secs() / 60).max(1);
+                match result {
+                    Ok(()) => {
+                        ui.set_docker_status(docker_status.to_shared_string());
+                        match info {
+                            Some(info) => update_docker_info(&ui, &info),
+                            None => show_offline_snapshot(&ui, &context_name),
+                        }
+                        ui.set_notification_message(
+                            format!(
+                                "Sistema retomado após ~{} min: conexão com '{}' revalidada",
+                                minutes, context_name
+                            )
+                            .into(),
+                        );
+                        ui.set_notification_is_error(false);
+                    }
+                    Err(e) => {
+                        ui.set_docker_status("NotRunning".into());
+                        show_offline_snapshot(&ui, &context_name);
+                        ui.set_notification_message(
+                            format!(
+                                "Sistema retomado após ~{} min, mas a reconexão com '{}' falhou:\n{}",
+                                minutes, context_name, e
+                            )
+                            .into(),
+                        );
+                        ui.set_notification_is_error(true);
+                    }
+                }
+                ui.set_show_notification(true);
+            })
+            .unwrap();
+        });
+    });
+}
+
+// Quando a cota do Docker Hub no host do daemon é renovada (consulta bloqueante, fora do
+// runtime assíncrono); None se não foi possível consultar
+async fn hub_retry_after(
+    commands: Option<(std::process::Command, std::process::Command)>,
+) -> Option<chrono::DateTime<chrono::Local>> {
+    let (token, headers) = commands?;
+    tokio::task::spawn_blocking(move || {
+        registry::check_hub_rate_limit(token, headers, credentials::docker_hub_login())
+    })
+    .await
+    .ok()?
+    .ok()
+    .map(|status| status.retry_at())
+}
+
+// Consulta a cota de pulls do Docker Hub no host do servidor ativo e atualiza a tela de imagens
+fn refresh_hub_rate_limit(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+) {
+    if let Some(ui) = ui_weak.upgrade() {
+        ui.set_checking_rate_limit(true);
+    }
+
+    tokio::spawn(async move {
+        let commands = docker_manager.lock().await.hub_rate_limit_commands();
+        let result = match commands {
+            Some((token, headers)) => tokio::task::spawn_blocking(move || {
+                registry::check_hub_rate_limit(token, headers, credentials::docker_hub_login())
+            })
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+            .and_then(|result| result)
+            .map(Some),
+            None => Ok(None),
+        };
+
+        slint::invoke_from_event_loop(move || {
+            if let Some(ui) = ui_weak.upgrade() {
+                ui.set_checking_rate_limit(false);
+                match result {
+                    Ok(Some(status)) => {
+                        let text = if status.is_exhausted() {
+                            format!(
+                                "Limite de pulls atingido, tente novamente às {}",
+                                status.retry_at().format("%H:%M")
+                            )
+                        } else {
+                            status.summary()
+                        };
+                        ui.set_hub_rate_limit_str(text.into());
+                        ui.set_hub_rate_limited(status.is_exhausted());
+                    }
+                    // Daemon remoto sem SSH: a cota local não é a dele
+                    Ok(None) => {
+                        ui.set_hub_rate_limit_str(
+                            "Cota Docker Hub: indisponível para servidores sem SSH".into(),
+                        );
+                        ui.set_hub_rate_limited(false);
+                    }
+                    Err(_) => {
+                        ui.set_hub_rate_limit_str("Cota Docker Hub: indisponível".into());
+                        ui.set_hub_rate_limited(false);
+                    }
+                }
+            }
+        })
+        .unwrap();
+    });
+}
+
+// Atualiza a lista de credenciais salvas no modal e no formulário de criação
+fn update_registry_credentials(ui: &AppWindow, saved: &[RegistryCredential]) {
+    let credentials: Vec<RegistryCredentialData> = saved
+        .iter()
+        .map(|credential| RegistryCredentialData {
+            name: credential.name.clone().into(),
+            server: credential.server.clone().into(),
+            username: credential.username.clone().into(),
+        })
+        .collect();
+    let names: Vec<slint::SharedString> = saved
+        .iter()
+        .map(|credential| credential.name.clone().into())
+        .collect();
+
+    ui.set_registry_credentials(std::rc::Rc::new(slint::VecModel::from(credentials)).into());
+    ui.set_registry_credential_names(std::rc::Rc::new(slint::VecModel::from(names)).into());
+}
+
+// Configura callbacks de cota, login e credenciais de registry
+fn setup_registry_callbacks(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+) {
+    let ui = ui_weak.upgrade().unwrap();
+
+    update_registry_credentials(&ui, &credentials::list());
+
+    ui.on_save_registry_credential({
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        move |name, server, username, password| {
+            let ui_weak_clone = ui_weak.clone();
+            let docker_manager_clone = docker_manager.clone();
+            let credential = RegistryCredential {
+                name: name.trim().to_string(),
+                server: server.trim().to_string(),
+                username: username.trim().to_string(),
+            };
+            let password = password.to_string();
+
+            tokio::spawn(async move {
+                // Keyring pode bloquear (D-Bus / prompt do sistema)
+                let credential_name = credential.name.clone();
+                let result = tokio::task::spawn_blocking(move || {
+                    credentials::save(credential, &password).map(|_| credentials::list())
+                })
+                .await
+                .map_err(|e| anyhow::anyhow!(e))
+                .and_then(|result| result);
+                let saved_hub = result.is_ok();
+
+                let ui_weak_result = ui_weak_clone.clone();
+                slint::invoke_from_event_loop(move || {
+                    if let Some(ui) = ui_weak_result.upgrade() {
+                        match result {
+                            Ok(saved) => {
+                                update_registry_credentials(&ui, &saved);
+                                ui.set_registry_credential_name("".into());
+                                ui.set_registry_login_password("".into());
+                                ui.set_notification_message(
+                                    format!("Credencial '{}' salva no keyring", credential_name)
+                                        .into(),
+                                );
+                                ui.set_notification_is_error(false);
+                            }
+                            Err(e) => {
+                                ui.set_notification_message(
+                                    format!("Falha ao salvar credencial:\n{}", e).into(),
+                                );
+                                ui.set_notification_is_error(true);
+                            }
+                        }
+                        ui.set_show_notification(true);
+                    }
+                })
+                .unwrap();
+
+                if saved_hub {
+                    refresh_hub_rate_limit(ui_weak_clone, docker_manager_clone);
+                }
+            });
+        }
+    });
+
+    ui.on_remove_registry_credential({
+        let ui_weak = ui_weak.clone();
+        move |name| {
+            let ui_weak_clone = ui_weak.clone();
+            let name = name.to_string();
+
+            tokio::spawn(async move {
+                let credential_name = name.clone();
+                let result = tokio::task::spawn_blocking(move || {
+                    credentials::remove(&name).map(|_| credentials::list())
+                })
+                .await
+                .map_err(|e| anyhow::anyhow!(e))
+                .and_then(|result| result);
+
+                slint::invoke_from_event_loop(move || {
+                    if let Some(ui) = ui_weak_clone.upgrade() {
+                        match result {
+                            Ok(saved) => {
+                                update_registry_credentials(&ui, &saved);
+                                if ui.get_create_registry_credential() == credential_name.as_str() {
+                                    ui.set_create_registry_credential("auto".into());
+                                }
+                                ui.set_notification_message(
+                                    format!("Credencial '{}' removida", credential_name).into(),
+                                );
+                                ui.set_notification_is_error(false);
+                            }
+                            Err(e) => {
+                                ui.set_notification_message(
+                                    format!("Falha ao remover credencial:\n{}", e).into(),
+                                );
+                                ui.set_notification_is_error(true);
+                            }
+                        }
+                        ui.set_show_notification(true);
+                    }
+                })
+                .unwrap();
+            });
+        }
+    });
+
+    ui.on_check_rate_limit({
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        move || {
+            refresh_hub_rate_limit(ui_weak.clone(), docker_manager.clone());
+        }
+    });
+
+    ui.on_registry_login({
+        let ui_weak = ui_weak.clone();
+        move |server, username, password| {
+            let ui_weak_clone = ui_weak.clone();
+            let docker_manager_clone = docker_manager.clone();
+            let server = server.trim().to_string();
+            let username = username.trim().to_string();
+            let password = password.to_string();
+
+            if let Some(ui) = ui_weak_clone.upgrade() {
+                ui.set_registry_logging_in(true);
+            }
+
+            tokio::spawn(async move {
+                let result = {
+                    let docker_manager = docker_manager_clone.lock().await;
+                    docker_manager
+                        .registry_login(&server, &username, &password)
+                        .await
+                };
+                let logged_in = result.is_ok();
+
+                let ui_weak_result = ui_weak_clone.clone();
+                slint::invoke_from_event_loop(move || {
+                    if let Some(ui) = ui_weak_result.upgrade() {
+                        ui.set_registry_logging_in(false);
+                        match result {
+                            Ok(()) => {
+                                ui.set_show_registry_login(false);
+                                ui.set_registry_login_password("".into());
+                                ui.set_notification_message(
+                                    format!(
+                                        "Login realizado em {}",
+                                        if server.is_empty() { "Docker Hub" } else { &server }
+                                    )
+                                    .into(),
+                                );
+                                ui.set_notification_is_error(false);
+                            }
+                            Err(e) => {
+                                ui.set_notification_message(e.to_string().into());
+                                ui.set_notification_is_error(true);
+                            }
+                        }
+                        ui.set_show_notification(true);
+                    }
+                })
+                .unwrap();
+
+                // Cota muda após autenticar
+                if logged_in {
+                    refresh_hub_rate_limit(ui_weak_clone, docker_manager_clone);
+                }
+            });
+        }
+    });
+}
+
+// Carrega o cache de build do servidor ativo
+fn load_build_cache(ui_weak: Weak<AppWindow>, docker_manager: Arc<tokio::sync::Mutex<DockerManager>>) {
+    if let Some(ui) = ui_weak.upgrade() {
+        ui.set_build_cache_loading(true);
+    }
+
+    tokio::spawn(async move {
+        let result = docker_manager.lock().await.list_build_cache().await;
+
+        slint::invoke_from_event_loop(move || {
+            if let Some(ui) = ui_weak.upgrade() {
+                ui.set_build_cache_loading(false);
+                match result {
+                    Ok(entries) => {
+                        let in_use = entries.iter().filter(|entry| entry.in_use).count();
+                        ui.set_build_cache_summary(
+                            format!("{} entradas no cache ({} em uso)", entries.len(), in_use)
+                                .into(),
+                        );
+
+                        let slint_entries: Vec<BuildCacheData> = entries
+                            .into_iter()
+                            .map(|entry| BuildCacheData {
+                                id: entry.id.into(),
+                                cache_type: entry.cache_type.into(),
+                                size: entry.size.into(),
+                                last_used: entry.last_used_since.into(),
+                                usage_count: entry.usage_count,
+                                in_use: entry.in_use,
+                                shared: entry.shared,
+                                description: entry.description.into(),
+                            })
+                            .collect();
+                        ui.set_build_cache(
+                            std::rc::Rc::new(slint::VecModel::from(slint_entries)).into(),
+                        );
+                    }
+                    Err(e) => {
+                        ui.set_build_cache_summary(format!("Erro: {}", e).into());
+                        ui.set_build_cache(std::rc::Rc::new(slint::VecModel::<BuildCacheData>::default()).into());
+                    }
+                }
+            }
+        })
+        .unwrap();
+    });
+}
+
+// Configura callbacks do cache de build
+fn setup_build_cache_callbacks(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+) {
+    let ui = ui_weak.upgrade().unwrap();
+
+    ui.on_refresh_build_cache({
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        move || {
+            load_build_cache(ui_weak.clone(), docker_manager.clone());
+        }
+    });
+
+    ui.on_prune_build_cache({
+        let ui_weak = ui_weak.clone();
+        move |older_than| {
+            let ui_weak_clone = ui_weak.clone();
+            let docker_manager_clone = docker_manager.clone();
+            let older_than = older_than.to_string();
+
+            if let Some(ui) = ui_weak_clone.upgrade() {
+                ui.set_build_cache_loading(true);
+            }
+
+            tokio::spawn(async move {
+                let result = {
+                    let docker_manager = docker_manager_clone.lock().await;
+                    docker_manager
+                        .prune_build_cache(if older_than.is_empty() {
+                            None
+                        } else {
+                            Some(older_than.as_str())
+                        })
+                        .await
+                };
+
+                let ui_weak_result = ui_weak_clone.clone();
+                slint::invoke_from_event_loop(move || {
+                    if let Some(ui) = ui_weak_result.upgrade() {
+                        match result {
+                            Ok(reclaimed) => {
+                                ui.set_notification_message(
+                                    format!("Cache de build limpo: {} liberados", reclaimed).into(),
+                                );
+                                ui.set_notification_is_error(false);
+                            }
+                            Err(e) => {
+                                ui.set_notification_message(e.to_string().into());
+                                ui.set_notification_is_error(true);
+                            }
+                        }
+                        ui.set_show_notification(true);
+                    }
+                })
+                .unwrap();
+
+                load_build_cache(ui_weak_clone, docker_manager_clone);
+            });
+        }
+    });
+}
+
+// Configura carregamento de imagens a partir de arquivos .tar (docker load)
+fn setup_load_archive_callbacks(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+) {
+    let ui = ui_weak.upgrade().unwrap();
+
+    ui.on_load_archive({
+        let ui_weak = ui_weak.clone();
+        move |path| {
+            let file = match image_archive::archive_path(&path) {
+                Ok(file) => file,
+                Err(e) => {
+                    if let Some(ui) = ui_weak.upgrade() {
+                        ui.set_load_archive_status(e.to_string().into());
+                    }
+                    return;
+                }
+            };
+
+            if let Some(ui) = ui_weak.upgrade() {
+                ui.set_loading_archive(true);
+                ui.set_load_archive_progress(0.0);
+                ui.set_load_archive_status(
+                    format!("Enviando {} para '{}'...", file.display(), ui.get_context_name())
+                        .into(),
+                );
+            }
+
+            let ui_weak_clone = ui_weak.clone();
+            let docker_manager_clone = docker_manager.clone();
+            tokio::spawn(async move {
+                // Comando montado sob o lock; o envio roda fora dele
+                let command = docker_manager_clone.lock().await.load_image_command();
+
+                let ui_weak_progress = ui_weak_clone.clone();
+                let mut last_refresh = std::time::Instant::now();
+                let result = image_archive::run_load(command, &file, |sent, total| {
+                    let throttled = last_refresh.elapsed() < Duration::from_millis(200);
+                    if total == 0 || (sent < total && throttled) {
+                        return;
+                    }
+                    last_refresh = std::time::Instant::now();
+
+                    let ui_weak_progress = ui_weak_progress.clone();
+                    slint::invoke_from_event_loop(move || {
+                        if let Some(ui) = ui_weak_progress.upgrade() {
+                            ui.set_load_archive_progress(sent as f32 / total as f32);
+                            ui.set_load_archive_status(
+                                if sent < total {
+                                    format!(
+                                        "Enviando {} de {}",
+                                        format::bytes(sent),
+                                        format::bytes(total)
+                                    )
+                                } else {
+                                    "Importando camadas no daemon...".to_string()
+                                }
+                                .into(),
+                            );
+                        }
+                    })
+                    .unwrap();
+                })
+                .await;
+
+                slint::invoke_from_event_loop(move || {
+                    if let Some(ui) = ui_weak_clone.upgrade() {
+                        ui.set_loading_archive(false);
+                        match result {
+                            Ok(images) => {
+                                let loaded = if images.is_empty() {
+                                    "imagem carregada".to_string()
+                                } else {
+                                    images.join(", ")
+                                };
+                                ui.set_load_archive_progress(1.0);
+                                ui.set_load_archive_status(format!("Carregada: {}", loaded).into());
+                                ui.set_load_archive_path("".into());
+                                ui.set_notification_message(
+                                    format!("[{}] Carregada: {}", ui.get_context_name(), loaded)
+                                        .into(),
+                                );
+                                ui.set_notification_is_error(false);
+                            }
+                            Err(e) => {
+                                ui.set_load_archive_progress(0.0);
+                                ui.set_load_archive_status(e.to_string().into());
+                                ui.set_notification_message(e.to_string().into());
+                                ui.set_notification_is_error(true);
+                            }
+                        }
+                        ui.set_show_notification(true);
+                    }
+                })
+                .unwrap();
+            });
+        }
+    });
+}
+
+// Atualiza progresso por plataforma e log do build
+fn update_build_progress(ui: &AppWindow, progress: &[PlatformProgress], log_lines: &[String]) {
+    let items: Vec<BuildPlatformProgress> = progress
+        .iter()
+        .map(|item| BuildPlatformProgress {
+            platform: item.platform.clone().into(),
+            step: item.step as i32,
+            total: item.total as i32,
+            status: item.status.clone().into(),
+        })
+        .collect();
+    ui.set_build_progress(std::rc::Rc::new(slint::VecModel::from(items)).into());
+    ui.set_build_log(log_lines.join("\n").into());
+}
+
+// Configura callbacks da tela de build (buildx multi-arquitetura)
+fn setup_build_callbacks(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+) {
+    let ui = ui_weak.upgrade().unwrap();
+
+    ui.on_start_build({
+        let ui_weak = ui_weak.clone();
+        move |context_dir, dockerfile, tag, amd64, arm64, push| {
+            let ui_weak_clone = ui_weak.clone();
+            let docker_manager_clone = docker_manager.clone();
+
+            let platforms: Vec<String> = [amd64, arm64]
+                .iter()
+                .zip(SUPPORTED_PLATFORMS)
+                .filter(|(selected, _)| **selected)
+                .map(|(_, platform)| platform.to_string())
+                .collect();
+            let request = BuildRequest {
+                context_dir: context_dir.to_string(),
+                dockerfile: dockerfile.to_string(),
+                tag: tag.to_string(),
+                platforms,
+                push,
+            };
+
+            if let Err(e) = request.validate() {
+                if let Some(ui) = ui_weak_clone.upgrade() {
+                    ui.set_notification_message(e.to_string().into());
+                    ui.set_notification_is_error(true);
+                    ui.set_show_notification(true);
+                }
+                return;
+            }
+
+            if let Some(ui) = ui_weak_clone.upgrade() {
+                ui.set_building(true);
+                update_build_progress(&ui, BuildProgress::new(&request.platforms).platforms(), &[]);
+            }
+
+            tokio::spawn(async move {
+                // Monta os comandos e libera o gerenciador durante o build
+                let (command, builder_setup) = {
+                    let manager = docker_manager_clone.lock().await;
+                    let setup = request
+                        .is_multi_platform()
+                        .then(|| manager.buildx_builder_setup());
+                    (manager.buildx_command(&request), setup)
+                };
+
+                let progress = Arc::new(std::sync::Mutex::new(BuildProgress::new(
+                    &request.platforms,
+                )));
+                let log_lines = Arc::new(std::sync::Mutex::new(Vec::<String>::new()));
+                let mut last_refresh = std::time::Instant::now();
+
+                // Multiplataforma: o builder docker-container precisa existir antes do build
+                let builder_ready = match builder_setup {
+                    Some(setup) => setup.ensure().await,
+                    None => Ok(()),
+                };
+
+                let result = if let Err(e) = builder_ready {
+                    Err(e)
+                } else {
+                    let progress = progress.clone();
+                    let log_lines = log_lines.clone();
+                    let ui_weak_progress = ui_weak_clone.clone();
+                    run_build(command, move |line| {
+                        let changed = progress.lock().unwrap().update(line);
+                        {
+                            let mut log_lines = log_lines.lock().unwrap();
+                            log_lines.push(line.to_string());
+                            let overflow = log_lines.len().saturating_sub(200);
+                            log_lines.drain(..overflow);
+                        }
+
+                        // Limita atualizações da interface a ~5 por segundo
+                        if changed || last_refresh.elapsed() >= Duration::from_millis(200) {
+                            last_refresh = std::time::Instant::now();
+                            let snapshot = progress.lock().unwrap().platforms().to_vec();
+                            let lines = log_lines.lock().unwrap().clone();
+                            let ui_weak_progress = ui_weak_progress.clone();
+                            slint::invoke_from_event_loop(move || {
+                                if let Some(ui) = ui_weak_progress.upgrade() {
+                                    update_build_progress(&ui, &snapshot, &lines);
+                                }
+                            })
+                            .unwrap();
+                        }
+                    })
+                    .await
+                };
+
+                progress.lock().unwrap().finish(result.is_ok());
+                let snapshot = progress.lock().unwrap().platforms().to_vec();
+                let lines = log_lines.lock().unwrap().clone();
+                let tag = request.tag.clone();
+
+                slint::invoke_from_event_loop(move || {
+                    if let Some(ui) = ui_weak_clone.upgrade() {
+                        ui.set_building(false);
+                        update_build_progress(&ui, &snapshot, &lines);
+                        match result {
+                            Ok(()) => {
+                                ui.set_notification_message(
+                                    if push {
+                                        format!("Build concluído e enviado para {}", tag)
+                                    } else {
+                                        "Build concluído".to_string()
+                                    }
+                                    .into(),
+                                );
+                                ui.set_notification_is_error(false);
                             }
-                        })
-                        .unwrap();
+                            Err(e) => {
+                                ui.set_notification_message(e.to_string().into());
+                                ui.set_notification_is_error(true);
+                            }
+                        }
+                        ui.set_show_notification(true);
                     }
-                    Err(e) => {
-                        let error_message = e.to_string();
+                })
+                .unwrap();
+            });
+        }
+    });
+}
+
+// Atualiza lista de stacks e a matriz de saúde da stack selecionada
+fn refresh_stacks(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+    selected_stack: String,
+) {
+    tokio::spawn(async move {
+        let (stacks, containers, compose, over_ssh) = {
+            let docker_manager = docker_manager.lock().await;
+            let stacks = docker_manager.list_compose_stacks().await;
+            let containers = if selected_stack.is_empty() {
+                None
+            } else {
+                Some(docker_manager.compose_containers(&selected_stack).await)
+            };
+            let compose = docker_manager.compose_cli().cloned();
+            let over_ssh = docker_manager.context().host.starts_with("ssh://");
+            (stacks, containers, compose, over_ssh)
+        };
+
+        slint::invoke_from_event_loop(move || {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+
+            ui.set_stack_compose_summary(
+                match &compose {
+                    Some(compose) => format!("Compose: {}", compose.summary()),
+                    None => "Compose não encontrado: stacks listadas pelos labels dos containers; implantar requer docker compose".to_string(),
+                }
+                .into(),
+            );
+            // Plugin v2 no host é o que permite rodar as stacks onde estão os arquivos
+            ui.set_stack_compose_installable(
+                over_ssh
+                    && !compose.as_ref().is_some_and(|compose| {
+                        compose.on_host
+                            && compose.binary == ComposeBinary::Plugin
+                            && !compose.is_legacy()
+                    }),
+            );
+
+            match stacks {
+                Ok(stacks) => {
+                    ui.set_stacks_error("".into());
+                    let slint_stacks: Vec<StackData> = stacks
+                        .into_iter()
+                        .map(|stack| StackData {
+                            name: stack.name.into(),
+                            status: stack.status.into(),
+                            config_files: stack.config_files.into(),
+                        })
+                        .collect();
+                    ui.set_stacks(std::rc::Rc::new(slint::VecModel::from(slint_stacks)).into());
+                    ui.set_stacks_updated(controller::updated_label());
+                }
+                Err(e) => ui.set_stacks_error(e.to_string().into()),
+            }
+
+            // Ignora respostas de uma stack que não está mais selecionada
+            if ui.get_selected_stack() != selected_stack.as_str() {
+                return;
+            }
+
+            match containers {
+                Some(Ok(containers)) => {
+                    let matrix = compose::service_matrix(&containers);
+                    let level = matrix
+                        .iter()
+                        .map(|service| service.level())
+                        .max()
+                        .unwrap_or(HealthLevel::Red);
+                    let converged = matrix
+                        .iter()
+                        .filter(|service| service.level() == HealthLevel::Green)
+                        .count();
+
+                    ui.set_stack_converged_status(
+                        if matrix.is_empty() {
+                            "nenhum container encontrado".to_string()
+                        } else if level == HealthLevel::Green {
+                            format!("convergido ({} serviços)", matrix.len())
+                        } else {
+                            format!("{}/{} serviços convergidos", converged, matrix.len())
+                        }
+                        .into(),
+                    );
+                    ui.set_stack_converged_level(level.as_str().into());
+
+                    let services: Vec<ServiceHealthData> = matrix
+                        .iter()
+                        .map(|service| ServiceHealthData {
+                            service: service.service.clone().into(),
+                            running: service.running as i32,
+                            starting: service.starting as i32,
+                            stopped: service.stopped as i32,
+                            health: service.health_label().into(),
+                            state_level: service.state_level().as_str().into(),
+                            health_level: service.health_level().as_str().into(),
+                        })
+                        .collect();
+                    ui.set_stack_services(std::rc::Rc::new(slint::VecModel::from(services)).into());
+                }
+                Some(Err(e)) => {
+                    ui.set_stack_converged_status(e.to_string().into());
+                    ui.set_stack_converged_level("red".into());
+                }
+                None => {}
+            }
+        })
+        .unwrap();
+    });
+}
+
+// Exibe a prévia do deploy e aguarda confirmação
+fn show_stack_diff(ui: &AppWindow, diff: &StackDiff) {
+    let changes: Vec<StackChangeData> = diff
+        .changes
+        .iter()
+        .map(|change| StackChangeData {
+            service: change.service.clone().into(),
+            action: change.action.label().into(),
+        })
+        .collect();
+    let images: Vec<slint::SharedString> = diff
+        .images_to_pull
+        .iter()
+        .map(|image| image.clone().into())
+        .collect();
+
+    ui.set_stack_diff_changes(std::rc::Rc::new(slint::VecModel::from(changes)).into());
+    ui.set_stack_diff_images(std::rc::Rc::new(slint::VecModel::from(images)).into());
+    ui.set_stack_diff_text(
+        if !diff.has_snapshot {
+            "Sem snapshot anterior: o diff de configuração fica disponível após o primeiro deploy feito por aqui.".to_string()
+        } else if diff.config_diff.is_empty() {
+            "Nenhuma mudança no compose config desde o último deploy.".to_string()
+        } else {
+            diff.config_diff.join("\n")
+        }
+        .into(),
+    );
+    ui.set_stack_diff_note(
+        if diff.has_changes() {
+            "Revise as mudanças abaixo antes de confirmar o deploy."
+        } else {
+            "Nenhuma mudança detectada; o deploy apenas garante o estado atual."
+        }
+        .into(),
+    );
+    ui.set_show_stack_diff(true);
+}
+
+// Prévia de um arquivo compose no servidor escolhido (conexão ativa ou temporária)
+async fn preview_file_stack(
+    docker_manager: &Arc<tokio::sync::Mutex<DockerManager>>,
+    stack: &ComposeStack,
+    server: &str,
+) -> anyhow::Result<StackDiff> {
+    {
+        let active = docker_manager.lock().await;
+        if active.context().name == server {
+            return active.preview_stack_update(stack).await;
+        }
+    }
+    DockerManager::for_context_name(server)
+        .await?
+        .preview_stack_update(stack)
+        .await
+}
+
+// Implanta um arquivo compose no servidor escolhido (conexão ativa ou temporária)
+async fn deploy_file_stack(
+    docker_manager: &Arc<tokio::sync::Mutex<DockerManager>>,
+    stack: &ComposeStack,
+    server: &str,
+    pull_policy: PullPolicy,
+) -> anyhow::Result<()> {
+    {
+        let active = docker_manager.lock().await;
+        if active.context().name == server {
+            return active.deploy_stack(stack, pull_policy).await;
+        }
+    }
+    DockerManager::for_context_name(server)
+        .await?
+        .deploy_stack(stack, pull_policy)
+        .await
+}
+
+// Busca a stack pelo nome no servidor ativo
+async fn find_stack(docker_manager: &DockerManager, name: &str) -> anyhow::Result<ComposeStack> {
+    docker_manager
+        .list_compose_stacks()
+        .await?
+        .into_iter()
+        .find(|stack| stack.name == name)
+        .ok_or_else(|| anyhow::anyhow!("Stack '{}' não encontrada", name))
+}
+
+// Configura tela de stacks do Compose
+fn setup_stack_callbacks(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+) {
+    let ui = ui_weak.upgrade().unwrap();
+
+    // Plugin do Compose no servidor SSH ativo; depois o compose é detectado de novo
+    ui.on_install_compose_plugin({
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        move || {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            let server = ui.get_context_name().to_string();
+            ui.set_stack_compose_installing(true);
+            ui.set_stack_compose_install_status("Verificando o host...".into());
+
+            let ui_weak = ui_weak.clone();
+            let docker_manager = docker_manager.clone();
+            tokio::spawn(async move {
+                let result: anyhow::Result<()> = async {
+                    let context = docker_install::find_server(&server)?;
+                    let probe = docker_install::probe(&context).await?;
+                    let script = docker_install::compose_plugin_script(&probe)?;
+                    let mut last_refresh = std::time::Instant::now();
+                    docker_install::run_install(&context, &script, |line| {
+                        // Só a última linha, limitada a ~5 atualizações por segundo
+                        if line.trim().is_empty()
+                            || last_refresh.elapsed() < Duration::from_millis(200)
+                        {
+                            return;
+                        }
+                        last_refresh = std::time::Instant::now();
+                        let line = line.to_string();
+                        let ui_weak = ui_weak.clone();
                         slint::invoke_from_event_loop(move || {
-                            if let Some(ui) = ui_weak_clone.upgrade() {
-                                ui.set_volume_list_error(error_message.into());
+                            if let Some(ui) = ui_weak.upgrade() {
+                                ui.set_stack_compose_install_status(line.into());
                             }
                         })
                         .unwrap();
+                    })
+                    .await
+                }
+                .await;
+
+                let detail = match &result {
+                    Ok(()) => "plugin do Compose".to_string(),
+                    Err(e) => format!("plugin do Compose: {}", e),
+                };
+                if let Err(e) = audit_log::append(&AuditEntry::new(
+                    &server,
+                    "compose.install",
+                    detail,
+                    result.is_ok(),
+                )) {
+                    eprintln!("Error writing audit log: {}", e);
+                }
+                if result.is_ok() {
+                    let mut manager = docker_manager.lock().await;
+                    if manager.context().name == server {
+                        manager.redetect_compose();
+                    }
+                }
+
+                slint::invoke_from_event_loop(move || {
+                    let Some(ui) = ui_weak.upgrade() else {
+                        return;
+                    };
+                    ui.set_stack_compose_installing(false);
+                    ui.set_stack_compose_install_status("".into());
+                    match result {
+                        Ok(()) => {
+                            ui.set_notification_message(
+                                format!("Plugin do Compose instalado em '{}'", server).into(),
+                            );
+                            ui.set_notification_is_error(false);
+                        }
+                        Err(e) => {
+                            ui.set_notification_message(
+                                format!("Falha ao instalar o plugin do Compose:\n{}", e).into(),
+                            );
+                            ui.set_notification_is_error(true);
+                        }
+                    }
+                    ui.set_show_notification(true);
+                    refresh_stacks(
+                        ui_weak,
+                        docker_manager,
+                        ui.get_selected_stack().to_string(),
+                    );
+                })
+                .unwrap();
+            });
+        }
+    });
+
+    ui.on_select_stack({
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        move |name| {
+            if let Some(ui) = ui_weak.upgrade() {
+                ui.set_stack_services(
+                    std::rc::Rc::new(slint::VecModel::<ServiceHealthData>::default()).into(),
+                );
+                ui.set_stack_converged_status("carregando...".into());
+                ui.set_stack_converged_level("yellow".into());
+            }
+            refresh_stacks(ui_weak.clone(), docker_manager.clone(), name.to_string());
+        }
+    });
+
+    ui.on_preview_stack_update({
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        move |name| {
+            let ui_weak_clone = ui_weak.clone();
+            let docker_manager_clone = docker_manager.clone();
+            let name = name.to_string();
+
+            if let Some(ui) = ui_weak_clone.upgrade() {
+                ui.set_stack_preview_loading(true);
+            }
+
+            tokio::spawn(async move {
+                let result = {
+                    let docker_manager = docker_manager_clone.lock().await;
+                    match find_stack(&docker_manager, &name).await {
+                        Ok(stack) => docker_manager.preview_stack_update(&stack).await,
+                        Err(e) => Err(e),
                     }
+                };
+
+                slint::invoke_from_event_loop(move || {
+                    let Some(ui) = ui_weak_clone.upgrade() else {
+                        return;
+                    };
+                    ui.set_stack_preview_loading(false);
+
+                    match result {
+                        Ok(diff) => show_stack_diff(&ui, &diff),
+                        Err(e) => {
+                            ui.set_notification_message(
+                                format!("Falha ao analisar a stack:\n{}", e).into(),
+                            );
+                            ui.set_notification_is_error(true);
+                            ui.set_show_notification(true);
+                        }
+                    }
+                })
+                .unwrap();
+            });
+        }
+    });
+
+    // Reinício gradual das réplicas de um serviço, pelas labels do compose
+    ui.on_rolling_restart_service({
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        move |stack, service| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            if !ui.get_stack_rolling_service().is_empty() {
+                return;
+            }
+            let selector = match LabelSelector::parse(&format!(
+                "com.docker.compose.project={},com.docker.compose.service={}",
+                stack, service
+            )) {
+                Ok(selector) => selector,
+                Err(e) => {
+                    ui.set_stacks_error(e.to_string().into());
+                    return;
+                }
+            };
+            ui.set_stack_rolling_service(service.clone());
+            ui.set_stack_rolling_status("Buscando réplicas...".into());
+
+            let ui_weak = ui_weak.clone();
+            let docker_manager = docker_manager.clone();
+            let stack = stack.to_string();
+            let service = service.to_string();
+            tokio::spawn(async move {
+                let refresh_ui = ui_weak.clone();
+                let replicas = docker_manager
+                    .lock()
+                    .await
+                    .containers_by_labels(&selector)
+                    .await;
+                let result = match replicas {
+                    Ok(replicas) if replicas.is_empty() => {
+                        Err(anyhow::anyhow!("Nenhuma réplica de '{}' encontrada", service))
+                    }
+                    Ok(replicas) => {
+                        let progress_ui = ui_weak.clone();
+                        rolling_restart(&docker_manager, &replicas, move |status| {
+                            let ui_weak = progress_ui.clone();
+                            slint::invoke_from_event_loop(move || {
+                                if let Some(ui) = ui_weak.upgrade() {
+                                    ui.set_stack_rolling_status(status.into());
+                                }
+                            })
+                            .unwrap();
+                        })
+                        .await
+                        .map(|()| replicas.len())
+                    }
+                    Err(e) => Err(e),
+                };
+
+                slint::invoke_from_event_loop(move || {
+                    let Some(ui) = ui_weak.upgrade() else {
+                        return;
+                    };
+                    ui.set_stack_rolling_service("".into());
+                    ui.set_stack_rolling_status("".into());
+                    match result {
+                        Ok(count) => {
+                            ui.set_notification_message(
+                                format!("'{}': {} réplicas reiniciadas uma a uma", service, count)
+                                    .into(),
+                            );
+                            ui.set_notification_is_error(false);
+                        }
+                        Err(e) => {
+                            ui.set_notification_message(e.to_string().into());
+                            ui.set_notification_is_error(true);
+                        }
+                    }
+                    ui.set_show_notification(true);
+                })
+                .unwrap();
+                refresh_stacks(refresh_ui, docker_manager, stack);
+            });
+        }
+    });
+
+    // Revisa um arquivo compose antes do primeiro deploy
+    ui.on_preview_compose_file({
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        move |path, project, server| {
+            let ui_weak_clone = ui_weak.clone();
+            let docker_manager_clone = docker_manager.clone();
+            let path = path.to_string();
+            let project = project.to_string();
+            let server = server.to_string();
+
+            if let Some(ui) = ui_weak_clone.upgrade() {
+                ui.set_stack_preview_loading(true);
+            }
+
+            tokio::spawn(async move {
+                let result = match compose::file_stack(&path, &project) {
+                    Ok(stack) => preview_file_stack(&docker_manager_clone, &stack, &server)
+                        .await
+                        .map(|diff| (stack.name, diff)),
+                    Err(e) => Err(e),
+                };
+
+                slint::invoke_from_event_loop(move || {
+                    let Some(ui) = ui_weak_clone.upgrade() else {
+                        return;
+                    };
+                    ui.set_stack_preview_loading(false);
+
+                    match result {
+                        Ok((project, diff)) => {
+                            ui.set_stack_deploy_pending(project.into());
+                            show_stack_diff(&ui, &diff);
+                        }
+                        Err(e) => {
+                            ui.set_notification_message(
+                                format!("Falha ao analisar o arquivo compose:\n{}", e).into(),
+                            );
+                            ui.set_notification_is_error(true);
+                            ui.set_show_notification(true);
+                        }
+                    }
+                })
+                .unwrap();
+            });
+        }
+    });
+
+    ui.on_confirm_stack_deploy({
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        move |name| {
+            let ui_weak_clone = ui_weak.clone();
+            let docker_manager_clone = docker_manager.clone();
+            let name = name.to_string();
+
+            // Deploy de arquivo: (caminho, servidor) do modal de implantação
+            let mut file_deploy = None;
+            let mut selected = name.clone();
+            let mut pull_policy = PullPolicy::default();
+            if let Some(ui) = ui_weak_clone.upgrade() {
+                ui.set_deploying_stack(true);
+                pull_policy = PullPolicy::from_ui(&ui.get_pull_policy());
+                let mut server = ui.get_context_name().to_string();
+                if !ui.get_stack_deploy_pending().is_empty() {
+                    server = ui.get_stack_deploy_server().to_string();
+                    // Implantada em outro servidor: mantém a seleção da tela atual
+                    if server != ui.get_context_name().as_str() {
+                        selected = ui.get_selected_stack().to_string();
+                    }
+                    file_deploy = Some((ui.get_stack_deploy_file().to_string(), server.clone()));
                 }
+                AppConfig::remember_pull_policy(&server, pull_policy);
+            }
+
+            tokio::spawn(async move {
+                let result = match &file_deploy {
+                    Some((path, server)) => match compose::file_stack(path, &name) {
+                        Ok(stack) => {
+                            deploy_file_stack(&docker_manager_clone, &stack, server, pull_policy)
+                                .await
+                        }
+                        Err(e) => Err(e),
+                    },
+                    None => {
+                        let docker_manager = docker_manager_clone.lock().await;
+                        match find_stack(&docker_manager, &name).await {
+                            Ok(stack) => docker_manager.deploy_stack(&stack, pull_policy).await,
+                            Err(e) => Err(e),
+                        }
+                    }
+                };
+
+                let ui_weak_result = ui_weak_clone.clone();
+                slint::invoke_from_event_loop(move || {
+                    if let Some(ui) = ui_weak_result.upgrade() {
+                        ui.set_deploying_stack(false);
+                        match result {
+                            Ok(()) => {
+                                ui.set_show_stack_diff(false);
+                                let message = match &file_deploy {
+                                    Some((_, server)) => {
+                                        ui.set_show_stack_file_deploy(false);
+                                        ui.set_stack_deploy_pending("".into());
+                                        ui.set_stack_deploy_file("".into());
+                                        ui.set_stack_deploy_project("".into());
+                                        if *server == ui.get_context_name().as_str() {
+                                            ui.set_selected_stack(name.clone().into());
+                                        }
+                                        format!("Stack '{}' implantada em '{}'", name, server)
+                                    }
+                                    None => format!("Stack '{}' atualizada", name),
+                                };
+                                ui.set_notification_message(message.into());
+                                ui.set_notification_is_error(false);
+                            }
+                            Err(e) => {
+                                ui.set_notification_message(
+                                    format!("Falha ao atualizar a stack:\n{}", e).into(),
+                                );
+                                ui.set_notification_is_error(true);
+                            }
+                        }
+                        ui.set_show_notification(true);
+                    }
+                })
+                .unwrap();
+
+                refresh_stacks(ui_weak_clone, docker_manager_clone, selected);
             });
         }
-    });
+    });
+
+    ui.on_refresh_stacks_clicked({
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        move || {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            refresh_stacks(
+                ui_weak.clone(),
+                docker_manager.clone(),
+                ui.get_selected_stack().to_string(),
+            );
+        }
+    });
+
+    start_screen_refresh_timer(ui_weak.clone(), Screen::Stacks, 5, move |ui| {
+        refresh_stacks(
+            ui.as_weak(),
+            docker_manager.clone(),
+            ui.get_selected_stack().to_string(),
+        );
+    });
+}
+
+// Atualiza uma tela ao entrar nela e depois a cada `interval_secs` enquanto estiver visível
+fn start_screen_refresh_timer(
+    ui_weak: Weak<AppWindow>,
+    screen: Screen,
+    interval_secs: u32,
+    refresh: impl Fn(&AppWindow) + 'static,
+) {
+    let timer = Timer::default();
+    let seconds_since_refresh = std::cell::Cell::new(u32::MAX);
+    timer.start(TimerMode::Repeated, Duration::from_secs(1), move || {
+        let Some(ui) = ui_weak.upgrade() else {
+            return;
+        };
+        if ui.get_current_screen() != screen.index() {
+            seconds_since_refresh.set(u32::MAX);
+            return;
+        }
 
-    // Callback para ações em volumes
-    ui.on_volume_action({
-        let ui_weak = ui_weak.clone();
-        let volume_manager = volume_ui_manager.clone();
-        move |volume_name, action| {
-            let ui_weak_clone = ui_weak.clone();
-            let volume_manager_clone = volume_manager.clone();
-            let volume_name_str = volume_name.to_string();
-            let action_str = action.to_string();
-            let loading_key = format!("{}_{}", volume_name_str, action_str);
+        if seconds_since_refresh.get() >= interval_secs {
+            seconds_since_refresh.set(1);
+            refresh(&ui);
+        } else {
+            seconds_since_refresh.set(seconds_since_refresh.get() + 1);
+        }
+    });
 
-            tokio::spawn(async move {
-                // Define o estado de loading
-                let ui_weak_loading = ui_weak_clone.clone();
-                let loading_key_clone = loading_key.clone();
-                slint::invoke_from_event_loop(move || {
-                    if let Some(ui) = ui_weak_loading.upgrade() {
-                        ui.set_volume_loading(loading_key_clone.into());
-                    }
-                })
-                .unwrap();
+    // Mantém o timer vivo
+    std::mem::forget(timer);
+}
 
-                let manager = volume_manager_clone.lock().await;
+// Atualiza a lista de nós do swarm
+fn refresh_swarm(ui_weak: Weak<AppWindow>, docker_manager: Arc<tokio::sync::Mutex<DockerManager>>) {
+    tokio::spawn(async move {
+        let result = docker_manager.lock().await.list_swarm_nodes().await;
 
-                // Executa a ação no volume
-                let result = manager
-                    .execute_volume_action(&volume_name_str, &action_str)
-                    .await;
+        slint::invoke_from_event_loop(move || {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
 
-                let ui_weak_result = ui_weak_clone.clone();
-                match result {
-                    Ok(success_message) => {
-                        slint::invoke_from_event_loop(move || {
-                            if let Some(ui) = ui_weak_result.upgrade() {
-                                ui.set_volume_loading("".into());
-                                ui.set_notification_message(success_message.into());
-                                ui.set_notification_is_error(false);
-                                ui.set_show_notification(true);
+            match result {
+                Ok(nodes) => {
+                    ui.set_swarm_updated(controller::updated_label());
+                    ui.set_swarm_error(
+                        if nodes.is_empty() { "Nenhum nó encontrado" } else { "" }.into(),
+                    );
+                    let slint_nodes: Vec<SwarmNodeData> = nodes
+                        .iter()
+                        .map(|node| {
+                            let labels: Vec<slint::SharedString> = node
+                                .labels
+                                .iter()
+                                .map(|(key, value)| {
+                                    if value.is_empty() {
+                                        key.clone().into()
+                                    } else {
+                                        format!("{}={}", key, value).into()
+                                    }
+                                })
+                                .collect();
+                            SwarmNodeData {
+                                id: node.id.clone().into(),
+                                hostname: node.hostname.clone().into(),
+                                role: node.role().into(),
+                                status: node.status.clone().into(),
+                                availability: node.availability.clone().into(),
+                                manager_status: node.manager_status.clone().into(),
+                                engine_version: node.engine_version.clone().into(),
+                                is_self: node.is_self,
+                                labels: std::rc::Rc::new(slint::VecModel::from(labels)).into(),
+                                task_count: node.task_count() as i32,
+                                task_summary: node.task_summary().into(),
                             }
                         })
-                        .unwrap();
+                        .collect();
+                    ui.set_swarm_nodes(std::rc::Rc::new(slint::VecModel::from(slint_nodes)).into());
+                }
+                Err(e) => ui.set_swarm_error(e.to_string().into()),
+            }
+        })
+        .unwrap();
+    });
+}
 
-                        // Timer para limpar mensagem de sucesso após 3 segundos
-                        let ui_weak_timer = ui_weak_clone.clone();
-                        tokio::spawn(async move {
-                            tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
-                            slint::invoke_from_event_loop(move || {
-                                if let Some(ui) = ui_weak_timer.upgrade() {
-                                    ui.set_show_notification(false);
-                                }
-                            })
-                            .unwrap();
-                        });
-                    }
-                    Err(error_message) => {
-                        let error_message_clone = error_message.clone();
-                        slint::invoke_from_event_loop(move || {
-                            if let Some(ui) = ui_weak_result.upgrade() {
-                                ui.set_volume_loading("".into());
-                                let formatted_error = if error_message_clone.starts_with("IN_USE:")
-                                {
-                                    error_message_clone
-                                        .strip_prefix("IN_USE:")
-                                        .unwrap_or(&error_message_clone)
-                                        .to_string()
-                                } else {
-                                    error_message_clone
-                                        .strip_prefix("OTHER_ERROR:")
-                                        .unwrap_or(&error_message_clone)
-                                        .to_string()
-                                };
-                                ui.set_notification_message(formatted_error.into());
-                                ui.set_notification_is_error(true);
-                                ui.set_show_notification(true);
-                            }
-                        })
-                        .unwrap();
-                    }
+// Ações sobre um nó do swarm
+enum NodeAction {
+    Availability(String),
+    AddLabel(String),
+    RemoveLabel(String),
+}
+
+fn apply_node_action(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+    node_id: String,
+    action: NodeAction,
+) {
+    if let Some(ui) = ui_weak.upgrade() {
+        ui.set_swarm_busy_node(node_id.clone().into());
+    }
+
+    tokio::spawn(async move {
+        let result = {
+            let docker_manager = docker_manager.lock().await;
+            match &action {
+                NodeAction::Availability(availability) => {
+                    docker_manager.set_node_availability(&node_id, availability).await
+                }
+                NodeAction::AddLabel(label) => {
+                    docker_manager.update_node_label(&node_id, label, false).await
                 }
+                NodeAction::RemoveLabel(label) => {
+                    docker_manager.update_node_label(&node_id, label, true).await
+                }
+            }
+        };
 
-                // Atualiza a lista imediatamente após a ação
-                drop(manager); // Libera o lock
-                let mut manager = volume_manager_clone.lock().await;
-                if let Ok(()) = manager.refresh_volumes().await {
-                    let volumes = manager.get_volumes();
-                    let ui_weak_final = ui_weak_clone.clone();
-                    slint::invoke_from_event_loop(move || {
-                        if let Some(ui) = ui_weak_final.upgrade() {
-                            update_ui_volumes_from_slint(&ui, &volumes);
-                        }
-                    })
-                    .unwrap();
+        let ui_weak_result = ui_weak.clone();
+        slint::invoke_from_event_loop(move || {
+            if let Some(ui) = ui_weak_result.upgrade() {
+                ui.set_swarm_busy_node("".into());
+                if let Err(e) = result {
+                    ui.set_notification_message(e.to_string().into());
+                    ui.set_notification_is_error(true);
+                    ui.set_show_notification(true);
                 }
-            });
+            }
+        })
+        .unwrap();
+
+        refresh_swarm(ui_weak, docker_manager);
+    });
+}
+
+// Configura tela de nós do swarm
+fn setup_swarm_callbacks(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+) {
+    let ui = ui_weak.upgrade().unwrap();
+
+    ui.on_set_node_availability({
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        move |node_id, availability| {
+            apply_node_action(
+                ui_weak.clone(),
+                docker_manager.clone(),
+                node_id.to_string(),
+                NodeAction::Availability(availability.to_string()),
+            );
+        }
+    });
+
+    ui.on_add_node_label({
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        move |node_id, label| {
+            apply_node_action(
+                ui_weak.clone(),
+                docker_manager.clone(),
+                node_id.to_string(),
+                NodeAction::AddLabel(label.to_string()),
+            );
+        }
+    });
+
+    ui.on_remove_node_label({
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        move |node_id, label| {
+            apply_node_action(
+                ui_weak.clone(),
+                docker_manager.clone(),
+                node_id.to_string(),
+                NodeAction::RemoveLabel(label.to_string()),
+            );
         }
     });
+
+    ui.on_refresh_swarm_clicked({
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        move || refresh_swarm(ui_weak.clone(), docker_manager.clone())
+    });
+
+    start_screen_refresh_timer(ui_weak.clone(), Screen::Swarm, 5, move |ui| {
+        refresh_swarm(ui.as_weak(), docker_manager.clone());
+    });
 }
 
 // Configura callbacks da interface
-fn setup_callbacks(ui_weak: Weak<AppWindow>, _app_state: AppState) {
+fn setup_callbacks(ui_weak: Weak<AppWindow>, _app_state: AppState, store: UiStore) {
     let ui = ui_weak.upgrade().unwrap();
 
     // Callback para mudança de tela
@@ -1076,445 +4450,950 @@ fn setup_callbacks(ui_weak: Weak<AppWindow>, _app_state: AppState) {
         let ui_weak = ui_weak.clone();
         move |screen_index| {
             if let Some(ui) = ui_weak.upgrade() {
-                ui.set_current_screen(screen_index);
+                store.dispatch(&ui, Transition::Navigate(Screen::from_index(screen_index)));
+            }
+        }
+    });
+
+    // Cadastro e edição de servidores, disponíveis também sem conexão
+    setup_server_form_callbacks(ui_weak.clone());
+
+    // Callback para salvar proxy global e do servidor ativo
+    ui.on_save_proxy_settings({
+        let ui_weak = ui_weak.clone();
+        move |global_url, no_proxy, server_url| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            let context_name = ui.get_context_name().to_string();
+
+            let global_url = global_url.trim().to_string();
+            let server_url = server_url.trim().to_string();
+            let result = AppConfig::update(|config| {
+                config.proxy = if global_url.is_empty() {
+                    None
+                } else {
+                    Some(ProxySettings {
+                        url: global_url,
+                        no_proxy: no_proxy.trim().to_string(),
+                    })
+                };
+
+                if server_url.is_empty() {
+                    config.server_proxies.remove(&context_name);
+                } else {
+                    config.server_proxies.insert(
+                        context_name,
+                        ProxySettings {
+                            url: server_url,
+                            no_proxy: String::new(),
+                        },
+                    );
+                }
+                Ok(())
+            });
+
+            match result {
+                Ok(_) => {
+                    ui.set_notification_message(
+                        "Proxy salvo. Conexões SSH usam o novo proxy ao reconectar.".into(),
+                    );
+                    ui.set_notification_is_error(false);
+                }
+                Err(e) => {
+                    ui.set_notification_message(format!("Falha ao salvar proxy:\n{}", e).into());
+                    ui.set_notification_is_error(true);
+                }
+            }
+            ui.set_show_notification(true);
+        }
+    });
+
+    // Callback para salvar as opções de transporte SSH do servidor ativo
+    ui.on_save_ssh_options({
+        let ui_weak = ui_weak.clone();
+        move |compression, ciphers, kex| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            let context_name = ui.get_context_name().to_string();
+            let options = SshOptions {
+                compression,
+                ciphers: ciphers.trim().to_string(),
+                kex: kex.trim().to_string(),
+            };
+
+            let result = options.validate().and_then(|()| {
+                AppConfig::update(|config| {
+                    if options.is_default() {
+                        config.ssh_options.remove(&context_name);
+                    } else {
+                        config.ssh_options.insert(context_name, options);
+                    }
+                    Ok(())
+                })
+            });
+            match result {
+                Ok(_) => {
+                    ui.set_notification_message(
+                        "Opções SSH salvas. Valem ao reconectar ao servidor.".into(),
+                    );
+                    ui.set_notification_is_error(false);
+                }
+                Err(e) => {
+                    ui.set_notification_message(
+                        format!("Falha ao salvar opções SSH:\n{}", e).into(),
+                    );
+                    ui.set_notification_is_error(true);
+                }
             }
+            ui.set_show_notification(true);
         }
     });
 }
 
-// Configura callbacks para criação de containers
-fn setup_create_container_callbacks(
+// Janelas de manutenção do servidor ativo e o indicador de alertas silenciados
+fn update_maintenance(ui: &AppWindow, config: &AppConfig, context_name: &str) {
+    let maintenance = config.maintenance.get(context_name).cloned().unwrap_or_default();
+    let now = chrono::Local::now();
+    let windows: Vec<MaintenanceWindowData> = maintenance
+        .windows
+        .iter()
+        .map(|window| MaintenanceWindowData {
+            id: window.id.clone().into(),
+            label: window.label().into(),
+        })
+        .collect();
+    ui.set_maintenance_windows(std::rc::Rc::new(slint::VecModel::from(windows)).into());
+    ui.set_maintenance_status(maintenance.active_reason(now).unwrap_or_default().into());
+    ui.set_maintenance_manual(
+        maintenance.until.is_some_and(|until| until > now.with_timezone(&chrono::Utc)),
+    );
+}
+
+// Tamanho dos caches por container do gerenciador ativo, a cada 5s nas configurações
+fn setup_diagnostics_timer(
     ui_weak: Weak<AppWindow>,
     docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
 ) {
-    let ui = ui_weak.upgrade().unwrap();
-
-    // Callback para criar container
-    ui.on_create_container({
-        let ui_weak = ui_weak.clone();
+    start_screen_refresh_timer(ui_weak, Screen::Settings, 5, move |ui| {
+        let ui_weak = ui.as_weak();
         let docker_manager = docker_manager.clone();
-        move |name, image, command, restart_policy, ports_text, volumes_text, env_vars_text| {
-            let ui_weak_clone = ui_weak.clone();
-            let docker_manager_clone = docker_manager.clone();
-            let name_str = name.to_string();
-            let image_str = image.to_string();
-            let command_str = command.to_string();
-            let restart_policy_str = restart_policy.to_string();
-            let ports_str = ports_text.to_string();
-            let volumes_str = volumes_text.to_string();
-            let env_vars_str = env_vars_text.to_string();
+        tokio::spawn(async move {
+            let sizes = docker_manager.lock().await.cache_sizes();
+            slint::invoke_from_event_loop(move || {
+                if let Some(ui) = ui_weak.upgrade() {
+                    ui.set_diagnostics(
+                        format!(
+                            "Deltas de CPU (previous_stats): {}\nTendências de memória: {}\nHistórico de deploys: {}",
+                            sizes.previous_stats, sizes.memory_trends, sizes.deploy_history
+                        )
+                        .into(),
+                    );
+                }
+            })
+            .unwrap();
+        });
+    });
+}
+
+// Exporta a mesma coleta do dashboard no esquema estável de stats_export
+fn setup_stats_export_callbacks(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+) {
+    let Some(ui) = ui_weak.upgrade() else {
+        return;
+    };
 
+    ui.on_open_stats_export({
+        let ui_weak = ui_weak.clone();
+        move || {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            let server = ui.get_context_name().to_string();
+            if ui.get_stats_export_path().is_empty() {
+                ui.set_stats_export_path(stats_export::default_path(&server).into());
+            }
+            ui.set_stats_export_json("".into());
+            ui.set_stats_export_error("".into());
+            ui.set_stats_export_status("".into());
+            ui.set_stats_export_loading(true);
+            ui.set_show_stats_export(true);
+
+            let ui_weak = ui_weak.clone();
+            let docker_manager = docker_manager.clone();
             tokio::spawn(async move {
-                // Define estado de loading
-                let ui_weak_loading = ui_weak_clone.clone();
+                // O gerenciador compartilhado já tem a amostra anterior para o delta de CPU
+                let result = docker_manager
+                    .lock()
+                    .await
+                    .get_docker_system_usage()
+                    .await
+                    .and_then(|usage| StatsSnapshot::new(&server, &usage).to_json());
                 slint::invoke_from_event_loop(move || {
-                    if let Some(ui) = ui_weak_loading.upgrade() {
-                        ui.set_creating_container(true);
+                    let Some(ui) = ui_weak.upgrade() else {
+                        return;
+                    };
+                    ui.set_stats_export_loading(false);
+                    match result {
+                        Ok(json) => ui.set_stats_export_json(json.into()),
+                        Err(e) => ui.set_stats_export_error(
+                            format!("Erro ao coletar estatísticas: {}", e).into(),
+                        ),
                     }
                 })
                 .unwrap();
+            });
+        }
+    });
 
-                // Valida campos obrigatórios
-                if name_str.trim().is_empty() {
-                    let ui_weak_error = ui_weak_clone.clone();
-                    slint::invoke_from_event_loop(move || {
-                        if let Some(ui) = ui_weak_error.upgrade() {
-                            ui.set_creating_container(false);
-                            ui.set_notification_message("Nome do container é obrigatório".into());
-                            ui.set_notification_is_error(true);
-                            ui.set_show_notification(true);
-                        }
-                    })
-                    .unwrap();
-                    return;
+    ui.on_save_stats_export({
+        let ui_weak = ui_weak.clone();
+        move |path| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            let json = ui.get_stats_export_json();
+            match stats_export::write_file(&path, &json) {
+                Ok(()) => {
+                    ui.set_stats_export_error("".into());
+                    ui.set_stats_export_status(format!("Gravado em {}", path.trim()).into());
                 }
+                Err(e) => ui.set_stats_export_error(e.to_string().into()),
+            }
+        }
+    });
+}
 
-                if image_str.trim().is_empty() {
-                    let ui_weak_error = ui_weak_clone.clone();
-                    slint::invoke_from_event_loop(move || {
-                        if let Some(ui) = ui_weak_error.upgrade() {
-                            ui.set_creating_container(false);
-                            ui.set_notification_message("Nome da imagem é obrigatório".into());
-                            ui.set_notification_is_error(true);
-                            ui.set_show_notification(true);
+// Configura janelas de manutenção por servidor; o indicador é reavaliado a cada 30s
+// para acompanhar o início e o fim das janelas
+fn setup_maintenance_callbacks(ui_weak: Weak<AppWindow>) {
+    let Some(ui) = ui_weak.upgrade() else {
+        return;
+    };
+
+    // Altera a manutenção do servidor ativo, salva e atualiza a tela
+    fn edit(
+        ui: &AppWindow,
+        change: impl FnOnce(&mut ServerMaintenance) -> anyhow::Result<()>,
+    ) {
+        let context_name = ui.get_context_name().to_string();
+        let result = AppConfig::update(|config| {
+            change(config.maintenance.entry(context_name.clone()).or_default())
+        });
+        match result {
+            Ok(_) => ui.set_maintenance_error("".into()),
+            Err(e) => ui.set_maintenance_error(e.to_string().into()),
+        }
+        update_maintenance(ui, &AppConfig::load(), &context_name);
+    }
+
+    ui.on_add_maintenance_window({
+        let ui_weak = ui_weak.clone();
+        move |days, start, end| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            edit(&ui, |maintenance| {
+                maintenance.windows.push(MaintenanceWindow::new(&days, &start, &end)?);
+                Ok(())
+            });
+        }
+    });
+
+    ui.on_remove_maintenance_window({
+        let ui_weak = ui_weak.clone();
+        move |id| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            edit(&ui, |maintenance| {
+                maintenance.windows.retain(|window| window.id != id.as_str());
+                Ok(())
+            });
+        }
+    });
+
+    ui.on_start_maintenance({
+        let ui_weak = ui_weak.clone();
+        move |minutes| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            edit(&ui, |maintenance| {
+                maintenance.until =
+                    Some(chrono::Utc::now() + chrono::Duration::minutes(minutes.max(1) as i64));
+                Ok(())
+            });
+        }
+    });
+
+    ui.on_end_maintenance({
+        let ui_weak = ui_weak.clone();
+        move || {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            edit(&ui, |maintenance| {
+                maintenance.until = None;
+                Ok(())
+            });
+        }
+    });
+
+    let timer = Timer::default();
+    timer.start(TimerMode::Repeated, Duration::from_secs(30), move || {
+        if let Some(ui) = ui_weak.upgrade() {
+            let context_name = ui.get_context_name().to_string();
+            update_maintenance(&ui, &AppConfig::load(), &context_name);
+        }
+    });
+    std::mem::forget(timer);
+}
+
+// Monitora o espaço livre do data-root do servidor ativo e avisa ao passar do limite
+fn setup_disk_watcher(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+    low_bandwidth: BandwidthMode,
+) {
+    let Some(ui) = ui_weak.upgrade() else {
+        return;
+    };
+    ui.set_disk_threshold(disk_watch::threshold(AppConfig::load().disk_warning_percent) as i32);
+
+    ui.on_set_disk_threshold({
+        let ui_weak = ui_weak.clone();
+        move |percent| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            let result = AppConfig::update(|config| {
+                config.disk_warning_percent = percent.clamp(1, 99) as u8;
+                Ok(())
+            });
+            if let Err(e) = result {
+                ui.set_notification_message(format!("Falha ao salvar:\n{}", e).into());
+                ui.set_notification_is_error(true);
+                ui.set_show_notification(true);
+                return;
+            }
+            ui.set_disk_threshold(percent);
+            let used = ui.get_disk_percent() as f64 * 100.0;
+            ui.set_disk_warning(!ui.get_disk_summary().is_empty() && used >= percent as f64);
+        }
+    });
+
+    // Aviso já dado por servidor, para não repetir a cada verificação
+    let watches: Arc<std::sync::Mutex<HashMap<String, DiskWatch>>> = Default::default();
+    let timer = Timer::default();
+    // Economia de banda: a cada 10 minutos
+    let mut gate = TickGate::new(10);
+    timer.start(TimerMode::Repeated, Duration::from_secs(60), move || {
+        if !gate.should_run(&low_bandwidth) {
+            return;
+        }
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        let watches = watches.clone();
+        tokio::spawn(async move {
+            let (server, info, command) = {
+                let manager = docker_manager.lock().await;
+                let Ok(info) = manager.get_docker_info().await else {
+                    return;
+                };
+                let command = manager.data_root_usage_command(&info.data_root);
+                (manager.context().name.clone(), info, command)
+            };
+            // Daemon remoto sem SSH: não há como consultar o disco do host
+            let usage = match command {
+                Some(command) if !info.data_root.is_empty() => {
+                    match tokio::process::Command::from(command).output().await {
+                        Ok(output) if output.status.success() => {
+                            disk_watch::parse_df(&String::from_utf8_lossy(&output.stdout)).ok()
                         }
-                    })
-                    .unwrap();
+                        _ => None,
+                    }
+                }
+                _ => None,
+            };
+
+            slint::invoke_from_event_loop(move || {
+                let Some(ui) = ui_weak.upgrade() else {
                     return;
+                };
+                // Servidor trocado durante a consulta
+                if ui.get_context_name() != server.as_str() {
+                    return;
+                }
+                let Some(usage) = usage else {
+                    ui.set_disk_summary("".into());
+                    ui.set_disk_warning(false);
+                    return;
+                };
+                let config = AppConfig::load();
+                let threshold = disk_watch::threshold(config.disk_warning_percent);
+                let percent = usage.percent();
+                ui.set_disk_summary(
+                    format!(
+                        "Disco do Docker: {}% usado · {} livres de {} · {} em {} ({})",
+                        format::number(percent, 0),
+                        format::bytes(usage.available),
+                        format::bytes(usage.total),
+                        info.storage_driver,
+                        info.data_root,
+                        usage.mount
+                    )
+                    .into(),
+                );
+                ui.set_disk_percent((percent / 100.0) as f32);
+                ui.set_disk_warning(percent >= threshold as f64);
+
+                let crossed = watches
+                    .lock()
+                    .map(|mut watches| {
+                        let watch = watches.entry(server.clone()).or_default();
+                        watch.crossed(percent, threshold)
+                    })
+                    .unwrap_or(false);
+                if crossed && !config.is_in_maintenance(&server) {
+                    ui.set_notification_message(
+                        format!(
+                            "Disco do Docker em {}% em '{}' ({} livres). Use \"Limpar\" no dashboard para liberar espaço.",
+                            format::number(percent, 0),
+                            server,
+                            format::bytes(usage.available)
+                        )
+                        .into(),
+                    );
+                    ui.set_notification_is_error(true);
+                    ui.set_show_notification(true);
                 }
+            })
+            .unwrap();
+        });
+    });
+    std::mem::forget(timer);
+}
 
-                // Parse dos campos de entrada
-                let ports = parse_ports_text(&ports_str);
-                let volumes = parse_volumes_text(&volumes_str);
-                let env_vars = parse_env_vars_text(&env_vars_str);
-
-                let create_request = CreateContainerRequest {
-                    name: name_str.trim().to_string(),
-                    image: image_str.trim().to_string(),
-                    ports,
-                    volumes,
-                    environment: env_vars,
-                    command: if command_str.trim().is_empty() {
-                        None
-                    } else {
-                        Some(command_str.trim().to_string())
-                    },
-                    restart_policy: restart_policy_str,
-                };
+// Configura o modo de economia de banda do servidor ativo
+fn setup_low_bandwidth_callback(ui_weak: Weak<AppWindow>, app_state: AppState) {
+    let Some(ui) = ui_weak.upgrade() else {
+        return;
+    };
 
-                // Executa criação
-                let docker_manager = docker_manager_clone.lock().await;
-                let result = docker_manager.create_container(create_request).await;
+    ui.on_set_low_bandwidth(move |enabled| {
+        let Some(ui) = ui_weak.upgrade() else {
+            return;
+        };
+        let context_name = ui.get_context_name().to_string();
 
-                match result {
-                    Ok(container_id) => {
-                        let ui_weak_success = ui_weak_clone.clone();
-                        let container_name = name_str.clone();
-                        slint::invoke_from_event_loop(move || {
-                            if let Some(ui) = ui_weak_success.upgrade() {
-                                ui.set_creating_container(false);
-                                ui.set_notification_message(
-                                    format!(
-                                        "Container '{}' criado e iniciado com sucesso!\nID: {}",
-                                        container_name,
-                                        &container_id[..12]
-                                    )
-                                    .into(),
-                                );
-                                ui.set_notification_is_error(false);
-                                ui.set_show_notification(true);
+        let result = AppConfig::update(|config| {
+            if enabled {
+                config.low_bandwidth_servers.insert(context_name);
+            } else {
+                config.low_bandwidth_servers.remove(&context_name);
+            }
+            Ok(())
+        });
 
-                                // Agenda fechamento do modal e notificação juntos após 3 segundos
-                                let ui_weak_timer = ui_weak_clone.clone();
-                                tokio::spawn(async move {
-                                    tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
-                                    slint::invoke_from_event_loop(move || {
-                                        if let Some(ui) = ui_weak_timer.upgrade() {
-                                            // Fecha modal e notificação juntos
-                                            ui.set_show_create_modal(false);
-                                            ui.set_show_notification(false);
-
-                                            // Limpa os campos
-                                            ui.set_create_container_name("".into());
-                                            ui.set_create_image_name("".into());
-                                            ui.set_create_command("".into());
-                                            ui.set_create_restart_policy("no".into());
-                                            ui.set_create_ports_text("".into());
-                                            ui.set_create_volumes_text("".into());
-                                            ui.set_create_env_vars_text("".into());
-                                        }
-                                    })
-                                    .unwrap();
-                                });
-                            }
-                        })
-                        .unwrap();
-                    }
-                    Err(e) => {
-                        let error_message = e.to_string();
-                        let ui_weak_error = ui_weak_clone.clone();
-                        slint::invoke_from_event_loop(move || {
-                            if let Some(ui) = ui_weak_error.upgrade() {
-                                ui.set_creating_container(false);
-                                ui.set_notification_message(
-                                    format!("Falha ao criar container:\n{}", error_message).into(),
-                                );
-                                ui.set_notification_is_error(true);
-                                ui.set_show_notification(true);
-                            }
-                        })
-                        .unwrap();
-                    }
-                }
-            });
+        match result {
+            Ok(_) => {
+                app_state.low_bandwidth.set_low(enabled);
+                ui.set_low_bandwidth(enabled);
+            }
+            Err(e) => {
+                ui.set_low_bandwidth(!enabled);
+                ui.set_notification_message(
+                    format!("Falha ao salvar modo de economia de banda:\n{}", e).into(),
+                );
+                ui.set_notification_is_error(true);
+                ui.set_show_notification(true);
+            }
         }
     });
+}
 
-    // Callback para cancelar criação
-    ui.on_cancel_create_container({
-        let ui_weak = ui_weak.clone();
-        move || {
-            if let Some(ui) = ui_weak.upgrade() {
-                ui.set_show_create_modal(false);
-                ui.set_create_container_name("".into());
-                ui.set_create_image_name("".into());
-                ui.set_create_command("".into());
-                ui.set_create_restart_policy("no".into());
-                ui.set_create_ports_text("".into());
-                ui.set_create_volumes_text("".into());
-                ui.set_create_env_vars_text("".into());
-                ui.set_creating_container(false);
+// Configura o modo de movimento reduzido (vale para todos os servidores)
+fn setup_reduced_motion_callback(ui_weak: Weak<AppWindow>, app_state: AppState) {
+    let Some(ui) = ui_weak.upgrade() else {
+        return;
+    };
+
+    ui.on_set_reduced_motion(move |enabled| {
+        let Some(ui) = ui_weak.upgrade() else {
+            return;
+        };
+
+        let result = AppConfig::update(|config| {
+            config.reduced_motion = enabled;
+            Ok(())
+        });
+
+        match result {
+            Ok(_) => {
+                app_state.low_bandwidth.set_reduced_motion(enabled);
+                ui.set_reduced_motion(enabled);
+            }
+            Err(e) => {
+                ui.set_reduced_motion(!enabled);
+                ui.set_notification_message(
+                    format!("Falha ao salvar modo de movimento reduzido:\n{}", e).into(),
+                );
+                ui.set_notification_is_error(true);
+                ui.set_show_notification(true);
             }
         }
     });
 }
 
-// Formata bytes em unidades legíveis
-fn format_bytes(bytes: u64) -> String {
-    const KB: u64 = 1024;
-    const MB: u64 = 1024 * KB;
-    const GB: u64 = 1024 * MB;
+// Configura o mirror do Docker Hub do servidor ativo
+fn setup_registry_mirror_callbacks(ui_weak: Weak<AppWindow>) {
+    let Some(ui) = ui_weak.upgrade() else {
+        return;
+    };
 
-    if bytes >= GB {
-        format!("{:.2} GB", bytes as f64 / GB as f64)
-    } else if bytes >= MB {
-        format!("{:.2} MB", bytes as f64 / MB as f64)
-    } else if bytes >= KB {
-        format!("{:.2} KB", bytes as f64 / KB as f64)
-    } else {
-        format!("{} B", bytes)
-    }
-}
+    ui.on_save_registry_mirror(move |mirror| {
+        let Some(ui) = ui_weak.upgrade() else {
+            return;
+        };
+        let context_name = ui.get_context_name().to_string();
 
-// Formata exibição de memória com porcentagem
-fn format_memory_display(percentage: f64, usage: u64, limit: u64) -> String {
-    const MB: u64 = 1024 * 1024;
-    const GB: u64 = 1024 * MB;
+        // Vazio remove o mirror e os pulls voltam a ir direto ao Hub
+        let mirror = if mirror.trim().is_empty() {
+            Ok(None)
+        } else {
+            registry::parse_mirror(&mirror).map(Some)
+        };
+        let result = mirror.and_then(|mirror| {
+            AppConfig::update(|config| {
+                match &mirror {
+                    Some(mirror) => {
+                        config
+                            .registry_mirrors
+                            .insert(context_name.clone(), mirror.clone());
+                    }
+                    None => {
+                        config.registry_mirrors.remove(&context_name);
+                    }
+                }
+                Ok(())
+            })?;
+            Ok(mirror)
+        });
 
-    if usage >= GB {
-        format!(
-            "{:.2}% ({:.2} GB | {:.2} GB)",
-            percentage,
-            usage as f64 / GB as f64,
-            limit as f64 / GB as f64
-        )
-    } else if usage < GB && limit >= GB {
-        format!(
-            "{:.2}% ({:.2} MB | {:.2} GB)",
-            percentage,
-            usage as f64 / MB as f64,
-            limit as f64 / GB as f64
-        )
-    } else {
-        format!(
-            "{:.2}% ({:.2} MB | {:.2} MB)",
-            percentage,
-            usage as f64 / MB as f64,
-            limit as f64 / MB as f64
-        )
-    }
+        match result {
+            Ok(mirror) => {
+                let message = match &mirror {
+                    Some(mirror) => {
+                        format!("Pulls de imagens do Hub passam a usar o mirror {}", mirror)
+                    }
+                    None => "Pulls voltam a baixar direto do Docker Hub".to_string(),
+                };
+                ui.set_registry_mirror(mirror.unwrap_or_default().into());
+                ui.set_notification_message(message.into());
+                ui.set_notification_is_error(false);
+            }
+            Err(e) => {
+                ui.set_notification_message(format!("Falha ao salvar mirror:\n{}", e).into());
+                ui.set_notification_is_error(true);
+            }
+        }
+        ui.set_show_notification(true);
+    });
 }
 
-// Configura timer para atualizar logs do container selecionado
-fn setup_container_logs_timer(
+// Configura a fonte de métricas (Prometheus) do servidor ativo
+fn setup_metrics_source_callbacks(
     ui_weak: Weak<AppWindow>,
     docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+    app_state: AppState,
+    container_chart_data: Arc<std::sync::Mutex<ContainerChartData>>,
 ) {
-    let timer = Timer::default();
+    let Some(ui) = ui_weak.upgrade() else {
+        return;
+    };
 
-    timer.start(TimerMode::Repeated, Duration::from_secs(1), move || {
-        let ui_weak_clone = ui_weak.clone();
-        let docker_manager_clone = docker_manager.clone();
-
-        // Coleta as informações necessárias antes do tokio::spawn
-        let (current_screen, container_name, lines_loaded) =
-            if let Some(ui) = ui_weak_clone.upgrade() {
-                let screen = ui.get_current_screen();
-                let selected = ui.get_selected_container();
-                let lines = ui.get_logs_lines_loaded();
-                (screen, selected.name.to_string(), lines)
-            } else {
-                return; // Se não conseguir fazer upgrade, sai
-            };
+    ui.on_save_metrics_source(move |url, selector| {
+        let Some(ui) = ui_weak.upgrade() else {
+            return;
+        };
+        let context_name = ui.get_context_name().to_string();
 
-        // Só busca logs se estivermos na tela de detalhes (tela 5)
-        if current_screen == 5 && !container_name.is_empty() {
-            tokio::spawn(async move {
-                let manager = docker_manager_clone.lock().await;
+        // URL vazia volta a usar o docker stats
+        let source = if url.trim().is_empty() {
+            Ok(None)
+        } else {
+            MetricsSource::new(&url, &selector).map(Some)
+        };
+        let result = source.and_then(|source| {
+            AppConfig::update(|config| {
+                match &source {
+                    Some(source) => {
+                        config.metrics_sources.insert(context_name.clone(), source.clone());
+                    }
+                    None => {
+                        config.metrics_sources.remove(&context_name);
+                    }
+                }
+                Ok(())
+            })?;
+            Ok(source)
+        });
 
-                // Usa o número de linhas já carregadas
-                let tail_lines = if lines_loaded > 50 {
-                    Some(lines_loaded.to_string())
+        match result {
+            Ok(source) => {
+                let message = if source.is_some() {
+                    "Gráficos deste servidor passam a usar o histórico do Prometheus"
                 } else {
-                    None
+                    "Gráficos deste servidor voltam a usar o docker stats"
                 };
+                ui.set_notification_message(message.into());
+                ui.set_notification_is_error(false);
 
-                match manager
-                    .get_container_logs(&container_name, tail_lines)
-                    .await
-                {
-                    Ok(logs) => {
-                        slint::invoke_from_event_loop(move || {
-                            if let Some(ui) = ui_weak_clone.upgrade() {
-                                ui.set_container_logs(logs.into());
-                            }
-                        })
-                        .unwrap();
-                    }
-                    Err(_) => {
-                        // Ignora erros de logs para não poluir interface
-                    }
-                }
-            });
+                // Pontos das duas fontes não se misturam no mesmo gráfico
+                clear_chart_history(&app_state, &container_chart_data);
+                let docker_manager = docker_manager.clone();
+                tokio::spawn(async move {
+                    docker_manager.lock().await.set_metrics_source(source);
+                });
+            }
+            Err(e) => {
+                ui.set_notification_message(
+                    format!("Falha ao salvar fonte de métricas:\n{}", e).into(),
+                );
+                ui.set_notification_is_error(true);
+            }
         }
+        ui.set_show_notification(true);
     });
-
-    // Mantém o timer vivo
-    std::mem::forget(timer);
 }
 
-// Funções auxiliares para parsing de entrada
+// Configura seletor de fuso dos logs (servidor, local ou UTC), salvo na configuração
+fn setup_log_timezone_callback(ui_weak: Weak<AppWindow>) {
+    let Some(ui) = ui_weak.upgrade() else {
+        return;
+    };
 
-// Parse do texto de portas: "8080:80/tcp,9000:9000/udp"
-fn parse_ports_text(ports_text: &str) -> Vec<PortMapping> {
-    if ports_text.trim().is_empty() {
-        return Vec::new();
-    }
+    let saved = AppConfig::load().log_timezone;
+    ui.set_log_timezone(if saved.is_empty() { "remote".into() } else { saved.into() });
 
-    ports_text
-        .split(',')
-        .filter_map(|port_str| {
-            let port_str = port_str.trim();
-            if port_str.is_empty() {
-                return None;
-            }
+    ui.on_set_log_timezone(move |mode| {
+        let Some(ui) = ui_weak.upgrade() else {
+            return;
+        };
+        ui.set_log_timezone(mode.clone());
 
-            // Separa protocolo se especificado
-            let (port_part, protocol) = if port_str.contains('/') {
-                let parts: Vec<&str> = port_str.split('/').collect();
-                (parts[0], parts.get(1).unwrap_or(&"tcp").to_string())
-            } else {
-                (port_str, "tcp".to_string())
-            };
+        let result = AppConfig::update(|config| {
+            config.log_timezone = mode.to_string();
+            Ok(())
+        });
+        if let Err(e) = result {
+            ui.set_notification_message(format!("Falha ao salvar configuração: {}", e).into());
+            ui.set_notification_is_error(true);
+            ui.set_show_notification(true);
+        }
+    });
+}
 
-            // Parse host:container
-            let parts: Vec<&str> = port_part.split(':').collect();
-            if parts.len() != 2 {
-                return None;
-            }
+// Configura separador decimal e unidades (KiB ou kB), salvos na configuração
+fn setup_number_format_callback(ui_weak: Weak<AppWindow>) {
+    let Some(ui) = ui_weak.upgrade() else {
+        return;
+    };
 
-            let host_port = parts[0].parse::<u16>().ok()?;
-            let container_port = parts[1].parse::<u16>().ok()?;
+    let saved = AppConfig::load().format;
+    ui.set_number_separator(saved.separator.as_str().into());
+    ui.set_byte_units(saved.units.as_str().into());
 
-            Some(PortMapping {
-                host_port,
-                container_port,
-                protocol,
-            })
-        })
-        .collect()
+    ui.on_set_number_format(move |separator, units| {
+        let Some(ui) = ui_weak.upgrade() else {
+            return;
+        };
+        let settings = FormatSettings {
+            separator: DecimalSeparator::from_ui(&separator),
+            units: ByteUnits::from_ui(&units),
+        };
+        ui.set_number_separator(settings.separator.as_str().into());
+        ui.set_byte_units(settings.units.as_str().into());
+        // Valores já exibidos mudam na próxima atualização de cada tela
+        format::apply(settings);
+
+        let result = AppConfig::update(|config| {
+            config.format = settings;
+            Ok(())
+        });
+        if let Err(e) = result {
+            ui.set_notification_message(format!("Falha ao salvar configuração: {}", e).into());
+            ui.set_notification_is_error(true);
+            ui.set_show_notification(true);
+        }
+    });
 }
 
-// Parse do texto de volumes: "/host/path:/container/path,/host2:/container2:ro"
-fn parse_volumes_text(volumes_text: &str) -> Vec<VolumeMapping> {
-    if volumes_text.trim().is_empty() {
-        return Vec::new();
-    }
-
-    volumes_text
-        .split(',')
-        .filter_map(|volume_str| {
-            let volume_str = volume_str.trim();
-            if volume_str.is_empty() {
-                return None;
-            }
-
-            let parts: Vec<&str> = volume_str.split(':').collect();
-            if parts.len() < 2 {
-                return None;
+// Lista os gatilhos de log do container selecionado com as ocorrências contadas
+fn update_log_triggers(ui: &AppWindow, state: &LogTriggerState) {
+    let selected = ui.get_selected_container();
+    let triggers: Vec<LogTriggerData> = state
+        .triggers()
+        .iter()
+        .filter(|trigger| {
+            trigger.server == selected.server.as_str() && trigger.container == selected.name.as_str()
+        })
+        .map(|trigger| {
+            let hits = state.hits(&trigger.id);
+            LogTriggerData {
+                id: trigger.id.clone().into(),
+                pattern: trigger.pattern.clone().into(),
+                action: trigger.action.label().into(),
+                hits: hits.map(|hits| hits.count as i32).unwrap_or(0),
+                last_match: hits
+                    .map(|hits| hits.last_line.clone())
+                    .unwrap_or_default()
+                    .into(),
             }
-
-            let host_path = parts[0].to_string();
-            let container_path = parts[1].to_string();
-            let read_only = parts.get(2).map_or(false, |&mode| mode == "ro");
-
-            Some(VolumeMapping {
-                host_path,
-                container_path,
-                read_only,
-            })
         })
-        .collect()
+        .collect();
+    ui.set_log_triggers(std::rc::Rc::new(slint::VecModel::from(triggers)).into());
 }
 
-// Parse do texto de variáveis de ambiente: "KEY1=value1,KEY2=value2"
-fn parse_env_vars_text(env_vars_text: &str) -> Vec<EnvVar> {
-    if env_vars_text.trim().is_empty() {
-        return Vec::new();
-    }
+// Configura inclusão/remoção de gatilhos de log do container selecionado
+fn setup_log_trigger_callbacks(
+    ui_weak: Weak<AppWindow>,
+    state: Arc<std::sync::Mutex<LogTriggerState>>,
+) {
+    let Some(ui) = ui_weak.upgrade() else {
+        return;
+    };
+
+    ui.on_add_log_trigger({
+        let ui_weak = ui_weak.clone();
+        let state = state.clone();
+        move |pattern, action, webhook_url| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            let selected = ui.get_selected_container();
+            if selected.name.is_empty() {
+                return;
+            }
+
+            let result = LogTrigger::new(
+                &selected.server,
+                &selected.name,
+                &pattern,
+                TriggerAction::from_ui(&action),
+                &webhook_url,
+            )
+            .and_then(|trigger| {
+                AppConfig::update(|config| {
+                    config.log_triggers.push(trigger);
+                    Ok(())
+                })
+            });
 
-    env_vars_text
-        .split(',')
-        .filter_map(|env_str| {
-            let env_str = env_str.trim();
-            if env_str.is_empty() {
-                return None;
+            let Ok(mut state) = state.lock() else {
+                return;
+            };
+            match result {
+                Ok(config) => {
+                    state.set_triggers(config.log_triggers);
+                    ui.set_log_trigger_error("".into());
+                }
+                Err(e) => ui.set_log_trigger_error(e.to_string().into()),
             }
+            update_log_triggers(&ui, &state);
+        }
+    });
+
+    ui.on_remove_log_trigger(move |id| {
+        let Some(ui) = ui_weak.upgrade() else {
+            return;
+        };
 
-            let parts: Vec<&str> = env_str.splitn(2, '=').collect();
-            if parts.len() != 2 {
-                return None;
+        let result = AppConfig::update(|config| {
+            config.log_triggers.retain(|trigger| trigger.id != id.as_str());
+            Ok(())
+        });
+        let Ok(mut state) = state.lock() else {
+            return;
+        };
+        match result {
+            Ok(config) => {
+                state.set_triggers(config.log_triggers);
+                ui.set_log_trigger_error("".into());
             }
+            Err(e) => ui.set_log_trigger_error(e.to_string().into()),
+        }
+        update_log_triggers(&ui, &state);
+    });
+}
 
-            Some(EnvVar {
-                key: parts[0].to_string(),
-                value: parts[1].to_string(),
-            })
-        })
-        .collect()
+// Texto do alerta de um gatilho, com o resultado da ação executada
+fn log_trigger_message(found: &TriggerMatch, result: &anyhow::Result<()>) -> String {
+    let line: String = found.line.chars().take(200).collect();
+    let outcome = match (found.trigger.action, result) {
+        (_, Err(e)) => format!("\n→ Falha na ação: {}", e),
+        (TriggerAction::Alert | TriggerAction::Watch, Ok(())) => String::new(),
+        (TriggerAction::Restart, Ok(())) => "\n→ Container reiniciado".to_string(),
+        (TriggerAction::Webhook, Ok(())) => "\n→ Webhook enviado".to_string(),
+    };
+    format!(
+        "[{}] {}: padrão '{}' encontrado\n{}{}",
+        found.trigger.server, found.trigger.container, found.trigger.pattern, line, outcome
+    )
 }
 
-// Configura callback para carregar mais logs
-fn setup_load_more_logs_callback(
+// Lê periodicamente os logs novos dos containers com gatilhos no servidor ativo,
+// executa as ações e atualiza as contagens na tela de detalhes
+fn setup_log_trigger_watcher(
     ui_weak: Weak<AppWindow>,
     docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+    state: Arc<std::sync::Mutex<LogTriggerState>>,
+    low_bandwidth: BandwidthMode,
 ) {
-    if let Some(ui) = ui_weak.upgrade() {
-        ui.on_load_more_logs(move || {
-            let ui_weak_clone = ui_weak.clone();
-            let docker_manager_clone = docker_manager.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(3));
+        // Economia de banda: a cada 15s
+        let mut gate = TickGate::new(5);
+
+        loop {
+            interval.tick().await;
+            if !gate.should_run(&low_bandwidth) {
+                continue;
+            }
 
-            // Pega as informações antes do spawn
-            let (container_name, current_lines) = if let Some(ui) = ui_weak_clone.upgrade() {
-                let selected = ui.get_selected_container();
-                let lines = ui.get_logs_lines_loaded();
-                (selected.name.to_string(), lines)
-            } else {
-                return;
-            };
+            let config = AppConfig::load();
+            let manager = docker_manager.lock().await;
+            let server = manager.context().name.clone();
 
-            if container_name.is_empty() {
-                return;
+            let triggers = match state.lock() {
+                Ok(state) => state.triggers().to_vec(),
+                Err(_) => continue,
+            };
+            let mut by_container: BTreeMap<String, Vec<LogTrigger>> = BTreeMap::new();
+            for trigger in triggers.iter().filter(|trigger| trigger.server == server) {
+                by_container
+                    .entry(trigger.container.clone())
+                    .or_default()
+                    .push(trigger.clone());
             }
 
-            // Define loading state
-            if let Some(ui) = ui_weak_clone.upgrade() {
-                ui.set_logs_loading(true);
+            let mut matches = Vec::new();
+            for (container, triggers) in &by_container {
+                let since = match state.lock() {
+                    Ok(mut state) => state.read_from(&server, container),
+                    Err(_) => continue,
+                };
+                let Ok(lines) = manager.get_container_log_lines_since(container, since).await else {
+                    continue;
+                };
+                if let Ok(mut state) = state.lock() {
+                    matches.extend(state.scan(&server, container, triggers, &lines));
+                }
+            }
+            // Em manutenção as ocorrências continuam contadas, sem alertas nem webhooks
+            let muted = config.is_in_maintenance(&server);
+
+            // Gatilhos de contagem só atualizam o selo da lista de containers
+            let matches: Vec<TriggerMatch> = matches
+                .into_iter()
+                .filter(|found| found.run_action && found.trigger.action != TriggerAction::Watch)
+                .filter(|found| !(muted && found.trigger.action == TriggerAction::Webhook))
+                .collect();
+
+            // Reinícios usam o gerenciador; webhooks rodam depois de liberá-lo
+            let mut results = Vec::new();
+            for found in &matches {
+                results.push(match found.trigger.action {
+                    TriggerAction::Restart => {
+                        manager.restart_container(&found.trigger.container).await
+                    }
+                    _ => Ok(()),
+                });
+            }
+            drop(manager);
+
+            for (found, result) in matches.iter().zip(results.iter_mut()) {
+                if found.trigger.action == TriggerAction::Webhook {
+                    let trigger = found.trigger.clone();
+                    let line = found.line.clone();
+                    let proxy = config.global_proxy();
+                    *result = tokio::task::spawn_blocking(move || {
+                        log_triggers::send_webhook(&trigger, &line, proxy)
+                    })
+                    .await
+                    .unwrap_or_else(|e| Err(anyhow::anyhow!(e)));
+                }
             }
 
-            // Incrementa o número de linhas a buscar
-            let new_lines_count = current_lines + 50;
-
-            tokio::spawn(async move {
-                let manager = docker_manager_clone.lock().await;
+            let messages: Vec<String> = matches
+                .iter()
+                .zip(results.iter())
+                .map(|(found, result)| log_trigger_message(found, result))
+                .collect();
 
-                // Busca mais 50 linhas
-                match manager
-                    .get_container_logs(&container_name, Some(new_lines_count.to_string()))
-                    .await
-                {
-                    Ok(new_logs) => {
-                        slint::invoke_from_event_loop(move || {
-                            if let Some(ui) = ui_weak_clone.upgrade() {
-                                ui.set_container_logs(new_logs.into());
-                                ui.set_logs_lines_loaded(new_lines_count);
-                                ui.set_logs_loading(false);
-                            }
-                        })
-                        .unwrap();
-                    }
-                    Err(_) => {
-                        slint::invoke_from_event_loop(move || {
-                            if let Some(ui) = ui_weak_clone.upgrade() {
-                                ui.set_logs_loading(false);
-                            }
-                        })
-                        .unwrap();
+            let ui_weak = ui_weak.clone();
+            let state = state.clone();
+            slint::invoke_from_event_loop(move || {
+                let Some(ui) = ui_weak.upgrade() else {
+                    return;
+                };
+                if ui.get_current_screen() == Screen::ContainerDetails.index() {
+                    if let Ok(state) = state.lock() {
+                        update_log_triggers(&ui, &state);
                     }
                 }
-            });
-        });
+                if !messages.is_empty() && !muted {
+                    ui.set_notification_message(messages.join("\n\n").into());
+                    ui.set_notification_is_error(true);
+                    ui.set_show_notification(true);
+                }
+            })
+            .unwrap();
+        }
+    });
+}
+
+// Gráficos do container desde o último recreate com o deploy anterior sobreposto
+fn render_deploy_charts(
+    ui: &AppWindow,
+    deploy: Option<&DeployComparison>,
+    chart_data: &std::sync::Mutex<ContainerChartData>,
+    cpu_renderer: &std::sync::Mutex<ChartRenderer>,
+    memory_renderer: &std::sync::Mutex<ChartRenderer>,
+) {
+    let Some(deploy) = deploy else {
+        // Economia de banda ou container recém-aberto: o coletor ainda não amostrou
+        ui.set_deploy_summary(
+            "Ainda sem amostras deste container: o histórico de deploys vem do docker stats de todos os containers, desligado na economia de banda".into(),
+        );
+        return;
+    };
+    let Ok(mut chart_data) = chart_data.try_lock() else {
+        return;
+    };
+    if chart_data.deploy_revision == Some(deploy.revision) {
+        return;
+    }
+    chart_data.deploy_revision = Some(deploy.revision);
+
+    let summary = deploy.summary();
+    ui.set_deploy_summary(summary.clone().into());
+    if let Ok(renderer) = cpu_renderer.try_lock() {
+        ui.set_container_cpu_chart(
+            renderer.render_deploy_chart(&deploy.series(DeployMetric::Cpu), 100.0),
+        );
+    }
+    if let Ok(renderer) = memory_renderer.try_lock() {
+        ui.set_container_memory_chart(
+            renderer.render_deploy_chart(&deploy.series(DeployMetric::Memory), 100.0),
+        );
     }
+    ui.set_container_cpu_chart_description(summary.clone().into());
+    ui.set_container_memory_chart_description(summary.into());
 }
 
 // Configura timer para atualizar stats do container selecionado
@@ -1524,54 +5403,124 @@ fn setup_container_stats_timer(
     container_chart_data: Arc<std::sync::Mutex<ContainerChartData>>,
     container_cpu_renderer: Arc<std::sync::Mutex<ChartRenderer>>,
     container_memory_renderer: Arc<std::sync::Mutex<ChartRenderer>>,
+    low_bandwidth: BandwidthMode,
 ) {
     let timer = Timer::default();
-
-    timer.start(TimerMode::Repeated, Duration::from_secs(1), move || {
+    // 500ms com os detalhes abertos; economia de banda: a cada 5s
+    let mut clock = SampleClock::default();
+
+    timer.start(TimerMode::Repeated, stats_sampling::TICK, move || {
+        let screen = ui_weak
+            .upgrade()
+            .map_or(Screen::Dashboard.index(), |ui| ui.get_current_screen());
+        let interval =
+            stats_sampling::interval(StatsConsumer::ContainerDetails, screen, &low_bandwidth);
+        if !clock.due(interval) {
+            return;
+        }
         let ui_weak_clone = ui_weak.clone();
         let docker_manager_clone = docker_manager.clone();
         let chart_data_clone = container_chart_data.clone();
         let cpu_renderer_clone = container_cpu_renderer.clone();
         let memory_renderer_clone = container_memory_renderer.clone();
+        let mode = low_bandwidth.clone();
 
         // Coleta as informações necessárias antes do tokio::spawn
-        let (current_screen, container_name) = if let Some(ui) = ui_weak_clone.upgrade() {
-            let screen = ui.get_current_screen();
-            let selected = ui.get_selected_container();
-            (screen, selected.name.to_string())
-        } else {
-            return; // Se não conseguir fazer upgrade, sai
-        };
+        let (current_screen, container_name, display_name, chart_key, deploy_compare) =
+            if let Some(ui) = ui_weak_clone.upgrade() {
+                let screen = ui.get_current_screen();
+                let selected = ui.get_selected_container();
+                let chart_key = format!("{}/{}", selected.server, selected.id);
+                (
+                    screen,
+                    selected.id.to_string(),
+                    selected.name.to_string(),
+                    chart_key,
+                    ui.get_deploy_compare(),
+                )
+            } else {
+                return; // Se não conseguir fazer upgrade, sai
+            };
 
-        // Só busca stats se estivermos na tela de detalhes (tela 5) e container em execução
-        if current_screen == 5 && !container_name.is_empty() {
+        // Só busca stats se estivermos na tela de detalhes e container em execução
+        if current_screen == Screen::ContainerDetails.index() && !container_name.is_empty() {
             tokio::spawn(async move {
                 let mut manager = docker_manager_clone.lock().await;
 
                 match manager.get_single_container_stats(&container_name).await {
                     Ok((cpu, cpu_total, memory, rx, tx)) => {
+                        let memory_trend = manager.memory_trend(&container_name);
+                        let deploy = manager.deploy_comparison(&display_name);
+
                         // Extrai percentual de memória do string
                         let memory_percentage = memory
                             .split('%')
                             .next()
-                            .and_then(|s| s.parse::<f32>().ok())
+                            .and_then(|s| s.replace(',', ".").parse::<f32>().ok())
                             .unwrap_or(0.0);
 
-                        // Atualiza dados dos gráficos
+                        // Atualiza dados dos gráficos (histórico do Prometheus, se configurado)
+                        let external_charts = manager.has_metrics_source();
+                        let history = manager.container_metrics_history(&chart_key, &display_name);
                         if let Ok(mut chart_data) = chart_data_clone.try_lock() {
-                            if chart_data.should_update() {
-                                chart_data.add_cpu_point(cpu as f32);
-                                chart_data.add_memory_point(memory_percentage);
+                            chart_data.select(&chart_key);
+                            match history {
+                                Some(Ok(history)) => chart_data.replace_history(history),
+                                Some(Err(e)) => eprintln!("Error querying Prometheus: {}", e),
+                                None if external_charts => {}
+                                None if chart_data.should_update() => {
+                                    chart_data.add_cpu_point(cpu as f32);
+                                    chart_data.add_memory_point(memory_percentage);
+                                }
+                                None => {}
                             }
                         }
 
                         slint::invoke_from_event_loop(move || {
                             if let Some(ui) = ui_weak_clone.upgrade() {
-                                ui.set_container_cpu_usage(format!("{:.1}%", cpu).into());
+                                ui.set_container_cpu_usage(format!("{}%", format::number(cpu, 1)).into());
                                 ui.set_container_cpu_total(format!("{}%", cpu_total * 100).into());
                                 ui.set_container_memory_usage(memory.into());
+                                match memory_trend {
+                                    Some(trend) => {
+                                        ui.set_container_memory_trend(trend.summary().into());
+                                        ui.set_container_memory_growing(trend.is_growing());
+                                    }
+                                    None => {
+                                        ui.set_container_memory_trend(
+                                            "Coletando histórico de memória...".into(),
+                                        );
+                                        ui.set_container_memory_growing(false);
+                                    }
+                                }
                                 ui.set_container_network_rx(rx.into());
                                 ui.set_container_network_tx(tx.into());
+                                ui.set_deploy_marker(
+                                    deploy.as_ref().map(|deploy| deploy.marker()).unwrap_or_default().into(),
+                                );
+
+                                // Comparação com o deploy anterior: redesenha só a cada amostra nova
+                                if deploy_compare {
+                                    render_deploy_charts(
+                                        &ui,
+                                        deploy.as_ref(),
+                                        &chart_data_clone,
+                                        &cpu_renderer_clone,
+                                        &memory_renderer_clone,
+                                    );
+                                    return;
+                                }
+                                if let Ok(mut chart_data) = chart_data_clone.try_lock() {
+                                    chart_data.deploy_revision = None;
+                                }
+
+                                // Movimento reduzido: redesenha só de tempos em tempos
+                                let redraw = chart_data_clone.try_lock().is_ok_and(
+                                    |mut chart_data| chart_data.redraw.should_redraw(&mode),
+                                );
+                                if !redraw {
+                                    return;
+                                }
 
                                 // Gera gráficos dentro do event loop para evitar problemas de threading
                                 if let (Ok(mut chart_data), Ok(renderer)) =
@@ -1582,6 +5531,9 @@ fn setup_container_stats_timer(
                                         100.0,
                                     );
                                     ui.set_container_cpu_chart(cpu_chart);
+                                    ui.set_container_cpu_chart_description(
+                                        chart_description(&chart_data.cpu_points),
+                                    );
                                 }
 
                                 if let (Ok(mut chart_data), Ok(renderer)) = (
@@ -1593,6 +5545,9 @@ fn setup_container_stats_timer(
                                         100.0,
                                     );
                                     ui.set_container_memory_chart(memory_chart);
+                                    ui.set_container_memory_chart_description(
+                                        chart_description(&chart_data.memory_points),
+                                    );
                                 }
                             }
                         })
@@ -1605,6 +5560,7 @@ fn setup_container_stats_timer(
                                 ui.set_container_cpu_usage("0.0%".into());
                                 ui.set_container_cpu_total("0%".into());
                                 ui.set_container_memory_usage("N/A".into());
+                                ui.set_container_memory_trend("".into());
                                 ui.set_container_network_rx("0 B/s".into());
                                 ui.set_container_network_tx("0 B/s".into());
                             }
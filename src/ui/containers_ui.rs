@@ -0,0 +1,1273 @@
+// Tela de containers: lista com busca/filtro, ações, grupos, limites de I/O, resumo e comparação
+use slint::{Model, Weak};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast;
+
+use super::controller::{
+    Action, ActionBus, Resource, ResourceAction, ResourceController, updated_label,
+};
+use super::store::{StateChange, Transition, UiStore};
+use crate::annotations::{Annotations, Tag};
+use crate::bandwidth::BandwidthMode;
+use crate::config::AppConfig;
+use crate::container_diff;
+use crate::container_groups::{self, ContainerGroup, LabelSelector, Readiness, SelectorAction};
+use crate::container_summary;
+use crate::docker::{DockerManager, is_active_state};
+use crate::log_triggers::LogTriggerState;
+use crate::list_containers::{ContainerUIManager, SlintContainerData, setup_container_ui_timer};
+use crate::resource_limits::{self, IoLimits};
+use crate::{AppWindow, ContainerData, ContainerDiffRow, ContainerGroupData, TagData};
+
+pub struct ContainersController {
+    manager: Arc<tokio::sync::Mutex<ContainerUIManager>>,
+    // Grupos, resumo, limites de I/O e comparação falam direto com o servidor
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+    bus: ActionBus,
+    store: UiStore,
+}
+
+impl ContainersController {
+    pub fn new(
+        docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+        bus: ActionBus,
+        store: UiStore,
+        log_triggers: Arc<std::sync::Mutex<LogTriggerState>>,
+    ) -> Self {
+        Self {
+            manager: Arc::new(tokio::sync::Mutex::new(ContainerUIManager::new(
+                docker_manager.clone(),
+                log_triggers,
+            ))),
+            docker_manager,
+            bus,
+            store,
+        }
+    }
+}
+
+impl ResourceController for ContainersController {
+    fn setup_callbacks(&self, ui_weak: Weak<AppWindow>) {
+        setup_container_callbacks(
+            ui_weak,
+            self.manager.clone(),
+            self.bus.clone(),
+            self.store.clone(),
+        );
+        setup_container_group_callbacks(
+            ui_weak.clone(),
+            self.docker_manager.clone(),
+            self.store.clone(),
+        );
+        setup_container_summary_callback(ui_weak.clone(), self.docker_manager.clone());
+        setup_update_resources_callbacks(
+            ui_weak.clone(),
+            self.docker_manager.clone(),
+            self.bus.clone(),
+        );
+        setup_container_compare_callbacks(ui_weak, self.docker_manager.clone());
+    }
+
+    fn start_refresh(&self, ui_weak: Weak<AppWindow>, low_bandwidth: BandwidthMode) {
+        let ui_weak_container = ui_weak.clone();
+        let store = self.store.clone();
+        let container_timer = setup_container_ui_timer(
+            self.manager.clone(),
+            Arc::new(move |containers| {
+                if let Some(ui) = ui_weak_container.upgrade() {
+                    update_ui_containers_from_slint(&ui, &containers);
+                    ui.set_containers_updated(updated_label());
+
+                    // Atualiza o container aberto na tela de detalhes
+                    if let Some(selected) = store.state().selected {
+                        // Busca por servidor + id: nomes se repetem entre servidores
+                        if let Some(updated_container) = containers
+                            .iter()
+                            .find(|c| c.server == selected.server && c.id == selected.id)
+                        {
+                            store.dispatch(
+                                &ui,
+                                Transition::UpdateSelected(to_container_data(
+                                    updated_container,
+                                    &Annotations::load(),
+                                )),
+                            );
+                        }
+                    }
+                }
+            }),
+            low_bandwidth,
+        );
+
+        // Mantém o timer vivo armazenando-o no contexto
+        std::mem::forget(container_timer);
+
+        // Container removido pela tela de detalhes: volta para a lista.
+        // Limites alterados com docker update já valem: tira o selo de reinício pendente
+        let mut actions = self.bus.subscribe();
+        let ui_weak_removed = ui_weak.clone();
+        let store = self.store.clone();
+        let ui_manager = self.manager.clone();
+        tokio::spawn(async move {
+            loop {
+                let action = match actions.recv().await {
+                    Ok(action) => action,
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                };
+                if action.resource == Resource::Container
+                    && action.action == Action::UpdateResources
+                    && action.succeeded
+                {
+                    ui_manager.lock().await.accept_live_update(&action.target);
+                    continue;
+                }
+                if action.resource != Resource::Container
+                    || action.action != Action::Remove
+                    || !action.succeeded
+                {
+                    continue;
+                }
+                let is_open = store
+                    .state()
+                    .selected
+                    .is_some_and(|selected| selected.id == action.target);
+                if !is_open {
+                    continue;
+                }
+                let ui_weak = ui_weak_removed.clone();
+                let store = store.clone();
+                slint::invoke_from_event_loop(move || {
+                    if let Some(ui) = ui_weak.upgrade() {
+                        store.dispatch(&ui, Transition::CloseDetails);
+                    }
+                })
+                .unwrap();
+            }
+        });
+
+        // Inicialização manual dos containers
+        let ui_manager = self.manager.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+            let mut manager = ui_manager.lock().await;
+            if let Ok(()) = manager.refresh_containers().await {
+                let filtered_containers = manager.get_filtered_containers();
+                slint::invoke_from_event_loop(move || {
+                    if let Some(ui) = ui_weak.upgrade() {
+                        update_ui_containers_from_slint(&ui, &filtered_containers);
+                    }
+                })
+                .unwrap();
+            }
+        });
+    }
+}
+
+// Converte container para o struct do Slint, com suas anotações locais
+pub fn to_container_data(container: &SlintContainerData, annotations: &Annotations) -> ContainerData {
+    let annotation = annotations
+        .container(&container.server, &container.id)
+        .cloned()
+        .unwrap_or_default();
+    ContainerData {
+        id: container.id.clone(),
+        server: container.server.clone(),
+        name: container.name.clone(),
+        image: container.image.clone(),
+        status: container.status.clone(),
+        ports: container.ports.clone(),
+        created: container.created.clone(),
+        pending: container.pending.clone(),
+        pending_reason: container.pending_reason.clone(),
+        log_watch: container.log_watch.clone(),
+        log_watch_hits: container.log_watch_hits,
+        note: annotation.note.into(),
+        tags: to_tag_data(&annotation.tags),
+    }
+}
+
+pub fn to_tag_data(tags: &[Tag]) -> slint::ModelRc<TagData> {
+    let tags: Vec<TagData> = tags
+        .iter()
+        .map(|tag| {
+            let (r, g, b) = tag.rgb();
+            TagData {
+                name: tag.name.clone().into(),
+                color: slint::Color::from_rgb_u8(r, g, b),
+            }
+        })
+        .collect();
+    std::rc::Rc::new(slint::VecModel::from(tags)).into()
+}
+
+// Converte containers para formato Slint e atualiza UI
+pub fn update_ui_containers_from_slint(ui: &AppWindow, containers: &[SlintContainerData]) {
+    let annotations = Annotations::load();
+    let slint_containers: Vec<_> = containers
+        .iter()
+        .map(|container| to_container_data(container, &annotations))
+        .collect();
+
+    let slint_model: std::rc::Rc<slint::VecModel<ContainerData>> =
+        std::rc::Rc::new(slint::VecModel::from(slint_containers));
+
+    ui.set_containers(slint_model.into());
+}
+
+// Configura callbacks específicos para containers
+fn setup_container_callbacks(
+    ui_weak: Weak<AppWindow>,
+    container_ui_manager: Arc<tokio::sync::Mutex<ContainerUIManager>>,
+    bus: ActionBus,
+    store: UiStore,
+) {
+    let ui = ui_weak.upgrade().unwrap();
+
+    // Abre os detalhes: o store reinicia logs, gráficos e console do container
+    ui.on_view_container_details({
+        let ui_weak = ui_weak.clone();
+        let store = store.clone();
+        move |container| {
+            if let Some(ui) = ui_weak.upgrade() {
+                store.dispatch(&ui, Transition::SelectContainer(container));
+            }
+        }
+    });
+
+    // Atualização manual da lista (também com a economia de banda ativa)
+    ui.on_refresh_containers_clicked({
+        let ui_weak = ui_weak.clone();
+        let container_manager = container_ui_manager.clone();
+        move || {
+            let ui_weak = ui_weak.clone();
+            let container_manager = container_manager.clone();
+            tokio::spawn(async move {
+                let mut manager = container_manager.lock().await;
+                // O erro vira texto: Box<dyn Error> não pode ir para o event loop
+                let result = manager.refresh_containers().await.map_err(|e| e.to_string());
+                let filtered_containers = manager.get_filtered_containers();
+                slint::invoke_from_event_loop(move || {
+                    let Some(ui) = ui_weak.upgrade() else {
+                        return;
+                    };
+                    match result {
+                        Ok(()) => {
+                            update_ui_containers_from_slint(&ui, &filtered_containers);
+                            ui.set_containers_updated(updated_label());
+                        }
+                        Err(e) => {
+                            ui.set_notification_message(
+                                format!("Erro ao atualizar containers: {}", e).into(),
+                            );
+                            ui.set_notification_is_error(true);
+                            ui.set_show_notification(true);
+                        }
+                    }
+                })
+                .unwrap();
+            });
+        }
+    });
+
+    // Callback para mudança na busca de containers
+    ui.on_search_changed({
+        let ui_weak = ui_weak.clone();
+        let container_manager = container_ui_manager.clone();
+        let store = store.clone();
+        move |search_text| {
+            if let Some(ui) = ui_weak.upgrade() {
+                store.dispatch(&ui, Transition::SetSearch(search_text.to_string()));
+            }
+            let ui_weak_clone = ui_weak.clone();
+            let container_manager_clone = container_manager.clone();
+            let search_string = search_text.to_string();
+
+            tokio::spawn(async move {
+                let mut manager = container_manager_clone.lock().await;
+                manager.set_search_filter(search_string);
+                let filtered_containers = manager.get_filtered_containers();
+                slint::invoke_from_event_loop(move || {
+                    if let Some(ui) = ui_weak_clone.upgrade() {
+                        update_ui_containers_from_slint(&ui, &filtered_containers);
+                    }
+                })
+                .unwrap();
+            });
+        }
+    });
+
+    // Callback para mudança no filtro de status
+    ui.on_filter_changed({
+        let ui_weak = ui_weak.clone();
+        let container_manager = container_ui_manager.clone();
+        move |status_filter| {
+            if let Some(ui) = ui_weak.upgrade() {
+                store.dispatch(&ui, Transition::SetStatusFilter(status_filter.to_string()));
+            }
+            let ui_weak_clone = ui_weak.clone();
+            let container_manager_clone = container_manager.clone();
+            let status_string = status_filter.to_string();
+
+            tokio::spawn(async move {
+                let mut manager = container_manager_clone.lock().await;
+                manager.set_status_filter(status_string);
+                let filtered_containers = manager.get_filtered_containers();
+                slint::invoke_from_event_loop(move || {
+                    if let Some(ui) = ui_weak_clone.upgrade() {
+                        update_ui_containers_from_slint(&ui, &filtered_containers);
+                    }
+                })
+                .unwrap();
+            });
+        }
+    });
+
+    // Callback para ações em containers
+    ui.on_container_action({
+        let ui_weak = ui_weak.clone();
+        let container_manager = container_ui_manager.clone();
+        move |container, action| {
+            let ui_weak_clone = ui_weak.clone();
+            let container_manager_clone = container_manager.clone();
+            let bus = bus.clone();
+            let container_name_str = container.name.to_string();
+            let container_id_str = container.id.to_string();
+            let server_str = container.server.to_string();
+            let action_str = action.to_string();
+            let loading_key = format!("{}/{}_{}", server_str, container_id_str, action_str);
+
+            // A ação só pode ir para o servidor de onde o container foi listado
+            if let Some(ui) = ui_weak.upgrade() {
+                if server_str != ui.get_context_name().as_str() {
+                    ui.set_notification_message(
+                        format!(
+                            "[{}] Container '{}' pertence a outro servidor; conecte-se a '{}' para gerenciá-lo",
+                            server_str, container_name_str, server_str
+                        )
+                        .into(),
+                    );
+                    ui.set_notification_is_error(true);
+                    ui.set_show_notification(true);
+                    return;
+                }
+            }
+
+            tokio::spawn(async move {
+                // Remover um container ativo exige a confirmação "Parar e remover";
+                // o estado é lido no servidor, a lista pode estar desatualizada
+                if action_str == "remove" {
+                    let state = {
+                        let manager = container_manager_clone.lock().await;
+                        manager.container_state(&container_id_str).await
+                    };
+                    if let Ok(state) = state {
+                        if is_active_state(&state) {
+                            let ui_weak_confirm = ui_weak_clone.clone();
+                            slint::invoke_from_event_loop(move || {
+                                if let Some(ui) = ui_weak_confirm.upgrade() {
+                                    ui.set_remove_confirm_container(ContainerData {
+                                        id: container_id_str.into(),
+                                        server: server_str.into(),
+                                        name: container_name_str.into(),
+                                        status: state.clone().into(),
+                                        ..Default::default()
+                                    });
+                                    ui.set_remove_confirm_state(state.into());
+                                    ui.set_show_remove_confirm(true);
+                                }
+                            })
+                            .unwrap();
+                            return;
+                        }
+                    }
+                }
+
+                // Define o estado de loading
+                let ui_weak_loading = ui_weak_clone.clone();
+                let loading_key_clone = loading_key.clone();
+                slint::invoke_from_event_loop(move || {
+                    if let Some(ui) = ui_weak_loading.upgrade() {
+                        ui.set_container_loading(loading_key_clone.into());
+                        ui.set_container_error("".into());
+                        ui.set_container_success("".into());
+                    }
+                })
+                .unwrap();
+
+                let (success, error_message) = {
+                    let manager = container_manager_clone.lock().await;
+
+                    // Executa a ação no container
+                    match manager
+                        .execute_container_action(&container_id_str, &action_str)
+                        .await
+                    {
+                        Ok(()) => (true, None),
+                        Err(e) => (false, Some(e.to_string())),
+                    }
+                };
+
+                if let Some(action) = Action::from_ui(&action_str) {
+                    bus.publish(ResourceAction {
+                        resource: Resource::Container,
+                        target: container_id_str.clone(),
+                        action,
+                        succeeded: success,
+                    });
+                }
+
+                // Limpa o loading e trata resultado
+                let ui_weak_result = ui_weak_clone.clone();
+                if success {
+                    let success_msg = match action_str.as_str() {
+                        "start" => format!(
+                            "[{}] Container '{}' iniciado com sucesso",
+                            server_str, container_name_str
+                        ),
+                        "stop" => format!(
+                            "[{}] Container '{}' parado com sucesso",
+                            server_str, container_name_str
+                        ),
+                        "pause" => format!(
+                            "[{}] Container '{}' pausado com sucesso",
+                            server_str, container_name_str
+                        ),
+                        "unpause" => format!(
+                            "[{}] Container '{}' despausado com sucesso",
+                            server_str, container_name_str
+                        ),
+                        "remove" => format!(
+                            "[{}] Container '{}' removido com sucesso",
+                            server_str, container_name_str
+                        ),
+                        "stop-remove" => format!(
+                            "[{}] Container '{}' parado e removido com sucesso",
+                            server_str, container_name_str
+                        ),
+                        "force-remove" => format!(
+                            "[{}] Container '{}' removido à força",
+                            server_str, container_name_str
+                        ),
+                        _ => format!(
+                            "[{}] Ação '{}' executada com sucesso no container '{}'",
+                            server_str, action_str, container_name_str
+                        ),
+                    };
+
+                    slint::invoke_from_event_loop(move || {
+                        if let Some(ui) = ui_weak_result.upgrade() {
+                            ui.set_container_loading("".into());
+                            ui.set_notification_message(success_msg.into());
+                            ui.set_notification_is_error(false);
+                            ui.set_show_notification(true);
+                        }
+                    })
+                    .unwrap();
+
+                    // Timer para limpar mensagem de sucesso após 3 segundos
+                    let ui_weak_timer = ui_weak_clone.clone();
+                    tokio::spawn(async move {
+                        tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
+                        slint::invoke_from_event_loop(move || {
+                            if let Some(ui) = ui_weak_timer.upgrade() {
+                                ui.set_show_notification(false);
+                            }
+                        })
+                        .unwrap();
+                    });
+                } else if let Some(error) = error_message {
+                    let error_msg = format!(
+                        "[{}] Erro ao executar '{}' no container '{}': {}",
+                        server_str, action_str, container_name_str, error
+                    );
+                    slint::invoke_from_event_loop(move || {
+                        if let Some(ui) = ui_weak_result.upgrade() {
+                            ui.set_container_loading("".into());
+                            ui.set_notification_message(error_msg.into());
+                            ui.set_notification_is_error(true);
+                            ui.set_show_notification(true);
+                        }
+                    })
+                    .unwrap();
+                }
+
+                // Atualiza a lista imediatamente após a ação bem-sucedida
+                if success {
+                    let mut manager = container_manager_clone.lock().await;
+                    if let Ok(()) = manager.refresh_containers().await {
+                        let filtered_containers = manager.get_filtered_containers();
+                        let ui_weak_final = ui_weak_clone.clone();
+                        slint::invoke_from_event_loop(move || {
+                            if let Some(ui) = ui_weak_final.upgrade() {
+                                update_ui_containers_from_slint(&ui, &filtered_containers);
+                                ui.set_containers_updated(updated_label());
+                            }
+                        })
+                        .unwrap();
+                    }
+                }
+            });
+        }
+    });
+}
+
+// Grupos de containers do servidor ativo, com a ordem de inicialização calculada
+pub fn update_container_groups(ui: &AppWindow, config: &AppConfig, context_name: &str) {
+    let groups: Vec<ContainerGroupData> = config
+        .container_groups
+        .iter()
+        .filter(|group| group.server == context_name)
+        .map(|group| ContainerGroupData {
+            name: group.name.clone().into(),
+            order: group
+                .start_order()
+                .map(|levels| container_groups::order_summary(&levels))
+                .unwrap_or_else(|e| e.to_string())
+                .into(),
+            spec: group.spec.clone().into(),
+        })
+        .collect();
+    ui.set_container_groups(std::rc::Rc::new(slint::VecModel::from(groups)).into());
+}
+
+// Sobe o grupo etapa por etapa; uma etapa só começa quando a anterior está pronta
+async fn start_container_group(
+    docker_manager: &Arc<tokio::sync::Mutex<DockerManager>>,
+    group: &ContainerGroup,
+    on_progress: impl Fn(String),
+) -> anyhow::Result<()> {
+    let levels = group.start_order()?;
+
+    for (index, level) in levels.iter().enumerate() {
+        let step = format!("Etapa {}/{}", index + 1, levels.len());
+        on_progress(format!("{}: iniciando {}", step, level.join(", ")));
+
+        {
+            let manager = docker_manager.lock().await;
+            if manager.context().name != group.server {
+                return Err(anyhow::anyhow!(
+                    "O grupo pertence ao servidor '{}', que não está mais ativo",
+                    group.server
+                ));
+            }
+            for container in level {
+                if manager.container_readiness(container).await? != Readiness::Ready {
+                    manager.start_container(container).await?;
+                }
+            }
+        }
+
+        // Aguarda todos da etapa ficarem prontos (healthy, se houver healthcheck)
+        wait_until_ready(docker_manager, level, |summary| {
+            on_progress(format!("{}: aguardando {}", step, summary))
+        })
+        .await?;
+    }
+
+    Ok(())
+}
+
+// Aguarda os containers ficarem rodando (e healthy, se houver healthcheck), por até 2 minutos;
+// `on_waiting` recebe quem ainda falta, ex: "api (healthcheck: starting)"
+async fn wait_until_ready(
+    docker_manager: &Arc<tokio::sync::Mutex<DockerManager>>,
+    containers: &[String],
+    on_waiting: impl Fn(String),
+) -> anyhow::Result<()> {
+    let deadline = std::time::Instant::now() + Duration::from_secs(120);
+    let mut pending: Vec<&String> = containers.iter().collect();
+    loop {
+        let mut waiting = Vec::new();
+        {
+            let manager = docker_manager.lock().await;
+            for container in pending {
+                match manager.container_readiness(container).await? {
+                    Readiness::Ready => {}
+                    Readiness::Waiting(reason) => waiting.push((container, reason)),
+                    Readiness::Failed(reason) => {
+                        return Err(anyhow::anyhow!(
+                            "'{}' não ficou pronto: {}",
+                            container,
+                            reason
+                        ));
+                    }
+                }
+            }
+        }
+        if waiting.is_empty() {
+            return Ok(());
+        }
+
+        let summary = waiting
+            .iter()
+            .map(|(container, reason)| format!("{} ({})", container, reason))
+            .collect::<Vec<_>>()
+            .join(", ");
+        if std::time::Instant::now() >= deadline {
+            return Err(anyhow::anyhow!("Tempo esgotado aguardando {}", summary));
+        }
+        on_waiting(summary);
+
+        pending = waiting.into_iter().map(|(container, _)| container).collect();
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+}
+
+// Reinicia as réplicas uma por vez, só seguindo para a próxima quando a anterior estiver
+// pronta; a primeira falha interrompe o restante
+pub async fn rolling_restart(
+    docker_manager: &Arc<tokio::sync::Mutex<DockerManager>>,
+    containers: &[String],
+    on_progress: impl Fn(String),
+) -> anyhow::Result<()> {
+    for (index, container) in containers.iter().enumerate() {
+        let step = format!("{}/{}", index + 1, containers.len());
+        on_progress(format!("Reiniciando {} ({})", container, step));
+
+        let restarted = docker_manager.lock().await.restart_container(container).await;
+        let ready = match restarted {
+            Ok(()) => {
+                wait_until_ready(docker_manager, std::slice::from_ref(container), |summary| {
+                    on_progress(format!("{}: aguardando {}", step, summary))
+                })
+                .await
+            }
+            Err(e) => Err(e),
+        };
+        if let Err(e) = ready {
+            return Err(anyhow::anyhow!(
+                "Reinício gradual interrompido em '{}' ({} de {} já reiniciados): {}",
+                container,
+                index,
+                containers.len(),
+                e
+            ));
+        }
+    }
+    Ok(())
+}
+
+// Aplica a ação do seletor aos containers; retorna as falhas. O reinício gradual para na
+// primeira falha, as demais ações seguem para os próximos containers
+async fn apply_selector_action(
+    docker_manager: &Arc<tokio::sync::Mutex<DockerManager>>,
+    action: SelectorAction,
+    containers: &[String],
+    on_progress: impl Fn(String),
+) -> Vec<String> {
+    if action == SelectorAction::RollingRestart {
+        return match rolling_restart(docker_manager, containers, on_progress).await {
+            Ok(_) => Vec::new(),
+            Err(e) => vec![e.to_string()],
+        };
+    }
+
+    let mut failures = Vec::new();
+    for (index, container) in containers.iter().enumerate() {
+        on_progress(format!(
+            "{}: {}/{} ({})",
+            action.label(),
+            index + 1,
+            containers.len(),
+            container
+        ));
+        let manager = docker_manager.lock().await;
+        let result = match action {
+            SelectorAction::Start => manager.start_container(container).await,
+            SelectorAction::Stop => manager.stop_container(container).await,
+            SelectorAction::Restart | SelectorAction::RollingRestart => {
+                manager.restart_container(container).await
+            }
+            SelectorAction::PullRecreate => manager.pull_and_recreate_container(container).await,
+        };
+        if let Err(e) = result {
+            failures.push(format!("{}: {}", container, e));
+        }
+    }
+    failures
+}
+
+// Configura cadastro e inicialização de grupos de containers
+fn setup_container_group_callbacks(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+    store: UiStore,
+) {
+    let Some(ui) = ui_weak.upgrade() else {
+        return;
+    };
+
+    ui.on_save_container_group({
+        let ui_weak = ui_weak.clone();
+        move |name, spec| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            let server = ui.get_context_name().to_string();
+
+            let result = ContainerGroup::new(&name, &server, &spec).and_then(|group| {
+                AppConfig::update(|config| {
+                    config
+                        .container_groups
+                        .retain(|saved| !(saved.server == server && saved.name == group.name));
+                    config.container_groups.push(group);
+                    Ok(())
+                })
+            });
+
+            match result {
+                Ok(_) => {
+                    ui.set_group_error("".into());
+                    ui.set_group_name("".into());
+                    ui.set_group_spec("".into());
+                }
+                Err(e) => ui.set_group_error(e.to_string().into()),
+            }
+            update_container_groups(&ui, &AppConfig::load(), &server);
+        }
+    });
+
+    ui.on_remove_container_group({
+        let ui_weak = ui_weak.clone();
+        move |name| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            let server = ui.get_context_name().to_string();
+
+            let result = AppConfig::update(|config| {
+                config
+                    .container_groups
+                    .retain(|group| !(group.server == server && group.name == name.as_str()));
+                Ok(())
+            });
+            match result {
+                Ok(_) => ui.set_group_error("".into()),
+                Err(e) => ui.set_group_error(e.to_string().into()),
+            }
+            update_container_groups(&ui, &AppConfig::load(), &server);
+        }
+    });
+
+    // Containers resolvidos pelo último seletor; as ações valem para esse conjunto
+    let selected = Arc::new(std::sync::Mutex::new(Vec::<String>::new()));
+
+    // Os nomes valem só no servidor em que foram resolvidos
+    store.subscribe({
+        let selected = selected.clone();
+        move |ui, change| {
+            if let StateChange::ServerChanged = change {
+                selected.lock().unwrap().clear();
+                ui.set_group_selector_resolved(false);
+                ui.set_group_selector_count(0);
+                ui.set_group_selector_summary("".into());
+            }
+        }
+    });
+
+    ui.on_resolve_group_selector({
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        let selected = selected.clone();
+        move |text| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            let selector = match LabelSelector::parse(&text) {
+                Ok(selector) => selector,
+                Err(e) => {
+                    ui.set_group_error(e.to_string().into());
+                    return;
+                }
+            };
+            ui.set_group_error("".into());
+            ui.set_group_selector_busy(true);
+            let server = ui.get_context_name().to_string();
+
+            let ui_weak = ui_weak.clone();
+            let docker_manager = docker_manager.clone();
+            let selected = selected.clone();
+            tokio::spawn(async move {
+                let result = docker_manager
+                    .lock()
+                    .await
+                    .containers_by_labels(&selector)
+                    .await;
+                slint::invoke_from_event_loop(move || {
+                    let Some(ui) = ui_weak.upgrade() else {
+                        return;
+                    };
+                    ui.set_group_selector_busy(false);
+                    // Servidor trocado durante a consulta: o resultado não vale para o atual
+                    if ui.get_context_name().as_str() != server {
+                        return;
+                    }
+                    match result {
+                        Ok(names) => {
+                            let mut summary = names.iter().take(8).cloned().collect::<Vec<_>>().join(", ");
+                            if names.len() > 8 {
+                                summary.push_str(&format!(" e mais {}", names.len() - 8));
+                            }
+                            ui.set_group_selector_count(names.len() as i32);
+                            ui.set_group_selector_summary(summary.into());
+                            ui.set_group_selector_resolved(true);
+                            *selected.lock().unwrap() = names;
+                        }
+                        Err(e) => ui.set_group_error(e.to_string().into()),
+                    }
+                })
+                .unwrap();
+            });
+        }
+    });
+
+    ui.on_apply_group_selector({
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        let selected = selected.clone();
+        move |action| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            let Some(action) = SelectorAction::from_ui(&action) else {
+                return;
+            };
+            let names = selected.lock().unwrap().clone();
+            if names.is_empty() || !ui.get_group_selector_resolved() {
+                return;
+            }
+            ui.set_group_selector_busy(true);
+            ui.set_group_error("".into());
+
+            let ui_weak = ui_weak.clone();
+            let docker_manager = docker_manager.clone();
+            tokio::spawn(async move {
+                let progress_ui = ui_weak.clone();
+                let failures = apply_selector_action(&docker_manager, action, &names, move |status| {
+                    let ui_weak = progress_ui.clone();
+                    slint::invoke_from_event_loop(move || {
+                        if let Some(ui) = ui_weak.upgrade() {
+                            ui.set_group_status(status.into());
+                        }
+                    })
+                    .unwrap();
+                })
+                .await;
+
+                slint::invoke_from_event_loop(move || {
+                    let Some(ui) = ui_weak.upgrade() else {
+                        return;
+                    };
+                    ui.set_group_selector_busy(false);
+                    if failures.is_empty() {
+                        ui.set_group_status(
+                            format!("{}: {} containers concluídos", action.label(), names.len()).into(),
+                        );
+                    } else {
+                        ui.set_group_status(
+                            format!(
+                                "{}: {} falha(s) em {} containers",
+                                action.label(),
+                                failures.len(),
+                                names.len()
+                            )
+                            .into(),
+                        );
+                        ui.set_group_error(failures.join("\n").into());
+                    }
+                })
+                .unwrap();
+            });
+        }
+    });
+
+    ui.on_start_container_group(move |name| {
+        let Some(ui) = ui_weak.upgrade() else {
+            return;
+        };
+        let server = ui.get_context_name().to_string();
+        let Some(group) = AppConfig::load()
+            .container_groups
+            .into_iter()
+            .find(|group| group.server == server && group.name == name.as_str())
+        else {
+            return;
+        };
+
+        ui.set_starting_group(true);
+        ui.set_group_error("".into());
+        ui.set_group_status("".into());
+
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        tokio::spawn(async move {
+            let progress_ui = ui_weak.clone();
+            let result = start_container_group(&docker_manager, &group, move |status| {
+                let ui_weak = progress_ui.clone();
+                slint::invoke_from_event_loop(move || {
+                    if let Some(ui) = ui_weak.upgrade() {
+                        ui.set_group_status(status.into());
+                    }
+                })
+                .unwrap();
+            })
+            .await;
+
+            slint::invoke_from_event_loop(move || {
+                let Some(ui) = ui_weak.upgrade() else {
+                    return;
+                };
+                ui.set_starting_group(false);
+                match result {
+                    Ok(()) => {
+                        ui.set_group_status("".into());
+                        ui.set_notification_message(
+                            format!("Grupo '{}' iniciado", group.name).into(),
+                        );
+                        ui.set_notification_is_error(false);
+                    }
+                    Err(e) => {
+                        ui.set_group_error(e.to_string().into());
+                        ui.set_notification_message(
+                            format!("Falha ao iniciar grupo '{}': {}", group.name, e).into(),
+                        );
+                        ui.set_notification_is_error(true);
+                    }
+                }
+                ui.set_show_notification(true);
+            })
+            .unwrap();
+        });
+    });
+}
+
+// Gera o resumo em Markdown do container selecionado para copiar
+fn setup_container_summary_callback(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+) {
+    let Some(ui) = ui_weak.upgrade() else {
+        return;
+    };
+
+    ui.on_export_container_summary(move || {
+        let Some(ui) = ui_weak.upgrade() else {
+            return;
+        };
+        let selected = ui.get_selected_container();
+        if selected.name.is_empty() {
+            return;
+        }
+        ui.set_container_summary("".into());
+        ui.set_container_summary_error("".into());
+        ui.set_container_summary_loading(true);
+        ui.set_show_container_summary(true);
+
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        let names = vec![selected.name.to_string()];
+        tokio::spawn(async move {
+            let result = docker_manager
+                .lock()
+                .await
+                .inspect_for_export(&names)
+                .await
+                .and_then(|sources| {
+                    sources
+                        .first()
+                        .map(container_summary::to_markdown)
+                        .ok_or_else(|| anyhow::anyhow!("Container não encontrado"))
+                });
+
+            slint::invoke_from_event_loop(move || {
+                if let Some(ui) = ui_weak.upgrade() {
+                    ui.set_container_summary_loading(false);
+                    match result {
+                        Ok(summary) => ui.set_container_summary(summary.into()),
+                        Err(e) => ui.set_container_summary_error(e.to_string().into()),
+                    }
+                }
+            })
+            .unwrap();
+        });
+    });
+}
+
+// Throttling de I/O do container selecionado: lê os limites atuais e aplica os novos
+fn setup_update_resources_callbacks(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+    action_bus: ActionBus,
+) {
+    let Some(ui) = ui_weak.upgrade() else {
+        return;
+    };
+
+    ui.on_open_update_resources({
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        move || {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            let name = ui.get_selected_container().name.to_string();
+            if name.is_empty() {
+                return;
+            }
+            ui.set_resources_blkio_weight("".into());
+            ui.set_resources_device_read_bps("".into());
+            ui.set_resources_device_write_bps("".into());
+            ui.set_resources_error("".into());
+            ui.set_resources_loading(true);
+            ui.set_show_update_resources(true);
+
+            let ui_weak = ui_weak.clone();
+            let docker_manager = docker_manager.clone();
+            tokio::spawn(async move {
+                let result = docker_manager
+                    .lock()
+                    .await
+                    .container_io_limits(&name)
+                    .await;
+
+                slint::invoke_from_event_loop(move || {
+                    if let Some(ui) = ui_weak.upgrade() {
+                        ui.set_resources_loading(false);
+                        match result {
+                            Ok(limits) => {
+                                let weight = limits
+                                    .blkio_weight
+                                    .map(|weight| weight.to_string())
+                                    .unwrap_or_default();
+                                ui.set_resources_blkio_weight(weight.into());
+                                ui.set_resources_device_read_bps(
+                                    resource_limits::format_device_rates(&limits.device_read_bps)
+                                        .into(),
+                                );
+                                ui.set_resources_device_write_bps(
+                                    resource_limits::format_device_rates(&limits.device_write_bps)
+                                        .into(),
+                                );
+                            }
+                            Err(e) => ui.set_resources_error(e.to_string().into()),
+                        }
+                    }
+                })
+                .unwrap();
+            });
+        }
+    });
+
+    ui.on_save_update_resources(move || {
+        let Some(ui) = ui_weak.upgrade() else {
+            return;
+        };
+        let selected = ui.get_selected_container();
+        let id = selected.id.to_string();
+        let name = selected.name.to_string();
+        let limits = match IoLimits::parse(
+            &ui.get_resources_blkio_weight(),
+            &ui.get_resources_device_read_bps(),
+            &ui.get_resources_device_write_bps(),
+        ) {
+            Ok(limits) => limits,
+            Err(e) => {
+                ui.set_resources_error(e.to_string().into());
+                return;
+            }
+        };
+        ui.set_resources_error("".into());
+        ui.set_resources_saving(true);
+
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        let action_bus = action_bus.clone();
+        tokio::spawn(async move {
+            let result = docker_manager
+                .lock()
+                .await
+                .update_container_io(&name, &limits)
+                .await;
+            // Só há sucesso depois que o inspect mostra os novos valores: o selo de
+            // reinício pendente pode ser retirado
+            action_bus.publish(ResourceAction {
+                resource: Resource::Container,
+                target: id,
+                action: Action::UpdateResources,
+                succeeded: result.is_ok(),
+            });
+
+            slint::invoke_from_event_loop(move || {
+                if let Some(ui) = ui_weak.upgrade() {
+                    ui.set_resources_saving(false);
+                    match result {
+                        Ok(recreated) => {
+                            ui.set_show_update_resources(false);
+                            let message = if recreated {
+                                format!("{} recriado com os novos limites de I/O", name)
+                            } else {
+                                format!("Limites de I/O de {} atualizados", name)
+                            };
+                            ui.set_notification_message(message.into());
+                            ui.set_notification_is_error(false);
+                            ui.set_show_notification(true);
+                        }
+                        Err(e) => ui.set_resources_error(format!("{:#}", e).into()),
+                    }
+                }
+            })
+            .unwrap();
+        });
+    });
+}
+
+// Inspect de um container: do servidor ativo quando for ele, senão de uma conexão própria
+async fn inspect_on_server(
+    docker_manager: &Arc<tokio::sync::Mutex<DockerManager>>,
+    server: &str,
+    id: &str,
+) -> anyhow::Result<serde_json::Value> {
+    let ids = [id.to_string()];
+    let inspected = {
+        let active = docker_manager.lock().await;
+        if active.context().name == server {
+            Some(active.inspect_containers(&ids).await?)
+        } else {
+            None
+        }
+    };
+    let inspected = match inspected {
+        Some(inspected) => inspected,
+        None => {
+            DockerManager::for_context_name(server)
+                .await?
+                .inspect_containers(&ids)
+                .await?
+        }
+    };
+    inspected
+        .into_iter()
+        .next()
+        .ok_or_else(|| anyhow::anyhow!("Container não encontrado em {}", server))
+}
+
+fn diff_title(container: &ContainerData) -> String {
+    format!("{} ({})", container.name, container.server)
+}
+
+// Marca um container como base e compara outro com ela, inclusive entre servidores
+fn setup_container_compare_callbacks(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+) {
+    let Some(ui) = ui_weak.upgrade() else {
+        return;
+    };
+
+    ui.on_compare_container({
+        let ui_weak = ui_weak.clone();
+        move || {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            let selected = ui.get_selected_container();
+            let base = ui.get_compare_base();
+            if selected.id.is_empty() {
+                return;
+            }
+            // Sem base, ou clicando de novo na própria base: marca/desmarca
+            if base.id.is_empty() || (base.id == selected.id && base.server == selected.server) {
+                let marked = base.id.is_empty();
+                ui.set_compare_base(if marked { selected } else { ContainerData::default() });
+                if marked {
+                    ui.set_notification_message(
+                        "Container marcado: abra outro, de qualquer servidor, e clique em \"Comparar com\""
+                            .into(),
+                    );
+                    ui.set_notification_is_error(false);
+                    ui.set_show_notification(true);
+                }
+                return;
+            }
+
+            ui.set_container_diff_left(diff_title(&base).into());
+            ui.set_container_diff_right(diff_title(&selected).into());
+            ui.set_container_diff_rows(std::rc::Rc::new(slint::VecModel::from(Vec::new())).into());
+            ui.set_container_diff_count(0);
+            ui.set_container_diff_error("".into());
+            ui.set_container_diff_loading(true);
+            ui.set_show_container_diff(true);
+
+            let ui_weak = ui_weak.clone();
+            let docker_manager = docker_manager.clone();
+            tokio::spawn(async move {
+                let result = async {
+                    let left = inspect_on_server(&docker_manager, &base.server, &base.id).await?;
+                    let right =
+                        inspect_on_server(&docker_manager, &selected.server, &selected.id).await?;
+                    anyhow::Ok(container_diff::compare(&left, &right))
+                }
+                .await;
+
+                slint::invoke_from_event_loop(move || {
+                    let Some(ui) = ui_weak.upgrade() else {
+                        return;
+                    };
+                    ui.set_container_diff_loading(false);
+                    match result {
+                        Ok(rows) => {
+                            let count = rows.iter().filter(|row| row.differs()).count();
+                            let rows: Vec<ContainerDiffRow> = rows
+                                .iter()
+                                .map(|row| ContainerDiffRow {
+                                    section: row.section.into(),
+                                    field: row.field.clone().into(),
+                                    left: row.left.clone().into(),
+                                    right: row.right.clone().into(),
+                                    differs: row.differs(),
+                                })
+                                .collect();
+                            ui.set_container_diff_count(count as i32);
+                            ui.set_container_diff_rows(
+                                std::rc::Rc::new(slint::VecModel::from(rows)).into(),
+                            );
+                        }
+                        Err(e) => ui.set_container_diff_error(e.to_string().into()),
+                    }
+                })
+                .unwrap();
+            });
+        }
+    });
+
+    ui.on_clear_compare_base({
+        let ui_weak = ui_weak.clone();
+        move || {
+            if let Some(ui) = ui_weak.upgrade() {
+                ui.set_compare_base(ContainerData::default());
+            }
+        }
+    });
+
+    // Troca as colunas sem consultar os servidores de novo
+    ui.on_swap_container_diff(move || {
+        let Some(ui) = ui_weak.upgrade() else {
+            return;
+        };
+        let rows: Vec<ContainerDiffRow> = ui
+            .get_container_diff_rows()
+            .iter()
+            .map(|row| ContainerDiffRow {
+                left: row.right.clone(),
+                right: row.left.clone(),
+                ..row
+            })
+            .collect();
+        let left = ui.get_container_diff_left();
+        ui.set_container_diff_left(ui.get_container_diff_right());
+        ui.set_container_diff_right(left);
+        ui.set_container_diff_rows(std::rc::Rc::new(slint::VecModel::from(rows)).into());
+    });
+}
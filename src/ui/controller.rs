@@ -0,0 +1,131 @@
+// Contrato comum das telas de recursos e barramento das ações executadas nelas
+use slint::Weak;
+use tokio::sync::broadcast;
+
+use crate::AppWindow;
+use crate::bandwidth::BandwidthMode;
+
+// Ações guardadas para assinantes lentos antes de descartar as mais antigas
+const BUS_CAPACITY: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resource {
+    Container,
+    Image,
+    Network,
+    Volume,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Start,
+    Stop,
+    Pause,
+    Unpause,
+    Restart,
+    Remove,
+    // Limites aplicados com docker update (ou recriando o container)
+    UpdateResources,
+}
+
+impl Action {
+    // Nome da ação enviado pelas telas Slint; parar e remover ou forçar a remoção
+    // também são remoções para quem assina o barramento
+    pub fn from_ui(action: &str) -> Option<Self> {
+        match action {
+            "start" => Some(Self::Start),
+            "stop" => Some(Self::Stop),
+            "pause" => Some(Self::Pause),
+            "unpause" => Some(Self::Unpause),
+            "restart" => Some(Self::Restart),
+            "remove" | "stop-remove" | "force-remove" => Some(Self::Remove),
+            _ => None,
+        }
+    }
+}
+
+// Ação concluída (com sucesso ou não) em um recurso do servidor ativo
+#[derive(Debug, Clone)]
+pub struct ResourceAction {
+    pub resource: Resource,
+    // ID do container/imagem/rede ou nome do volume
+    pub target: String,
+    pub action: Action,
+    pub succeeded: bool,
+}
+
+impl ResourceAction {
+    // Remover um container pode liberar imagens, redes e volumes que ele usava
+    pub fn frees_resources(&self) -> bool {
+        self.succeeded && self.resource == Resource::Container && self.action == Action::Remove
+    }
+}
+
+// Publica as ações de todas as telas; recursos como operações em lote e desfazer
+// assinam aqui em vez de alterar os callbacks de cada tela
+#[derive(Clone)]
+pub struct ActionBus(broadcast::Sender<ResourceAction>);
+
+impl Default for ActionBus {
+    fn default() -> Self {
+        let (sender, _) = broadcast::channel(BUS_CAPACITY);
+        Self(sender)
+    }
+}
+
+impl ActionBus {
+    // Sem assinantes a ação é simplesmente descartada
+    pub fn publish(&self, action: ResourceAction) {
+        let _ = self.0.send(action);
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<ResourceAction> {
+        self.0.subscribe()
+    }
+}
+
+// Texto do indicador de última atualização das telas
+pub fn updated_label() -> slint::SharedString {
+    format!("Atualizado às {}", chrono::Local::now().format("%H:%M:%S")).into()
+}
+
+// Tela de um recurso: callbacks de visualização/gerenciamento e atualização automática
+pub trait ResourceController {
+    fn setup_callbacks(&self, ui_weak: Weak<AppWindow>);
+
+    fn start_refresh(&self, ui_weak: Weak<AppWindow>, low_bandwidth: BandwidthMode);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn removed(action: &str, resource: Resource, succeeded: bool) -> ResourceAction {
+        ResourceAction {
+            resource,
+            target: "abc123".to_string(),
+            action: Action::from_ui(action).unwrap(),
+            succeeded,
+        }
+    }
+
+    #[test]
+    fn test_action_from_ui() {
+        assert_eq!(Action::from_ui("start"), Some(Action::Start));
+        assert_eq!(Action::from_ui("unpause"), Some(Action::Unpause));
+        assert_eq!(Action::from_ui("remove"), Some(Action::Remove));
+        assert_eq!(Action::from_ui("stop-remove"), Some(Action::Remove));
+        assert_eq!(Action::from_ui("force-remove"), Some(Action::Remove));
+        assert_eq!(Action::from_ui("update-resources"), None);
+        assert_eq!(Action::from_ui("desconhecida"), None);
+    }
+
+    #[test]
+    fn test_frees_resources() {
+        assert!(removed("stop-remove", Resource::Container, true).frees_resources());
+        assert!(removed("force-remove", Resource::Container, true).frees_resources());
+        assert!(!removed("stop-remove", Resource::Container, false).frees_resources());
+        assert!(!removed("remove", Resource::Image, true).frees_resources());
+        assert!(!removed("stop", Resource::Container, true).frees_resources());
+    }
+}
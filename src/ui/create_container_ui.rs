@@ -0,0 +1,548 @@
+// Modal de criação de containers: validação dos campos, opções avançadas (cpuset e limites
+// de I/O), portas sugeridas pela imagem, estimativa do pull e descrição do Docker Hub
+use slint::Weak;
+use std::sync::Arc;
+
+use super::hub_retry_after;
+use crate::AppWindow;
+use crate::config::AppConfig;
+use crate::credentials::CredentialSelection;
+use crate::docker::{
+    CreateContainerRequest, DockerManager, EnvVar, PortMapping, PullError, PullPolicy,
+    VolumeMapping,
+};
+use crate::registry;
+
+// Configura callbacks para criação de containers
+pub fn setup_create_container_callbacks(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+) {
+    let ui = ui_weak.upgrade().unwrap();
+
+    // Callback para criar container
+    ui.on_create_container({
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        move |name,
+              image,
+              command,
+              restart_policy,
+              ports_text,
+              volumes_text,
+              env_vars_text,
+              registry_credential| {
+            let ui_weak_clone = ui_weak.clone();
+            let docker_manager_clone = docker_manager.clone();
+            let name_str = name.to_string();
+            let image_str = image.to_string();
+            let command_str = command.to_string();
+            let restart_policy_str = restart_policy.to_string();
+            let ports_str = ports_text.to_string();
+            let volumes_str = volumes_text.to_string();
+            let env_vars_str = env_vars_text.to_string();
+            let registry_credential_str = registry_credential.to_string();
+
+            // "Criar mesmo assim" vale apenas para esta tentativa
+            let allow_arch_mismatch = ui_weak
+                .upgrade()
+                .map(|ui| {
+                    let allow = ui.get_create_allow_arch_mismatch();
+                    ui.set_create_allow_arch_mismatch(false);
+                    ui.set_create_arch_warning("".into());
+                    allow
+                })
+                .unwrap_or(false);
+
+            let pull_policy = ui_weak
+                .upgrade()
+                .map(|ui| {
+                    let policy = PullPolicy::from_ui(&ui.get_pull_policy());
+                    AppConfig::remember_pull_policy(&ui.get_context_name(), policy);
+                    policy
+                })
+                .unwrap_or_default();
+
+            // Seção "Avançado": validada em create_container
+            let [
+                cpuset_cpus,
+                cpuset_mems,
+                blkio_weight,
+                device_read_bps,
+                device_write_bps,
+            ] = ui_weak
+                .upgrade()
+                .map(|ui| {
+                    [
+                        ui.get_create_cpuset_cpus(),
+                        ui.get_create_cpuset_mems(),
+                        ui.get_create_blkio_weight(),
+                        ui.get_create_device_read_bps(),
+                        ui.get_create_device_write_bps(),
+                    ]
+                    .map(|value| value.to_string())
+                })
+                .unwrap_or_default();
+
+            tokio::spawn(async move {
+                // Define estado de loading
+                let ui_weak_loading = ui_weak_clone.clone();
+                slint::invoke_from_event_loop(move || {
+                    if let Some(ui) = ui_weak_loading.upgrade() {
+                        ui.set_creating_container(true);
+                    }
+                })
+                .unwrap();
+
+                // Valida campos obrigatórios
+                if name_str.trim().is_empty() {
+                    let ui_weak_error = ui_weak_clone.clone();
+                    slint::invoke_from_event_loop(move || {
+                        if let Some(ui) = ui_weak_error.upgrade() {
+                            ui.set_creating_container(false);
+                            ui.set_notification_message("Nome do container é obrigatório".into());
+                            ui.set_notification_is_error(true);
+                            ui.set_show_notification(true);
+                        }
+                    })
+                    .unwrap();
+                    return;
+                }
+
+                if image_str.trim().is_empty() {
+                    let ui_weak_error = ui_weak_clone.clone();
+                    slint::invoke_from_event_loop(move || {
+                        if let Some(ui) = ui_weak_error.upgrade() {
+                            ui.set_creating_container(false);
+                            ui.set_notification_message("Nome da imagem é obrigatório".into());
+                            ui.set_notification_is_error(true);
+                            ui.set_show_notification(true);
+                        }
+                    })
+                    .unwrap();
+                    return;
+                }
+
+                // Parse dos campos de entrada
+                let ports = parse_ports_text(&ports_str);
+                let volumes = parse_volumes_text(&volumes_str);
+                let env_vars = parse_env_vars_text(&env_vars_str);
+
+                let create_request = CreateContainerRequest {
+                    name: name_str.trim().to_string(),
+                    image: image_str.trim().to_string(),
+                    ports,
+                    volumes,
+                    environment: env_vars,
+                    command: if command_str.trim().is_empty() {
+                        None
+                    } else {
+                        Some(command_str.trim().to_string())
+                    },
+                    restart_policy: restart_policy_str,
+                    registry_credential: registry_credential_str,
+                    allow_arch_mismatch,
+                    pull_policy,
+                    cpuset_cpus,
+                    cpuset_mems,
+                    blkio_weight,
+                    device_read_bps,
+                    device_write_bps,
+                };
+
+                // Executa criação
+                let docker_manager = docker_manager_clone.lock().await;
+                let server_name = docker_manager.context().name.clone();
+                let result = docker_manager.create_container(create_request).await;
+                // A consulta da cota do Hub em caso de erro leva até 20s: libera o gerenciador antes
+                let quota_commands = match &result {
+                    Err(e) if e.is::<PullError>() => docker_manager.hub_rate_limit_commands(),
+                    _ => None,
+                };
+                drop(docker_manager);
+
+                match result {
+                    Ok(container_id) => {
+                        let ui_weak_success = ui_weak_clone.clone();
+                        let container_name = name_str.clone();
+                        slint::invoke_from_event_loop(move || {
+                            if let Some(ui) = ui_weak_success.upgrade() {
+                                ui.set_creating_container(false);
+                                ui.set_notification_message(
+                                    format!(
+                                        "[{}] Container '{}' criado e iniciado com sucesso!\nID: {}",
+                                        server_name,
+                                        container_name,
+                                        &container_id[..12]
+                                    )
+                                    .into(),
+                                );
+                                ui.set_notification_is_error(false);
+                                ui.set_show_notification(true);
+
+                                // Agenda fechamento do modal e notificação juntos após 3 segundos
+                                let ui_weak_timer = ui_weak_clone.clone();
+                                tokio::spawn(async move {
+                                    tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
+                                    slint::invoke_from_event_loop(move || {
+                                        if let Some(ui) = ui_weak_timer.upgrade() {
+                                            // Fecha modal e notificação juntos
+                                            ui.set_show_create_modal(false);
+                                            ui.set_show_notification(false);
+
+                                            // Limpa os campos
+                                            ui.set_create_container_name("".into());
+                                            ui.set_create_image_name("".into());
+                                            ui.set_create_command("".into());
+                                            ui.set_create_restart_policy("no".into());
+                                            ui.set_create_ports_text("".into());
+                                            ui.set_create_volumes_text("".into());
+                                            ui.set_create_env_vars_text("".into());
+                                            ui.set_create_registry_credential("auto".into());
+                                            ui.set_create_arch_warning("".into());
+                                            ui.set_create_cpuset_cpus("".into());
+                                            ui.set_create_cpuset_mems("".into());
+                                            ui.set_create_blkio_weight("".into());
+                                            ui.set_create_device_read_bps("".into());
+                                            ui.set_create_device_write_bps("".into());
+                                        }
+                                    })
+                                    .unwrap();
+                                });
+                            }
+                        })
+                        .unwrap();
+                    }
+                    Err(e) => {
+                        let (error_message, rate_limited) = match e.downcast::<PullError>() {
+                            Ok(PullError::RateLimited { image, .. }) => {
+                                let retry_after = hub_retry_after(quota_commands).await;
+                                (PullError::RateLimited { image, retry_after }.to_string(), true)
+                            }
+                            Err(e) => (e.to_string(), false),
+                        };
+                        let ui_weak_error = ui_weak_clone.clone();
+                        slint::invoke_from_event_loop(move || {
+                            if let Some(ui) = ui_weak_error.upgrade() {
+                                ui.set_creating_container(false);
+
+                                // Rate limit do Docker Hub: sugere login
+                                if rate_limited {
+                                    ui.set_hub_rate_limited(true);
+                                    ui.set_notification_message(error_message.into());
+                                    ui.set_notification_is_error(true);
+                                    ui.set_show_notification(true);
+                                    ui.set_show_registry_login(true);
+                                    return;
+                                }
+
+                                // Arquitetura incompatível: mostra aviso no modal
+                                if let Some(message) = error_message.strip_prefix("ARCH_MISMATCH:") {
+                                    ui.set_create_arch_warning(message.into());
+                                    return;
+                                }
+
+                                ui.set_notification_message(
+                                    format!("Falha ao criar container:\n{}", error_message).into(),
+                                );
+                                ui.set_notification_is_error(true);
+                                ui.set_show_notification(true);
+                            }
+                        })
+                        .unwrap();
+                    }
+                }
+            });
+        }
+    });
+
+    // Executar a partir da lista de imagens: abre o modal com a imagem e as portas sugeridas
+    ui.on_run_image({
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        move |image| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            ui.set_create_image_name(image.clone());
+            ui.set_create_ports_text("".into());
+            ui.set_create_arch_warning("".into());
+            set_hub_description(&ui, None);
+            ui.set_hub_description_error("".into());
+            ui.set_pull_estimate("".into());
+            ui.set_show_create_modal(true);
+
+            let ui_weak = ui_weak.clone();
+            let docker_manager = docker_manager.clone();
+            let image = image.to_string();
+            tokio::spawn(async move {
+                let exposed = docker_manager.lock().await.image_exposed_ports(&image).await;
+                let Ok(exposed) = exposed else {
+                    return;
+                };
+                let suggested = suggested_port_mappings(&exposed);
+                slint::invoke_from_event_loop(move || {
+                    let Some(ui) = ui_weak.upgrade() else {
+                        return;
+                    };
+                    // Não sobrescreve o que o usuário já digitou
+                    if ui.get_show_create_modal()
+                        && ui.get_create_image_name() == image.as_str()
+                        && ui.get_create_ports_text().is_empty()
+                    {
+                        ui.set_create_ports_text(suggested.into());
+                    }
+                })
+                .unwrap();
+            });
+        }
+    });
+
+    // Callback para cancelar criação
+    ui.on_cancel_create_container({
+        let ui_weak = ui_weak.clone();
+        move || {
+            if let Some(ui) = ui_weak.upgrade() {
+                ui.set_show_create_modal(false);
+                ui.set_create_container_name("".into());
+                ui.set_create_image_name("".into());
+                ui.set_create_command("".into());
+                ui.set_create_restart_policy("no".into());
+                ui.set_create_ports_text("".into());
+                ui.set_create_volumes_text("".into());
+                ui.set_create_env_vars_text("".into());
+                ui.set_create_arch_warning("".into());
+                ui.set_create_cpuset_cpus("".into());
+                ui.set_create_cpuset_mems("".into());
+                ui.set_create_blkio_weight("".into());
+                ui.set_create_device_read_bps("".into());
+                ui.set_create_device_write_bps("".into());
+                ui.set_creating_container(false);
+                set_hub_description(&ui, None);
+                ui.set_hub_description_error("".into());
+                ui.set_pull_estimate("".into());
+            }
+        }
+    });
+
+    // Estimativa do pull: manifest do registry menos as camadas que o servidor já tem
+    ui.on_estimate_pull({
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        move |image, credential| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            ui.set_estimating_pull(true);
+            ui.set_pull_estimate("".into());
+
+            let ui_weak = ui_weak.clone();
+            let docker_manager = docker_manager.clone();
+            let image = image.to_string();
+            tokio::spawn(async move {
+                let selection = CredentialSelection::from_ui(&credential);
+                let result = docker_manager.lock().await.estimate_pull(&image, &selection).await;
+
+                slint::invoke_from_event_loop(move || {
+                    let Some(ui) = ui_weak.upgrade() else {
+                        return;
+                    };
+                    ui.set_estimating_pull(false);
+                    if ui.get_create_image_name() != image.as_str() {
+                        return;
+                    }
+                    match result {
+                        Ok(estimate) => {
+                            ui.set_pull_estimate(estimate.label().into());
+                            ui.set_pull_estimate_error(false);
+                        }
+                        Err(e) => {
+                            ui.set_pull_estimate(format!("Sem estimativa: {}", e).into());
+                            ui.set_pull_estimate_error(true);
+                        }
+                    }
+                })
+                .unwrap();
+            });
+        }
+    });
+
+    // Descrição da imagem no Docker Hub, para conferir variáveis e portas antes de criar
+    ui.on_load_hub_description({
+        let ui_weak = ui_weak.clone();
+        move |image| {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            ui.set_hub_description_loading(true);
+            ui.set_hub_description_error("".into());
+
+            let ui_weak = ui_weak.clone();
+            let image = image.to_string();
+            tokio::spawn(async move {
+                let result = tokio::task::spawn_blocking({
+                    let image = image.clone();
+                    move || {
+                        registry::fetch_hub_description(&image, AppConfig::load().global_proxy())
+                    }
+                })
+                .await
+                .map_err(|e| anyhow::anyhow!(e))
+                .and_then(|result| result);
+
+                slint::invoke_from_event_loop(move || {
+                    let Some(ui) = ui_weak.upgrade() else {
+                        return;
+                    };
+                    ui.set_hub_description_loading(false);
+                    // A imagem mudou enquanto buscava
+                    if ui.get_create_image_name() != image.as_str() {
+                        return;
+                    }
+                    match result {
+                        Ok(description) => set_hub_description(&ui, Some(&description)),
+                        Err(e) => {
+                            set_hub_description(&ui, None);
+                            ui.set_hub_description_error(e.to_string().into());
+                        }
+                    }
+                })
+                .unwrap();
+            });
+        }
+    });
+}
+
+// Preenche (ou limpa) a descrição do Docker Hub no modal de criação
+fn set_hub_description(ui: &AppWindow, description: Option<&registry::HubDescription>) {
+    let Some(description) = description else {
+        ui.set_hub_description_header("".into());
+        ui.set_hub_description_summary("".into());
+        ui.set_hub_description_readme("".into());
+        ui.set_hub_description_env("".into());
+        ui.set_hub_description_ports("".into());
+        return;
+    };
+    ui.set_hub_description_header(description.header().into());
+    ui.set_hub_description_summary(description.summary.clone().into());
+    ui.set_hub_description_readme(description.readme.clone().into());
+    ui.set_hub_description_env(description.env_vars.join(", ").into());
+    ui.set_hub_description_ports(description.ports.join(", ").into());
+}
+
+// Funções auxiliares para parsing de entrada
+
+// Mapeamentos sugeridos para as portas expostas, na mesma porta do host: "80:80,53:53/udp"
+fn suggested_port_mappings(exposed: &[String]) -> String {
+    exposed
+        .iter()
+        .filter_map(|port| {
+            let (number, protocol) = port.split_once('/').unwrap_or((port, "tcp"));
+            let number = number.parse::<u16>().ok()?;
+            Some(match protocol {
+                "tcp" => format!("{}:{}", number, number),
+                protocol => format!("{}:{}/{}", number, number, protocol),
+            })
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+// Parse do texto de portas: "8080:80/tcp,9000:9000/udp"
+fn parse_ports_text(ports_text: &str) -> Vec<PortMapping> {
+    if ports_text.trim().is_empty() {
+        return Vec::new();
+    }
+
+    ports_text
+        .split(',')
+        .filter_map(|port_str| {
+            let port_str = port_str.trim();
+            if port_str.is_empty() {
+                return None;
+            }
+
+            // Separa protocolo se especificado
+            let (port_part, protocol) = if port_str.contains('/') {
+                let parts: Vec<&str> = port_str.split('/').collect();
+                (parts[0], parts.get(1).unwrap_or(&"tcp").to_string())
+            } else {
+                (port_str, "tcp".to_string())
+            };
+
+            // Parse host:container
+            let parts: Vec<&str> = port_part.split(':').collect();
+            if parts.len() != 2 {
+                return None;
+            }
+
+            let host_port = parts[0].parse::<u16>().ok()?;
+            let container_port = parts[1].parse::<u16>().ok()?;
+
+            Some(PortMapping {
+                host_port,
+                container_port,
+                protocol,
+            })
+        })
+        .collect()
+}
+
+// Parse do texto de volumes: "/host/path:/container/path,/host2:/container2:ro"
+fn parse_volumes_text(volumes_text: &str) -> Vec<VolumeMapping> {
+    if volumes_text.trim().is_empty() {
+        return Vec::new();
+    }
+
+    volumes_text
+        .split(',')
+        .filter_map(|volume_str| {
+            let volume_str = volume_str.trim();
+            if volume_str.is_empty() {
+                return None;
+            }
+
+            let parts: Vec<&str> = volume_str.split(':').collect();
+            if parts.len() < 2 {
+                return None;
+            }
+
+            let host_path = parts[0].to_string();
+            let container_path = parts[1].to_string();
+            let read_only = parts.get(2).map_or(false, |&mode| mode == "ro");
+
+            Some(VolumeMapping {
+                host_path,
+                container_path,
+                read_only,
+            })
+        })
+        .collect()
+}
+
+// Parse do texto de variáveis de ambiente: "KEY1=value1,KEY2=value2"
+fn parse_env_vars_text(env_vars_text: &str) -> Vec<EnvVar> {
+    if env_vars_text.trim().is_empty() {
+        return Vec::new();
+    }
+
+    env_vars_text
+        .split(',')
+        .filter_map(|env_str| {
+            let env_str = env_str.trim();
+            if env_str.is_empty() {
+                return None;
+            }
+
+            let parts: Vec<&str> = env_str.splitn(2, '=').collect();
+            if parts.len() != 2 {
+                return None;
+            }
+
+            Some(EnvVar {
+                key: parts[0].to_string(),
+                value: parts[1].to_string(),
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,383 @@
+// Tela de imagens: lista, atualização, remoção e agrupamento por repositório
+use slint::Weak;
+use std::sync::Arc;
+use tokio::task::JoinSet;
+
+use super::controller::{
+    Action, ActionBus, Resource, ResourceAction, ResourceController, updated_label,
+};
+use super::store::Screen;
+use crate::bandwidth::BandwidthMode;
+use crate::docker::DockerManager;
+use crate::format;
+use crate::image_groups::{self, ImageLayers};
+use crate::list_images::{ImageUIManager, SlintImageData};
+use crate::{AppWindow, ImageData, RepoGroupData};
+
+pub struct ImagesController {
+    manager: Arc<tokio::sync::Mutex<ImageUIManager>>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+    bus: ActionBus,
+}
+
+impl ImagesController {
+    pub fn new(docker_manager: Arc<tokio::sync::Mutex<DockerManager>>, bus: ActionBus) -> Self {
+        Self {
+            manager: Arc::new(tokio::sync::Mutex::new(ImageUIManager::new(
+                docker_manager.clone(),
+            ))),
+            docker_manager,
+            bus,
+        }
+    }
+}
+
+impl ResourceController for ImagesController {
+    fn setup_callbacks(&self, ui_weak: Weak<AppWindow>) {
+        setup_image_callbacks(ui_weak.clone(), self.manager.clone(), self.bus.clone());
+        setup_repo_group_callbacks(ui_weak, self.docker_manager.clone());
+    }
+
+    // Atualiza imagens a cada segundo enquanto a tela está aberta e logo após a remoção de
+    // um container
+    fn start_refresh(&self, ui_weak: Weak<AppWindow>, low_bandwidth: BandwidthMode) {
+        let ui_manager = self.manager.clone();
+        let mut actions = self.bus.subscribe();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(1));
+
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        // Economia de banda: só pelo botão de atualizar; com a tela fechada, não consulta
+                        if low_bandwidth.is_low()
+                            || !low_bandwidth.is_visible(&[Screen::Images.index()])
+                        {
+                            continue;
+                        }
+                    }
+                    Ok(action) = actions.recv() => {
+                        if !action.frees_resources() {
+                            continue;
+                        }
+                    }
+                }
+
+                let mut manager = ui_manager.lock().await;
+                match manager.refresh_images().await {
+                    Ok(()) => {
+                        let images = manager.get_images();
+                        let ui_weak_clone = ui_weak.clone();
+                        slint::invoke_from_event_loop(move || {
+                            if let Some(ui) = ui_weak_clone.upgrade() {
+                                ui.set_image_list_error("".into());
+                                update_ui_images_from_slint(&ui, &images);
+                                ui.set_images_updated(updated_label());
+                            }
+                        })
+                        .unwrap();
+                    }
+                    Err(e) => {
+                        let error_message = e.to_string();
+                        let ui_weak_clone = ui_weak.clone();
+                        slint::invoke_from_event_loop(move || {
+                            if let Some(ui) = ui_weak_clone.upgrade() {
+                                ui.set_image_list_error(error_message.into());
+                            }
+                        })
+                        .unwrap();
+                    }
+                }
+            }
+        });
+    }
+}
+
+// Converte imagens para formato Slint e atualiza UI
+pub fn update_ui_images_from_slint(ui: &AppWindow, images: &[SlintImageData]) {
+    let slint_images: Vec<_> = images
+        .iter()
+        .map(|image| ImageData {
+            id: image.id.clone(),
+            tag: image.tag.clone(),
+            size: image.size.clone(),
+            created: image.created.clone(),
+            in_use: image.in_use,
+        })
+        .collect();
+
+    let slint_model: std::rc::Rc<slint::VecModel<ImageData>> =
+        std::rc::Rc::new(slint::VecModel::from(slint_images));
+
+    ui.set_images(slint_model.into());
+}
+
+// Configura callbacks específicos para imagens
+fn setup_image_callbacks(
+    ui_weak: Weak<AppWindow>,
+    image_ui_manager: Arc<tokio::sync::Mutex<ImageUIManager>>,
+    bus: ActionBus,
+) {
+    let ui = ui_weak.upgrade().unwrap();
+
+    // Callback para refresh de imagens
+    ui.on_refresh_images_clicked({
+        let ui_weak = ui_weak.clone();
+        let image_manager = image_ui_manager.clone();
+        move || {
+            let ui_weak_clone = ui_weak.clone();
+            let image_manager_clone = image_manager.clone();
+
+            tokio::spawn(async move {
+                let mut manager = image_manager_clone.lock().await;
+                match manager.refresh_images().await {
+                    Ok(()) => {
+                        let images = manager.get_images();
+                        slint::invoke_from_event_loop(move || {
+                            if let Some(ui) = ui_weak_clone.upgrade() {
+                                ui.set_image_list_error("".into());
+                                update_ui_images_from_slint(&ui, &images);
+                                ui.set_images_updated(updated_label());
+                            }
+                        })
+                        .unwrap();
+                    }
+                    Err(e) => {
+                        let error_message = e.to_string();
+                        slint::invoke_from_event_loop(move || {
+                            if let Some(ui) = ui_weak_clone.upgrade() {
+                                ui.set_image_list_error(error_message.into());
+                            }
+                        })
+                        .unwrap();
+                    }
+                }
+            });
+        }
+    });
+
+    // Callback para ações em imagens
+    ui.on_image_action({
+        let ui_weak = ui_weak.clone();
+        let image_manager = image_ui_manager.clone();
+        move |image_id, action| {
+            let ui_weak_clone = ui_weak.clone();
+            let image_manager_clone = image_manager.clone();
+            let bus = bus.clone();
+            let image_id_str = image_id.to_string();
+            let action_str = action.to_string();
+            let loading_key = format!("{}_{}", image_id_str, action_str);
+
+            tokio::spawn(async move {
+                // Define o estado de loading
+                let ui_weak_loading = ui_weak_clone.clone();
+                let loading_key_clone = loading_key.clone();
+                slint::invoke_from_event_loop(move || {
+                    if let Some(ui) = ui_weak_loading.upgrade() {
+                        ui.set_image_loading(loading_key_clone.into());
+                    }
+                })
+                .unwrap();
+
+                let manager = image_manager_clone.lock().await;
+
+                // Executa a ação na imagem
+                let result = manager
+                    .execute_image_action(&image_id_str, &action_str)
+                    .await;
+
+                if let Some(action) = Action::from_ui(&action_str) {
+                    bus.publish(ResourceAction {
+                        resource: Resource::Image,
+                        target: image_id_str.clone(),
+                        action,
+                        succeeded: result.is_ok(),
+                    });
+                }
+
+                let ui_weak_result = ui_weak_clone.clone();
+                match result {
+                    Ok(success_message) => {
+                        slint::invoke_from_event_loop(move || {
+                            if let Some(ui) = ui_weak_result.upgrade() {
+                                ui.set_image_loading("".into());
+                                ui.set_notification_message(success_message.into());
+                                ui.set_notification_is_error(false);
+                                ui.set_show_notification(true);
+                            }
+                        })
+                        .unwrap();
+
+                        // Timer para limpar mensagem de sucesso após 3 segundos
+                        let ui_weak_timer = ui_weak_clone.clone();
+                        tokio::spawn(async move {
+                            tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
+                            slint::invoke_from_event_loop(move || {
+                                if let Some(ui) = ui_weak_timer.upgrade() {
+                                    ui.set_show_notification(false);
+                                }
+                            })
+                            .unwrap();
+                        });
+                    }
+                    Err(error_message) => {
+                        let error_message_clone = error_message.clone();
+                        slint::invoke_from_event_loop(move || {
+                            if let Some(ui) = ui_weak_result.upgrade() {
+                                ui.set_image_loading("".into());
+                                let formatted_error = if error_message_clone.starts_with("IN_USE:")
+                                {
+                                    error_message_clone
+                                        .strip_prefix("IN_USE:")
+                                        .unwrap_or(&error_message_clone)
+                                        .to_string()
+                                } else {
+                                    error_message_clone
+                                        .strip_prefix("OTHER_ERROR:")
+                                        .unwrap_or(&error_message_clone)
+                                        .to_string()
+                                };
+                                ui.set_notification_message(formatted_error.into());
+                                ui.set_notification_is_error(true);
+                                ui.set_show_notification(true);
+                            }
+                        })
+                        .unwrap();
+                    }
+                }
+
+                // Atualiza a lista imediatamente após a ação
+                drop(manager); // Libera o lock
+                let mut manager = image_manager_clone.lock().await;
+                if let Ok(()) = manager.refresh_images().await {
+                    let images = manager.get_images();
+                    let ui_weak_final = ui_weak_clone.clone();
+                    slint::invoke_from_event_loop(move || {
+                        if let Some(ui) = ui_weak_final.upgrade() {
+                            update_ui_images_from_slint(&ui, &images);
+                            ui.set_images_updated(updated_label());
+                        }
+                    })
+                    .unwrap();
+                }
+            });
+        }
+    });
+}
+
+// Configura o agrupamento de imagens por repositório
+fn setup_repo_group_callbacks(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+) {
+    let ui = ui_weak.upgrade().unwrap();
+
+    ui.on_refresh_repo_groups({
+        let ui_weak = ui_weak.clone();
+        move || {
+            load_repo_groups(ui_weak.clone(), docker_manager.clone());
+        }
+    });
+}
+
+// Lê o histórico de camadas de todas as imagens em paralelo e agrupa por repositório
+fn load_repo_groups(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+) {
+    if let Some(ui) = ui_weak.upgrade() {
+        ui.set_repo_groups_loading(true);
+    }
+
+    tokio::spawn(async move {
+        let listed = {
+            let manager = docker_manager.lock().await;
+            manager.list_images().await.map(|images| {
+                images
+                    .into_iter()
+                    .map(|image| {
+                        let command = manager.image_history_command(&image.id);
+                        (image, command)
+                    })
+                    .collect::<Vec<_>>()
+            })
+        };
+
+        let result = match listed {
+            Ok(images) => {
+                let mut tasks = JoinSet::new();
+                for (image, command) in images {
+                    tasks.spawn(async move {
+                        let output = tokio::process::Command::from(command).output().await;
+                        let layers = match output {
+                            Ok(output) if output.status.success() => image_groups::parse_history(
+                                &String::from_utf8_lossy(&output.stdout),
+                            ),
+                            Ok(output) => Err(anyhow::anyhow!(
+                                "{}",
+                                String::from_utf8_lossy(&output.stderr).trim()
+                            )),
+                            Err(e) => Err(e.into()),
+                        };
+                        layers.map(|layers| ImageLayers { image, layers })
+                    });
+                }
+
+                let mut images = Vec::new();
+                let mut failed = 0;
+                while let Some(joined) = tasks.join_next().await {
+                    match joined {
+                        Ok(Ok(image)) => images.push(image),
+                        _ => failed += 1,
+                    }
+                }
+                Ok((image_groups::group(&images), images.len(), failed))
+            }
+            Err(e) => Err(e),
+        };
+
+        slint::invoke_from_event_loop(move || {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            ui.set_repo_groups_loading(false);
+            match result {
+                Ok((groups, analyzed, failed)) => {
+                    let unique: u64 = groups.iter().map(|group| group.unique_size).sum();
+                    let mut summary = format!(
+                        "{} repositórios em {} imagens, {} em camadas próprias",
+                        groups.len(),
+                        analyzed,
+                        format::bytes(unique)
+                    );
+                    if failed > 0 {
+                        summary.push_str(&format!(" ({} imagens sem histórico)", failed));
+                    }
+                    ui.set_repo_groups_summary(summary.into());
+
+                    let slint_groups: Vec<RepoGroupData> = groups
+                        .into_iter()
+                        .map(|group| RepoGroupData {
+                            repository: group.repository.into(),
+                            tags: group.tags.join(", ").into(),
+                            image_count: group.image_count as i32,
+                            unique_size: format::bytes(group.unique_size).into(),
+                            shared_size: format::bytes(group.shared_size).into(),
+                            in_use: group.in_use,
+                        })
+                        .collect();
+                    ui.set_repo_groups(
+                        std::rc::Rc::new(slint::VecModel::from(slint_groups)).into(),
+                    );
+                }
+                Err(e) => {
+                    ui.set_repo_groups_summary(format!("Erro: {}", e).into());
+                    ui.set_repo_groups(
+                        std::rc::Rc::new(slint::VecModel::<RepoGroupData>::default()).into(),
+                    );
+                }
+            }
+        })
+        .unwrap();
+    });
+}
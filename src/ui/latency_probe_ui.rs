@@ -0,0 +1,160 @@
+// Probe de latência das portas publicadas do container aberto: mede desta máquina e, em
+// conexões SSH, no próprio servidor, para separar lentidão da aplicação e do host
+use slint::{Model, Weak};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use crate::AppWindow;
+use crate::chart::ChartRenderer;
+use crate::docker::DockerManager;
+use crate::latency_probe::{self, ProbeSeries};
+
+// Configura o probe de latência das portas publicadas, desta máquina e no servidor via SSH
+pub fn setup_latency_probe_callbacks(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+) {
+    let Some(ui) = ui_weak.upgrade() else {
+        return;
+    };
+    // Cada início incrementa a geração; a task anterior para ao perceber a mudança
+    let generation = Arc::new(AtomicU64::new(0));
+    let renderers = Arc::new([
+        {
+            let mut renderer = ChartRenderer::new(520, 100);
+            renderer.set_line_color([59, 130, 246]);
+            renderer
+        },
+        {
+            let mut renderer = ChartRenderer::new(520, 100);
+            renderer.set_line_color([16, 185, 129]);
+            renderer
+        },
+    ]);
+
+    ui.on_open_latency_probe({
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        let renderers = renderers.clone();
+        move || {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            let first_port = ui.get_selected_container_ports().row_data(0).unwrap_or(0);
+            ui.set_probe_port(first_port);
+            ui.set_probe_local_chart(renderers[0].render_sparkline(&[]));
+            ui.set_probe_server_chart(renderers[1].render_sparkline(&[]));
+            ui.set_probe_local_summary("".into());
+            ui.set_probe_server_summary("".into());
+            ui.set_show_latency_probe(true);
+
+            // Só conexões SSH permitem medir no host; servidor local já é o próprio host
+            let ui_weak = ui_weak.clone();
+            let docker_manager = docker_manager.clone();
+            tokio::spawn(async move {
+                let manager = docker_manager.lock().await;
+                let available = manager.context().is_remote()
+                    && manager.host_command(&["true"]).is_some();
+                let note = if !manager.context().is_remote() {
+                    "Servidor local: a medição desta máquina já é feita no host."
+                } else {
+                    "A medição no servidor exige uma conexão ssh://."
+                };
+                drop(manager);
+                slint::invoke_from_event_loop(move || {
+                    if let Some(ui) = ui_weak.upgrade() {
+                        ui.set_probe_server_available(available);
+                        ui.set_probe_server_note(note.into());
+                    }
+                })
+                .unwrap();
+            });
+        }
+    });
+
+    ui.on_stop_latency_probe({
+        let ui_weak = ui_weak.clone();
+        let generation = generation.clone();
+        move || {
+            generation.fetch_add(1, Ordering::SeqCst);
+            if let Some(ui) = ui_weak.upgrade() {
+                ui.set_probe_running(false);
+            }
+        }
+    });
+
+    ui.on_start_latency_probe(move |port, path| {
+        let Some(ui) = ui_weak.upgrade() else {
+            return;
+        };
+        if port <= 0 {
+            return;
+        }
+        let current = generation.fetch_add(1, Ordering::SeqCst) + 1;
+        let local_url = latency_probe::probe_url(&ui.get_context_url_host(), port, &path);
+        let server_url = latency_probe::probe_url("127.0.0.1", port, &path);
+        let probe_server = ui.get_probe_server_available();
+        ui.set_probe_running(true);
+        ui.set_probe_local_summary("Aguardando medição...".into());
+        ui.set_probe_server_summary("Aguardando medição...".into());
+
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        let generation = generation.clone();
+        let renderers = renderers.clone();
+        tokio::spawn(async move {
+            let mut local = ProbeSeries::default();
+            let mut server = ProbeSeries::default();
+            while generation.load(Ordering::SeqCst) == current {
+                let mut local_command = std::process::Command::new("curl");
+                local_command.args(latency_probe::curl_args(&local_url));
+                let server_args = latency_probe::curl_args(&server_url);
+                let server_command = if probe_server {
+                    let mut args: Vec<&str> = vec!["curl"];
+                    args.extend(server_args.iter().map(String::as_str));
+                    docker_manager.lock().await.host_command(&args)
+                } else {
+                    None
+                };
+
+                // As duas rotas rodam juntas para compararem o mesmo instante
+                let (local_result, server_result) = tokio::join!(
+                    latency_probe::run(local_command),
+                    async {
+                        match server_command {
+                            Some(command) => Some(latency_probe::run(command).await),
+                            None => None,
+                        }
+                    }
+                );
+                local.record(local_result);
+                if let Some(result) = server_result {
+                    server.record(result);
+                }
+                if generation.load(Ordering::SeqCst) != current {
+                    break;
+                }
+
+                let local_values = local.totals();
+                let server_values = server.totals();
+                let local_summary = local.summary();
+                let server_summary = server.summary();
+                let ui_weak = ui_weak.clone();
+                let renderers = renderers.clone();
+                slint::invoke_from_event_loop(move || {
+                    let Some(ui) = ui_weak.upgrade() else {
+                        return;
+                    };
+                    ui.set_probe_local_chart(renderers[0].render_sparkline(&local_values));
+                    ui.set_probe_server_chart(renderers[1].render_sparkline(&server_values));
+                    ui.set_probe_local_summary(local_summary.into());
+                    ui.set_probe_server_summary(server_summary.into());
+                })
+                .unwrap();
+
+                tokio::time::sleep(Duration::from_secs(2)).await;
+            }
+        });
+    });
+}
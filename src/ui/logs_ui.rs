@@ -0,0 +1,240 @@
+// Logs do container aberto paginados por janelas de tempo: o timer busca as linhas novas e
+// o botão (ou a rolagem até o topo) carrega o bloco anterior
+use slint::{Timer, TimerMode, Weak};
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::store::Screen;
+use crate::AppWindow;
+use crate::bandwidth::{BandwidthMode, TickGate};
+use crate::docker::{DockerManager, LogTimeMode};
+use crate::log_pages::{self, LogPages, NewerRequest};
+
+// Texto dos logs carregados, no fuso escolhido
+fn render_log_pages(pages: &LogPages, manager: &DockerManager, time_mode: LogTimeMode) -> String {
+    pages
+        .lines()
+        .map(|(time, message)| {
+            format!("[{}] {}", manager.format_log_time(*time, time_mode), message)
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn show_log_pages(ui_weak: &Weak<AppWindow>, logs: String, lines: usize, exhausted: bool) {
+    let ui_weak = ui_weak.clone();
+    slint::invoke_from_event_loop(move || {
+        if let Some(ui) = ui_weak.upgrade() {
+            ui.set_container_logs(logs.into());
+            ui.set_logs_lines_loaded(lines as i32);
+            ui.set_logs_exhausted(exhausted);
+        }
+    })
+    .unwrap();
+}
+
+// Configura timer para buscar as linhas novas dos logs do container selecionado
+pub fn setup_container_logs_timer(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+    log_pages: Arc<std::sync::Mutex<LogPages>>,
+    low_bandwidth: BandwidthMode,
+) {
+    let timer = Timer::default();
+    // Economia de banda: a cada 5s
+    let mut gate = TickGate::new(5);
+
+    timer.start(TimerMode::Repeated, Duration::from_secs(1), move || {
+        if !gate.should_run(&low_bandwidth) {
+            return;
+        }
+        let ui_weak_clone = ui_weak.clone();
+        let docker_manager_clone = docker_manager.clone();
+        let log_pages = log_pages.clone();
+
+        // Coleta as informações necessárias antes do tokio::spawn
+        let (current_screen, container_name, key, time_mode) =
+            if let Some(ui) = ui_weak_clone.upgrade() {
+                let screen = ui.get_current_screen();
+                let selected = ui.get_selected_container();
+                let key = format!("{}/{}", selected.server, selected.id);
+                let time_mode = LogTimeMode::from_ui(&ui.get_log_timezone());
+                (screen, selected.id.to_string(), key, time_mode)
+            } else {
+                return; // Se não conseguir fazer upgrade, sai
+            };
+
+        // Só busca logs se estivermos na tela de detalhes
+        if current_screen == Screen::ContainerDetails.index() && !container_name.is_empty() {
+            tokio::spawn(async move {
+                let request = {
+                    let mut pages = log_pages.lock().unwrap();
+                    pages.select(&key);
+                    pages.newer_request()
+                };
+                let manager = docker_manager_clone.lock().await;
+
+                // Só as linhas posteriores à última recebida
+                let requested_at = chrono::Utc::now();
+                let result = match request {
+                    NewerRequest::Initial => {
+                        manager
+                            .get_container_log_window(
+                                &container_name,
+                                None,
+                                None,
+                                Some(log_pages::INITIAL_LINES),
+                            )
+                            .await
+                    }
+                    NewerRequest::Since(newest) => {
+                        manager
+                            .get_container_log_window(&container_name, Some(newest), None, None)
+                            .await
+                    }
+                };
+                // Ignora erros de logs para não poluir interface
+                let Ok(lines) = result else {
+                    return;
+                };
+
+                let (logs, count, exhausted) = {
+                    let mut pages = log_pages.lock().unwrap();
+                    if pages.key() != key {
+                        return;
+                    }
+                    pages.append_newer(lines, requested_at);
+                    (
+                        render_log_pages(&pages, &manager, time_mode),
+                        pages.line_count(),
+                        pages.is_exhausted(),
+                    )
+                };
+                show_log_pages(&ui_weak_clone, logs, count, exhausted);
+            });
+        }
+    });
+
+    // Mantém o timer vivo
+    std::mem::forget(timer);
+}
+
+// Buscas por clique: janelas vazias dobram de tamanho até achar linhas ou chegar à
+// criação do container
+const OLDER_LOG_ATTEMPTS: usize = 8;
+
+// Busca o bloco de logs anterior à linha mais antiga carregada
+async fn load_older_logs(
+    manager: &DockerManager,
+    log_pages: &Arc<std::sync::Mutex<LogPages>>,
+    key: &str,
+    container_name: &str,
+) -> anyhow::Result<()> {
+    let needs_created_at = log_pages.lock().unwrap().needs_created_at();
+    if needs_created_at {
+        let created_at = manager.container_created_at(container_name).await?;
+        let mut pages = log_pages.lock().unwrap();
+        if pages.key() == key {
+            pages.set_created_at(created_at);
+        }
+    }
+
+    for _ in 0..OLDER_LOG_ATTEMPTS {
+        let window = {
+            let pages = log_pages.lock().unwrap();
+            if pages.key() != key {
+                return Ok(());
+            }
+            pages.older_window()
+        };
+        let Some((since, until)) = window else {
+            return Ok(());
+        };
+        let lines = manager
+            .get_container_log_window(
+                container_name,
+                Some(since),
+                Some(until),
+                Some(log_pages::OLDER_PAGE_LINES),
+            )
+            .await?;
+
+        let mut pages = log_pages.lock().unwrap();
+        if pages.key() != key {
+            return Ok(());
+        }
+        let before = pages.line_count();
+        pages.prepend_older(lines, since);
+        if pages.line_count() > before {
+            return Ok(());
+        }
+    }
+    Ok(())
+}
+
+// Configura callback para carregar o bloco de logs anterior (botão ou rolagem até o topo)
+pub fn setup_load_more_logs_callback(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+    log_pages: Arc<std::sync::Mutex<LogPages>>,
+) {
+    let Some(ui) = ui_weak.upgrade() else {
+        return;
+    };
+
+    ui.on_load_more_logs(move || {
+        let Some(ui) = ui_weak.upgrade() else {
+            return;
+        };
+        let selected = ui.get_selected_container();
+        if selected.id.is_empty() || ui.get_logs_loading() {
+            return;
+        }
+        let key = format!("{}/{}", selected.server, selected.id);
+        let container_name = selected.id.to_string();
+        let time_mode = LogTimeMode::from_ui(&ui.get_log_timezone());
+        {
+            let pages = log_pages.lock().unwrap();
+            if pages.key() != key || !pages.can_load_older() {
+                return;
+            }
+        }
+        ui.set_logs_loading(true);
+
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        let log_pages = log_pages.clone();
+        tokio::spawn(async move {
+            let manager = docker_manager.lock().await;
+            let result = load_older_logs(&manager, &log_pages, &key, &container_name).await;
+            let view = {
+                let pages = log_pages.lock().unwrap();
+                (pages.key() == key).then(|| {
+                    (
+                        render_log_pages(&pages, &manager, time_mode),
+                        pages.line_count(),
+                        pages.is_exhausted(),
+                    )
+                })
+            };
+            if let Some((logs, count, exhausted)) = view {
+                show_log_pages(&ui_weak, logs, count, exhausted);
+            }
+
+            slint::invoke_from_event_loop(move || {
+                let Some(ui) = ui_weak.upgrade() else {
+                    return;
+                };
+                ui.set_logs_loading(false);
+                if let Err(e) = result {
+                    ui.set_notification_message(
+                        format!("Falha ao carregar logs anteriores:\n{}", e).into(),
+                    );
+                    ui.set_notification_is_error(true);
+                    ui.set_show_notification(true);
+                }
+            })
+            .unwrap();
+        });
+    });
+}
@@ -0,0 +1,497 @@
+// Tela de redes: lista, atualização, criação, remoção e endereços IP alocados
+use slint::Weak;
+use std::sync::Arc;
+
+use super::controller::{
+    Action, ActionBus, Resource, ResourceAction, ResourceController, updated_label,
+};
+use super::store::Screen;
+use crate::bandwidth::BandwidthMode;
+use crate::docker::DockerManager;
+use crate::ip_allocation::SubnetUsage;
+use crate::list_networks::{NetworkUIManager, SlintNetworkData};
+use crate::network_create::{self, NetworkCreateRequest, NetworkDriver};
+use crate::{AppWindow, HostInterfaceData, IpAllocationData, NetworkData, SubnetUsageData};
+
+pub struct NetworksController {
+    manager: Arc<tokio::sync::Mutex<NetworkUIManager>>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+    bus: ActionBus,
+}
+
+impl NetworksController {
+    pub fn new(docker_manager: Arc<tokio::sync::Mutex<DockerManager>>, bus: ActionBus) -> Self {
+        Self {
+            manager: Arc::new(tokio::sync::Mutex::new(NetworkUIManager::new(
+                docker_manager.clone(),
+            ))),
+            docker_manager,
+            bus,
+        }
+    }
+}
+
+impl ResourceController for NetworksController {
+    fn setup_callbacks(&self, ui_weak: Weak<AppWindow>) {
+        setup_network_callbacks(ui_weak.clone(), self.manager.clone(), self.bus.clone());
+        setup_network_ip_callbacks(ui_weak.clone(), self.docker_manager.clone());
+        setup_network_create_callbacks(ui_weak, self.docker_manager.clone());
+    }
+
+    // Atualiza redes a cada segundo enquanto a tela está aberta e logo após a remoção de
+    // um container
+    fn start_refresh(&self, ui_weak: Weak<AppWindow>, low_bandwidth: BandwidthMode) {
+        let ui_manager = self.manager.clone();
+        let mut actions = self.bus.subscribe();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(1));
+
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        // Economia de banda: só pelo botão de atualizar; com a tela fechada, não consulta
+                        if low_bandwidth.is_low()
+                            || !low_bandwidth.is_visible(&[Screen::Networks.index()])
+                        {
+                            continue;
+                        }
+                    }
+                    Ok(action) = actions.recv() => {
+                        if !action.frees_resources() {
+                            continue;
+                        }
+                    }
+                }
+
+                let mut manager = ui_manager.lock().await;
+                match manager.refresh_networks().await {
+                    Ok(()) => {
+                        let networks = manager.get_networks();
+                        let ui_weak_clone = ui_weak.clone();
+                        slint::invoke_from_event_loop(move || {
+                            if let Some(ui) = ui_weak_clone.upgrade() {
+                                ui.set_network_list_error("".into());
+                                update_ui_networks_from_slint(&ui, &networks);
+                                ui.set_networks_updated(updated_label());
+                            }
+                        })
+                        .unwrap();
+                    }
+                    Err(e) => {
+                        let error_message = e.to_string();
+                        let ui_weak_clone = ui_weak.clone();
+                        slint::invoke_from_event_loop(move || {
+                            if let Some(ui) = ui_weak_clone.upgrade() {
+                                ui.set_network_list_error(error_message.into());
+                            }
+                        })
+                        .unwrap();
+                    }
+                }
+            }
+        });
+    }
+}
+
+// Converte networks para formato Slint e atualiza UI
+pub fn update_ui_networks_from_slint(ui: &AppWindow, networks: &[SlintNetworkData]) {
+    let slint_networks: Vec<_> = networks
+        .iter()
+        .map(|network| NetworkData {
+            id: network.id.clone(),
+            name: network.name.clone(),
+            driver: network.driver.clone(),
+            scope: network.scope.clone(),
+            created: network.created.clone(),
+            containers_count: network.containers_count,
+            is_system: network.is_system,
+        })
+        .collect();
+
+    let slint_model: std::rc::Rc<slint::VecModel<NetworkData>> =
+        std::rc::Rc::new(slint::VecModel::from(slint_networks));
+
+    ui.set_networks(slint_model.into());
+}
+
+// Configura callbacks específicos para networks
+fn setup_network_callbacks(
+    ui_weak: Weak<AppWindow>,
+    network_ui_manager: Arc<tokio::sync::Mutex<NetworkUIManager>>,
+    bus: ActionBus,
+) {
+    let ui = ui_weak.upgrade().unwrap();
+
+    // Callback para refresh de networks
+    ui.on_refresh_networks_clicked({
+        let ui_weak = ui_weak.clone();
+        let network_manager = network_ui_manager.clone();
+        move || {
+            let ui_weak_clone = ui_weak.clone();
+            let network_manager_clone = network_manager.clone();
+
+            tokio::spawn(async move {
+                let mut manager = network_manager_clone.lock().await;
+                match manager.refresh_networks().await {
+                    Ok(()) => {
+                        let networks = manager.get_networks();
+                        slint::invoke_from_event_loop(move || {
+                            if let Some(ui) = ui_weak_clone.upgrade() {
+                                ui.set_network_list_error("".into());
+                                update_ui_networks_from_slint(&ui, &networks);
+                                ui.set_networks_updated(updated_label());
+                            }
+                        })
+                        .unwrap();
+                    }
+                    Err(e) => {
+                        let error_message = e.to_string();
+                        slint::invoke_from_event_loop(move || {
+                            if let Some(ui) = ui_weak_clone.upgrade() {
+                                ui.set_network_list_error(error_message.into());
+                            }
+                        })
+                        .unwrap();
+                    }
+                }
+            });
+        }
+    });
+
+    // Callback para ações em networks
+    ui.on_network_action({
+        let ui_weak = ui_weak.clone();
+        let network_manager = network_ui_manager.clone();
+        move |network_id, action| {
+            let ui_weak_clone = ui_weak.clone();
+            let network_manager_clone = network_manager.clone();
+            let bus = bus.clone();
+            let network_id_str = network_id.to_string();
+            let action_str = action.to_string();
+            let loading_key = format!("{}_{}", network_id_str, action_str);
+
+            tokio::spawn(async move {
+                // Define o estado de loading
+                let ui_weak_loading = ui_weak_clone.clone();
+                let loading_key_clone = loading_key.clone();
+                slint::invoke_from_event_loop(move || {
+                    if let Some(ui) = ui_weak_loading.upgrade() {
+                        ui.set_network_loading(loading_key_clone.into());
+                    }
+                })
+                .unwrap();
+
+                let manager = network_manager_clone.lock().await;
+
+                // Executa a ação na network
+                let result = manager
+                    .execute_network_action(&network_id_str, &action_str)
+                    .await;
+
+                if let Some(action) = Action::from_ui(&action_str) {
+                    bus.publish(ResourceAction {
+                        resource: Resource::Network,
+                        target: network_id_str.clone(),
+                        action,
+                        succeeded: result.is_ok(),
+                    });
+                }
+
+                let ui_weak_result = ui_weak_clone.clone();
+                match result {
+                    Ok(success_message) => {
+                        slint::invoke_from_event_loop(move || {
+                            if let Some(ui) = ui_weak_result.upgrade() {
+                                ui.set_network_loading("".into());
+                                ui.set_notification_message(success_message.into());
+                                ui.set_notification_is_error(false);
+                                ui.set_show_notification(true);
+                            }
+                        })
+                        .unwrap();
+
+                        // Timer para limpar mensagem de sucesso após 3 segundos
+                        let ui_weak_timer = ui_weak_clone.clone();
+                        tokio::spawn(async move {
+                            tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
+                            slint::invoke_from_event_loop(move || {
+                                if let Some(ui) = ui_weak_timer.upgrade() {
+                                    ui.set_show_notification(false);
+                                }
+                            })
+                            .unwrap();
+                        });
+                    }
+                    Err(error_message) => {
+                        let error_message_clone = error_message.clone();
+                        slint::invoke_from_event_loop(move || {
+                            if let Some(ui) = ui_weak_result.upgrade() {
+                                ui.set_network_loading("".into());
+                                let formatted_error = if error_message_clone.starts_with("IN_USE:")
+                                {
+                                    error_message_clone
+                                        .strip_prefix("IN_USE:")
+                                        .unwrap_or(&error_message_clone)
+                                        .to_string()
+                                } else {
+                                    error_message_clone
+                                        .strip_prefix("OTHER_ERROR:")
+                                        .unwrap_or(&error_message_clone)
+                                        .to_string()
+                                };
+                                ui.set_notification_message(formatted_error.into());
+                                ui.set_notification_is_error(true);
+                                ui.set_show_notification(true);
+                            }
+                        })
+                        .unwrap();
+                    }
+                }
+
+                // Atualiza a lista imediatamente após a ação
+                drop(manager); // Libera o lock
+                let mut manager = network_manager_clone.lock().await;
+                if let Ok(()) = manager.refresh_networks().await {
+                    let networks = manager.get_networks();
+                    let ui_weak_final = ui_weak_clone.clone();
+                    slint::invoke_from_event_loop(move || {
+                        if let Some(ui) = ui_weak_final.upgrade() {
+                            update_ui_networks_from_slint(&ui, &networks);
+                            ui.set_networks_updated(updated_label());
+                        }
+                    })
+                    .unwrap();
+                }
+            });
+        }
+    });
+}
+
+fn to_subnet_usage_data(subnet: SubnetUsage) -> SubnetUsageData {
+    let mut details = Vec::new();
+    if !subnet.gateway.is_empty() {
+        details.push(format!("gateway {}", subnet.gateway));
+    }
+    if !subnet.ip_range.is_empty() {
+        details.push(format!("alocação em {}", subnet.ip_range));
+    }
+
+    let allocations: Vec<IpAllocationData> = subnet
+        .allocations
+        .iter()
+        .map(|allocation| IpAllocationData {
+            ip: allocation.ip.clone().into(),
+            owner: allocation.owner.clone().into(),
+        })
+        .collect();
+    let free_ranges: Vec<slint::SharedString> = subnet
+        .free_ranges
+        .iter()
+        .map(|range| range.clone().into())
+        .collect();
+
+    SubnetUsageData {
+        subnet: subnet.subnet.clone().into(),
+        details: details.join(" · ").into(),
+        summary: subnet.summary().into(),
+        usage: subnet.usage(),
+        allocations: std::rc::Rc::new(slint::VecModel::from(allocations)).into(),
+        free_ranges: std::rc::Rc::new(slint::VecModel::from(free_ranges)).into(),
+    }
+}
+
+// Configura a visualização de IPs alocados e faixas livres de uma network
+fn setup_network_ip_callbacks(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+) {
+    let ui = ui_weak.upgrade().unwrap();
+
+    ui.on_load_network_ips({
+        let ui_weak = ui_weak.clone();
+        move |network_id| {
+            if let Some(ui) = ui_weak.upgrade() {
+                ui.set_network_ips_loading(true);
+                ui.set_network_ips_error("".into());
+            }
+
+            let ui_weak = ui_weak.clone();
+            let docker_manager = docker_manager.clone();
+            let network_id = network_id.to_string();
+            tokio::spawn(async move {
+                let result = docker_manager
+                    .lock()
+                    .await
+                    .network_ip_usage(&network_id)
+                    .await;
+
+                slint::invoke_from_event_loop(move || {
+                    let Some(ui) = ui_weak.upgrade() else {
+                        return;
+                    };
+                    // Resposta de uma network que já não está aberta
+                    if ui.get_network_ips_id() != network_id.as_str() {
+                        return;
+                    }
+                    ui.set_network_ips_loading(false);
+                    match result {
+                        Ok(subnets) => {
+                            let subnets: Vec<SubnetUsageData> =
+                                subnets.into_iter().map(to_subnet_usage_data).collect();
+                            ui.set_network_ips(
+                                std::rc::Rc::new(slint::VecModel::from(subnets)).into(),
+                            );
+                        }
+                        Err(e) => ui.set_network_ips_error(e.to_string().into()),
+                    }
+                })
+                .unwrap();
+            });
+        }
+    });
+}
+
+// Configura o formulário de criação de network e a busca de interfaces do host
+fn setup_network_create_callbacks(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+) {
+    let ui = ui_weak.upgrade().unwrap();
+
+    ui.on_load_host_interfaces({
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        move || {
+            if let Some(ui) = ui_weak.upgrade() {
+                ui.set_host_interfaces_loading(true);
+                ui.set_host_interfaces_error("".into());
+            }
+
+            let ui_weak = ui_weak.clone();
+            let docker_manager = docker_manager.clone();
+            tokio::spawn(async move {
+                // O ssh pode demorar; roda fora do lock do gerenciador
+                let command = docker_manager.lock().await.host_interfaces_command();
+                let result = match command {
+                    Ok(command) => tokio::process::Command::from(command)
+                        .output()
+                        .await
+                        .map_err(|e| anyhow::anyhow!("Falha ao executar ip link: {}", e))
+                        .and_then(|output| {
+                            if output.status.success() {
+                                Ok(network_create::parse_interfaces(
+                                    &String::from_utf8_lossy(&output.stdout),
+                                ))
+                            } else {
+                                Err(anyhow::anyhow!(
+                                    "Falha ao listar interfaces: {}",
+                                    String::from_utf8_lossy(&output.stderr).trim()
+                                ))
+                            }
+                        }),
+                    Err(e) => Err(e),
+                };
+
+                slint::invoke_from_event_loop(move || {
+                    let Some(ui) = ui_weak.upgrade() else {
+                        return;
+                    };
+                    ui.set_host_interfaces_loading(false);
+                    match result {
+                        Ok(interfaces) => {
+                            if interfaces.is_empty() {
+                                ui.set_host_interfaces_error(
+                                    "Nenhuma interface Ethernet encontrada; informe a interface manualmente"
+                                        .into(),
+                                );
+                            }
+                            // Sugere a primeira interface ativa
+                            let suggested = interfaces
+                                .iter()
+                                .find(|interface| interface.up)
+                                .filter(|_| ui.get_create_network_parent().is_empty());
+                            if let Some(interface) = suggested {
+                                ui.set_create_network_parent(interface.name.clone().into());
+                            }
+                            let interfaces: Vec<HostInterfaceData> = interfaces
+                                .into_iter()
+                                .map(|interface| HostInterfaceData {
+                                    name: interface.name.into(),
+                                    mac: interface.mac.into(),
+                                    up: interface.up,
+                                })
+                                .collect();
+                            ui.set_host_interfaces(
+                                std::rc::Rc::new(slint::VecModel::from(interfaces)).into(),
+                            );
+                        }
+                        Err(e) => ui.set_host_interfaces_error(e.to_string().into()),
+                    }
+                })
+                .unwrap();
+            });
+        }
+    });
+
+    ui.on_create_network({
+        let ui_weak = ui_weak.clone();
+        move |name, driver, parent, subnet, gateway, ip_range, mode| {
+            let driver = NetworkDriver::from_ui(&driver);
+            let request = NetworkCreateRequest {
+                name: name.to_string(),
+                driver,
+                // Campos guiados ficam preenchidos ao voltar para bridge
+                parent: if driver.needs_parent() {
+                    parent.to_string()
+                } else {
+                    String::new()
+                },
+                subnet: subnet.to_string(),
+                gateway: gateway.to_string(),
+                ip_range: ip_range.to_string(),
+                mode: if driver.needs_parent() {
+                    mode.to_string()
+                } else {
+                    String::new()
+                },
+            };
+
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            // Erros de preenchimento aparecem sem chamar o docker
+            if let Err(e) = request.validate() {
+                ui.set_create_network_error(e.to_string().into());
+                return;
+            }
+            ui.set_create_network_error("".into());
+            ui.set_creating_network(true);
+
+            let ui_weak = ui_weak.clone();
+            let docker_manager = docker_manager.clone();
+            tokio::spawn(async move {
+                let result = docker_manager.lock().await.create_network(&request).await;
+
+                slint::invoke_from_event_loop(move || {
+                    let Some(ui) = ui_weak.upgrade() else {
+                        return;
+                    };
+                    ui.set_creating_network(false);
+                    match result {
+                        Ok(()) => {
+                            ui.set_show_create_network(false);
+                            ui.set_notification_message(
+                                format!("Network {} criada", request.name.trim()).into(),
+                            );
+                            ui.set_notification_is_error(false);
+                            ui.set_show_notification(true);
+                            ui.invoke_refresh_networks_clicked();
+                        }
+                        Err(e) => ui.set_create_network_error(e.to_string().into()),
+                    }
+                })
+                .unwrap();
+            });
+        }
+    });
+}
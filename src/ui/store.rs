@@ -0,0 +1,276 @@
+// Estado de navegação da interface (servidor, tela, filtros e container selecionado)
+// Todas as transições passam por aqui; os efeitos na tela ficam em um só lugar
+use std::sync::{Arc, Mutex};
+
+use crate::format;
+use crate::{AppWindow, ContainerData};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Screen {
+    Dashboard,
+    Containers,
+    Images,
+    Networks,
+    Volumes,
+    ContainerDetails,
+    Settings,
+    Build,
+    Stacks,
+    Swarm,
+}
+
+impl Screen {
+    // Índice usado pela propriedade `current-screen` do Slint
+    pub fn from_index(index: i32) -> Self {
+        match index {
+            1 => Screen::Containers,
+            2 => Screen::Images,
+            3 => Screen::Networks,
+            4 => Screen::Volumes,
+            5 => Screen::ContainerDetails,
+            6 => Screen::Settings,
+            7 => Screen::Build,
+            8 => Screen::Stacks,
+            9 => Screen::Swarm,
+            _ => Screen::Dashboard,
+        }
+    }
+
+    pub fn index(self) -> i32 {
+        match self {
+            Screen::Dashboard => 0,
+            Screen::Containers => 1,
+            Screen::Images => 2,
+            Screen::Networks => 3,
+            Screen::Volumes => 4,
+            Screen::ContainerDetails => 5,
+            Screen::Settings => 6,
+            Screen::Build => 7,
+            Screen::Stacks => 8,
+            Screen::Swarm => 9,
+        }
+    }
+}
+
+// Container aberto na tela de detalhes; nomes se repetem entre servidores
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectedContainer {
+    pub server: String,
+    pub id: String,
+    pub name: String,
+}
+
+impl SelectedContainer {
+    fn from_data(container: &ContainerData) -> Self {
+        Self {
+            server: container.server.to_string(),
+            id: container.id.to_string(),
+            name: container.name.to_string(),
+        }
+    }
+
+    // Chave "servidor/id" usada pelos históricos do container
+    pub fn key(&self) -> String {
+        format!("{}/{}", self.server, self.id)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UiState {
+    pub server: String,
+    pub screen: Screen,
+    pub search: String,
+    pub status_filter: String,
+    pub selected: Option<SelectedContainer>,
+}
+
+// Pedidos de mudança de estado vindos dos callbacks
+pub enum Transition {
+    Navigate(Screen),
+    // Abre os detalhes do container
+    SelectContainer(ContainerData),
+    // Dados novos (status, notas, tags) do container já aberto
+    UpdateSelected(ContainerData),
+    // Container aberto deixou de existir
+    CloseDetails,
+    SwitchServer(String),
+    SetSearch(String),
+    SetStatusFilter(String),
+}
+
+// Mudanças efetivas, entregues aos assinantes na ordem em que ocorreram
+#[derive(Debug, Clone)]
+pub enum StateChange {
+    ScreenChanged { from: Screen, to: Screen },
+    ContainerSelected(SelectedContainer),
+    ContainerCleared,
+    ServerChanged,
+    FiltersChanged,
+}
+
+type Listener = Box<dyn Fn(&AppWindow, &StateChange) + Send + Sync>;
+
+#[derive(Clone)]
+pub struct UiStore {
+    state: Arc<Mutex<UiState>>,
+    listeners: Arc<Mutex<Vec<Listener>>>,
+}
+
+impl UiStore {
+    pub fn new(server: &str) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(UiState {
+                server: server.to_string(),
+                screen: Screen::Dashboard,
+                search: String::new(),
+                status_filter: "all".to_string(),
+                selected: None,
+            })),
+            listeners: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    pub fn state(&self) -> UiState {
+        self.state.lock().unwrap().clone()
+    }
+
+    // Efeitos que não pertencem à tela (históricos, consoles etc.) assinam as mudanças
+    pub fn subscribe(&self, listener: impl Fn(&AppWindow, &StateChange) + Send + Sync + 'static) {
+        self.listeners.lock().unwrap().push(Box::new(listener));
+    }
+
+    // Aplica a transição no event loop do Slint: atualiza o estado, a tela e avisa os assinantes
+    pub fn dispatch(&self, ui: &AppWindow, transition: Transition) {
+        let changes = {
+            let mut state = self.state.lock().unwrap();
+            reduce(&mut state, ui, transition)
+        };
+        if changes.is_empty() {
+            return;
+        }
+
+        let state = self.state();
+        for change in &changes {
+            render(ui, &state, change);
+        }
+        let listeners = self.listeners.lock().unwrap();
+        for change in &changes {
+            for listener in listeners.iter() {
+                listener(ui, change);
+            }
+        }
+    }
+}
+
+fn navigate(state: &mut UiState, to: Screen, changes: &mut Vec<StateChange>) {
+    if state.screen == to {
+        return;
+    }
+    changes.push(StateChange::ScreenChanged {
+        from: state.screen,
+        to,
+    });
+    state.screen = to;
+    if to != Screen::ContainerDetails && state.selected.take().is_some() {
+        changes.push(StateChange::ContainerCleared);
+    }
+}
+
+// Container aberto nos detalhes e suas portas publicadas, para os links do navegador
+fn set_selected_container(ui: &AppWindow, container: ContainerData) {
+    let mut ports: Vec<i32> = container
+        .ports
+        .split(", ")
+        .filter_map(|port| port.parse().ok())
+        .collect();
+    // Bindings IPv4 e IPv6 repetem a mesma porta
+    ports.sort_unstable();
+    ports.dedup();
+    ui.set_selected_container_ports(std::rc::Rc::new(slint::VecModel::from(ports)).into());
+    ui.set_selected_container(container);
+}
+
+fn reduce(state: &mut UiState, ui: &AppWindow, transition: Transition) -> Vec<StateChange> {
+    let mut changes = Vec::new();
+    match transition {
+        Transition::Navigate(screen) => navigate(state, screen, &mut changes),
+        Transition::SelectContainer(container) => {
+            let selected = SelectedContainer::from_data(&container);
+            set_selected_container(ui, container);
+            if state.selected.as_ref() != Some(&selected) {
+                state.selected = Some(selected.clone());
+                changes.push(StateChange::ContainerSelected(selected));
+            }
+            navigate(state, Screen::ContainerDetails, &mut changes);
+        }
+        Transition::UpdateSelected(container) => {
+            if state.selected.as_ref() == Some(&SelectedContainer::from_data(&container)) {
+                set_selected_container(ui, container);
+            }
+        }
+        Transition::CloseDetails => {
+            if state.screen == Screen::ContainerDetails {
+                navigate(state, Screen::Containers, &mut changes);
+            }
+        }
+        Transition::SwitchServer(server) => {
+            if state.server != server {
+                state.server = server;
+                changes.push(StateChange::ServerChanged);
+                // Detalhes do container não fazem sentido em outro servidor
+                if state.screen == Screen::ContainerDetails {
+                    navigate(state, Screen::Containers, &mut changes);
+                }
+            }
+        }
+        Transition::SetSearch(search) => {
+            if state.search != search {
+                state.search = search;
+                changes.push(StateChange::FiltersChanged);
+            }
+        }
+        Transition::SetStatusFilter(filter) => {
+            if state.status_filter != filter {
+                state.status_filter = filter;
+                changes.push(StateChange::FiltersChanged);
+            }
+        }
+    }
+    changes
+}
+
+// Propriedades da janela derivadas de cada mudança
+fn render(ui: &AppWindow, state: &UiState, change: &StateChange) {
+    match change {
+        StateChange::ScreenChanged { from, to } => {
+            ui.set_current_screen(to.index());
+            if *from == Screen::ContainerDetails {
+                ui.set_metrics_expanded(false);
+                ui.set_logs_expanded(false);
+            }
+        }
+        StateChange::ContainerSelected(_) => {
+            // Preenchidos pela paginação dos logs do novo container
+            ui.set_logs_lines_loaded(0);
+            ui.set_logs_exhausted(false);
+            // Preenchido pelo monitor de gatilhos
+            ui.set_log_triggers(Default::default());
+            ui.set_log_trigger_error("".into());
+            ui.set_annotation_error("".into());
+            ui.set_container_logs("".into());
+            ui.set_container_cpu_usage(format!("{}%", format::number(0.0, 1)).into());
+            ui.set_container_memory_usage(format::bytes(0).into());
+            ui.set_container_memory_trend("".into());
+            ui.set_container_memory_growing(false);
+            ui.set_container_network_rx(format::bytes_rate(0).into());
+            ui.set_container_network_tx(format::bytes_rate(0).into());
+            ui.set_container_cpu_chart(Default::default());
+            ui.set_container_memory_chart(Default::default());
+        }
+        StateChange::ContainerCleared | StateChange::ServerChanged => {}
+        StateChange::FiltersChanged => {
+            ui.set_search_text(state.search.as_str().into());
+            ui.set_status_filter(state.status_filter.as_str().into());
+        }
+    }
+}
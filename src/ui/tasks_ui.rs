@@ -0,0 +1,268 @@
+// Fila de tarefas em segundo plano: execução registrada em tasks.json, lista nas
+// configurações e retomada das tarefas interrompidas na abertura do app
+use anyhow::{Result, anyhow};
+use slint::Weak;
+use std::sync::Arc;
+
+use super::{audit_prune, prune_server};
+use crate::docker::DockerManager;
+use crate::format;
+use crate::prune::PruneTarget;
+use crate::task_queue::{self, TaskKind, TaskRecord, TaskState};
+use crate::volume_migration::{self, MigrationReport, MigrationRequest, Progress, SourceCommands};
+use crate::{AppWindow, BackgroundTaskData};
+
+// Atualiza a lista de tarefas exibida nas configurações
+pub fn update_background_tasks(ui: &AppWindow) {
+    let tasks: Vec<BackgroundTaskData> = task_queue::load()
+        .iter()
+        .map(|task| BackgroundTaskData {
+            id: task.id.clone().into(),
+            label: task.kind.label().into(),
+            status: task.status_label().into(),
+            failed: task.state == TaskState::Failed,
+            running: !task.is_finished(),
+        })
+        .collect();
+    ui.set_background_tasks(std::rc::Rc::new(slint::VecModel::from(tasks)).into());
+}
+
+fn refresh(ui_weak: &Weak<AppWindow>) {
+    let ui_weak = ui_weak.clone();
+    slint::invoke_from_event_loop(move || {
+        if let Some(ui) = ui_weak.upgrade() {
+            update_background_tasks(&ui);
+        }
+    })
+    .unwrap();
+}
+
+fn record_finish(ui_weak: &Weak<AppWindow>, task_id: &str, result: &Result<String>) {
+    if let Err(e) = task_queue::finish(task_id, result) {
+        eprintln!("Error updating task queue: {}", e);
+    }
+    refresh(ui_weak);
+}
+
+// Executa uma limpeza já enfileirada
+pub async fn run_prune_task(
+    ui_weak: &Weak<AppWindow>,
+    docker_manager: &Arc<tokio::sync::Mutex<DockerManager>>,
+    task_id: &str,
+    server: &str,
+    target: PruneTarget,
+) -> Result<String> {
+    task_queue::start(task_id)?;
+    refresh(ui_weak);
+    let result = prune_server(docker_manager, server, target).await;
+    let detail = match &result {
+        Ok(reclaimed) => Ok(format!("{} liberados", reclaimed)),
+        Err(e) => Err(anyhow!("{}", e)),
+    };
+    record_finish(ui_weak, task_id, &detail);
+    result
+}
+
+// Executa uma migração de volume já enfileirada, a partir do servidor de origem registrado
+pub async fn run_migration_task(
+    ui_weak: &Weak<AppWindow>,
+    docker_manager: &Arc<tokio::sync::Mutex<DockerManager>>,
+    task_id: &str,
+    source_server: &str,
+    request: &MigrationRequest,
+    mut on_progress: impl FnMut(Progress),
+) -> Result<MigrationReport> {
+    let partial_target = task_queue::get(task_id).is_some_and(|task| task.partial_target);
+    task_queue::start(task_id)?;
+    refresh(ui_weak);
+
+    let result = async {
+        if partial_target {
+            // A execução anterior parou no meio da cópia: descarta o volume incompleto
+            on_progress(Progress::Step("Removendo a cópia incompleta no destino..."));
+            let target = DockerManager::for_context_name(&request.target_server).await?;
+            if target.volume_exists(request.target_volume.trim()).await? {
+                target.remove_volume(request.target_volume.trim()).await?;
+            }
+            task_queue::set_partial_target(task_id, false)?;
+        }
+        let source = source_commands(docker_manager, source_server, &request.source_volume).await?;
+        let result = volume_migration::migrate(source, request, |progress| {
+            if matches!(progress, Progress::TargetCreated) {
+                task_queue::set_partial_target(task_id, true)
+                    .unwrap_or_else(|e| eprintln!("Error updating task queue: {}", e));
+            }
+            on_progress(progress);
+        })
+        .await;
+        // Terminou (com sucesso ou não): a própria migração decide o que fica no destino
+        task_queue::set_partial_target(task_id, false)?;
+        result
+    }
+    .await;
+
+    let detail = match &result {
+        Ok(report) => Ok(format!(
+            "{} copiados · checksum {} conferido",
+            format::bytes(report.bytes),
+            volume_migration::short(&report.checksum)
+        )),
+        Err(e) => Err(anyhow!("{}", e)),
+    };
+    record_finish(ui_weak, task_id, &detail);
+    result
+}
+
+// Comandos da origem: do servidor ativo quando for ele, senão de uma conexão própria
+async fn source_commands(
+    docker_manager: &Arc<tokio::sync::Mutex<DockerManager>>,
+    server: &str,
+    volume: &str,
+) -> Result<SourceCommands> {
+    {
+        let active = docker_manager.lock().await;
+        if active.context().name == server {
+            return Ok(SourceCommands {
+                server: server.to_string(),
+                archive: active.volume_archive_command(volume),
+                checksum: active.volume_checksum_command(volume),
+            });
+        }
+    }
+    let manager = DockerManager::for_context_name(server).await?;
+    Ok(SourceCommands {
+        server: server.to_string(),
+        archive: manager.volume_archive_command(volume),
+        checksum: manager.volume_checksum_command(volume),
+    })
+}
+
+// Roda de novo uma tarefa registrada (nova tentativa ou retomada) e avisa o resultado
+fn spawn_task(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+    task: TaskRecord,
+) {
+    tokio::spawn(async move {
+        let label = task.kind.label();
+        let result = match &task.kind {
+            TaskKind::Prune { server, target } => {
+                let target = PruneTarget::from_ui(target);
+                let result =
+                    run_prune_task(&ui_weak, &docker_manager, &task.id, server, target).await;
+                audit_prune(server, "prune (nova tentativa)", target, &result);
+                result.map(|_| ())
+            }
+            TaskKind::VolumeMigration {
+                source_server,
+                source_volume,
+                target_server,
+                target_volume,
+            } => {
+                let request = MigrationRequest {
+                    source_volume: source_volume.clone(),
+                    target_server: target_server.clone(),
+                    target_volume: target_volume.clone(),
+                };
+                run_migration_task(
+                    &ui_weak,
+                    &docker_manager,
+                    &task.id,
+                    source_server,
+                    &request,
+                    |_| {},
+                )
+                .await
+                .map(|_| ())
+            }
+        };
+
+        slint::invoke_from_event_loop(move || {
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            update_background_tasks(&ui);
+            let message = match &result {
+                Ok(()) => format!("{}: concluída", label),
+                Err(e) => format!("{}: {}", label, e),
+            };
+            ui.set_notification_message(message.into());
+            ui.set_notification_is_error(result.is_err());
+            ui.set_show_notification(true);
+        })
+        .unwrap();
+    });
+}
+
+// Configura a lista de tarefas e retoma as que foram interrompidas na última execução
+pub fn setup_task_queue_callbacks(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+) {
+    let Some(ui) = ui_weak.upgrade() else {
+        return;
+    };
+
+    ui.on_retry_background_task({
+        let ui_weak = ui_weak.clone();
+        let docker_manager = docker_manager.clone();
+        move |id| {
+            let Some(task) = task_queue::get(&id) else {
+                return;
+            };
+            if task.state != TaskState::Failed {
+                return;
+            }
+            spawn_task(ui_weak.clone(), docker_manager.clone(), task);
+        }
+    });
+
+    ui.on_remove_background_task({
+        let ui_weak = ui_weak.clone();
+        move |id| {
+            if let Err(e) = task_queue::remove(&id) {
+                eprintln!("Error updating task queue: {}", e);
+            }
+            if let Some(ui) = ui_weak.upgrade() {
+                update_background_tasks(&ui);
+            }
+        }
+    });
+
+    ui.on_clear_finished_tasks({
+        let ui_weak = ui_weak.clone();
+        move || {
+            if let Err(e) = task_queue::clear_done() {
+                eprintln!("Error updating task queue: {}", e);
+            }
+            if let Some(ui) = ui_weak.upgrade() {
+                update_background_tasks(&ui);
+            }
+        }
+    });
+
+    let (resumable, interrupted) = match task_queue::recover_interrupted() {
+        Ok(recovered) => recovered,
+        Err(e) => {
+            eprintln!("Error recovering task queue: {}", e);
+            (Vec::new(), 0)
+        }
+    };
+    update_background_tasks(&ui);
+
+    if interrupted > 0 {
+        ui.set_notification_message(
+            format!(
+                "{} tarefa(s) interrompida(s) ao fechar a aplicação; tente de novo em Configurações > Tarefas em segundo plano",
+                interrupted
+            )
+            .into(),
+        );
+        ui.set_notification_is_error(true);
+        ui.set_show_notification(true);
+    }
+
+    for task in resumable {
+        spawn_task(ui_weak.clone(), docker_manager.clone(), task);
+    }
+}
@@ -0,0 +1,459 @@
+// Tela de volumes: lista, atualização, criação, migração entre servidores e remoção
+use slint::Weak;
+use std::sync::Arc;
+
+use super::controller::{
+    Action, ActionBus, Resource, ResourceAction, ResourceController, updated_label,
+};
+use super::store::Screen;
+use super::tasks_ui;
+use crate::bandwidth::BandwidthMode;
+use crate::docker::DockerManager;
+use crate::format;
+use crate::list_volumes::{SlintVolumeData, VolumeUIManager};
+use crate::volume_create::{VolumeCreateRequest, VolumePreset};
+use crate::task_queue::{self, TaskKind};
+use crate::volume_migration::{self, MigrationRequest, Progress};
+use crate::{AppWindow, VolumeData};
+
+pub struct VolumesController {
+    manager: Arc<tokio::sync::Mutex<VolumeUIManager>>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+    bus: ActionBus,
+}
+
+impl VolumesController {
+    pub fn new(docker_manager: Arc<tokio::sync::Mutex<DockerManager>>, bus: ActionBus) -> Self {
+        Self {
+            manager: Arc::new(tokio::sync::Mutex::new(VolumeUIManager::new(
+                docker_manager.clone(),
+            ))),
+            docker_manager,
+            bus,
+        }
+    }
+}
+
+impl ResourceController for VolumesController {
+    fn setup_callbacks(&self, ui_weak: Weak<AppWindow>) {
+        setup_volume_callbacks(ui_weak.clone(), self.manager.clone(), self.bus.clone());
+        setup_volume_create_callbacks(ui_weak.clone(), self.docker_manager.clone());
+        setup_volume_migration_callbacks(ui_weak, self.docker_manager.clone());
+    }
+
+    // Atualiza volumes a cada segundo enquanto a tela está aberta e logo após a remoção de
+    // um container
+    fn start_refresh(&self, ui_weak: Weak<AppWindow>, low_bandwidth: BandwidthMode) {
+        let ui_manager = self.manager.clone();
+        let mut actions = self.bus.subscribe();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(1));
+
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        // Economia de banda: só pelo botão de atualizar; com a tela fechada, não consulta
+                        if low_bandwidth.is_low()
+                            || !low_bandwidth.is_visible(&[Screen::Volumes.index()])
+                        {
+                            continue;
+                        }
+                    }
+                    Ok(action) = actions.recv() => {
+                        if !action.frees_resources() {
+                            continue;
+                        }
+                    }
+                }
+
+                let mut manager = ui_manager.lock().await;
+                match manager.refresh_volumes().await {
+                    Ok(()) => {
+                        let volumes = manager.get_volumes();
+                        let ui_weak_clone = ui_weak.clone();
+                        slint::invoke_from_event_loop(move || {
+                            if let Some(ui) = ui_weak_clone.upgrade() {
+                                ui.set_volume_list_error("".into());
+                                update_ui_volumes_from_slint(&ui, &volumes);
+                                ui.set_volumes_updated(updated_label());
+                            }
+                        })
+                        .unwrap();
+                    }
+                    Err(e) => {
+                        let error_message = e.to_string();
+                        let ui_weak_clone = ui_weak.clone();
+                        slint::invoke_from_event_loop(move || {
+                            if let Some(ui) = ui_weak_clone.upgrade() {
+                                ui.set_volume_list_error(error_message.into());
+                            }
+                        })
+                        .unwrap();
+                    }
+                }
+            }
+        });
+    }
+}
+
+// Converte volumes para formato Slint e atualiza UI
+pub fn update_ui_volumes_from_slint(ui: &AppWindow, volumes: &[SlintVolumeData]) {
+    let slint_volumes: Vec<_> = volumes
+        .iter()
+        .map(|volume| VolumeData {
+            name: volume.name.clone(),
+            driver: volume.driver.clone(),
+            mountpoint: volume.mountpoint.clone(),
+            created: volume.created.clone(),
+            containers_count: volume.containers_count,
+        })
+        .collect();
+
+    let slint_model: std::rc::Rc<slint::VecModel<VolumeData>> =
+        std::rc::Rc::new(slint::VecModel::from(slint_volumes));
+
+    ui.set_volumes(slint_model.into());
+}
+
+// Configura callbacks específicos para volumes
+fn setup_volume_callbacks(
+    ui_weak: Weak<AppWindow>,
+    volume_ui_manager: Arc<tokio::sync::Mutex<VolumeUIManager>>,
+    bus: ActionBus,
+) {
+    let ui = ui_weak.upgrade().unwrap();
+
+    // Callback para refresh de volumes
+    ui.on_refresh_volumes_clicked({
+        let ui_weak = ui_weak.clone();
+        let volume_manager = volume_ui_manager.clone();
+        move || {
+            let ui_weak_clone = ui_weak.clone();
+            let volume_manager_clone = volume_manager.clone();
+
+            tokio::spawn(async move {
+                let mut manager = volume_manager_clone.lock().await;
+                match manager.refresh_volumes().await {
+                    Ok(()) => {
+                        let volumes = manager.get_volumes();
+                        slint::invoke_from_event_loop(move || {
+                            if let Some(ui) = ui_weak_clone.upgrade() {
+                                ui.set_volume_list_error("".into());
+                                update_ui_volumes_from_slint(&ui, &volumes);
+                                ui.set_volumes_updated(updated_label());
+                            }
+                        })
+                        .unwrap();
+                    }
+                    Err(e) => {
+                        let error_message = e.to_string();
+                        slint::invoke_from_event_loop(move || {
+                            if let Some(ui) = ui_weak_clone.upgrade() {
+                                ui.set_volume_list_error(error_message.into());
+                            }
+                        })
+                        .unwrap();
+                    }
+                }
+            });
+        }
+    });
+
+    // Callback para ações em volumes
+    ui.on_volume_action({
+        let ui_weak = ui_weak.clone();
+        let volume_manager = volume_ui_manager.clone();
+        move |volume_name, action| {
+            let ui_weak_clone = ui_weak.clone();
+            let volume_manager_clone = volume_manager.clone();
+            let bus = bus.clone();
+            let volume_name_str = volume_name.to_string();
+            let action_str = action.to_string();
+            let loading_key = format!("{}_{}", volume_name_str, action_str);
+
+            tokio::spawn(async move {
+                // Define o estado de loading
+                let ui_weak_loading = ui_weak_clone.clone();
+                let loading_key_clone = loading_key.clone();
+                slint::invoke_from_event_loop(move || {
+                    if let Some(ui) = ui_weak_loading.upgrade() {
+                        ui.set_volume_loading(loading_key_clone.into());
+                    }
+                })
+                .unwrap();
+
+                let manager = volume_manager_clone.lock().await;
+
+                // Executa a ação no volume
+                let result = manager
+                    .execute_volume_action(&volume_name_str, &action_str)
+                    .await;
+
+                if let Some(action) = Action::from_ui(&action_str) {
+                    bus.publish(ResourceAction {
+                        resource: Resource::Volume,
+                        target: volume_name_str.clone(),
+                        action,
+                        succeeded: result.is_ok(),
+                    });
+                }
+
+                let ui_weak_result = ui_weak_clone.clone();
+                match result {
+                    Ok(success_message) => {
+                        slint::invoke_from_event_loop(move || {
+                            if let Some(ui) = ui_weak_result.upgrade() {
+                                ui.set_volume_loading("".into());
+                                ui.set_notification_message(success_message.into());
+                                ui.set_notification_is_error(false);
+                                ui.set_show_notification(true);
+                            }
+                        })
+                        .unwrap();
+
+                        // Timer para limpar mensagem de sucesso após 3 segundos
+                        let ui_weak_timer = ui_weak_clone.clone();
+                        tokio::spawn(async move {
+                            tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
+                            slint::invoke_from_event_loop(move || {
+                                if let Some(ui) = ui_weak_timer.upgrade() {
+                                    ui.set_show_notification(false);
+                                }
+                            })
+                            .unwrap();
+                        });
+                    }
+                    Err(error_message) => {
+                        let error_message_clone = error_message.clone();
+                        slint::invoke_from_event_loop(move || {
+                            if let Some(ui) = ui_weak_result.upgrade() {
+                                ui.set_volume_loading("".into());
+                                let formatted_error = if error_message_clone.starts_with("IN_USE:")
+                                {
+                                    error_message_clone
+                                        .strip_prefix("IN_USE:")
+                                        .unwrap_or(&error_message_clone)
+                                        .to_string()
+                                } else {
+                                    error_message_clone
+                                        .strip_prefix("OTHER_ERROR:")
+                                        .unwrap_or(&error_message_clone)
+                                        .to_string()
+                                };
+                                ui.set_notification_message(formatted_error.into());
+                                ui.set_notification_is_error(true);
+                                ui.set_show_notification(true);
+                            }
+                        })
+                        .unwrap();
+                    }
+                }
+
+                // Atualiza a lista imediatamente após a ação
+                drop(manager); // Libera o lock
+                let mut manager = volume_manager_clone.lock().await;
+                if let Ok(()) = manager.refresh_volumes().await {
+                    let volumes = manager.get_volumes();
+                    let ui_weak_final = ui_weak_clone.clone();
+                    slint::invoke_from_event_loop(move || {
+                        if let Some(ui) = ui_weak_final.upgrade() {
+                            update_ui_volumes_from_slint(&ui, &volumes);
+                            ui.set_volumes_updated(updated_label());
+                        }
+                    })
+                    .unwrap();
+                }
+            });
+        }
+    });
+}
+
+// Configura o formulário de criação de volume (local, NFS ou CIFS)
+fn setup_volume_create_callbacks(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+) {
+    let ui = ui_weak.upgrade().unwrap();
+
+    ui.on_create_volume({
+        let ui_weak = ui_weak.clone();
+        move |name, preset, server, path, nfs_version, username, password, extra_options| {
+            let preset = VolumePreset::from_ui(&preset);
+            let request = VolumeCreateRequest {
+                name: name.to_string(),
+                preset,
+                server: server.to_string(),
+                path: path.to_string(),
+                // Campos de outro preset ficam preenchidos no formulário
+                nfs_version: if preset == VolumePreset::Nfs {
+                    nfs_version.to_string()
+                } else {
+                    String::new()
+                },
+                username: if preset == VolumePreset::Cifs {
+                    username.to_string()
+                } else {
+                    String::new()
+                },
+                password: if preset == VolumePreset::Cifs {
+                    password.to_string()
+                } else {
+                    String::new()
+                },
+                extra_options: extra_options.to_string(),
+            };
+
+            let Some(ui) = ui_weak.upgrade() else {
+                return;
+            };
+            // Erros de preenchimento aparecem sem chamar o docker
+            if let Err(e) = request.validate() {
+                ui.set_create_volume_error(e.to_string().into());
+                return;
+            }
+            ui.set_create_volume_error("".into());
+            ui.set_creating_volume(true);
+
+            let ui_weak = ui_weak.clone();
+            let docker_manager = docker_manager.clone();
+            tokio::spawn(async move {
+                let result = docker_manager.lock().await.create_volume(&request).await;
+
+                slint::invoke_from_event_loop(move || {
+                    let Some(ui) = ui_weak.upgrade() else {
+                        return;
+                    };
+                    ui.set_creating_volume(false);
+                    match result {
+                        Ok(()) => {
+                            ui.set_show_create_volume(false);
+                            ui.set_create_volume_password("".into());
+                            ui.set_notification_message(
+                                format!("Volume {} criado", request.name.trim()).into(),
+                            );
+                            ui.set_notification_is_error(false);
+                            ui.set_show_notification(true);
+                            ui.invoke_refresh_volumes_clicked();
+                        }
+                        Err(e) => ui.set_create_volume_error(e.to_string().into()),
+                    }
+                })
+                .unwrap();
+            });
+        }
+    });
+}
+
+// Configura a migração de um volume do servidor ativo para outro servidor
+fn setup_volume_migration_callbacks(
+    ui_weak: Weak<AppWindow>,
+    docker_manager: Arc<tokio::sync::Mutex<DockerManager>>,
+) {
+    let ui = ui_weak.upgrade().unwrap();
+
+    ui.on_migrate_volume({
+        let ui_weak = ui_weak.clone();
+        move |source_volume, target_server, target_volume| {
+            let request = MigrationRequest {
+                source_volume: source_volume.to_string(),
+                target_server: target_server.to_string(),
+                target_volume: target_volume.trim().to_string(),
+            };
+
+            if let Some(ui) = ui_weak.upgrade() {
+                ui.set_migration_running(true);
+                ui.set_migration_status("".into());
+                ui.set_migration_error("".into());
+                ui.set_migration_result("".into());
+            }
+
+            let ui_weak = ui_weak.clone();
+            let docker_manager = docker_manager.clone();
+            tokio::spawn(async move {
+                // A cópia pode levar minutos: fica registrada na fila para ser repetida se
+                // o app fechar no meio, e roda fora do lock do servidor ativo
+                let source_server = docker_manager.lock().await.context().name.clone();
+                // Pedido inválido nem entra na fila
+                let task = request.validate(&source_server).and_then(|_| {
+                    task_queue::enqueue(TaskKind::VolumeMigration {
+                        source_server: source_server.clone(),
+                        source_volume: request.source_volume.clone(),
+                        target_server: request.target_server.clone(),
+                        target_volume: request.target_volume.clone(),
+                    })
+                });
+
+                let mut last_update = std::time::Instant::now();
+                let progress_ui = ui_weak.clone();
+                let on_progress = |progress: Progress| {
+                    let status = match progress {
+                        Progress::Step(step) => step.to_string(),
+                        Progress::TargetCreated => return,
+                        Progress::Copied(bytes) => {
+                            // Atualiza a interface no máximo duas vezes por segundo
+                            if last_update.elapsed() < std::time::Duration::from_millis(500) {
+                                return;
+                            }
+                            last_update = std::time::Instant::now();
+                            format!("Copiando dados... {} transferidos", format::bytes(bytes))
+                        }
+                    };
+                    let ui_weak = progress_ui.clone();
+                    slint::invoke_from_event_loop(move || {
+                        if let Some(ui) = ui_weak.upgrade() {
+                            ui.set_migration_status(status.into());
+                        }
+                    })
+                    .unwrap();
+                };
+                let result = match task {
+                    Ok(task) => {
+                        tasks_ui::run_migration_task(
+                            &ui_weak,
+                            &docker_manager,
+                            &task.id,
+                            &source_server,
+                            &request,
+                            on_progress,
+                        )
+                        .await
+                    }
+                    Err(e) => Err(e),
+                };
+
+                slint::invoke_from_event_loop(move || {
+                    let Some(ui) = ui_weak.upgrade() else {
+                        return;
+                    };
+                    ui.set_migration_running(false);
+                    ui.set_migration_status("".into());
+                    match result {
+                        Ok(report) => {
+                            ui.set_migration_result(
+                                format!(
+                                    "{} copiados para {} em {} · checksum {} conferido",
+                                    format::bytes(report.bytes),
+                                    request.target_volume,
+                                    request.target_server,
+                                    volume_migration::short(&report.checksum)
+                                )
+                                .into(),
+                            );
+                            ui.set_notification_message(
+                                format!(
+                                    "Volume {} migrado para {}",
+                                    request.source_volume, request.target_server
+                                )
+                                .into(),
+                            );
+                            ui.set_notification_is_error(false);
+                            ui.set_show_notification(true);
+                        }
+                        Err(e) => ui.set_migration_error(e.to_string().into()),
+                    }
+                })
+                .unwrap();
+            });
+        }
+    });
+}
@@ -0,0 +1,99 @@
+// Funções auxiliares compartilhadas entre módulos: decodificação de URLs e flags da linha
+// de comando
+use anyhow::{Result, anyhow, bail};
+
+// Decodifica %XX (ex: senha "p%40ss" -> "p@ss"); sequências inválidas ficam como estão
+pub fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+// Lê argumentos `--flag valor` ou `--flag=valor` na ordem em que aparecem; só as flags
+// informadas são aceitas e todas exigem valor
+pub fn parse_flags<'f>(args: &[String], flags: &[&'f str]) -> Result<Vec<(&'f str, String)>> {
+    let mut parsed = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) => (flag, Some(value.to_string())),
+            None => (arg.as_str(), None),
+        };
+        let Some(flag) = flags.iter().copied().find(|known| *known == flag) else {
+            bail!("Argumento desconhecido: {}", arg);
+        };
+        let value = match inline {
+            Some(value) => value,
+            None => args
+                .next()
+                .cloned()
+                .ok_or_else(|| anyhow!("{} requer um valor", flag))?,
+        };
+        parsed.push((flag, value));
+    }
+    Ok(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percent_decode() {
+        assert_eq!(percent_decode("p%40ss"), "p@ss");
+        assert_eq!(percent_decode("caf%C3%A9"), "café");
+        assert_eq!(percent_decode("a+b"), "a+b");
+        assert_eq!(percent_decode("100%"), "100%");
+        assert_eq!(percent_decode("%zz%4"), "%zz%4");
+    }
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_flags() {
+        let parsed = parse_flags(
+            &args(&["--server", "prod", "--output=stats.json"]),
+            &["--server", "--output"],
+        )
+        .unwrap();
+        assert_eq!(
+            parsed,
+            vec![
+                ("--server", "prod".to_string()),
+                ("--output", "stats.json".to_string())
+            ]
+        );
+        // O valor pode conter '=' quando vem separado
+        assert_eq!(
+            parse_flags(&args(&["--server", "a=b"]), &["--server"]).unwrap(),
+            vec![("--server", "a=b".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_parse_flags_errors() {
+        let error = parse_flags(&args(&["--server"]), &["--server"]).unwrap_err();
+        assert_eq!(error.to_string(), "--server requer um valor");
+        let error = parse_flags(&args(&["--port", "80"]), &["--server"]).unwrap_err();
+        assert_eq!(error.to_string(), "Argumento desconhecido: --port");
+    }
+}
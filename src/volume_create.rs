@@ -0,0 +1,162 @@
+// Criação de volumes, com presets do driver local para montar compartilhamentos NFS e CIFS
+use anyhow::{Result, bail};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum VolumePreset {
+    // Diretório no disco do servidor
+    #[default]
+    Local,
+    Nfs,
+    Cifs,
+}
+
+impl VolumePreset {
+    pub fn from_ui(value: &str) -> Self {
+        match value {
+            "nfs" => VolumePreset::Nfs,
+            "cifs" => VolumePreset::Cifs,
+            _ => VolumePreset::Local,
+        }
+    }
+
+    pub fn is_network(&self) -> bool {
+        !matches!(self, VolumePreset::Local)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct VolumeCreateRequest {
+    pub name: String,
+    pub preset: VolumePreset,
+    // Host ou IP do servidor de arquivos
+    pub server: String,
+    // Export NFS ("/srv/dados") ou compartilhamento CIFS ("dados/app")
+    pub path: String,
+    // Versão do NFS, ex: "4.1"; vazio usa a negociada pelo kernel
+    pub nfs_version: String,
+    // Usuário CIFS; vazio monta como convidado
+    pub username: String,
+    pub password: String,
+    // Opções de montagem adicionais separadas por vírgula, ex: "ro,soft"
+    pub extra_options: String,
+}
+
+impl VolumeCreateRequest {
+    // Confere os campos antes de chamar o docker, apontando o que corrigir
+    pub fn validate(&self) -> Result<()> {
+        let name = self.name.trim();
+        if name.is_empty() {
+            bail!("Informe o nome do volume");
+        }
+        if !name.starts_with(|c: char| c.is_ascii_alphanumeric())
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+        {
+            bail!(
+                "Nome inválido: comece com letra ou número e use apenas letras, números, '_', '.' ou '-'"
+            );
+        }
+        if !self.preset.is_network() {
+            return Ok(());
+        }
+
+        let server = self.server.trim();
+        if server.is_empty() {
+            bail!("Informe o servidor de arquivos (host ou IP)");
+        }
+        if server.contains([',', ' ', '/']) {
+            bail!("Servidor inválido: {} (informe só o host ou IP)", server);
+        }
+
+        let path = self.path.trim();
+        match self.preset {
+            VolumePreset::Nfs if !path.starts_with('/') => {
+                bail!("O export NFS deve ser um caminho absoluto, ex: /srv/dados")
+            }
+            VolumePreset::Cifs if path.trim_matches('/').is_empty() => {
+                bail!("Informe o compartilhamento CIFS, ex: dados")
+            }
+            _ => {}
+        }
+
+        // Vírgulas separam as opções de montagem e quebrariam o valor
+        for (label, value) in [
+            ("Versão do NFS", &self.nfs_version),
+            ("Usuário", &self.username),
+            ("Senha", &self.password),
+        ] {
+            if value.contains(',') {
+                bail!("{} não pode conter vírgula", label);
+            }
+        }
+        if self.preset == VolumePreset::Cifs
+            && self.username.trim().is_empty()
+            && !self.password.is_empty()
+        {
+            bail!("Informe o usuário da senha CIFS");
+        }
+        if self.extra_options.contains(char::is_whitespace) {
+            bail!("Opções de montagem não podem ter espaços; separe-as por vírgula");
+        }
+        Ok(())
+    }
+
+    // Valor de -o do driver local (opções de montagem)
+    fn mount_options(&self) -> String {
+        let mut options = vec![format!("addr={}", self.server.trim())];
+        match self.preset {
+            VolumePreset::Nfs => {
+                if !self.nfs_version.trim().is_empty() {
+                    options.push(format!("nfsvers={}", self.nfs_version.trim()));
+                }
+            }
+            VolumePreset::Cifs => {
+                if self.username.trim().is_empty() {
+                    options.push("guest".to_string());
+                } else {
+                    options.push(format!("username={}", self.username.trim()));
+                    if !self.password.is_empty() {
+                        options.push(format!("password={}", self.password));
+                    }
+                }
+            }
+            VolumePreset::Local => {}
+        }
+        options.extend(
+            self.extra_options
+                .split(',')
+                .map(str::trim)
+                .filter(|option| !option.is_empty())
+                .map(str::to_string),
+        );
+        options.join(",")
+    }
+
+    // Opções do driver local (type, device e o=). Vão pela API, não pela linha de comando:
+    // o= leva a senha CIFS, que ficaria visível na lista de processos
+    pub fn driver_opts(&self) -> HashMap<String, String> {
+        let device = match self.preset {
+            VolumePreset::Local => None,
+            // ":" indica ao driver que o endereço vem do addr=
+            VolumePreset::Nfs => Some(("nfs", format!(":{}", self.path.trim()))),
+            VolumePreset::Cifs => Some((
+                "cifs",
+                format!(
+                    "//{}/{}",
+                    self.server.trim(),
+                    self.path.trim().trim_matches('/')
+                ),
+            )),
+        };
+        let Some((kind, device)) = device else {
+            return HashMap::new();
+        };
+        HashMap::from([
+            ("type".to_string(), kind.to_string()),
+            ("device".to_string(), device),
+            ("o".to_string(), self.mount_options()),
+        ])
+    }
+}
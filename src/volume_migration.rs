@@ -0,0 +1,225 @@
+// Migração de um volume para outro servidor: o tar do volume de origem é enviado direto
+// para um container no destino (pelo túnel SSH em servidores ssh://) e o conteúdo
+// é conferido com um checksum calculado nos dois lados
+use anyhow::{Context, Result, anyhow, bail};
+use std::process::{Command, Stdio};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+use crate::docker::DockerManager;
+use crate::volume_create::VolumeCreateRequest;
+
+// Imagem auxiliar que lê e grava o conteúdo dos volumes (tar, find e sha256sum)
+pub const HELPER_IMAGE: &str = "alpine:3";
+
+// Checksum de todos os arquivos do volume, independente da ordem do tar;
+// vazio quando o volume não tem arquivos
+pub const CHECKSUM_SCRIPT: &str =
+    "cd /volume && find . -type f -exec sha256sum {} + | sort -k 2 | sha256sum";
+
+#[derive(Debug, Clone)]
+pub struct MigrationRequest {
+    pub source_volume: String,
+    pub target_server: String,
+    pub target_volume: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct MigrationReport {
+    // Tamanho do tar transferido
+    pub bytes: u64,
+    pub checksum: String,
+}
+
+// Comandos da origem, obtidos antes para não segurar o lock do servidor ativo
+pub struct SourceCommands {
+    pub server: String,
+    pub archive: Command,
+    pub checksum: Command,
+}
+
+pub enum Progress {
+    Step(&'static str),
+    // Volume criado no destino; até o checksum conferir, o conteúdo pode estar incompleto
+    TargetCreated,
+    Copied(u64),
+}
+
+impl MigrationRequest {
+    pub fn validate(&self, source_server: &str) -> Result<()> {
+        if self.target_server.is_empty() {
+            bail!("Escolha o servidor de destino");
+        }
+        // Nome do volume de destino segue as mesmas regras da criação
+        VolumeCreateRequest {
+            name: self.target_volume.clone(),
+            ..Default::default()
+        }
+        .validate()?;
+        if self.target_server == source_server && self.target_volume.trim() == self.source_volume {
+            bail!("Origem e destino são o mesmo volume; escolha outro servidor ou outro nome");
+        }
+        Ok(())
+    }
+}
+
+pub async fn migrate(
+    source: SourceCommands,
+    request: &MigrationRequest,
+    mut on_progress: impl FnMut(Progress),
+) -> Result<MigrationReport> {
+    request.validate(&source.server)?;
+    let target_volume = request.target_volume.trim();
+
+    on_progress(Progress::Step("Conectando ao servidor de destino..."));
+    let target = DockerManager::for_context_name(&request.target_server).await?;
+    if target.volume_exists(target_volume).await? {
+        bail!(
+            "O volume {} já existe em {}; escolha outro nome para não sobrescrever dados",
+            target_volume,
+            request.target_server
+        );
+    }
+
+    on_progress(Progress::Step("Calculando checksum na origem..."));
+    let source_checksum = run_checksum(source.checksum, "na origem").await?;
+
+    on_progress(Progress::Step("Criando volume no destino..."));
+    target
+        .create_volume(&VolumeCreateRequest {
+            name: target_volume.to_string(),
+            ..Default::default()
+        })
+        .await?;
+    on_progress(Progress::TargetCreated);
+
+    on_progress(Progress::Step("Copiando dados..."));
+    let bytes = match stream(
+        source.archive,
+        target.volume_restore_command(target_volume),
+        &mut on_progress,
+    )
+    .await
+    {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            // Não deixa um volume pela metade no destino
+            let _ = target.remove_volume(target_volume).await;
+            return Err(e);
+        }
+    };
+
+    on_progress(Progress::Step("Verificando checksum no destino..."));
+    let target_checksum =
+        run_checksum(target.volume_checksum_command(target_volume), "no destino").await?;
+    if target_checksum != source_checksum {
+        return Err(anyhow!(
+            "Checksum diferente entre origem ({}) e destino ({}); o volume {} foi mantido em {} para análise. Pare os containers que gravam no volume e tente de novo",
+            short(&source_checksum),
+            short(&target_checksum),
+            target_volume,
+            request.target_server
+        ));
+    }
+
+    Ok(MigrationReport {
+        bytes,
+        checksum: source_checksum,
+    })
+}
+
+async fn run_checksum(command: Command, side: &str) -> Result<String> {
+    let output = tokio::process::Command::from(command)
+        .output()
+        .await
+        .context("Falha ao executar o checksum do volume")?;
+    if !output.status.success() {
+        bail!(
+            "Falha ao calcular checksum {}: {}",
+            side,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    String::from_utf8_lossy(&output.stdout)
+        .split_whitespace()
+        .next()
+        .map(str::to_string)
+        .ok_or_else(|| anyhow!("Checksum vazio"))
+}
+
+// Liga a saída do tar da origem à entrada do tar do destino
+async fn stream(
+    archive: Command,
+    restore: Command,
+    on_progress: &mut impl FnMut(Progress),
+) -> Result<u64> {
+    let mut archive = tokio::process::Command::from(archive);
+    archive.stdout(Stdio::piped()).stderr(Stdio::piped());
+    let mut archive = archive
+        .spawn()
+        .context("Falha ao iniciar a cópia na origem")?;
+
+    let mut restore = tokio::process::Command::from(restore);
+    restore
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped());
+    let mut restore = restore
+        .spawn()
+        .context("Falha ao iniciar a restauração no destino")?;
+
+    let mut reader = archive
+        .stdout
+        .take()
+        .context("Saída da origem indisponível")?;
+    let mut writer = restore
+        .stdin
+        .take()
+        .context("Entrada do destino indisponível")?;
+    let mut buffer = vec![0u8; 256 * 1024];
+    let mut copied = 0u64;
+    loop {
+        let read = reader
+            .read(&mut buffer)
+            .await
+            .context("Falha ao ler o volume de origem")?;
+        if read == 0 {
+            break;
+        }
+        // Se o destino encerrar antes, o erro real vem no stderr abaixo
+        if writer.write_all(&buffer[..read]).await.is_err() {
+            break;
+        }
+        copied += read as u64;
+        on_progress(Progress::Copied(copied));
+    }
+    // Fecha os dois lados para nenhum processo ficar bloqueado no pipe
+    drop(writer);
+    drop(reader);
+
+    let archive = archive
+        .wait_with_output()
+        .await
+        .context("Falha ao aguardar a origem")?;
+    let restore = restore
+        .wait_with_output()
+        .await
+        .context("Falha ao aguardar o destino")?;
+    if !archive.status.success() {
+        bail!(
+            "Falha ao ler o volume de origem: {}",
+            String::from_utf8_lossy(&archive.stderr).trim()
+        );
+    }
+    if !restore.status.success() {
+        bail!(
+            "Falha ao gravar no volume de destino: {}",
+            String::from_utf8_lossy(&restore.stderr).trim()
+        );
+    }
+    Ok(copied)
+}
+
+// Início do checksum exibido nas mensagens
+pub fn short(checksum: &str) -> &str {
+    &checksum[..checksum.len().min(12)]
+}